@@ -0,0 +1,480 @@
+use super::*;
+
+/// The first 256 bits of the fraction of `2/π`, most significant word first.
+const TWO_OVER_PI_BITS: [u32; 8] = [
+  0xa2f9836e, 0x4e441529, 0xfc2757d1, 0xf534ddc0, 0xdb629599, 0x3c439041,
+  0xfe5163ab, 0xdebbc561,
+];
+
+/// Inputs from this magnitude on are reduced with [`rem_pio2_large`] by the
+/// precise trigonometric functions, as the reduction of the `f64` functions
+/// is only accurate up to about `2^26`.
+const PRECISE_TRIG_LARGE: f32 = 1048576.0;
+
+/// Reduces a finite `x` with `|x| >= 2^20` to `r` in `[-π/4, π/4]` and the
+/// quadrant `n`, so that `x = r + n·π/2` modulo `2π`.
+///
+/// This is the Payne-Hanek reduction: with `|x| = m·2^e` for a 24-bit integer
+/// `m`, only the bits of `2/π` from `2^-(e-1)` onward affect `x·2/π` modulo
+/// `4`. Multiplying `m` by the next 96 of those bits gives the quadrant and
+/// about 70 bits of the remainder, which is more than enough for any `f32`.
+#[cold]
+fn rem_pio2_large(x: f32) -> (f64, u32) {
+  let bits = x.to_bits();
+  let m = u128::from((bits & 0x007f_ffff) | 0x0080_0000);
+  let e = ((bits >> 23) & 0xff) as i32 - 150;
+  // The 96 bits of `2/π` starting at `2^-(e-1)`, as the integer
+  // `floor(2/π·2^(e+94)) mod 2^96`.
+  let shift = (162 - e) as usize;
+  let word = shift / 32;
+  let mut window = 0_u128;
+  for i in (0..4).rev() {
+    // word `i` counted from the least significant end of the 256 bits
+    let w = TWO_OVER_PI_BITS.get(7_usize.wrapping_sub(word + i)).copied();
+    window = (window << 32) | u128::from(w.unwrap_or(0));
+  }
+  let window = (window >> (shift % 32)) & ((1 << 96) - 1);
+  // `m·window / 2^94` is `|x|·2/π` modulo 4
+  let product = (m * window) & ((1 << 96) - 1);
+  let n = (product + (1 << 93)) >> 94;
+  let fraction = product as i128 - (n << 94) as i128;
+  let r =
+    fraction as f64 * (core::f64::consts::FRAC_PI_2 / (1_u128 << 94) as f64);
+  let n = (n & 3) as u32;
+  if x < 0.0 { (-r, n.wrapping_neg() & 3) } else { (r, n) }
+}
+
+/// Implements the `fast_*` and `precise_*` accuracy tiers of the math
+/// functions for a SIMD vector of `f32`.
+///
+/// The precise tier evaluates the `f64` function of the `Wide` vector and
+/// rounds the result, which has far less error than half an ULP of `f32`
+/// before rounding. Vectors that are wider than the widest `f64` vector are
+/// split into `Half`s instead.
+macro_rules! impl_accuracy_tiers {
+  (
+    Simd = $Simd:ident,
+    Int = $Int:ident,
+    $kind:ident = $Other:ident,
+  ) => {
+    impl_accuracy_tiers!(@fast $Simd, $Int);
+
+    impl $Simd {
+      impl_accuracy_tiers!(@precise [$kind $Other] precise_exp, exp);
+      impl_accuracy_tiers!(@precise [$kind $Other] precise_exp2, exp2);
+      impl_accuracy_tiers!(@precise [$kind $Other] precise_exp10, exp10);
+      impl_accuracy_tiers!(@precise [$kind $Other] precise_exp_m1, exp_m1);
+      impl_accuracy_tiers!(@precise [$kind $Other] precise_ln, ln);
+      impl_accuracy_tiers!(@precise [$kind $Other] precise_ln_1p, ln_1p);
+      impl_accuracy_tiers!(@precise [$kind $Other] precise_log2, log2);
+      impl_accuracy_tiers!(@precise [$kind $Other] precise_log10, log10);
+      impl_accuracy_tiers!(@precise [$kind $Other] precise_sin, sin);
+      impl_accuracy_tiers!(@precise [$kind $Other] precise_cos, cos);
+      impl_accuracy_tiers!(@precise [$kind $Other] precise_tan, tan);
+
+      /// Simultaneously computes [`precise_sin`](Self::precise_sin) and
+      /// [`precise_cos`](Self::precise_cos) for each input element.
+      ///
+      /// # Precision
+      ///
+      /// Both results are within 1 ULP of the exact value for all inputs.
+      /// They can differ between platforms within that bound.
+      #[inline]
+      #[must_use]
+      pub fn precise_sin_cos(self) -> (Self, Self) {
+        impl_accuracy_tiers!(@body [$kind $Other] self, precise_sin_cos, sin_cos)
+      }
+
+      impl_accuracy_tiers!(@precise [$kind $Other] precise_asin, asin);
+      impl_accuracy_tiers!(@precise [$kind $Other] precise_acos, acos);
+      impl_accuracy_tiers!(@precise [$kind $Other] precise_atan, atan);
+
+      /// Computes [`atan2`](Self::atan2) for each input element with at
+      /// most 1 ULP of error.
+      ///
+      /// # Precision
+      ///
+      /// The result is within 1 ULP of the exact value for all inputs. It
+      /// can differ between platforms within that bound.
+      #[inline]
+      #[must_use]
+      pub fn precise_atan2(self, x: Self) -> Self {
+        impl_accuracy_tiers!(@body [$kind $Other] self, x, precise_atan2, atan2)
+      }
+
+      impl_accuracy_tiers!(@precise [$kind $Other] precise_sinh, sinh);
+      impl_accuracy_tiers!(@precise [$kind $Other] precise_cosh, cosh);
+      impl_accuracy_tiers!(@precise [$kind $Other] precise_tanh, tanh);
+      impl_accuracy_tiers!(@precise [$kind $Other] precise_cbrt, cbrt);
+
+      /// Computes [`powf_simd`](Self::powf_simd) for each input element with
+      /// at most 1 ULP of error.
+      ///
+      /// Infinite and NaN inputs are handled like `powf_simd`.
+      ///
+      /// # Precision
+      ///
+      /// The result is within 1 ULP of the exact value for all finite inputs.
+      /// It can differ between platforms within that bound.
+      #[inline]
+      #[must_use]
+      pub fn precise_powf_simd(self, n: Self) -> Self {
+        impl_accuracy_tiers!(@body [$kind $Other] self, n, precise_powf_simd, powf_simd)
+      }
+
+      /// Returns the reciprocal (inverse) of a number, `1/x`, rounded
+      /// correctly.
+      ///
+      /// This is the same as `ONE / x`, unlike [`recip`](Self::recip), which
+      /// uses a fast approximation on some platforms.
+      ///
+      /// # Precision
+      ///
+      /// The result is within 0.5 ULP of the exact value, the same on all
+      /// platforms.
+      #[inline]
+      #[must_use]
+      pub fn precise_recip(self) -> Self {
+        Self::ONE / self
+      }
+
+      /// Returns the square root of the reciprocal (inverse) of a number,
+      /// `sqrt(1/x)`, with at most 1 ULP of error.
+      ///
+      /// Unlike [`recip_sqrt`](Self::recip_sqrt), this never uses a fast
+      /// approximation.
+      ///
+      /// # Precision
+      ///
+      /// The result is within 1 ULP of the exact value for all inputs. It
+      /// can differ between platforms within that bound.
+      #[inline]
+      #[must_use]
+      pub fn precise_recip_sqrt(self) -> Self {
+        impl_accuracy_tiers!(@body [$kind $Other] self, precise_recip_sqrt, recip_sqrt)
+      }
+
+      impl_accuracy_tiers!(@sin_cos_wide [$kind $Other]);
+    }
+  };
+  (@precise [$kind:ident $Other:ident] $name:ident, $base:ident) => {
+    #[doc = concat!("Computes [`", stringify!($base), "`](Self::", stringify!($base), ") for each input element with at most 1 ULP")]
+    /// of error.
+    ///
+    /// # Precision
+    ///
+    /// The result is within 1 ULP of the exact value for all inputs. It can
+    /// differ between platforms within that bound.
+    #[inline]
+    #[must_use]
+    pub fn $name(self) -> Self {
+      impl_accuracy_tiers!(@body [$kind $Other] self, $name, $base)
+    }
+  };
+  // `f32x16` splits into two `f32x8`
+  (@body [Half $Half:ident] $x:ident, precise_sin_cos, $base:ident) => {{
+    let (low, high) = $x.split();
+    let (low_sin, low_cos) = low.precise_sin_cos();
+    let (high_sin, high_cos) = high.precise_sin_cos();
+    (low_sin.concat(high_sin), low_cos.concat(high_cos))
+  }};
+  (@body [Half $Half:ident] $x:ident, $name:ident, $base:ident) => {{
+    let (low, high) = $x.split();
+    low.$name().concat(high.$name())
+  }};
+  (@body [Half $Half:ident] $x:ident, $y:ident, $name:ident, $base:ident) => {{
+    let (low, high) = $x.split();
+    let (y_low, y_high) = $y.split();
+    low.$name(y_low).concat(high.$name(y_high))
+  }};
+  (@sin_cos_wide [Half $Half:ident]) => {};
+  // the other vectors compute in `f64`
+  (@body [Wide $Wide:ident] $x:ident, precise_sin, $base:ident) => {{
+    let (s, _) = $x.precise_sin_cos_wide();
+    s.cast_lossy()
+  }};
+  (@body [Wide $Wide:ident] $x:ident, precise_cos, $base:ident) => {{
+    let (_, c) = $x.precise_sin_cos_wide();
+    c.cast_lossy()
+  }};
+  (@body [Wide $Wide:ident] $x:ident, precise_tan, $base:ident) => {{
+    let (s, c) = $x.precise_sin_cos_wide();
+    (s / c).cast_lossy()
+  }};
+  (@body [Wide $Wide:ident] $x:ident, precise_sin_cos, $base:ident) => {{
+    let (s, c) = $x.precise_sin_cos_wide();
+    (s.cast_lossy(), c.cast_lossy())
+  }};
+  (@body [Wide $Wide:ident] $x:ident, precise_recip_sqrt, $base:ident) => {
+    ($Wide::ONE / $Wide::from($x).sqrt()).cast_lossy()
+  };
+  (@body [Wide $Wide:ident] $x:ident, $name:ident, $base:ident) => {
+    $Wide::from($x).$base().cast_lossy()
+  };
+  (@body [Wide $Wide:ident] $x:ident, $y:ident, $name:ident, $base:ident) => {
+    $Wide::from($x).$base($Wide::from($y)).cast_lossy()
+  };
+  (@sin_cos_wide [Wide $Wide:ident]) => {
+    /// Computes `sin` and `cos` in `f64`, reducing large inputs exactly.
+    #[inline]
+    fn precise_sin_cos_wide(self) -> ($Wide, $Wide) {
+      const LARGE: f32 = PRECISE_TRIG_LARGE;
+      let x = $Wide::from(self);
+      if (self.abs().simd_ge(Self::splat(LARGE)) & self.is_finite()).none() {
+        return x.sin_cos();
+      }
+      let mut r = x.to_array();
+      let mut quadrant = r.map(|_| 0.0);
+      for (r, quadrant) in r.iter_mut().zip(&mut quadrant) {
+        if r.abs() >= f64::from(LARGE) && r.is_finite() {
+          let (reduced, n) = rem_pio2_large(*r as f32);
+          *r = reduced;
+          *quadrant = f64::from(n);
+        }
+      }
+      let (s, c) = $Wide::from(r).sin_cos();
+      // rotate by the quadrant like `sin_cos_pi`
+      const TWO: $Wide = $Wide::splat(2.0);
+      const THREE: $Wide = $Wide::splat(3.0);
+      let quadrant = $Wide::from(quadrant);
+      let swap = quadrant.simd_eq($Wide::ONE) | quadrant.simd_eq(THREE);
+      let s_swapped = swap.select(c, s);
+      let c_swapped = swap.select(s, c);
+      let sin = quadrant.simd_ge(TWO).select(-s_swapped, s_swapped);
+      let cos = (quadrant.simd_eq($Wide::ONE) | quadrant.simd_eq(TWO))
+        .select(-c_swapped, c_swapped);
+      (sin, cos)
+    }
+  };
+  (@fast $Simd:ident, $Int:ident) => {
+    impl $Simd {
+      /// Computes a fast approximation of `2^(self)` for each input element.
+      ///
+      /// Results that would be subnormal are flushed to zero, and the
+      /// result for NaN is unspecified.
+      ///
+      /// # Precision
+      ///
+      /// The result is within 64 ULP of the exact value. It can differ
+      /// between platforms within that bound.
+      #[inline]
+      #[must_use]
+      pub fn fast_exp2(self) -> Self {
+        const C1: $Simd = $Simd::splat(0.693044845);
+        const C2: $Simd = $Simd::splat(0.241280205);
+        const C3: $Simd = $Simd::splat(0.0522424739);
+        const C4: $Simd = $Simd::splat(0.0134266844);
+        let x = self.fast_max($Simd::splat(-127.0)).fast_min($Simd::splat(128.0));
+        let n = x.floor();
+        let f = x - n;
+        // 2^f = 1 + f·p(f) for f in [0, 1)
+        let p = f.mul_add(polynomial_3!(f, C1, C2, C3, C4), Self::ONE);
+        // 2^n from the exponent bits, which are 0 for n = -127 and infinity
+        // for n = 128
+        let scale: $Simd = cast((n.fast_trunc_int() + $Int::splat(127)) << 23);
+        p * scale
+      }
+
+      /// Computes a fast approximation of `e^(self)` for each input element.
+      ///
+      /// Results that would be subnormal are flushed to zero, and the
+      /// result for NaN is unspecified.
+      ///
+      /// # Precision
+      ///
+      /// The result is within 128 ULP of the exact value. It can differ
+      /// between platforms within that bound.
+      #[inline]
+      #[must_use]
+      pub fn fast_exp(self) -> Self {
+        (self * Self::LOG2_E).fast_exp2()
+      }
+
+      /// Computes a fast approximation of the natural logarithm for each
+      /// input element.
+      ///
+      /// The result is unspecified for zero, subnormal numbers, negative
+      /// numbers, infinity and NaN.
+      ///
+      /// # Precision
+      ///
+      /// For positive normal numbers, the result is within 256 ULP of the
+      /// exact value. It can differ between platforms within that bound.
+      #[inline]
+      #[must_use]
+      pub fn fast_ln(self) -> Self {
+        const C0: $Simd = $Simd::splat(-0.499917406);
+        const C1: $Simd = $Simd::splat(0.332740313);
+        const C2: $Simd = $Simd::splat(-0.253879681);
+        const C3: $Simd = $Simd::splat(0.219281045);
+        const C4: $Simd = $Simd::splat(-0.142160486);
+        let (e, u) = Self::fast_log_split(self);
+        // ln(1 + u) = u + u²·p(u)
+        let ln_m = (u * u).mul_add(polynomial_4!(u, C0, C1, C2, C3, C4), u);
+        e.mul_add(Self::LN_2, ln_m)
+      }
+
+      /// Computes a fast approximation of the base 2 logarithm for each
+      /// input element.
+      ///
+      /// The result is unspecified for zero, subnormal numbers, negative
+      /// numbers, infinity and NaN.
+      ///
+      /// # Precision
+      ///
+      /// For positive normal numbers, the result is within 256 ULP of the
+      /// exact value. It can differ between platforms within that bound.
+      #[inline]
+      #[must_use]
+      pub fn fast_log2(self) -> Self {
+        const C0: $Simd = $Simd::splat(1.44270162);
+        const C1: $Simd = $Simd::splat(-0.721206389);
+        const C2: $Simd = $Simd::splat(0.479811858);
+        const C3: $Simd = $Simd::splat(-0.366491717);
+        const C4: $Simd = $Simd::splat(0.318199879);
+        const C5: $Simd = $Simd::splat(-0.206190953);
+        let (e, u) = Self::fast_log_split(self);
+        // log2(1 + u) = u·p(u)
+        u.mul_add(polynomial_5!(u, C0, C1, C2, C3, C4, C5), e)
+      }
+
+      /// Splits a positive normal number into `(e, u)` with
+      /// `x = 2^e·(1 + u)` and `1 + u` in `[sqrt(1/2), sqrt(2))`.
+      #[inline]
+      fn fast_log_split(x: Self) -> (Self, Self) {
+        const SQRT_HALF_BITS: i32 = 0x3f35_04f3;
+        let t = cast::<_, $Int>(x) - $Int::splat(SQRT_HALF_BITS);
+        let e: $Simd = (t >> 23_u32).cast_lossy();
+        let m: $Simd =
+          cast((t & $Int::splat(0x007f_ffff)) + $Int::splat(SQRT_HALF_BITS));
+        (e, m - Self::ONE)
+      }
+
+      /// Simultaneously computes fast approximations of the sine and cosine
+      /// of a number (in radians) for each input element.
+      ///
+      /// The result is unspecified for infinity and NaN.
+      ///
+      /// # Precision
+      ///
+      /// For `|x| <= 10000`, the absolute error of each result is at most
+      /// `2^-16`. It can differ between platforms within that bound. The error
+      /// grows for larger numbers.
+      #[inline]
+      #[must_use]
+      pub fn fast_sin_cos(self) -> (Self, Self) {
+        // π/2 split so that q·DP1 is exact for |q| < 2^16
+        const DP1: $Simd = $Simd::splat(1.5703125);
+        const DP2: $Simd = $Simd::splat(4.83826794e-4);
+        const S1: $Simd = $Simd::splat(-0.166633904);
+        const S2: $Simd = $Simd::splat(0.00816328189);
+        const C1: $Simd = $Simd::splat(-0.499760557);
+        const C2: $Simd = $Simd::splat(0.040458452);
+        let q = (self * Self::FRAC_2_PI).round_ties_even();
+        let r = q.mul_neg_add(DP2, q.mul_neg_add(DP1, self));
+        let z = r * r;
+        let s = (r * z).mul_add(z.mul_add(S2, S1), r);
+        let c = z.mul_add(z.mul_add(C2, C1), Self::ONE);
+        // select the quadrant like `sin_cos`
+        let q = q.fast_trunc_int();
+        let swap = (q & $Int::ONE).simd_ne($Int::ZERO);
+        let sin_sign: $Simd = cast((q & $Int::splat(2)) << 30);
+        let cos_sign: $Simd = cast(((q + $Int::ONE) & $Int::splat(2)) << 30);
+        (swap.select(c, s) ^ sin_sign, swap.select(s, c) ^ cos_sign)
+      }
+
+      /// Computes a fast approximation of the sine of a number (in radians)
+      /// for each input element.
+      ///
+      /// See [`fast_sin_cos`](Self::fast_sin_cos) for the precision.
+      #[inline]
+      #[must_use]
+      pub fn fast_sin(self) -> Self {
+        let (s, _) = self.fast_sin_cos();
+        s
+      }
+
+      /// Computes a fast approximation of the cosine of a number (in
+      /// radians) for each input element.
+      ///
+      /// See [`fast_sin_cos`](Self::fast_sin_cos) for the precision.
+      #[inline]
+      #[must_use]
+      pub fn fast_cos(self) -> Self {
+        let (_, c) = self.fast_sin_cos();
+        c
+      }
+
+      /// Computes a fast approximation of the arctangent of a number for
+      /// each input element.
+      ///
+      /// The result for NaN is unspecified.
+      ///
+      /// # Precision
+      ///
+      /// The result is within 128 ULP of the exact value. It can differ
+      /// between platforms within that bound.
+      #[inline]
+      #[must_use]
+      pub fn fast_atan(self) -> Self {
+        let a = self.abs();
+        let t = a.fast_min(Self::ONE) / a.fast_max(Self::ONE);
+        let p = Self::fast_atan_kernel(t);
+        a.simd_gt(Self::ONE).select(Self::FRAC_PI_2 - p, p).flip_signs(self)
+      }
+
+      /// Computes a fast approximation of the four quadrant arctangent of
+      /// `self` (y) and `x` in radians for each input element.
+      ///
+      /// The result is unspecified if both `self` and `x` are zero or
+      /// infinite, or if either is NaN.
+      ///
+      /// # Precision
+      ///
+      /// The result is within 128 ULP of the exact value. It can differ
+      /// between platforms within that bound.
+      #[inline]
+      #[must_use]
+      pub fn fast_atan2(self, x: Self) -> Self {
+        let ax = x.abs();
+        let ay = self.abs();
+        let t = ax.fast_min(ay) / ax.fast_max(ay);
+        let p = Self::fast_atan_kernel(t);
+        let r = ay.simd_gt(ax).select(Self::FRAC_PI_2 - p, p);
+        let r = x.is_sign_negative().select(Self::PI - r, r);
+        r.flip_signs(self)
+      }
+
+      /// Returns `atan(t)` for `t` in `[0, 1]`.
+      #[inline]
+      fn fast_atan_kernel(t: Self) -> Self {
+        const C0: $Simd = $Simd::splat(-0.333089);
+        const C1: $Simd = $Simd::splat(0.196183087);
+        const C2: $Simd = $Simd::splat(-0.122514991);
+        const C3: $Simd = $Simd::splat(0.058770229);
+        const C4: $Simd = $Simd::splat(-0.0139550903);
+        let z = t * t;
+        // atan(t) = t + t·z·p(z)
+        (t * z).mul_add(polynomial_4!(z, C0, C1, C2, C3, C4), t)
+      }
+    }
+  };
+}
+
+impl_accuracy_tiers! {
+  Simd = f32x4,
+  Int = i32x4,
+  Wide = f64x4,
+}
+
+impl_accuracy_tiers! {
+  Simd = f32x8,
+  Int = i32x8,
+  Wide = f64x8,
+}
+
+impl_accuracy_tiers! {
+  Simd = f32x16,
+  Int = i32x16,
+  Half = f32x8,
+}
@@ -0,0 +1,302 @@
+use super::*;
+
+/// A lane index that is out of range for every SIMD vector, so that `permute`
+/// and `swizzle` set that lane to `0`. It stays out of range after adding the
+/// offset of a group of 8 lanes, see [`compress_indices`].
+// The tables are unused when every type is compressed with AVX-512.
+#[allow(dead_code)]
+const NONE: u8 = 0x80;
+
+/// For each 8-bit bitmask, the indices of its set bits in increasing order,
+/// as the bytes of a `u64`. The remaining bytes are [`NONE`].
+#[allow(dead_code)]
+const COMPRESS_TABLE: [u64; 256] = {
+  let mut table = [0; 256];
+  let mut bits = 0;
+  while bits < 256 {
+    let mut indices = [NONE; 8];
+    let mut count = 0;
+    let mut lane = 0;
+    while lane < 8 {
+      if bits & (1 << lane) != 0 {
+        indices[count] = lane as u8;
+        count += 1;
+      }
+      lane += 1;
+    }
+    table[bits] = u64::from_le_bytes(indices);
+    bits += 1;
+  }
+  table
+};
+
+/// For each 8-bit bitmask, the number of set bits below each bit, as the
+/// bytes of a `u64`.
+#[allow(dead_code)]
+const EXPAND_TABLE: [u64; 256] = {
+  let mut table = [0; 256];
+  let mut bits = 0;
+  while bits < 256 {
+    let mut ranks = [0; 8];
+    let mut lane = 1;
+    while lane < 8 {
+      ranks[lane] = ranks[lane - 1] + ((bits >> (lane - 1)) & 1) as u8;
+      lane += 1;
+    }
+    table[bits] = u64::from_le_bytes(ranks);
+    bits += 1;
+  }
+  table
+};
+
+/// Returns the indices of the set bits of the lowest `N` bits of `bitmask` in
+/// increasing order, followed by [`NONE`].
+///
+/// Each group of 8 lanes is looked up in [`COMPRESS_TABLE`], offset by the
+/// first lane of the group, and written right after the indices of the
+/// previous groups.
+#[allow(dead_code)]
+#[inline]
+fn compress_indices<const N: usize>(bitmask: u64) -> [u8; N] {
+  let mut indices = [NONE; 64 + 8];
+  let mut count = 0;
+  for group in 0..N.div_ceil(8) {
+    let bits = (bitmask >> (8 * group)) as u8;
+    let offset = 0x0808_0808_0808_0808 * group as u64;
+    let group_indices = COMPRESS_TABLE[usize::from(bits)] + offset;
+    indices[count..count + 8].copy_from_slice(&group_indices.to_le_bytes());
+    count += bits.count_ones() as usize;
+  }
+  core::array::from_fn(|i| indices[i])
+}
+
+/// Returns the number of set bits below each of the lowest `N` bits of
+/// `bitmask`, which is the index of the element each set lane takes when
+/// expanding.
+#[allow(dead_code)]
+#[inline]
+fn expand_indices<const N: usize>(bitmask: u64) -> [u8; N] {
+  let mut indices = [0; 64 + 8];
+  let mut count = 0;
+  for group in 0..N.div_ceil(8) {
+    let bits = (bitmask >> (8 * group)) as u8;
+    let ranks = EXPAND_TABLE[usize::from(bits)] + 0x0101_0101_0101_0101 * count;
+    indices[8 * group..8 * group + 8].copy_from_slice(&ranks.to_le_bytes());
+    count += u64::from(bits.count_ones());
+  }
+  core::array::from_fn(|i| indices[i])
+}
+
+/// Implements `compress`, `compress_store` and `expand`, which move the lanes
+/// selected by a mask to the front of a vector and back.
+///
+/// Where the `cfg` is enabled, the given AVX-512 `maskz_compress` and
+/// `mask_expand` intrinsics (`vpcompress`/`vpexpand`) are used. Otherwise, the
+/// lane indices for each group of 8 lanes of the bitmask are looked up in a
+/// table, and the `table`/`idx` expression moves the lanes with `permute` or
+/// `swizzle`.
+macro_rules! impl_compress {
+  ($(
+    $Simd:ident: $T:ident, $N:literal, $Mask:ident, $Index:ident,
+    #[cfg($($native:meta),+)] $compress:ident, $expand:ident,
+    |$table:ident, $idx:ident| $permute:expr;
+  )*) => {$(
+    impl $Simd {
+      /// Moves the lanes that are `true` in `mask` to the front, keeping
+      /// their order. Returns the compressed vector, with the remaining lanes
+      /// set to `0`, and the number of `true` lanes.
+      ///
+      /// See [`compress_store`](Self::compress_store) to write only the
+      /// selected elements to a slice, e.g. for filtering.
+      ///
+      /// Currently this function is accelerated with `vpcompress` on AVX-512,
+      /// and otherwise looks up the lane indices for the bitmask of `mask` in
+      /// a table.
+      #[inline]
+      #[must_use]
+      pub fn compress(self, mask: $Mask) -> (Self, usize) {
+        let bitmask = mask.to_bitmask();
+        let count = bitmask.count_ones() as usize;
+        pick! {
+          if #[cfg($($native),+)] {
+            #[cfg(target_arch = "x86")]
+            use core::arch::x86::*;
+            #[cfg(target_arch = "x86_64")]
+            use core::arch::x86_64::*;
+
+            (Self::from(unsafe { $compress(bitmask as _, self.into()) }), count)
+          } else {
+            let $table = self;
+            let $idx = $Index::new(compress_indices(bitmask).map(|i| i as _));
+            ($permute, count)
+          }
+        }
+      }
+
+      /// Stores the lanes that are `true` in `mask` to the start of `slice`,
+      /// keeping their order, and returns the number of stored elements.
+      ///
+      /// Elements of `slice` past the stored elements are left unchanged.
+      ///
+      /// # Panics
+      ///
+      /// Panics if `slice` is shorter than the number of `true` lanes.
+      #[inline]
+      pub fn compress_store(self, mask: $Mask, slice: &mut [$T]) -> usize {
+        let (compressed, count) = self.compress(mask);
+        compressed.store_partial(&mut slice[..count]);
+        count
+      }
+
+      /// Moves the first elements of `self` to the lanes that are `true` in
+      /// `mask`, keeping their order, and takes the other lanes from `src`.
+      ///
+      /// This is the inverse of [`compress`](Self::compress): the `i`-th
+      /// `true` lane is set to `self[i]`.
+      ///
+      /// Currently this function is accelerated with `vpexpand` on AVX-512.
+      #[inline]
+      #[must_use]
+      pub fn expand(self, mask: $Mask, src: Self) -> Self {
+        let bitmask = mask.to_bitmask();
+        pick! {
+          if #[cfg($($native),+)] {
+            #[cfg(target_arch = "x86")]
+            use core::arch::x86::*;
+            #[cfg(target_arch = "x86_64")]
+            use core::arch::x86_64::*;
+
+            Self::from(unsafe { $expand(src.into(), bitmask as _, self.into()) })
+          } else {
+            let $table = self;
+            let $idx = $Index::new(expand_indices(bitmask).map(|i| i as _));
+            mask.select($permute, src)
+          }
+        }
+      }
+    }
+  )*};
+}
+
+impl_compress! {
+  f32x4: f32, 4, m32x4, i32x4,
+    #[cfg(target_feature = "avx512f", target_feature = "avx512vl")]
+    _mm_maskz_compress_ps, _mm_mask_expand_ps,
+    |table, idx| table.permute(idx);
+  f32x8: f32, 8, m32x8, i32x8,
+    #[cfg(target_feature = "avx512f", target_feature = "avx512vl")]
+    _mm256_maskz_compress_ps, _mm256_mask_expand_ps,
+    |table, idx| table.permute(idx);
+  f32x16: f32, 16, m32x16, i32x16,
+    #[cfg(target_feature = "avx512f")]
+    _mm512_maskz_compress_ps, _mm512_mask_expand_ps,
+    |table, idx| table.permute(idx);
+  f64x2: f64, 2, m64x2, i64x2,
+    #[cfg(target_feature = "avx512f", target_feature = "avx512vl")]
+    _mm_maskz_compress_pd, _mm_mask_expand_pd,
+    |table, idx| table.permute(idx);
+  f64x4: f64, 4, m64x4, i64x4,
+    #[cfg(target_feature = "avx512f", target_feature = "avx512vl")]
+    _mm256_maskz_compress_pd, _mm256_mask_expand_pd,
+    |table, idx| table.permute(idx);
+  f64x8: f64, 8, m64x8, i64x8,
+    #[cfg(target_feature = "avx512f")]
+    _mm512_maskz_compress_pd, _mm512_mask_expand_pd,
+    |table, idx| table.permute(idx);
+  i8x16: i8, 16, m8x16, i8x16,
+    #[cfg(target_feature = "avx512vbmi2", target_feature = "avx512vl")]
+    _mm_maskz_compress_epi8, _mm_mask_expand_epi8,
+    |table, idx| table.swizzle(idx);
+  i8x32: i8, 32, m8x32, i8x32,
+    #[cfg(target_feature = "avx512vbmi2", target_feature = "avx512vl")]
+    _mm256_maskz_compress_epi8, _mm256_mask_expand_epi8,
+    |table, idx| table.swizzle(idx);
+  i8x64: i8, 64, m8x64, i8x64,
+    #[cfg(target_feature = "avx512vbmi2")]
+    _mm512_maskz_compress_epi8, _mm512_mask_expand_epi8,
+    |table, idx| table.swizzle(idx);
+  i16x8: i16, 8, m16x8, i16x8,
+    #[cfg(target_feature = "avx512vbmi2", target_feature = "avx512vl")]
+    _mm_maskz_compress_epi16, _mm_mask_expand_epi16,
+    |table, idx| table.permute(idx);
+  i16x16: i16, 16, m16x16, i16x16,
+    #[cfg(target_feature = "avx512vbmi2", target_feature = "avx512vl")]
+    _mm256_maskz_compress_epi16, _mm256_mask_expand_epi16,
+    |table, idx| table.permute(idx);
+  i16x32: i16, 32, m16x32, i16x32,
+    #[cfg(target_feature = "avx512vbmi2")]
+    _mm512_maskz_compress_epi16, _mm512_mask_expand_epi16,
+    |table, idx| table.permute(idx);
+  i32x4: i32, 4, m32x4, i32x4,
+    #[cfg(target_feature = "avx512f", target_feature = "avx512vl")]
+    _mm_maskz_compress_epi32, _mm_mask_expand_epi32,
+    |table, idx| table.permute(idx);
+  i32x8: i32, 8, m32x8, i32x8,
+    #[cfg(target_feature = "avx512f", target_feature = "avx512vl")]
+    _mm256_maskz_compress_epi32, _mm256_mask_expand_epi32,
+    |table, idx| table.permute(idx);
+  i32x16: i32, 16, m32x16, i32x16,
+    #[cfg(target_feature = "avx512f")]
+    _mm512_maskz_compress_epi32, _mm512_mask_expand_epi32,
+    |table, idx| table.permute(idx);
+  i64x2: i64, 2, m64x2, i64x2,
+    #[cfg(target_feature = "avx512f", target_feature = "avx512vl")]
+    _mm_maskz_compress_epi64, _mm_mask_expand_epi64,
+    |table, idx| table.permute(idx);
+  i64x4: i64, 4, m64x4, i64x4,
+    #[cfg(target_feature = "avx512f", target_feature = "avx512vl")]
+    _mm256_maskz_compress_epi64, _mm256_mask_expand_epi64,
+    |table, idx| table.permute(idx);
+  i64x8: i64, 8, m64x8, i64x8,
+    #[cfg(target_feature = "avx512f")]
+    _mm512_maskz_compress_epi64, _mm512_mask_expand_epi64,
+    |table, idx| table.permute(idx);
+  u8x16: u8, 16, m8x16, i8x16,
+    #[cfg(target_feature = "avx512vbmi2", target_feature = "avx512vl")]
+    _mm_maskz_compress_epi8, _mm_mask_expand_epi8,
+    |table, idx| cast(cast::<u8x16, i8x16>(table).swizzle(idx));
+  u8x32: u8, 32, m8x32, i8x32,
+    #[cfg(target_feature = "avx512vbmi2", target_feature = "avx512vl")]
+    _mm256_maskz_compress_epi8, _mm256_mask_expand_epi8,
+    |table, idx| cast(cast::<u8x32, i8x32>(table).swizzle(idx));
+  u8x64: u8, 64, m8x64, i8x64,
+    #[cfg(target_feature = "avx512vbmi2")]
+    _mm512_maskz_compress_epi8, _mm512_mask_expand_epi8,
+    |table, idx| cast(cast::<u8x64, i8x64>(table).swizzle(idx));
+  u16x8: u16, 8, m16x8, i16x8,
+    #[cfg(target_feature = "avx512vbmi2", target_feature = "avx512vl")]
+    _mm_maskz_compress_epi16, _mm_mask_expand_epi16,
+    |table, idx| table.permute(idx);
+  u16x16: u16, 16, m16x16, i16x16,
+    #[cfg(target_feature = "avx512vbmi2", target_feature = "avx512vl")]
+    _mm256_maskz_compress_epi16, _mm256_mask_expand_epi16,
+    |table, idx| table.permute(idx);
+  u16x32: u16, 32, m16x32, i16x32,
+    #[cfg(target_feature = "avx512vbmi2")]
+    _mm512_maskz_compress_epi16, _mm512_mask_expand_epi16,
+    |table, idx| table.permute(idx);
+  u32x4: u32, 4, m32x4, i32x4,
+    #[cfg(target_feature = "avx512f", target_feature = "avx512vl")]
+    _mm_maskz_compress_epi32, _mm_mask_expand_epi32,
+    |table, idx| table.permute(idx);
+  u32x8: u32, 8, m32x8, i32x8,
+    #[cfg(target_feature = "avx512f", target_feature = "avx512vl")]
+    _mm256_maskz_compress_epi32, _mm256_mask_expand_epi32,
+    |table, idx| table.permute(idx);
+  u32x16: u32, 16, m32x16, i32x16,
+    #[cfg(target_feature = "avx512f")]
+    _mm512_maskz_compress_epi32, _mm512_mask_expand_epi32,
+    |table, idx| table.permute(idx);
+  u64x2: u64, 2, m64x2, i64x2,
+    #[cfg(target_feature = "avx512f", target_feature = "avx512vl")]
+    _mm_maskz_compress_epi64, _mm_mask_expand_epi64,
+    |table, idx| table.permute(idx);
+  u64x4: u64, 4, m64x4, i64x4,
+    #[cfg(target_feature = "avx512f", target_feature = "avx512vl")]
+    _mm256_maskz_compress_epi64, _mm256_mask_expand_epi64,
+    |table, idx| table.permute(idx);
+  u64x8: u64, 8, m64x8, i64x8,
+    #[cfg(target_feature = "avx512f")]
+    _mm512_maskz_compress_epi64, _mm512_mask_expand_epi64,
+    |table, idx| table.permute(idx);
+}
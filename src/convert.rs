@@ -0,0 +1,290 @@
+use super::*;
+
+/// Conversions between SIMD vectors with the same number of elements, such as
+/// [`f64x4`] to [`f32x4`] or [`i16x16`] to [`u8x16`].
+///
+/// This is implemented for every pair of SIMD vectors with the same number of
+/// elements, and each method converts element by element:
+///
+/// * [`cast_lossy`](Self::cast_lossy) converts like the `as` operator:
+///   integers wrap when narrowing and keep their bits when only the sign
+///   changes, floats are rounded to the nearest value, and floats are
+///   truncated towards zero when converted to integers, saturating at the
+///   limits of the integer type with `NaN` becoming `0`.
+/// * [`saturating_cast`](Self::saturating_cast) is the same, except that
+///   integers saturate at the limits of the new integer type instead of
+///   wrapping.
+/// * [`convert`](Self::convert) is for conversions that never lose
+///   information, such as [`i16x8`] to [`i32x8`] or [`f32x4`] to [`f64x4`],
+///   which implement [`From`].
+///
+/// LLVM compiles these to conversion instructions of the target where
+/// available, such as `cvtps2pd`, `packuswb` and `vpmovzxbd` on x86 and
+/// `fcvtzs`, `ucvtf` and `sqxtn` on NEON.
+///
+/// ```
+/// use wide::{SimdCast, f32x4, f32x8, f64x4, i16x8, i32x8, u8x16, u16x16};
+///
+/// let values = f64x4::from([1.5, -2.5, 1e300, f64::NAN]);
+/// let floats: f32x4 = values.cast_lossy();
+/// assert_eq!(floats.to_array()[..2], [1.5, -2.5]);
+/// assert_eq!(floats.to_array()[2], f32::INFINITY);
+///
+/// let floats = f32x8::from([0.5, 1.9, -1.9, 1e10, -1e10, f32::NAN, 2.0, 3.0]);
+/// let ints: i32x8 = floats.cast_lossy();
+/// assert_eq!(ints.to_array(), [0, 1, -1, i32::MAX, i32::MIN, 0, 2, 3]);
+///
+/// let mut wide = [0; 16];
+/// wide[..4].copy_from_slice(&[300, 5, 255, 256]);
+/// let wide = u16x16::from(wide);
+/// let narrow: u8x16 = wide.saturating_cast();
+/// assert_eq!(narrow.to_array()[..4], [255, 5, 255, 255]);
+/// let narrow: u8x16 = wide.cast_lossy();
+/// assert_eq!(narrow.to_array()[..4], [44, 5, 255, 0]);
+///
+/// let lossless: i32x8 = i16x8::splat(-1).convert();
+/// assert_eq!(lossless, i32x8::splat(-1));
+/// ```
+pub trait SimdCast<To>: SimdVector {
+  /// Converts each element like the `as` operator, see [`SimdCast`].
+  fn cast_lossy(self) -> To;
+
+  /// Converts each element like the `as` operator, but saturates integers at
+  /// the limits of the new integer type, see [`SimdCast`].
+  fn saturating_cast(self) -> To;
+
+  /// Converts each element without losing information, the same as
+  /// [`From`].
+  #[inline]
+  fn convert(self) -> To
+  where
+    To: From<Self>,
+  {
+    To::from(self)
+  }
+}
+
+/// Implements [`SimdCast`] between all pairs of the given integer and float
+/// SIMD vectors, which must have the same number of elements.
+///
+/// Most conversions convert each element of an array with `as`, which LLVM
+/// turns into the conversion instructions of the target. Saturating float to
+/// integer conversions compile to long instruction sequences on x86, so they
+/// use `trunc_int` instead where that helps.
+macro_rules! impl_simd_cast {
+  (
+    ints = $ints:tt,
+    floats = $floats:tt,
+  ) => {
+    impl_simd_cast!(@rows int $ints, $ints, $floats, $floats);
+    impl_simd_cast!(@rows float $floats, $ints, $floats, $floats);
+  };
+  (
+    @rows $kind:ident [$($From:ident: $T:ident),*],
+    $ints:tt, $floats:tt, $all_floats:tt
+  ) => {
+    $(impl_simd_cast!(@row $kind $From: $T, $ints, $floats, $all_floats);)*
+  };
+  (
+    @row $kind:ident $From:ident: $T:ident,
+    [$($Int:ident: $IT:ident),*], [$($Float:ident: $FT:ident),*],
+    $all_floats:tt
+  ) => {
+    $(impl_simd_cast!(@pair $kind $From: $T => int $Int: $IT, $all_floats);)*
+    $(
+      impl_simd_cast!(@pair $kind $From: $T => float $Float: $FT, $all_floats);
+    )*
+  };
+  (@pair int $From:ident: $T:ident => int $To:ident: $U:ident, $f:tt) => {
+    impl SimdCast<$To> for $From {
+      #[inline]
+      fn cast_lossy(self) -> $To {
+        impl_simd_cast!(@map self, $To, |x| x as $U)
+      }
+
+      #[inline]
+      fn saturating_cast(self) -> $To {
+        impl_simd_cast!(@map self, $To, |x| {
+          (x as i128).clamp($U::MIN as i128, $U::MAX as i128) as $U
+        })
+      }
+    }
+  };
+  (@pair float $From:ident: f32 => int $To:ident: $U:ident, $f:tt) => {
+    impl_simd_cast!(
+      @float_to_int $From: f32 => $To: $U, $f,
+      any(target_arch = "x86", target_arch = "x86_64")
+    );
+  };
+  (@pair float $From:ident: f64 => int $To:ident: $U:ident, $f:tt) => {
+    // `trunc_int` of `f64` is only faster than `as` with AVX-512.
+    impl_simd_cast!(
+      @float_to_int $From: f64 => $To: $U, $f,
+      all(target_feature = "avx512dq", target_feature = "avx512vl")
+    );
+  };
+  (
+    @float_to_int $From:ident: $T:ident => $To:ident: $U:ident, $f:tt,
+    $($trunc:tt)*
+  ) => {
+    impl SimdCast<$To> for $From {
+      #[inline]
+      fn cast_lossy(self) -> $To {
+        pick! {
+          if #[cfg($($trunc)*)] {
+            impl_simd_cast!(@trunc self, $T => $To: $U, $f)
+          } else {
+            impl_simd_cast!(@map self, $To, |x| x as $U)
+          }
+        }
+      }
+
+      #[inline]
+      fn saturating_cast(self) -> $To {
+        self.cast_lossy()
+      }
+    }
+  };
+  (@pair float $From:ident: $T:ident => float $To:ident: $U:ident, $f:tt) => {
+    impl SimdCast<$To> for $From {
+      #[inline]
+      fn cast_lossy(self) -> $To {
+        let result = impl_simd_cast!(@map self, $To, |x| x as $U);
+        // `as` does not specify the bits of NaN results.
+        if cfg!(feature = "deterministic") {
+          result.is_nan().select($To::NAN, result)
+        } else {
+          result
+        }
+      }
+
+      #[inline]
+      fn saturating_cast(self) -> $To {
+        self.cast_lossy()
+      }
+    }
+  };
+  (
+    @pair $kind:ident $From:ident: $T:ident => float $To:ident: $U:ident,
+    $f:tt
+  ) => {
+    impl SimdCast<$To> for $From {
+      #[inline]
+      fn cast_lossy(self) -> $To {
+        impl_simd_cast!(@map self, $To, |x| x as $U)
+      }
+
+      #[inline]
+      fn saturating_cast(self) -> $To {
+        self.cast_lossy()
+      }
+    }
+  };
+  // Converts each element of `$v` with `$f`. This is a `while` loop because
+  // LLVM does not always inline `map` for arrays with 16 or more elements.
+  (@map $v:expr, $To:ident, |$x:ident| $f:expr) => {{
+    let array = $v.to_array();
+    let mut out = [Default::default(); <$To as SimdVector>::LANES];
+    let mut i = 0;
+    while i < array.len() {
+      let $x = array[i];
+      out[i] = $f;
+      i += 1;
+    }
+    $To::new(out)
+  }};
+  // Saturating float to integer conversions, built on `trunc_int`, which
+  // converts to the signed integers of the same size.
+  (@trunc $v:ident, f32 => $To:ident: i32, $f:tt) => {
+    $v.trunc_int()
+  };
+  (@trunc $v:ident, f64 => $To:ident: i64, $f:tt) => {
+    $v.trunc_int()
+  };
+  (@trunc $v:ident, f32 => $To:ident: u32, $f:tt) => {
+    impl_simd_cast!(@trunc_unsigned $v, $To: u32, 2147483648.0)
+  };
+  (@trunc $v:ident, f64 => $To:ident: u64, $f:tt) => {
+    impl_simd_cast!(@trunc_unsigned $v, $To: u64, 9223372036854775808.0)
+  };
+  // `f32` to `f64` is exact, so `i64` and `u64` use the `f64` conversions.
+  (@trunc $v:ident, f32 => $To:ident: i64, [$F32:ident: f32, $F64:ident: f64]) => {
+    SimdCast::<$F64>::cast_lossy($v).cast_lossy()
+  };
+  (@trunc $v:ident, f32 => $To:ident: u64, [$F32:ident: f32, $F64:ident: f64]) => {
+    SimdCast::<$F64>::cast_lossy($v).cast_lossy()
+  };
+  (@trunc $v:ident, $T:ident => $To:ident: $U:ident, $f:tt) => {
+    $v.trunc_int().saturating_cast()
+  };
+  // Values from `$half` up are shifted down by `$half` before `trunc_int`,
+  // and then get the top bit set again.
+  (@trunc_unsigned $v:ident, $To:ident: $U:ident, $half:literal) => {{
+    let high = $v.simd_ge(Self::splat($half));
+    let ints = high.select($v - Self::splat($half), $v).trunc_int();
+    ints.max(Default::default()).cast_unsigned()
+      | ($To::from(high) & $To::splat(1 << ($U::BITS - 1)))
+  }};
+}
+
+impl_simd_cast! {
+  ints = [i64x2: i64, u64x2: u64],
+  floats = [f64x2: f64],
+}
+impl_simd_cast! {
+  ints = [i32x4: i32, u32x4: u32, i64x4: i64, u64x4: u64],
+  floats = [f32x4: f32, f64x4: f64],
+}
+impl_simd_cast! {
+  ints = [
+    i16x8: i16, u16x8: u16, i32x8: i32, u32x8: u32, i64x8: i64, u64x8: u64
+  ],
+  floats = [f32x8: f32, f64x8: f64],
+}
+impl_simd_cast! {
+  ints = [
+    i8x16: i8, u8x16: u8, i16x16: i16, u16x16: u16, i32x16: i32, u32x16: u32
+  ],
+  floats = [f32x16: f32],
+}
+impl_simd_cast! {
+  ints = [i8x32: i8, u8x32: u8, i16x32: i16, u16x32: u16],
+  floats = [],
+}
+impl_simd_cast! {
+  ints = [i8x64: i8, u8x64: u8],
+  floats = [],
+}
+
+/// Implements [`From`] for conversions with [`SimdCast`] that never lose
+/// information.
+macro_rules! impl_from_lossless {
+  ($($From:ident => [$($To:ident),*],)*) => {
+    $($(
+      impl From<$From> for $To {
+        #[doc = concat!("Converts each element to `", stringify!($To), "` without losing information.")]
+        #[inline]
+        fn from(v: $From) -> Self {
+          v.cast_lossy()
+        }
+      }
+    )*)*
+  };
+}
+
+impl_from_lossless! {
+  f32x4 => [f64x4],
+  i32x4 => [i64x4],
+  u32x4 => [u64x4, i64x4, f64x4],
+  f32x8 => [f64x8],
+  i16x8 => [i64x8, f32x8, f64x8],
+  u16x8 => [i32x8, u64x8, i64x8, f32x8, f64x8],
+  i32x8 => [i64x8],
+  u32x8 => [u64x8, i64x8, f64x8],
+  i8x16 => [i32x16, f32x16],
+  u8x16 => [u32x16, i32x16, f32x16],
+  i16x16 => [i32x16, f32x16],
+  u16x16 => [i32x16, f32x16],
+  i8x32 => [i16x32],
+  u8x32 => [u16x32, i16x32],
+}
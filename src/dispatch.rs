@@ -0,0 +1,293 @@
+//! Runtime selection of the widest SIMD vectors supported by the current CPU.
+//!
+//! The backend of every SIMD type in this crate is chosen at compile time with
+//! `#[cfg(target_feature = ...)]`, so a binary built for baseline `x86_64` only
+//! uses SSE2. This module is an opt-in layer on top of that: write a
+//! [`Kernel`] once, generic over a [`Width`], and [`run`] picks the widest
+//! vectors the CPU supports and compiles the kernel with the matching target
+//! features enabled.
+//!
+//! ```
+//! use wide::{
+//!   SimdVector,
+//!   dispatch::{self, Kernel, Width},
+//! };
+//!
+//! struct Sum<'a>(&'a [f32]);
+//!
+//! impl Kernel for Sum<'_> {
+//!   type Output = f32;
+//!
+//!   #[inline(always)]
+//!   fn run<W: Width>(self) -> f32 {
+//!     let chunks = self.0.chunks(W::F32::LANES);
+//!     chunks.map(|c| W::F32::load_or(c, 0.0)).sum::<W::F32>().reduce_add()
+//!   }
+//! }
+//!
+//! assert_eq!(dispatch::run(Sum(&[1.5; 100])), 150.0);
+//! ```
+//!
+//! Only the target features are selected at runtime, the SIMD types keep the
+//! backend chosen at compile time. On a baseline build an [`f32x8`] is still
+//! two [`f32x4`] internally, but inside the kernel the compiler is allowed to
+//! merge them into AVX instructions. Building with the features enabled, e.g.
+//! with `-C target-cpu=native`, is still the fastest option when the binary
+//! only runs on one machine.
+//!
+//! Runtime detection requires the `std` feature. Without it, [`detect`] only
+//! reports the features enabled at compile time.
+//!
+//! [`f32x8`]: crate::f32x8
+//! [`f32x4`]: crate::f32x4
+
+use super::*;
+
+use core::fmt::Debug;
+
+mod sealed {
+  pub trait Sealed {}
+}
+
+/// A set of SIMD vector types that all have the same size in bits.
+///
+/// This trait is sealed, it is implemented by [`W128`], [`W256`] and
+/// [`W512`].
+pub trait Width: sealed::Sealed + Copy + Debug + 'static {
+  /// The size of the SIMD vectors in bits.
+  const BITS: usize;
+
+  /// SIMD vector of `f32`.
+  type F32: SimdFloat<Elem = f32>;
+  /// SIMD vector of `f64`.
+  type F64: SimdFloat<Elem = f64>;
+  /// SIMD vector of `i8`.
+  type I8: SimdInt<Elem = i8>;
+  /// SIMD vector of `i16`.
+  type I16: SimdInt<Elem = i16>;
+  /// SIMD vector of `i32`.
+  type I32: SimdInt<Elem = i32>;
+  /// SIMD vector of `i64`.
+  type I64: SimdInt<Elem = i64>;
+  /// SIMD vector of `u8`.
+  type U8: SimdUint<Elem = u8>;
+  /// SIMD vector of `u16`.
+  type U16: SimdUint<Elem = u16>;
+  /// SIMD vector of `u32`.
+  type U32: SimdUint<Elem = u32>;
+  /// SIMD vector of `u64`.
+  type U64: SimdUint<Elem = u64>;
+}
+
+macro_rules! impl_width {
+  (
+    $(#[$attr:meta])*
+    $Width:ident {
+      BITS = $bits:literal,
+      F32 = $F32:ident,
+      F64 = $F64:ident,
+      I8 = $I8:ident,
+      I16 = $I16:ident,
+      I32 = $I32:ident,
+      I64 = $I64:ident,
+      U8 = $U8:ident,
+      U16 = $U16:ident,
+      U32 = $U32:ident,
+      U64 = $U64:ident,
+    }
+  ) => {
+    $(#[$attr])*
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    pub struct $Width;
+
+    impl sealed::Sealed for $Width {}
+
+    impl Width for $Width {
+      const BITS: usize = $bits;
+
+      type F32 = $F32;
+      type F64 = $F64;
+      type I8 = $I8;
+      type I16 = $I16;
+      type I32 = $I32;
+      type I64 = $I64;
+      type U8 = $U8;
+      type U16 = $U16;
+      type U32 = $U32;
+      type U64 = $U64;
+    }
+  };
+}
+
+impl_width! {
+  /// 128-bit SIMD vectors, such as [`f32x4`].
+  W128 {
+    BITS = 128,
+    F32 = f32x4,
+    F64 = f64x2,
+    I8 = i8x16,
+    I16 = i16x8,
+    I32 = i32x4,
+    I64 = i64x2,
+    U8 = u8x16,
+    U16 = u16x8,
+    U32 = u32x4,
+    U64 = u64x2,
+  }
+}
+
+impl_width! {
+  /// 256-bit SIMD vectors, such as [`f32x8`].
+  W256 {
+    BITS = 256,
+    F32 = f32x8,
+    F64 = f64x4,
+    I8 = i8x32,
+    I16 = i16x16,
+    I32 = i32x8,
+    I64 = i64x4,
+    U8 = u8x32,
+    U16 = u16x16,
+    U32 = u32x8,
+    U64 = u64x4,
+  }
+}
+
+impl_width! {
+  /// 512-bit SIMD vectors, such as [`f32x16`].
+  W512 {
+    BITS = 512,
+    F32 = f32x16,
+    F64 = f64x8,
+    I8 = i8x64,
+    I16 = i16x32,
+    I32 = i32x16,
+    I64 = i64x8,
+    U8 = u8x64,
+    U16 = u16x32,
+    U32 = u32x16,
+    U64 = u64x8,
+  }
+}
+
+/// A computation that can run with any [`Width`] of SIMD vectors.
+///
+/// `run` should be marked `#[inline(always)]`. Otherwise it may be compiled
+/// as a separate function without the target features selected by [`run`].
+pub trait Kernel {
+  /// The result of the computation.
+  type Output;
+
+  /// Runs the computation with SIMD vectors of the given width.
+  fn run<W: Width>(self) -> Self::Output;
+}
+
+/// The set of CPU features used by [`run`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Level {
+  /// The features enabled at compile time, used with [`W128`].
+  Baseline,
+  /// `avx2` and `fma`, used with [`W256`].
+  Avx2,
+  /// `avx512f`, `avx512bw`, `avx512dq` and `avx512vl`, used with [`W512`].
+  Avx512,
+}
+
+/// Returns the best [`Level`] supported by the current CPU.
+///
+/// With the `std` feature this checks the CPU at runtime (the result is cached
+/// by the standard library). Without it, only the features enabled at compile
+/// time are taken into account.
+#[inline]
+#[must_use]
+pub fn detect() -> Level {
+  #[cfg(all(
+    feature = "std",
+    any(target_arch = "x86", target_arch = "x86_64")
+  ))]
+  {
+    use std::is_x86_feature_detected as detected;
+
+    if detected!("avx512f")
+      && detected!("avx512bw")
+      && detected!("avx512dq")
+      && detected!("avx512vl")
+      && detected!("avx2")
+      && detected!("fma")
+    {
+      Level::Avx512
+    } else if detected!("avx2") && detected!("fma") {
+      Level::Avx2
+    } else {
+      Level::Baseline
+    }
+  }
+  #[cfg(not(all(
+    feature = "std",
+    any(target_arch = "x86", target_arch = "x86_64")
+  )))]
+  {
+    pick! {
+      if #[cfg(
+        target_feature = "avx512f",
+        target_feature = "avx512bw",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl",
+        target_feature = "avx2",
+        target_feature = "fma"
+      )] {
+        Level::Avx512
+      } else if #[cfg(target_feature = "avx2", target_feature = "fma")] {
+        Level::Avx2
+      } else {
+        Level::Baseline
+      }
+    }
+  }
+}
+
+/// Runs `kernel` with the widest SIMD vectors supported by the current CPU,
+/// see the [module documentation](self).
+#[inline]
+pub fn run<K: Kernel>(kernel: K) -> K::Output {
+  run_at(detect(), kernel)
+}
+
+/// Runs `kernel` with the SIMD vectors of the given [`Level`].
+///
+/// # Panics
+///
+/// Panics if the current CPU does not support `level`, that is if `level` is
+/// greater than [`detect()`].
+#[inline]
+pub fn run_at<K: Kernel>(level: Level, kernel: K) -> K::Output {
+  assert!(level <= detect(), "`{level:?}` is not supported by this CPU");
+
+  match level {
+    Level::Baseline => kernel.run::<W128>(),
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    // SAFETY: The CPU was checked to support the features.
+    Level::Avx2 => unsafe { x86::run_avx2(kernel) },
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    // SAFETY: The CPU was checked to support the features.
+    Level::Avx512 => unsafe { x86::run_avx512(kernel) },
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    _ => unreachable!(),
+  }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+  use super::*;
+
+  #[target_feature(enable = "avx2,fma")]
+  pub(super) fn run_avx2<K: Kernel>(kernel: K) -> K::Output {
+    kernel.run::<W256>()
+  }
+
+  #[target_feature(enable = "avx2,fma,avx512f,avx512bw,avx512dq,avx512vl")]
+  pub(super) fn run_avx512<K: Kernel>(kernel: K) -> K::Output {
+    kernel.run::<W512>()
+  }
+}
@@ -87,6 +87,7 @@ impl_simd! {
     T = f32,
     N = 4,
     Simd = f32x4,
+    Mask = m32x4,
     optional_type_x86_inner { X86Inner = __m128 },
     optional_type_arm_inner { ArmInner = float32x4_t },
     optional_type_wasm_inner { WasmInner = v128 },
@@ -352,8 +353,10 @@ impl_simd_float! {
     T = f32,
     N = 4,
     Simd = f32x4,
+    Mask = m32x4,
     UnsignedT = u32,
     UnsignedSimd = u32x4,
+    SignedSimd = i32x4,
   }
   old_powf_simd_fn_name = pow_f32x4,
 
@@ -386,18 +389,18 @@ impl_simd_float! {
   fn add(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse")] {
-        Self { sse: add_m128(self.sse, rhs.sse) }
+        Self { sse: add_m128(self.sse, rhs.sse) }.canonicalize_nan()
       } else if #[cfg(target_feature="simd128")] {
-        Self { simd: f32x4_add(self.simd, rhs.simd) }
+        Self { simd: f32x4_add(self.simd, rhs.simd) }.canonicalize_nan()
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        unsafe { Self { neon: vaddq_f32(self.neon, rhs.neon) } }
+        unsafe { Self { neon: vaddq_f32(self.neon, rhs.neon) }.canonicalize_nan() }
       } else {
         Self { arr: [
           self.arr[0] + rhs.arr[0],
           self.arr[1] + rhs.arr[1],
           self.arr[2] + rhs.arr[2],
           self.arr[3] + rhs.arr[3],
-        ]}
+        ]}.canonicalize_nan()
       }
     }
   }
@@ -406,18 +409,18 @@ impl_simd_float! {
   fn sub(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse")] {
-        Self { sse: sub_m128(self.sse, rhs.sse) }
+        Self { sse: sub_m128(self.sse, rhs.sse) }.canonicalize_nan()
       } else if #[cfg(target_feature="simd128")] {
-        Self { simd: f32x4_sub(self.simd, rhs.simd) }
+        Self { simd: f32x4_sub(self.simd, rhs.simd) }.canonicalize_nan()
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        unsafe {Self { neon: vsubq_f32(self.neon, rhs.neon) }}
+        unsafe { Self { neon: vsubq_f32(self.neon, rhs.neon) }.canonicalize_nan() }
       } else {
         Self { arr: [
           self.arr[0] - rhs.arr[0],
           self.arr[1] - rhs.arr[1],
           self.arr[2] - rhs.arr[2],
           self.arr[3] - rhs.arr[3],
-        ]}
+        ]}.canonicalize_nan()
       }
     }
   }
@@ -426,18 +429,18 @@ impl_simd_float! {
   fn mul(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse")] {
-        Self { sse: mul_m128(self.sse, rhs.sse) }
+        Self { sse: mul_m128(self.sse, rhs.sse) }.canonicalize_nan()
       } else if #[cfg(target_feature="simd128")] {
-        Self { simd: f32x4_mul(self.simd, rhs.simd) }
+        Self { simd: f32x4_mul(self.simd, rhs.simd) }.canonicalize_nan()
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        unsafe {Self { neon: vmulq_f32(self.neon, rhs.neon) }}
+        unsafe { Self { neon: vmulq_f32(self.neon, rhs.neon) }.canonicalize_nan() }
       } else {
         Self { arr: [
           self.arr[0] * rhs.arr[0],
           self.arr[1] * rhs.arr[1],
           self.arr[2] * rhs.arr[2],
           self.arr[3] * rhs.arr[3],
-        ]}
+        ]}.canonicalize_nan()
       }
     }
   }
@@ -446,18 +449,18 @@ impl_simd_float! {
   fn div(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse")] {
-        Self { sse: div_m128(self.sse, rhs.sse) }
+        Self { sse: div_m128(self.sse, rhs.sse) }.canonicalize_nan()
       } else if #[cfg(target_feature="simd128")] {
-        Self { simd: f32x4_div(self.simd, rhs.simd) }
+        Self { simd: f32x4_div(self.simd, rhs.simd) }.canonicalize_nan()
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        unsafe {Self { neon: vdivq_f32(self.neon, rhs.neon) }}
+        unsafe { Self { neon: vdivq_f32(self.neon, rhs.neon) }.canonicalize_nan() }
       } else {
         Self { arr: [
           self.arr[0] / rhs.arr[0],
           self.arr[1] / rhs.arr[1],
           self.arr[2] / rhs.arr[2],
           self.arr[3] / rhs.arr[3],
-        ]}
+        ]}.canonicalize_nan()
       }
     }
   }
@@ -545,67 +548,104 @@ impl_simd_float! {
   }
 
   #[inline]
-  pub fn is_nan(self) -> Self {
+  pub fn is_nan(self) -> m32x4 {
     pick! {
       if #[cfg(target_feature="sse")] {
-        Self { sse: cmp_unord_mask_m128(self.sse, self.sse) }
+        m32x4::from_simd_unchecked(Self { sse: cmp_unord_mask_m128(self.sse, self.sse) })
       } else if #[cfg(target_feature="simd128")] {
-        Self { simd: f32x4_ne(self.simd, self.simd) }
+        m32x4::from_simd_unchecked(Self { simd: f32x4_ne(self.simd, self.simd) })
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        unsafe {Self { neon: vreinterpretq_f32_u32(vmvnq_u32(vceqq_f32(self.neon, self.neon))) }}
+        unsafe {m32x4::from_simd_unchecked(Self { neon: vreinterpretq_f32_u32(vmvnq_u32(vceqq_f32(self.neon, self.neon))) })}
       } else {
-        Self { arr: [
+        m32x4::from_simd_unchecked(Self { arr: [
           if self.arr[0].is_nan() { f32::from_bits(u32::MAX) } else { 0.0 },
           if self.arr[1].is_nan() { f32::from_bits(u32::MAX) } else { 0.0 },
           if self.arr[2].is_nan() { f32::from_bits(u32::MAX) } else { 0.0 },
           if self.arr[3].is_nan() { f32::from_bits(u32::MAX) } else { 0.0 },
-        ]}
+        ]})
       }
     }
   }
 
   #[inline]
-  pub fn is_inf(self) -> Self {
+  pub fn is_inf(self) -> m32x4 {
     let shifted_inf = u32x4::from(0xFF000000);
     let u: u32x4 = cast(self);
     let shift_u = u << 1_u64;
     let out = (shift_u).simd_eq(shifted_inf);
-    cast(out)
+    out
   }
 
   #[inline]
-  pub fn is_finite(self) -> Self {
+  pub fn is_finite(self) -> m32x4 {
     let shifted_exp_mask = u32x4::from(0xFF000000);
     let u: u32x4 = cast(self);
     let shift_u = u << 1_u64;
     let out = !(shift_u & shifted_exp_mask).simd_eq(shifted_exp_mask);
-    cast(out)
+    out
   }
 
   #[inline]
-  pub fn is_sign_positive(self) -> Self {
+  pub fn is_sign_positive(self) -> m32x4 {
     const SIGN_MASK: u32x4 = u32x4::splat((-0.0_f32).to_bits());
 
     let bits = cast::<f32x4, u32x4>(self);
     let sign = bits & SIGN_MASK;
     let result = sign.simd_eq(u32x4::ZERO);
-    cast::<u32x4, f32x4>(result)
+    result
   }
 
   #[inline]
-  pub fn is_sign_negative(self) -> Self {
+  pub fn is_sign_negative(self) -> m32x4 {
     const SIGN_MASK: u32x4 = u32x4::splat((-0.0_f32).to_bits());
 
     let bits = cast::<f32x4, u32x4>(self);
     let sign = bits & SIGN_MASK;
     let result = sign.simd_eq(SIGN_MASK);
-    cast::<u32x4, f32x4>(result)
+    result
+  }
+
+  #[inline]
+  pub fn logb(self) -> Self {
+    pick! {
+      if #[cfg(all(target_feature="avx512f", target_feature="avx512vl"))] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm_getexp_ps;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm_getexp_ps;
+
+        // TODO(safe_arch): Add `_mm_getexp_ps`.
+        Self { sse: m128(unsafe { _mm_getexp_ps(self.sse.0) }) }.canonicalize_nan()
+      } else {
+        self.logb_portable()
+      }
+    }
+  }
+
+  #[inline]
+  pub fn ldexp(self, n: i32x4) -> Self {
+    pick! {
+      if #[cfg(all(target_feature="avx512f", target_feature="avx512vl"))] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm_scalef_ps;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm_scalef_ps;
+
+        let n = Self::exponent_to_float(n);
+        // TODO(safe_arch): Add `_mm_scalef_ps`.
+        Self { sse: m128(unsafe { _mm_scalef_ps(self.sse.0, n.sse.0) }) }.canonicalize_nan()
+      } else {
+        self.ldexp_portable(n)
+      }
+    }
   }
 
   #[inline]
   pub fn recip(self) -> Self {
     pick! {
-      if #[cfg(target_feature="sse")] {
+      if #[cfg(feature="deterministic")] {
+        Self::ONE / self
+      } else if #[cfg(target_feature="sse")] {
         Self { sse: reciprocal_m128(self.sse) }
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: f32x4_div(f32x4_splat(1.0), self.simd) }
@@ -625,7 +665,9 @@ impl_simd_float! {
   #[inline]
   pub fn recip_sqrt(self) -> Self {
     pick! {
-      if #[cfg(target_feature="sse")] {
+      if #[cfg(feature="deterministic")] {
+        Self::ONE / self.sqrt()
+      } else if #[cfg(target_feature="sse")] {
         Self { sse: reciprocal_sqrt_m128(self.sse) }
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: f32x4_div(f32x4_splat(1.0), f32x4_sqrt(self.simd)) }
@@ -652,7 +694,9 @@ impl_simd_float! {
   #[inline]
   pub fn max(self, rhs: Self) -> Self {
     pick! {
-      if #[cfg(target_feature="sse")] {
+      if #[cfg(feature="deterministic")] {
+        self.is_nan().select(rhs, self.simd_lt(rhs).select(rhs, self))
+      } else if #[cfg(target_feature="sse")] {
         // max_m128 seems to do rhs < self ? self : rhs. So if there's any NaN
         // involved, it chooses rhs, so we need to specifically check rhs for
         // NaN.
@@ -687,7 +731,9 @@ impl_simd_float! {
   #[inline]
   pub fn fast_max(self, rhs: Self) -> Self {
     pick! {
-      if #[cfg(target_feature="sse")] {
+      if #[cfg(feature="deterministic")] {
+        self.simd_lt(rhs).select(rhs, self)
+      } else if #[cfg(target_feature="sse")] {
         Self { sse: max_m128(self.sse, rhs.sse) }
       } else if #[cfg(target_feature="simd128")] {
         Self {
@@ -709,7 +755,9 @@ impl_simd_float! {
   #[inline]
   pub fn min(self, rhs: Self) -> Self {
     pick! {
-      if #[cfg(target_feature="sse")] {
+      if #[cfg(feature="deterministic")] {
+        self.is_nan().select(rhs, self.simd_gt(rhs).select(rhs, self))
+      } else if #[cfg(target_feature="sse")] {
         // min_m128 seems to do self < rhs ? self : rhs. So if there's any NaN
         // involved, it chooses rhs, so we need to specifically check rhs for
         // NaN.
@@ -744,7 +792,9 @@ impl_simd_float! {
   #[inline]
   pub fn fast_min(self, rhs: Self) -> Self {
     pick! {
-      if #[cfg(target_feature="sse")] {
+      if #[cfg(feature="deterministic")] {
+        self.simd_gt(rhs).select(rhs, self)
+      } else if #[cfg(target_feature="sse")] {
         Self { sse: min_m128(self.sse, rhs.sse) }
       } else if #[cfg(target_feature="simd128")] {
         Self {
@@ -766,15 +816,18 @@ impl_simd_float! {
   #[inline]
   pub fn clamp(self, min: Self, max: Self) -> Self {
     pick! {
-      if #[cfg(any(
-        target_feature="simd128",
-        all(target_feature="neon",target_arch="aarch64"),
+      if #[cfg(all(
+        not(feature="deterministic"),
+        any(
+          target_feature="simd128",
+          all(target_feature="neon",target_arch="aarch64"),
+        ),
       ))] {
         // `fast_clamp` already works.
         self.fast_clamp(min, max)
       } else {
         // This works since all bits set is NaN.
-        self.fast_clamp(min, max) | min.is_nan() | max.is_nan()
+        self.fast_clamp(min, max) | Self::from(min.is_nan() | max.is_nan())
       }
     }
   }
@@ -782,7 +835,10 @@ impl_simd_float! {
   #[inline]
   pub fn fast_clamp(self, min: Self, max: Self) -> Self {
     pick! {
-      if #[cfg(target_feature="sse")] {
+      if #[cfg(feature="deterministic")] {
+        let result = self.simd_gt(max).select(max, self);
+        result.simd_lt(min).select(min, result)
+      } else if #[cfg(target_feature="sse")] {
         // For both `min_m128` and `max_m128` if any input is NaN, `rhs` gets
         // chosen. For `self` to be chosen, `self` must be the second argument.
         Self { sse: max_m128(min.sse, min_m128(max.sse, self.sse)) }
@@ -819,14 +875,14 @@ impl_simd_float! {
   pub fn floor(self) -> Self {
     pick! {
       if #[cfg(target_feature="simd128")] {
-        Self { simd: f32x4_floor(self.simd) }
+        Self { simd: f32x4_floor(self.simd) }.canonicalize_nan()
       } else if #[cfg(target_feature="sse4.1")] {
-        Self { sse: floor_m128(self.sse) }
+        Self { sse: floor_m128(self.sse) }.canonicalize_nan()
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        unsafe {Self { neon: vrndmq_f32(self.neon) }}
+        unsafe { Self { neon: vrndmq_f32(self.neon) }.canonicalize_nan() }
       } else if #[cfg(feature="std")] {
         let base: [f32; 4] = cast(self);
-        cast(base.map(|val| val.floor()))
+        Self::from(base.map(|val| val.floor())).canonicalize_nan()
       } else {
         let base: [f32; 4] = cast(self);
         let rounded: [f32; 4] = cast(self.round());
@@ -844,14 +900,14 @@ impl_simd_float! {
   pub fn ceil(self) -> Self {
     pick! {
       if #[cfg(target_feature="simd128")] {
-        Self { simd: f32x4_ceil(self.simd) }
+        Self { simd: f32x4_ceil(self.simd) }.canonicalize_nan()
       } else if #[cfg(target_feature="sse4.1")] {
-        Self { sse: ceil_m128(self.sse) }
+        Self { sse: ceil_m128(self.sse) }.canonicalize_nan()
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        unsafe {Self { neon: vrndpq_f32(self.neon) }}
+        unsafe { Self { neon: vrndpq_f32(self.neon) }.canonicalize_nan() }
       } else if #[cfg(feature="std")] {
         let base: [f32; 4] = cast(self);
-        cast(base.map(|val| val.ceil()))
+        Self::from(base.map(|val| val.ceil())).canonicalize_nan()
       } else {
         let base: [f32; 4] = cast(self);
         let rounded: [f32; 4] = cast(self.round());
@@ -878,13 +934,13 @@ impl_simd_float! {
         let result_abs = Self { sse: round_m128::<{round_op!(Zero)}>(adjusted_self.sse) };
         // The addition breaks for `0.5.next_down()` which incorrectly rounds to
         // `1.0`. This resets the result back to `0.0`.
-        let result_abs = result_abs & self_abs.simd_ne(HALF_NEXT_DOWN);
+        let result_abs = result_abs & Self::from(self_abs.simd_ne(HALF_NEXT_DOWN));
 
         // Large value, infinity and NaN need special handling.
         let bounds_mask: Self = cast(cmp_lt_mask_i32_m128i(cast(self_abs), cast(BOUNDS_LIMIT)));
 
         // `abs` keeps the original sign.
-        bounds_mask.abs().bitselect(result_abs, self)
+        bounds_mask.abs().bitselect(result_abs, self).canonicalize_nan()
       } else if #[cfg(target_feature="sse2")] {
         const_f32_as_f32x4!(HALF_NEXT_DOWN, 0.5_f32.next_down());
         const_f32_as_f32x4!(BOUNDS_LIMIT, 8388608.0);
@@ -897,13 +953,13 @@ impl_simd_float! {
         };
         // The addition breaks for `0.5.next_down()` which incorrectly rounds to
         // `1.0`. This resets the result back to `0.0`.
-        let result_abs = result_abs & self_abs.simd_ne(HALF_NEXT_DOWN);
+        let result_abs = result_abs & Self::from(self_abs.simd_ne(HALF_NEXT_DOWN));
 
         // Large value, infinity and NaN need special handling.
         let bounds_mask: Self = cast(cmp_lt_mask_i32_m128i(cast(self_abs), cast(BOUNDS_LIMIT)));
 
         // `abs` keeps the original sign.
-        bounds_mask.abs().bitselect(result_abs, self)
+        bounds_mask.abs().bitselect(result_abs, self).canonicalize_nan()
       } else if #[cfg(target_feature="simd128")] {
         const_f32_as_f32x4!(HALF_NEXT_DOWN, 0.5_f32.next_down());
         const_f32_as_f32x4!(BOUNDS_LIMIT, 8388608.0);
@@ -914,15 +970,15 @@ impl_simd_float! {
         let result_abs = Self { simd: f32x4_trunc(adjusted_self.simd) };
         // The addition breaks for `0.5.next_down()` which incorrectly rounds to
         // `1.0`. This resets the result back to `0.0`.
-        let result_abs = result_abs & self_abs.simd_ne(HALF_NEXT_DOWN);
+        let result_abs = result_abs & Self::from(self_abs.simd_ne(HALF_NEXT_DOWN));
 
         // Large value, infinity and NaN need special handling.
         let bounds_mask = Self { simd: i32x4_lt(self_abs.simd, BOUNDS_LIMIT.simd) };
 
         // `abs` keeps the original sign.
-        bounds_mask.abs().bitselect(result_abs, self)
+        bounds_mask.abs().bitselect(result_abs, self).canonicalize_nan()
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        unsafe {Self { neon: vrndaq_f32(self.neon) }}
+        unsafe { Self { neon: vrndaq_f32(self.neon) }.canonicalize_nan() }
       } else {
         const_f32_as_f32x4!(HALF_NEXT_DOWN, 0.5_f32.next_down());
         const_f32_as_f32x4!(BOUNDS_LIMIT, 8388608.0);
@@ -938,13 +994,13 @@ impl_simd_float! {
         ]);
         // The addition breaks for `0.5.next_down()` which incorrectly rounds to
         // `1.0`. This resets the result back to `0.0`.
-        let result_abs = result_abs & self_abs.simd_ne(HALF_NEXT_DOWN);
+        let result_abs = result_abs & Self::from(self_abs.simd_ne(HALF_NEXT_DOWN));
 
         // Large value, infinity and NaN need special handling.
-        let bounds_mask: Self = cast(cast::<_, i32x4>(self_abs).simd_lt(cast::<_, i32x4>(BOUNDS_LIMIT)));
+        let bounds_mask = Self::from(cast::<_, i32x4>(self_abs).simd_lt(cast::<_, i32x4>(BOUNDS_LIMIT)));
 
         // `abs` keeps the original sign.
-        bounds_mask.abs().bitselect(result_abs, self)
+        bounds_mask.abs().bitselect(result_abs, self).canonicalize_nan()
       }
     }
   }
@@ -955,8 +1011,8 @@ impl_simd_float! {
       if #[cfg(target_feature="sse2")] {
         // Based on: https://github.com/v8/v8/blob/210987a552a2bf2a854b0baa9588a5959ff3979d/src/codegen/shared-ia32-x64/macro-assembler-shared-ia32-x64.h#L489-L504
         let non_nan_mask = self.simd_eq(self);
-        let non_nan = self & non_nan_mask;
-        let flip_to_max: i32x4 = cast(self.simd_ge(Self::splat(2147483648.0)));
+        let non_nan = self & Self::from(non_nan_mask);
+        let flip_to_max = i32x4::from(self.simd_ge(Self::splat(2147483648.0)));
         let cast: i32x4 = cast(convert_to_i32_m128i_from_m128(non_nan.sse));
         flip_to_max ^ cast
       } else if #[cfg(target_feature="simd128")] {
@@ -964,7 +1020,7 @@ impl_simd_float! {
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
         cast(unsafe {Self { neon: vreinterpretq_f32_s32(vcvtnq_s32_f32(self.neon)) }})
       } else {
-        let rounded: [f32; 4] = cast(self.round());
+        let rounded: [f32; 4] = cast(self.round_ties_even());
         cast([
           rounded[0] as i32,
           rounded[1] as i32,
@@ -978,7 +1034,9 @@ impl_simd_float! {
   #[inline]
   pub fn fast_round_int(self) -> i32x4 {
     pick! {
-      if #[cfg(target_feature="sse2")] {
+      if #[cfg(feature="deterministic")] {
+        self.round_int()
+      } else if #[cfg(target_feature="sse2")] {
         cast(convert_to_i32_m128i_from_m128(self.sse))
       } else {
         self.round_int()
@@ -990,17 +1048,19 @@ impl_simd_float! {
   pub fn round_ties_even(self) -> Self {
     pick! {
       if #[cfg(target_feature="sse4.1")] {
-        Self { sse: round_m128::<{round_op!(Nearest)}>(self.sse) }
+        Self { sse: round_m128::<{round_op!(Nearest)}>(self.sse) }.canonicalize_nan()
       } else if #[cfg(target_feature="sse2")] {
         let mi: m128i = convert_to_i32_m128i_from_m128(self.sse);
         let f: f32x4 = f32x4 { sse: convert_to_m128_from_i32_m128i(mi) };
         let i: i32x4 = cast(mi);
-        let mask: f32x4 = cast(i.simd_eq(i32x4::from(0x80000000_u32 as i32)));
-        mask.select(self, f)
+        let mask = i.simd_eq(i32x4::from(0x80000000_u32 as i32));
+        // The conversion loses the sign of results that are zero.
+        let f = f | (self & Self::splat(-0.0));
+        mask.select(self, f).canonicalize_nan()
       } else if #[cfg(target_feature="simd128")] {
-        Self { simd: f32x4_nearest(self.simd) }
+        Self { simd: f32x4_nearest(self.simd) }.canonicalize_nan()
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        unsafe {Self { neon: vrndnq_f32(self.neon) }}
+        unsafe { Self { neon: vrndnq_f32(self.neon) }.canonicalize_nan() }
       } else {
         // Note(Lokathor): This software fallback is probably very slow compared
         // to having a hardware option available, even just the sse2 version is
@@ -1011,14 +1071,14 @@ impl_simd_float! {
         let mut y: f32x4;
 
         let no_op_magic = i32x4::from(0x7f + 23);
-        let no_op_mask: f32x4 = cast(e.simd_gt(no_op_magic) | e.simd_eq(no_op_magic));
+        let no_op_mask = e.simd_gt(no_op_magic) | e.simd_eq(no_op_magic);
         let no_op_val: f32x4 = self;
 
         let zero_magic = i32x4::from(0x7f - 1);
-        let zero_mask: f32x4 = cast(e.simd_lt(zero_magic));
+        let zero_mask = e.simd_lt(zero_magic);
         let zero_val: f32x4 = self * f32x4::from(0.0);
 
-        let neg_bit: f32x4 = cast(cast::<u32x4, i32x4>(u).simd_lt(i32x4::default()));
+        let neg_bit = cast::<u32x4, i32x4>(u).simd_lt(i32x4::default());
         let x: f32x4 = neg_bit.select(-self, self);
         y = x + to_int - to_int - x;
         y = y.simd_gt(f32x4::from(0.5)).select(
@@ -1027,7 +1087,7 @@ impl_simd_float! {
         );
         y = neg_bit.select(-y, y);
 
-        no_op_mask.select(no_op_val, zero_mask.select(zero_val, y))
+        no_op_mask.select(no_op_val, zero_mask.select(zero_val, y)).canonicalize_nan()
       }
     }
   }
@@ -1036,7 +1096,7 @@ impl_simd_float! {
   pub fn trunc(self) -> Self {
     pick! {
       if #[cfg(target_feature="sse4.1")] {
-        Self { sse: round_m128::<{round_op!(Zero)}>(self.sse) }
+        Self { sse: round_m128::<{round_op!(Zero)}>(self.sse) }.canonicalize_nan()
       } else if #[cfg(target_feature="sse2")] {
         // Ported from https://docs.rs/glam/latest/glam/f32/struct.Vec4.html#method.trunc
         // Based on https://github.com/microsoft/DirectXMath `XMVectorTruncate`
@@ -1049,11 +1109,11 @@ impl_simd_float! {
         ));
 
         // Reset the sign bit of the mask to preverse the sign of `self`.
-        bounds_mask.abs().select(result, self)
+        bounds_mask.abs().bitselect(result, self).canonicalize_nan()
       } else if #[cfg(target_feature="simd128")] {
-        Self { simd: f32x4_trunc(self.simd) }
+        Self { simd: f32x4_trunc(self.simd) }.canonicalize_nan()
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        unsafe { Self { neon: vrndq_f32(self.neon) } }
+        unsafe { Self { neon: vrndq_f32(self.neon) }.canonicalize_nan() }
       } else {
         let array: [f32; 4] = cast(self);
         let result: Self = cast([
@@ -1065,10 +1125,10 @@ impl_simd_float! {
 
         // Out of range values are either already round, infinite or NaN.
         const BOUNDS_LIMIT: i32 = 8388608_f32.to_bits() as i32;
-        let bounds_mask: Self = cast(cast::<f32x4, i32x4>(self.abs()).simd_lt(i32x4::splat(BOUNDS_LIMIT)));
+        let bounds_mask = Self::from(cast::<f32x4, i32x4>(self.abs()).simd_lt(i32x4::splat(BOUNDS_LIMIT)));
 
         // Reset the sign bit of the mask to preverse the sign of `self`.
-        bounds_mask.abs().select(result, self)
+        bounds_mask.abs().bitselect(result, self).canonicalize_nan()
       }
     }
   }
@@ -1079,8 +1139,8 @@ impl_simd_float! {
       if #[cfg(target_feature="sse2")] {
         // Based on: https://github.com/v8/v8/blob/210987a552a2bf2a854b0baa9588a5959ff3979d/src/codegen/shared-ia32-x64/macro-assembler-shared-ia32-x64.h#L489-L504
         let non_nan_mask = self.simd_eq(self);
-        let non_nan = self & non_nan_mask;
-        let flip_to_max: i32x4 = cast(self.simd_ge(Self::splat(2147483648.0)));
+        let non_nan = self & Self::from(non_nan_mask);
+        let flip_to_max = i32x4::from(self.simd_ge(Self::splat(2147483648.0)));
         let cast: i32x4 = cast(truncate_m128_to_m128i(non_nan.sse));
         flip_to_max ^ cast
       } else if #[cfg(target_feature="simd128")] {
@@ -1102,7 +1162,9 @@ impl_simd_float! {
   #[inline]
   pub fn fast_trunc_int(self) -> i32x4 {
     pick! {
-      if #[cfg(target_feature="sse2")] {
+      if #[cfg(feature="deterministic")] {
+        self.trunc_int()
+      } else if #[cfg(target_feature="sse2")] {
         cast(truncate_m128_to_m128i(self.sse))
       } else {
         self.trunc_int()
@@ -1117,10 +1179,13 @@ impl_simd_float! {
   ///   accuracy)
   /// - On ARM64 with NEON: Uses `vfmaq_f32` (single rounding, best accuracy)
   /// - Without FMA support: Uses `(self * m) + a` (two roundings)
+  /// - With the `deterministic` feature: Uses `(self * m) + a` (two roundings)
   #[inline]
   pub fn mul_add(self, a: Self, b: Self) -> Self {
     pick! {
-      if #[cfg(all(target_feature="sse2",target_feature="fma"))] {
+      if #[cfg(feature="deterministic")] {
+        (self * a) + b
+      } else if #[cfg(all(target_feature="sse2",target_feature="fma"))] {
         Self { sse: fused_mul_add_m128(self.sse, a.sse, b.sse) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))] {
         unsafe { Self { neon: vfmaq_f32(b.neon, self.neon, a.neon) } }
@@ -1138,10 +1203,13 @@ impl_simd_float! {
   /// - On ARM64 with NEON: Uses `vfmaq_f32(-s, self, m)` (single rounding, best
   ///   accuracy)
   /// - Without FMA support: Uses `(self * m) - s` (two roundings)
+  /// - With the `deterministic` feature: Uses `(self * m) - s` (two roundings)
   #[inline]
   pub fn mul_sub(self, a: Self, b: Self) -> Self {
     pick! {
-      if #[cfg(all(target_feature="sse2",target_feature="fma"))] {
+      if #[cfg(feature="deterministic")] {
+        (self * a) - b
+      } else if #[cfg(all(target_feature="sse2",target_feature="fma"))] {
         Self { sse: fused_mul_sub_m128(self.sse, a.sse, b.sse) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))] {
         unsafe { Self { neon: vfmaq_f32(vnegq_f32(b.neon), self.neon, a.neon) } }
@@ -1158,10 +1226,13 @@ impl_simd_float! {
   ///   accuracy)
   /// - On ARM64 with NEON: Uses `vfmsq_f32` (single rounding, best accuracy)
   /// - Without FMA support: Uses `a - (self * m)` (two roundings)
+  /// - With the `deterministic` feature: Uses `a - (self * m)` (two roundings)
   #[inline]
   pub fn mul_neg_add(self, a: Self, b: Self) -> Self {
     pick! {
-      if #[cfg(all(target_feature="sse2",target_feature="fma"))] {
+      if #[cfg(feature="deterministic")] {
+        b - (self * a)
+      } else if #[cfg(all(target_feature="sse2",target_feature="fma"))] {
         Self { sse: fused_mul_neg_add_m128(self.sse, a.sse, b.sse) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))] {
         unsafe { Self { neon: vfmsq_f32(b.neon, self.neon, a.neon) } }
@@ -1179,10 +1250,13 @@ impl_simd_float! {
   /// - On ARM64 with NEON: Uses `-(vfmaq_f32(s, self, m))` (single rounding,
   ///   best accuracy)
   /// - Without FMA support: Uses `-(self * m) - s` (two roundings)
+  /// - With the `deterministic` feature: Uses `-(self * m) - s` (two roundings)
   #[inline]
   pub fn mul_neg_sub(self, a: Self, b: Self) -> Self {
     pick! {
-      if #[cfg(all(target_feature="sse2",target_feature="fma"))] {
+      if #[cfg(feature="deterministic")] {
+        -(self * a) - b
+      } else if #[cfg(all(target_feature="sse2",target_feature="fma"))] {
         Self { sse: fused_mul_neg_sub_m128(self.sse, a.sse, b.sse) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))] {
         unsafe { Self { neon: vnegq_f32(vfmaq_f32(b.neon, self.neon, a.neon)) } }
@@ -1254,10 +1328,10 @@ impl_simd_float! {
     let ei = cast::<_, i32x4>(ee.round_int());
     let ej = cast::<_, i32x4>(ei + (cast::<_, i32x4>(z) >> 23));
 
-    let overflow = cast::<_, f32x4>(ej.simd_gt(i32x4::splat(0x0FF)))
-      | (ee.simd_gt(f32x4::splat(300.0)));
-    let underflow = cast::<_, f32x4>(ej.simd_lt(i32x4::splat(0x000)))
-      | (ee.simd_lt(f32x4::splat(-300.0)));
+    let overflow = ej.simd_gt(i32x4::splat(0x0FF))
+      | ee.simd_gt(f32x4::splat(300.0));
+    let underflow = ej.simd_lt(i32x4::splat(0x000))
+      | ee.simd_lt(f32x4::splat(-300.0));
 
     // Add exponent by integer addition
     let z = cast::<_, f32x4>(cast::<_, i32x4>(z) + (ei << 23));
@@ -1308,25 +1382,25 @@ impl_simd_float! {
   pub fn sqrt(self) -> Self {
     pick! {
       if #[cfg(target_feature="sse")] {
-        Self { sse: sqrt_m128(self.sse) }
+        Self { sse: sqrt_m128(self.sse) }.canonicalize_nan()
       } else if #[cfg(target_feature="simd128")] {
-        Self { simd: f32x4_sqrt(self.simd) }
+        Self { simd: f32x4_sqrt(self.simd) }.canonicalize_nan()
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        unsafe {Self { neon: vsqrtq_f32(self.neon) }}
+        unsafe { Self { neon: vsqrtq_f32(self.neon) }.canonicalize_nan() }
       } else if #[cfg(feature="std")] {
         Self { arr: [
           self.arr[0].sqrt(),
           self.arr[1].sqrt(),
           self.arr[2].sqrt(),
           self.arr[3].sqrt(),
-        ]}
+        ]}.canonicalize_nan()
       } else {
         Self { arr: [
           software_sqrt(self.arr[0] as f64) as f32,
           software_sqrt(self.arr[1] as f64) as f32,
           software_sqrt(self.arr[2] as f64) as f32,
           software_sqrt(self.arr[3] as f64) as f32,
-        ]}
+        ]}.canonicalize_nan()
       }
     }
   }
@@ -1431,6 +1505,55 @@ impl_simd_float! {
     result
   }
 
+  #[inline]
+  pub fn exp10(self) -> Self {
+    const_f32_as_f32x4!(P0, 1.0 / 2.0);
+    const_f32_as_f32x4!(P1, 1.0 / 6.0);
+    const_f32_as_f32x4!(P2, 1.0 / 24.0);
+    const_f32_as_f32x4!(P3, 1.0 / 120.0);
+    const_f32_as_f32x4!(P4, 1.0 / 720.0);
+    const_f32_as_f32x4!(P5, 1.0 / 5040.0);
+    // 10^x = 2^r·e^(y·ln(10)) with r = round(x·log2(10)), where y is reduced
+    // with log10(2) split into a 12-bit upper part, so that r·LOG10_2_HI is
+    // exact, and the remainder.
+    const_f32_as_f32x4!(LOG10_2_HI, 0.301025390625);
+    const_f32_as_f32x4!(LOG10_2_LO, 4.605039e-6);
+    // max_x = log10(f32::MAX), min_x is just below log10 of the smallest
+    // subnormal.
+    let max_x = f32x4::from(38.53184);
+    let min_x = f32x4::from(-45.01);
+    let finite = self.is_finite();
+    let neg_underflow = self.simd_lt(min_x) & finite;
+    if neg_underflow.all() {
+      return Self::ZERO;
+    }
+    let max_r = f32x4::from(127.0);
+    let r = (self * Self::LOG2_10).round_ties_even();
+    let big = r.simd_gt(max_r);
+    let r_safe = big.select(max_r, r);
+    let excess = r - max_r;
+    let excess = big.select(excess, Self::ZERO);
+    let scale = Self::vm_pow2n(excess);
+    let y = r.mul_neg_add(LOG10_2_HI, self);
+    let y = r.mul_neg_add(LOG10_2_LO, y);
+    let x = y * Self::LN_10;
+    let z = polynomial_5!(x, P0, P1, P2, P3, P4, P5);
+    let x2 = x * x;
+    let z = z.mul_add(x2, x);
+    let n2 = Self::vm_pow2n(r_safe);
+    let z = (z + Self::ONE) * scale * n2;
+    let nan_mask = self.is_nan();
+    let mut result = nan_mask.select(Self::nan_pow(), z);
+    let pos_overflow = self.simd_gt(max_x) & finite;
+    result = pos_overflow.select(Self::infinity(), result);
+    result = neg_underflow.select(Self::ZERO, result);
+    let pos_inf = !finite & !self.is_sign_negative() & !nan_mask;
+    result = pos_inf.select(Self::infinity(), result);
+    let neg_inf = !finite & self.is_sign_negative() & !nan_mask;
+    result = neg_inf.select(Self::ZERO, result);
+    result
+  }
+
   #[inline]
   pub fn ln(self) -> Self {
     const_f32_as_f32x4!(HALF, 0.5);
@@ -1627,11 +1750,11 @@ impl_simd_float! {
     let notbig = t.simd_le(Self::SQRT_2 + Self::ONE);
 
     let mut s = notbig.select(Self::FRAC_PI_4, Self::FRAC_PI_2);
-    s = notsmal & s;
+    s = Self::from(notsmal) & s;
 
-    let mut a = notbig & t;
+    let mut a = Self::from(notbig) & t;
     a = notsmal.select(a - Self::ONE, a);
-    let mut b = notbig & Self::ONE;
+    let mut b = Self::from(notbig) & Self::ONE;
     b = notsmal.select(b + t, b);
     let z = a / b;
 
@@ -1683,7 +1806,7 @@ impl_simd_float! {
 
     let a = notsmal.select(t - Self::ONE, t);
     let b = notsmal.select(t + Self::ONE, Self::ONE);
-    let s = notsmal & Self::FRAC_PI_4;
+    let s = Self::from(notsmal) & Self::FRAC_PI_4;
     let z = a / b;
 
     let zz = z * z;
@@ -1737,18 +1860,18 @@ impl_simd_float! {
 
     let swap = !(q & i32x4::from(1)).simd_eq(i32x4::from(0));
 
-    let mut overflow: f32x4 = cast(q.simd_gt(i32x4::from(0x2000000)));
+    let mut overflow = q.simd_gt(i32x4::from(0x2000000));
     overflow &= xa.is_finite();
     s = overflow.select(f32x4::from(0.0), s);
     c = overflow.select(f32x4::from(1.0), c);
 
     // calc sin
-    let mut sin1 = cast::<_, f32x4>(swap).select(c, s);
+    let mut sin1 = swap.select(c, s);
     let sign_sin: i32x4 = (q << 30) ^ cast::<_, i32x4>(self);
     sin1 = sin1.flip_signs(cast(sign_sin));
 
     // calc cos
-    let mut cos1 = cast::<_, f32x4>(swap).select(s, c);
+    let mut cos1 = swap.select(s, c);
     let sign_cos: i32x4 = ((q + i32x4::from(1)) & i32x4::from(2)) << 30;
     cos1 ^= cast::<_, f32x4>(sign_cos);
 
@@ -1932,6 +2055,169 @@ impl_simd_float! {
     let result = small.select(self, exp_based);
     large.select(Self::ONE.flip_signs(self), result)
   }
+
+  #[inline]
+  pub fn erf(self) -> Self {
+    let (erf, _) = self.erf_erfc();
+    erf
+  }
+
+  #[inline]
+  pub fn erfc(self) -> Self {
+    let (_, erfc) = self.erf_erfc();
+    erfc
+  }
+
+  #[inline]
+  pub fn ln_gamma(self) -> Self {
+    // Stirling's series from x = 6 up, otherwise ln(Γ(x)) is reduced to
+    // ln(Γ(1 + t)) for 0.75 <= x < 1.5 or to ln(Γ(2 + t)) with |t| <= 0.5 by
+    // the recurrence Γ(x + 1) = x·Γ(x). Negative numbers use the reflection
+    // formula Γ(x)·Γ(-x) = -π/(x·sin(πx)).
+    const_f32_as_f32x4!(LN_PI, 1.1447299);
+    const_f32_as_f32x4!(LN_SQRT_2PI_MINUS_HALF, 0.41893855);
+    let negative = self.simd_lt(Self::ZERO);
+    let x = self.abs();
+
+    let below_half = x.simd_lt(Self::HALF);
+    let below_1_5 = x.simd_lt(Self::from(1.5));
+    let large = x.simd_ge(Self::from(6.0));
+    let mut t = below_half.select(
+      x,
+      below_1_5
+        .select(x - Self::ONE, large.select(Self::ZERO, x - Self::from(2.0))),
+    );
+    let mut product = Self::ONE;
+    loop {
+      let reduce = t.simd_ge(Self::HALF);
+      if !reduce.any() {
+        break;
+      }
+      product = reduce.select(product * (t + Self::ONE), product);
+      t = reduce.select(t - Self::ONE, t);
+    }
+    let ln = (below_half | large).select(x, product).ln();
+    let below = t.ln_1p() + below_half.select(ln, Self::ZERO);
+    let small = Self::ln_gamma_2(t) + below_1_5.select(-below, ln);
+    // Near the zero at x = 1 the difference above loses a few bits.
+    let near_one = x.simd_ge(Self::from(0.75)) & below_1_5;
+    let small = if near_one.any() {
+      near_one.select(Self::ln_gamma_1(t), small)
+    } else {
+      small
+    };
+
+    // The -1 is folded into ln(x) to not overflow just below the largest
+    // finite result.
+    let w = Self::ONE / x;
+    let stirling = (x - Self::HALF).mul_add(
+      ln - Self::ONE,
+      Self::stirling_series(w).mul_add(w, LN_SQRT_2PI_MINUS_HALF),
+    );
+    let result = large.select(stirling, small);
+
+    if negative.any() {
+      // Γ(x) is about 1/x close to zero, where x·sin(πx) would underflow.
+      let sin = ((self - self.round()) * Self::PI).sin();
+      let reflected = LN_PI - ((sin * self).abs().ln() + result);
+      let reflected =
+        x.simd_lt(Self::from(1e-20)).select(-x.ln(), reflected);
+      let result = negative.select(reflected, result);
+      self.is_inf().select(Self::INFINITY, result)
+    } else {
+      self.is_inf().select(Self::INFINITY, result)
+    }
+  }
+
+  #[inline]
+  pub fn gamma(self) -> Self {
+    // Stirling's formula from x = 6 up, otherwise Γ(x) is reduced to
+    // Γ(2 + t) with |t| <= 0.5 by the recurrence Γ(x + 1) = x·Γ(x).
+    // Negative numbers use the reflection formula Γ(x)·Γ(-x) = -π/(x·sin(πx)).
+    const_f32_as_f32x4!(MAX_X, 35.040096);
+    const_f32_as_f32x4!(TINY, 2.9387359e-39); // 2^-128
+    const_f32_as_f32x4!(P_LN2, 66.54213); // 96·ln(2)
+    const_f32_as_f32x4!(P_LN2_LO, -1.8284681e-7); // 96·ln(2) - P_LN2
+    const_f32_as_f32x4!(SCALE_DOWN, 1.26217745e-29); // 2^-96
+    let negative = self.simd_lt(Self::ZERO);
+    let x = self.abs();
+
+    let below_half = x.simd_lt(Self::HALF);
+    let below_1_5 = x.simd_lt(Self::from(1.5));
+    let large = x.simd_ge(Self::from(6.0));
+    let mut t = below_half.select(
+      x,
+      below_1_5
+        .select(x - Self::ONE, large.select(Self::ZERO, x - Self::from(2.0))),
+    );
+    let mut product = Self::ONE;
+    loop {
+      let reduce = t.simd_ge(Self::HALF);
+      if !reduce.any() {
+        break;
+      }
+      product = reduce.select(product * (t + Self::ONE), product);
+      t = reduce.select(t - Self::ONE, t);
+    }
+    let divisor = below_half
+      .select(x * (x + Self::ONE), below_1_5.select(x, Self::ONE));
+    let small = Self::ln_gamma_2(t).exp() * product / divisor;
+
+    let (high, low) =
+      Self::stirling_exponent(large.select(x, Self::from(6.0)));
+    let power = high.exp();
+    let stirling = power.mul_add(low, power);
+    let stirling = x.simd_gt(MAX_X).select(Self::INFINITY, stirling);
+    let result = large.select(stirling, small);
+
+    let result = if negative.any() {
+      // 1/Γ(-x) comes straight from the exponent, as Γ(x) is subnormal
+      // where Γ(-x) overflows. Where it would be subnormal itself, it is
+      // scaled up by 2^P until the last multiplication so that it keeps its
+      // precision. Subtracting P·ln(2) rounded from the exponent is exact
+      // there, as the exponent is at most twice as large, and the rounding
+      // error goes into `low`.
+      let scaled = high.simd_gt(P_LN2);
+      let power = scaled.select(P_LN2 - high, -high).exp();
+      let low = scaled.select(low - P_LN2_LO, low);
+      let inverse =
+        large.select(power.mul_add(-low, power), Self::ONE / small);
+      let n = self.round();
+      let half_n = n * Self::HALF;
+      let sin = ((self - n) * Self::PI).sin();
+      let sin = half_n.simd_ne(half_n.floor()).select(-sin, sin);
+      let reflected = (-Self::PI / sin) * (inverse / self);
+      let reflected = scaled.select(reflected * SCALE_DOWN, reflected);
+      // Γ has poles at the negative integers and is NaN at -∞.
+      let reflected = self.simd_eq(n).select(Self::NAN, reflected);
+      negative.select(reflected, result)
+    } else {
+      result
+    };
+    // Γ(x) is about 1/x close to zero, which overflows up to TINY, and
+    // Γ(-0.0) is -∞, like 1/x.
+    x.simd_le(TINY).select(Self::INFINITY.flip_signs(self), result)
+  }
+
+  #[inline]
+  pub fn hypot(self, y: Self) -> Self {
+    // Scales by a power of two when the squares could overflow or underflow,
+    // so the square root of the fused x² + y² is off by at most one rounding.
+    const_f32_as_f32x4!(LARGE, 1.1529215e18); // 2^60
+    const_f32_as_f32x4!(SMALL, 8.6736174e-19); // 2^-60
+    const_f32_as_f32x4!(SCALE_DOWN, 8.4703295e-22); // 2^-70
+    const_f32_as_f32x4!(SCALE_UP, 1.2379400e27); // 2^90
+    let x_abs = self.abs();
+    let y_abs = y.abs();
+    let max = x_abs.fast_max(y_abs);
+    let scale = max
+      .simd_gt(LARGE)
+      .select(SCALE_DOWN, max.simd_lt(SMALL).select(SCALE_UP, Self::ONE));
+    let x_scaled = x_abs * scale;
+    let y_scaled = y_abs * scale;
+    let result = x_scaled.mul_add(x_scaled, y_scaled * y_scaled).sqrt() / scale;
+    (self.is_inf() | y.is_inf()).select(Self::INFINITY, result)
+  }
 }
 
 /// The following functionality exists only for [`f32x4`], or only for
@@ -1951,7 +2237,7 @@ impl f32x4 {
       let valid = self.simd_ge(f32x4::from(-149.0));
       let shift_f = self + f32x4::from(149.0);
       let mut shift_i = shift_f.trunc_int();
-      shift_i = cast::<_, i32x4>(valid).select(shift_i, i32x4::ZERO);
+      shift_i = valid.select(shift_i, i32x4::ZERO);
       let mantissa = i32x4::ONE << shift_i;
       let sub_result = cast::<_, f32x4>(mantissa);
       let sub_result = valid.select(sub_result, f32x4::ZERO);
@@ -1982,11 +2268,10 @@ impl f32x4 {
     cast::<_, f32x4>(t2)
   }
   #[inline]
-  fn is_zero_or_subnormal(self) -> Self {
+  fn is_zero_or_subnormal(self) -> m32x4 {
     let t = cast::<_, i32x4>(self);
     let t = t & i32x4::splat(0x7F800000);
-    let mask = t.simd_eq(i32x4::splat(0));
-    cast::<_, f32x4>(mask)
+    t.simd_eq(i32x4::splat(0))
   }
   #[inline]
   fn infinity() -> Self {
@@ -2001,6 +2286,236 @@ impl f32x4 {
     cast::<_, f32x4>(i32x4::splat(0x7FC00000 | 0x101 & 0x003FFFFF))
   }
 
+  // Returns `(erf(x), erfc(x))` with the rational approximations of fdlibm's
+  // s_erf.c (Sun Microsystems, https://www.netlib.org/fdlibm/), which split
+  // |x| into [0, 0.84375), [0.84375, 1.25) and [1.25, ∞).
+  #[inline]
+  fn erf_erfc(self) -> (Self, Self) {
+    const_f32_as_f32x4!(ERX, 0.8450629);
+    const_f32_as_f32x4!(PP0, 0.12837917);
+    const_f32_as_f32x4!(PP1, -0.3250421);
+    const_f32_as_f32x4!(PP2, -0.02848175);
+    const_f32_as_f32x4!(PP3, -0.0057702702);
+    const_f32_as_f32x4!(PP4, -2.3763017e-05);
+    const_f32_as_f32x4!(QQ1, 0.3979172);
+    const_f32_as_f32x4!(QQ2, 0.06502225);
+    const_f32_as_f32x4!(QQ3, 0.005081306);
+    const_f32_as_f32x4!(QQ4, 0.00013249474);
+    const_f32_as_f32x4!(QQ5, -3.9602282e-06);
+    const_f32_as_f32x4!(PA0, -0.0023621186);
+    const_f32_as_f32x4!(PA1, 0.4148561);
+    const_f32_as_f32x4!(PA2, -0.37220788);
+    const_f32_as_f32x4!(PA3, 0.31834662);
+    const_f32_as_f32x4!(PA4, -0.110894695);
+    const_f32_as_f32x4!(PA5, 0.035478305);
+    const_f32_as_f32x4!(PA6, -0.0021663755);
+    const_f32_as_f32x4!(QA1, 0.10642088);
+    const_f32_as_f32x4!(QA2, 0.54039794);
+    const_f32_as_f32x4!(QA3, 0.071828656);
+    const_f32_as_f32x4!(QA4, 0.12617122);
+    const_f32_as_f32x4!(QA5, 0.013637084);
+    const_f32_as_f32x4!(QA6, 0.0119845);
+    const_f32_as_f32x4!(RA0, -0.009864944);
+    const_f32_as_f32x4!(RA1, -0.69385856);
+    const_f32_as_f32x4!(RA2, -10.558626);
+    const_f32_as_f32x4!(RA3, -62.37533);
+    const_f32_as_f32x4!(RA4, -162.39667);
+    const_f32_as_f32x4!(RA5, -184.60509);
+    const_f32_as_f32x4!(RA6, -81.28744);
+    const_f32_as_f32x4!(RA7, -9.814329);
+    const_f32_as_f32x4!(SA1, 19.651272);
+    const_f32_as_f32x4!(SA2, 137.65776);
+    const_f32_as_f32x4!(SA3, 434.5659);
+    const_f32_as_f32x4!(SA4, 645.38727);
+    const_f32_as_f32x4!(SA5, 429.00815);
+    const_f32_as_f32x4!(SA6, 108.635);
+    const_f32_as_f32x4!(SA7, 6.5702496);
+    const_f32_as_f32x4!(SA8, -0.060424414);
+    const_f32_as_f32x4!(RB0, -0.009864943);
+    const_f32_as_f32x4!(RB1, -0.79928327);
+    const_f32_as_f32x4!(RB2, -17.757956);
+    const_f32_as_f32x4!(RB3, -160.63638);
+    const_f32_as_f32x4!(RB4, -637.56647);
+    const_f32_as_f32x4!(RB5, -1025.0951);
+    const_f32_as_f32x4!(RB6, -483.5192);
+    const_f32_as_f32x4!(SB1, 30.33806);
+    const_f32_as_f32x4!(SB2, 325.7925);
+    const_f32_as_f32x4!(SB3, 1536.7296);
+    const_f32_as_f32x4!(SB4, 3199.8582);
+    const_f32_as_f32x4!(SB5, 2553.0503);
+    const_f32_as_f32x4!(SB6, 474.52853);
+    const_f32_as_f32x4!(SB7, -22.440952);
+    let x = self.abs();
+    let negative = self.is_sign_negative();
+
+    // erf(x) = x + x·P(x²)/Q(x²)
+    let z = self * self;
+    let p = polynomial_4!(z, PP0, PP1, PP2, PP3, PP4);
+    let q = polynomial_5!(z, Self::ONE, QQ1, QQ2, QQ3, QQ4, QQ5);
+    let y = self * (p / q);
+    let erf_small = self + y;
+    let erfc_small = x
+      .simd_lt(Self::from(0.25))
+      .select(Self::ONE - erf_small, Self::HALF - (y + (self - Self::HALF)));
+
+    // erf(x) = erx + P(|x| - 1)/Q(|x| - 1)
+    let s = x - Self::ONE;
+    let p = polynomial_6!(s, PA0, PA1, PA2, PA3, PA4, PA5, PA6);
+    let q = polynomial_6!(s, Self::ONE, QA1, QA2, QA3, QA4, QA5, QA6);
+    let erf_medium = ERX + p / q;
+    let erfc_medium =
+      negative.select(Self::ONE + erf_medium, (Self::ONE - ERX) - p / q);
+    let erf_medium = erf_medium.flip_signs(self);
+
+    // erfc(x) = exp(-x² - 0.5625 + R(1/x²)/S(1/x²))/x, where -x² is split
+    // into -z² - (x - z)(x + z) with the upper bits z of x, whose square is
+    // exact. erfc(x) underflows to zero from x = 10.1 up.
+    let large = x.simd_ge(Self::from(1.25));
+    let (erf_large, erfc_large) = if large.any() {
+      let x = x.fast_min(Self::from(11.0));
+      let s = Self::ONE / (x * x);
+      let r = polynomial_7!(s, RA0, RA1, RA2, RA3, RA4, RA5, RA6, RA7);
+      let q =
+        polynomial_8!(s, Self::ONE, SA1, SA2, SA3, SA4, SA5, SA6, SA7, SA8);
+      let rs_near = r / q;
+      let r = polynomial_6!(s, RB0, RB1, RB2, RB3, RB4, RB5, RB6);
+      let q = polynomial_7!(s, Self::ONE, SB1, SB2, SB3, SB4, SB5, SB6, SB7);
+      let rs = x.simd_lt(Self::from(1.0 / 0.35)).select(rs_near, r / q);
+      let z = Self::from_bits(x.to_bits() & u32x4::splat(0xffff_e000));
+      let erfc = (-z).mul_sub(z, Self::from(0.5625)).exp()
+        * (z - x).mul_add(z + x, rs).exp()
+        / x;
+      (
+        (Self::ONE - erfc).flip_signs(self),
+        negative.select(Self::from(2.0) - erfc, erfc),
+      )
+    } else {
+      (Self::ZERO, Self::ZERO)
+    };
+
+    let small = x.simd_lt(Self::from(0.84375));
+    let erf = small.select(erf_small, large.select(erf_large, erf_medium));
+    let erfc = small.select(erfc_small, large.select(erfc_large, erfc_medium));
+    let nan = self.is_nan();
+    (nan.select(self, erf), nan.select(self, erfc))
+  }
+
+  // ln(Γ(1 + t)) for -0.25 <= t <= 0.5, from a Chebyshev interpolation of
+  // ln(Γ(1 + t))/t.
+  #[inline]
+  fn ln_gamma_1(t: Self) -> Self {
+    const_f32_as_f32x4!(C0, -0.5772157);
+    const_f32_as_f32x4!(C1, 0.822467);
+    const_f32_as_f32x4!(C2, -0.4006858);
+    const_f32_as_f32x4!(C3, 0.27058387);
+    const_f32_as_f32x4!(C4, -0.20737854);
+    const_f32_as_f32x4!(C5, 0.16940622);
+    const_f32_as_f32x4!(C6, -0.14396948);
+    const_f32_as_f32x4!(C7, 0.12816949);
+    const_f32_as_f32x4!(C8, -0.11751288);
+    const_f32_as_f32x4!(C9, 0.09014059);
+    const_f32_as_f32x4!(C10, -0.036314152);
+    let low = polynomial_7!(t, C0, C1, C2, C3, C4, C5, C6, C7);
+    let high = polynomial_2!(t, C8, C9, C10);
+    let t2 = t * t;
+    let t4 = t2 * t2;
+    t * (t4 * t4).mul_add(high, low)
+  }
+
+  // ln(Γ(2 + t)) for |t| <= 0.5, from a Chebyshev interpolation of
+  // ln(Γ(2 + t))/t, which keeps the relative error small around the zero.
+  #[inline]
+  fn ln_gamma_2(t: Self) -> Self {
+    const_f32_as_f32x4!(C0, 0.42278433);
+    const_f32_as_f32x4!(C1, 0.32246703);
+    const_f32_as_f32x4!(C2, -0.067352295);
+    const_f32_as_f32x4!(C3, 0.020581625);
+    const_f32_as_f32x4!(C4, -0.0073859207);
+    const_f32_as_f32x4!(C5, 0.0028788687);
+    const_f32_as_f32x4!(C6, -0.0011874848);
+    const_f32_as_f32x4!(C7, 0.00057025126);
+    const_f32_as_f32x4!(C8, -0.00025056204);
+    t * polynomial_8!(t, C0, C1, C2, C3, C4, C5, C6, C7, C8)
+  }
+
+  // The Stirling series ln(Γ(x)) - (x - 0.5)·ln(x) + x - ln(2π)/2 divided by
+  // w = 1/x, as a polynomial in w².
+  #[inline]
+  fn stirling_series(w: Self) -> Self {
+    const_f32_as_f32x4!(S0, 1.0 / 12.0);
+    const_f32_as_f32x4!(S1, -1.0 / 360.0);
+    const_f32_as_f32x4!(S2, 1.0 / 1260.0);
+    polynomial_2!(w * w, S0, S1, S2)
+  }
+
+  // ln(Γ(x)) for x >= 6 from Stirling's series as an unevaluated sum
+  // `high + low`. The exponent of Γ(x) = e^(high + low) needs about seven
+  // more bits than a plain `ln` provides, so ln(x) is the kernel of fdlibm's
+  // e_logf.c evaluated in double-double arithmetic.
+  #[inline]
+  fn stirling_exponent(x: Self) -> (Self, Self) {
+    const_f32_as_f32x4!(LN2_HI, 6.9313812256e-01);
+    const_f32_as_f32x4!(LN2_LO, 9.0580006145e-06);
+    const_f32_as_f32x4!(LG1, 6.6666662693e-01);
+    const_f32_as_f32x4!(LG2, 4.0000972152e-01);
+    const_f32_as_f32x4!(LG3, 2.8498786688e-01);
+    const_f32_as_f32x4!(LG4, 2.4279078841e-01);
+    const_f32_as_f32x4!(LN_SQRT_2PI, 0.9189385);
+
+    // x = 2^e·m with sqrt(0.5) <= m < sqrt(2), so ln(x) = e·ln(2) + ln(1 + f)
+    // where f = m - 1 is exact.
+    let m = x.fraction_2();
+    let e = x.exponent() + Self::ONE;
+    let below = m.simd_lt(Self::FRAC_1_SQRT_2);
+    let m = below.select(m + m, m);
+    let e = below.select(e - Self::ONE, e);
+    let f = m - Self::ONE;
+    let s = f / (Self::from(2.0) + f);
+    let z = s * s;
+    let r = z * polynomial_3!(z, LG1, LG2, LG3, LG4);
+    let (hfsq, hfsq_low) = Self::two_product(Self::HALF * f, f);
+    let (difference, difference_low) = Self::two_sum(f, -hfsq);
+    let (ln_m, ln_m_low) = Self::two_sum(difference, s * (hfsq + r));
+    let (ln_x, ln_x_low) = Self::two_sum(e * LN2_HI, ln_m);
+    let ln_x_low =
+      ln_x_low + ln_m_low + (difference_low - hfsq_low + e * LN2_LO);
+
+    let v = x - Self::HALF;
+    let (product, product_low) = Self::two_product(v, ln_x);
+    let (sum, sum_low) = Self::two_sum(product, -x);
+    let w = Self::ONE / x;
+    let series = Self::stirling_series(w).mul_add(w, LN_SQRT_2PI);
+    let (high, high_low) = Self::two_sum(sum, series);
+    let low = v.mul_add(ln_x_low, product_low) + sum_low + high_low;
+    Self::two_sum(high, low)
+  }
+
+  // The exact rounding error of `a + b`.
+  #[inline]
+  fn two_sum(a: Self, b: Self) -> (Self, Self) {
+    let sum = a + b;
+    let b_part = sum - a;
+    (sum, (a - (sum - b_part)) + (b - b_part))
+  }
+
+  // The exact rounding error of `a * b` by Dekker's algorithm, which doesn't
+  // rely on `mul_add` being fused.
+  #[inline]
+  fn two_product(a: Self, b: Self) -> (Self, Self) {
+    const_f32_as_f32x4!(SPLIT, 4097.0); // 2^12 + 1
+    let split = |v: Self| {
+      let c = v * SPLIT;
+      let high = c - (c - v);
+      (high, v - high)
+    };
+    let product = a * b;
+    let (a_high, a_low) = split(a);
+    let (b_high, b_low) = split(b);
+    let error = ((a_high * b_high - product) + a_high * b_low + a_low * b_high)
+      + a_low * b_low;
+    (product, error)
+  }
+
   /// Returns `[self[0], b[0], self[1], b[1]]`.
   #[must_use]
   #[inline]
@@ -2083,6 +2598,6 @@ impl f32x4 {
   #[must_use]
   #[deprecated(since = "1.4.0", note = "renamed to `is_sign_negative`")]
   pub fn sign_bit(self) -> Self {
-    self.is_sign_negative()
+    self.is_sign_negative().into()
   }
 }
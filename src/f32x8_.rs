@@ -36,6 +36,7 @@ impl_simd! {
     T = f32,
     N = 8,
     Simd = f32x8,
+    Mask = m32x8,
     optional_type_x86_inner { X86Inner = __m256 },
     optional_type_arm_inner {},
     optional_type_wasm_inner {},
@@ -48,8 +49,8 @@ impl_simd! {
         Self { avx: cmp_op_mask_m256::<{cmp_op!(EqualOrdered)}>(self.avx, rhs.avx) }
       } else {
         Self {
-          a : self.a.simd_eq(rhs.a),
-          b : self.b.simd_eq(rhs.b),
+          a : self.a.simd_eq(rhs.a).into(),
+          b : self.b.simd_eq(rhs.b).into(),
         }
       }
     }
@@ -62,8 +63,8 @@ impl_simd! {
         Self { avx: cmp_op_mask_m256::<{cmp_op!(NotEqualUnordered)}>(self.avx, rhs.avx) }
       } else {
         Self {
-          a : self.a.simd_ne(rhs.a),
-          b : self.b.simd_ne(rhs.b),
+          a : self.a.simd_ne(rhs.a).into(),
+          b : self.b.simd_ne(rhs.b).into(),
         }
       }
     }
@@ -76,8 +77,8 @@ impl_simd! {
         Self { avx: cmp_op_mask_m256::<{cmp_op!(LessThanOrdered)}>(self.avx, rhs.avx) }
       } else {
         Self {
-          a : self.a.simd_lt(rhs.a),
-          b : self.b.simd_lt(rhs.b),
+          a : self.a.simd_lt(rhs.a).into(),
+          b : self.b.simd_lt(rhs.b).into(),
         }
       }
     }
@@ -90,8 +91,8 @@ impl_simd! {
         Self { avx: cmp_op_mask_m256::<{cmp_op!(GreaterThanOrdered)}>(self.avx, rhs.avx) }
       } else {
         Self {
-          a : self.a.simd_gt(rhs.a),
-          b : self.b.simd_gt(rhs.b),
+          a : self.a.simd_gt(rhs.a).into(),
+          b : self.b.simd_gt(rhs.b).into(),
         }
       }
     }
@@ -104,8 +105,8 @@ impl_simd! {
         Self { avx: cmp_op_mask_m256::<{cmp_op!(LessEqualOrdered)}>(self.avx, rhs.avx) }
       } else {
         Self {
-          a : self.a.simd_le(rhs.a),
-          b : self.b.simd_le(rhs.b),
+          a : self.a.simd_le(rhs.a).into(),
+          b : self.b.simd_le(rhs.b).into(),
         }
       }
     }
@@ -118,8 +119,8 @@ impl_simd! {
         Self { avx: cmp_op_mask_m256::<{cmp_op!(GreaterEqualOrdered)}>(self.avx, rhs.avx) }
       } else {
         Self {
-          a : self.a.simd_ge(rhs.a),
-          b : self.b.simd_ge(rhs.b),
+          a : self.a.simd_ge(rhs.a).into(),
+          b : self.b.simd_ge(rhs.b).into(),
         }
       }
     }
@@ -271,8 +272,10 @@ impl_simd_float! {
     T = f32,
     N = 8,
     Simd = f32x8,
+    Mask = m32x8,
     UnsignedT = u32,
     UnsignedSimd = u32x8,
+    SignedSimd = i32x8,
   }
   old_powf_simd_fn_name = pow_f32x8,
 
@@ -308,7 +311,7 @@ impl_simd_float! {
   fn add(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx")] {
-        Self { avx: add_m256(self.avx, rhs.avx) }
+        Self { avx: add_m256(self.avx, rhs.avx) }.canonicalize_nan()
       } else {
         Self {
           a : self.a.add(rhs.a),
@@ -322,7 +325,7 @@ impl_simd_float! {
   fn sub(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx")] {
-        Self { avx: sub_m256(self.avx, rhs.avx) }
+        Self { avx: sub_m256(self.avx, rhs.avx) }.canonicalize_nan()
       } else {
         Self {
           a : self.a.sub(rhs.a),
@@ -336,7 +339,7 @@ impl_simd_float! {
   fn mul(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx")] {
-        Self { avx: mul_m256(self.avx, rhs.avx) }
+        Self { avx: mul_m256(self.avx, rhs.avx) }.canonicalize_nan()
       } else {
         Self {
           a : self.a.mul(rhs.a),
@@ -350,7 +353,7 @@ impl_simd_float! {
   fn div(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx")] {
-        Self { avx: div_m256(self.avx, rhs.avx) }
+        Self { avx: div_m256(self.avx, rhs.avx) }.canonicalize_nan()
       } else {
         Self {
           a : self.a.div(rhs.a),
@@ -459,61 +462,98 @@ impl_simd_float! {
   }
 
   #[inline]
-  pub fn is_nan(self) -> Self {
+  pub fn is_nan(self) -> m32x8 {
     pick! {
       if #[cfg(target_feature="avx")] {
-        Self { avx: cmp_op_mask_m256::<{cmp_op!(Unordered)}>(self.avx, self.avx) }
+        m32x8::from_simd_unchecked(Self { avx: cmp_op_mask_m256::<{cmp_op!(Unordered)}>(self.avx, self.avx) })
       } else {
-        Self {
-          a : self.a.is_nan(),
-          b : self.b.is_nan(),
-        }
+        m32x8::from_simd_unchecked(Self {
+          a : self.a.is_nan().into(),
+          b : self.b.is_nan().into(),
+        })
       }
     }
   }
 
   #[inline]
-  pub fn is_inf(self) -> Self {
+  pub fn is_inf(self) -> m32x8 {
     let shifted_inf = u32x8::from(0xFF000000);
     let u: u32x8 = cast(self);
     let shift_u = u << 1_u64;
     let out = (shift_u).simd_eq(shifted_inf);
-    cast(out)
+    out
   }
 
   #[inline]
-  pub fn is_finite(self) -> Self {
+  pub fn is_finite(self) -> m32x8 {
     let shifted_exp_mask = u32x8::from(0xFF000000);
     let u: u32x8 = cast(self);
     let shift_u = u << 1_u64;
     let out = !(shift_u & shifted_exp_mask).simd_eq(shifted_exp_mask);
-    cast(out)
+    out
   }
 
   #[inline]
-  pub fn is_sign_positive(self) -> Self {
+  pub fn is_sign_positive(self) -> m32x8 {
     const SIGN_MASK: u32x8 = u32x8::splat((-0.0_f32).to_bits());
 
     let bits = cast::<f32x8, u32x8>(self);
     let sign = bits & SIGN_MASK;
     let result = sign.simd_eq(u32x8::ZERO);
-    cast::<u32x8, f32x8>(result)
+    result
   }
 
   #[inline]
-  pub fn is_sign_negative(self) -> Self {
+  pub fn is_sign_negative(self) -> m32x8 {
     const SIGN_MASK: u32x8 = u32x8::splat((-0.0_f32).to_bits());
 
     let bits = cast::<f32x8, u32x8>(self);
     let sign = bits & SIGN_MASK;
     let result = sign.simd_eq(SIGN_MASK);
-    cast::<u32x8, f32x8>(result)
+    result
+  }
+
+  #[inline]
+  pub fn logb(self) -> Self {
+    pick! {
+      if #[cfg(all(target_feature="avx512f", target_feature="avx512vl"))] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm256_getexp_ps;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm256_getexp_ps;
+
+        // TODO(safe_arch): Add `_mm256_getexp_ps`.
+        Self { avx: m256(unsafe { _mm256_getexp_ps(self.avx.0) }) }.canonicalize_nan()
+      } else {
+        self.logb_portable()
+      }
+    }
+  }
+
+  #[inline]
+  pub fn ldexp(self, n: i32x8) -> Self {
+    pick! {
+      if #[cfg(all(target_feature="avx512f", target_feature="avx512vl"))] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm256_scalef_ps;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm256_scalef_ps;
+
+        let n = Self::exponent_to_float(n);
+        // TODO(safe_arch): Add `_mm256_scalef_ps`.
+        Self { avx: m256(unsafe { _mm256_scalef_ps(self.avx.0, n.avx.0) }) }.canonicalize_nan()
+      } else {
+        self.ldexp_portable(n)
+      }
+    }
   }
 
   #[inline]
   pub fn recip(self) -> Self {
     pick! {
-      if #[cfg(target_feature="avx")] {
+      if #[cfg(feature="deterministic")] {
+        Self::ONE / self
+      } else if #[cfg(target_feature="avx")] {
         Self { avx: reciprocal_m256(self.avx) }
       } else {
         Self {
@@ -527,7 +567,9 @@ impl_simd_float! {
   #[inline]
   pub fn recip_sqrt(self) -> Self {
     pick! {
-      if #[cfg(target_feature="avx")] {
+      if #[cfg(feature="deterministic")] {
+        Self::ONE / self.sqrt()
+      } else if #[cfg(target_feature="avx")] {
         Self { avx: reciprocal_sqrt_m256(self.avx) }
       } else {
         Self {
@@ -541,7 +583,9 @@ impl_simd_float! {
   #[inline]
   pub fn max(self, rhs: Self) -> Self {
     pick! {
-      if #[cfg(target_feature="avx")] {
+      if #[cfg(feature="deterministic")] {
+        self.is_nan().select(rhs, self.simd_lt(rhs).select(rhs, self))
+      } else if #[cfg(target_feature="avx")] {
         // max_m256 seems to do rhs < self ? self : rhs. So if there's any NaN
         // involved, it chooses rhs, so we need to specifically check rhs for
         // NaN.
@@ -559,7 +603,9 @@ impl_simd_float! {
   #[inline]
   pub fn fast_max(self, rhs: Self) -> Self {
     pick! {
-      if #[cfg(target_feature="avx")] {
+      if #[cfg(feature="deterministic")] {
+        self.simd_lt(rhs).select(rhs, self)
+      } else if #[cfg(target_feature="avx")] {
         Self { avx: max_m256(self.avx, rhs.avx) }
       } else {
         Self {
@@ -573,7 +619,9 @@ impl_simd_float! {
   #[inline]
   pub fn min(self, rhs: Self) -> Self {
     pick! {
-      if #[cfg(target_feature="avx")] {
+      if #[cfg(feature="deterministic")] {
+        self.is_nan().select(rhs, self.simd_gt(rhs).select(rhs, self))
+      } else if #[cfg(target_feature="avx")] {
         // min_m256 seems to do rhs > self ? self : rhs. So if there's any NaN
         // involved, it chooses rhs, so we need to specifically check rhs for
         // NaN.
@@ -590,7 +638,9 @@ impl_simd_float! {
   #[inline]
   pub fn fast_min(self, rhs: Self) -> Self {
     pick! {
-      if #[cfg(target_feature="avx")] {
+      if #[cfg(feature="deterministic")] {
+        self.simd_gt(rhs).select(rhs, self)
+      } else if #[cfg(target_feature="avx")] {
         Self { avx: min_m256(self.avx, rhs.avx) }
       } else {
         Self {
@@ -606,7 +656,7 @@ impl_simd_float! {
     pick! {
       if #[cfg(target_feature="avx")] {
         // This works since all bits set is NaN.
-        self.fast_clamp(min, max) | min.is_nan() | max.is_nan()
+        self.fast_clamp(min, max) | Self::from(min.is_nan() | max.is_nan())
       } else {
         // Some targets have better implementations than the above one.
         Self {
@@ -620,7 +670,10 @@ impl_simd_float! {
   #[inline]
   pub fn fast_clamp(self, min: Self, max: Self) -> Self {
     pick! {
-      if #[cfg(target_feature="avx")] {
+      if #[cfg(feature="deterministic")] {
+        let result = self.simd_gt(max).select(max, self);
+        result.simd_lt(min).select(min, result)
+      } else if #[cfg(target_feature="avx")] {
         // For both `min_m256` and `max_m256` if any input is NaN, `rhs` gets
         // chosen. For `self` to be chosen, `self` must be the second argument.
         Self { avx: max_m256(min.avx, min_m256(max.avx, self.avx)) }
@@ -652,7 +705,7 @@ impl_simd_float! {
   pub fn floor(self) -> Self {
     pick! {
       if #[cfg(target_feature="avx")] {
-        Self { avx: floor_m256(self.avx) }
+        Self { avx: floor_m256(self.avx) }.canonicalize_nan()
       } else {
         Self {
           a : self.a.floor(),
@@ -666,7 +719,7 @@ impl_simd_float! {
   pub fn ceil(self) -> Self {
     pick! {
       if #[cfg(target_feature="avx")] {
-        Self { avx: ceil_m256(self.avx) }
+        Self { avx: ceil_m256(self.avx) }.canonicalize_nan()
       } else {
         Self {
           a : self.a.ceil(),
@@ -689,13 +742,13 @@ impl_simd_float! {
         let result_abs = Self { avx: round_m256::<{round_op!(Zero)}>(adjusted_self.avx) };
         // The addition breaks for `0.5.next_down()` which incorrectly rounds to
         // `1.0`. This resets the result back to `0.0`.
-        let result_abs = result_abs & self_abs.simd_ne(HALF_NEXT_DOWN);
+        let result_abs = result_abs & Self::from(self_abs.simd_ne(HALF_NEXT_DOWN));
 
         // Large value, infinity and NaN need special handling.
         let bounds_mask: Self = cast(cmp_gt_mask_i32_m256i(cast(BOUNDS_LIMIT), cast(self_abs)));
 
         // `abs` keeps the original sign.
-        bounds_mask.abs().bitselect(result_abs, self)
+        bounds_mask.abs().bitselect(result_abs, self).canonicalize_nan()
       } else {
         let [a, b] = cast::<f32x8, [f32x4; 2]>(self);
         cast([a.round(), b.round()])
@@ -709,8 +762,8 @@ impl_simd_float! {
       if #[cfg(target_feature="avx")] {
         // Based on: https://github.com/v8/v8/blob/210987a552a2bf2a854b0baa9588a5959ff3979d/src/codegen/shared-ia32-x64/macro-assembler-shared-ia32-x64.h#L489-L504
         let non_nan_mask = self.simd_eq(self);
-        let non_nan = self & non_nan_mask;
-        let flip_to_max: i32x8 = cast(self.simd_ge(Self::splat(2147483648.0)));
+        let non_nan = self & Self::from(non_nan_mask);
+        let flip_to_max = i32x8::from(self.simd_ge(Self::splat(2147483648.0)));
         let cast: i32x8 = cast(convert_to_i32_m256i_from_m256(non_nan.avx));
         flip_to_max ^ cast
       } else {
@@ -725,7 +778,9 @@ impl_simd_float! {
   #[inline]
   pub fn fast_round_int(self) -> i32x8 {
     pick! {
-      if #[cfg(target_feature="avx")] {
+      if #[cfg(feature="deterministic")] {
+        self.round_int()
+      } else if #[cfg(target_feature="avx")] {
         cast(convert_to_i32_m256i_from_m256(self.avx))
       } else {
         cast([
@@ -740,7 +795,7 @@ impl_simd_float! {
     pick! {
       // NOTE: Is there an SSE2 version of this? f32x4 version probably translates but I've not had time to figure it out
       if #[cfg(target_feature="avx")] {
-        Self { avx: round_m256::<{round_op!(Nearest)}>(self.avx) }
+        Self { avx: round_m256::<{round_op!(Nearest)}>(self.avx) }.canonicalize_nan()
       } else {
         Self {
           a : self.a.round_ties_even(),
@@ -754,7 +809,7 @@ impl_simd_float! {
   pub fn trunc(self) -> Self {
     pick! {
       if #[cfg(target_feature="avx")] {
-        Self { avx: round_m256::<{round_op!(Zero)}>(self.avx) }
+        Self { avx: round_m256::<{round_op!(Zero)}>(self.avx) }.canonicalize_nan()
       } else {
         Self {
           a : self.a.trunc(),
@@ -770,8 +825,8 @@ impl_simd_float! {
         if #[cfg(target_feature="avx")] {
         // Based on: https://github.com/v8/v8/blob/210987a552a2bf2a854b0baa9588a5959ff3979d/src/codegen/shared-ia32-x64/macro-assembler-shared-ia32-x64.h#L489-L504
         let non_nan_mask = self.simd_eq(self);
-        let non_nan = self & non_nan_mask;
-        let flip_to_max: i32x8 = cast(self.simd_ge(Self::splat(2147483648.0)));
+        let non_nan = self & Self::from(non_nan_mask);
+        let flip_to_max = i32x8::from(self.simd_ge(Self::splat(2147483648.0)));
         let cast: i32x8 = cast(convert_truncate_to_i32_m256i_from_m256(non_nan.avx));
         flip_to_max ^ cast
       } else {
@@ -786,7 +841,9 @@ impl_simd_float! {
   #[inline]
   pub fn fast_trunc_int(self) -> i32x8 {
     pick! {
-      if #[cfg(all(target_feature="avx"))] {
+      if #[cfg(feature="deterministic")] {
+        self.trunc_int()
+      } else if #[cfg(all(target_feature="avx"))] {
         cast(convert_truncate_to_i32_m256i_from_m256(self.avx))
       } else {
         cast([
@@ -804,10 +861,13 @@ impl_simd_float! {
   ///   accuracy)
   /// - On `x86`/`x86_64` with AVX only: Uses `(self * m) + a` (two roundings)
   /// - Other platforms: Delegates to [`f32x4`] (may use NEON FMA or fallback)
+  /// - With the `deterministic` feature: Uses `(self * m) + a` (two roundings)
   #[inline]
   pub fn mul_add(self, a: Self, b: Self) -> Self {
     pick! {
-      if #[cfg(all(target_feature="avx",target_feature="fma"))] {
+      if #[cfg(feature="deterministic")] {
+        (self * a) + b
+      } else if #[cfg(all(target_feature="avx",target_feature="fma"))] {
         Self { avx: fused_mul_add_m256(self.avx, a.avx, b.avx) }
       } else if #[cfg(target_feature="avx")] {
         // still want to use 256 bit ops
@@ -828,10 +888,13 @@ impl_simd_float! {
   ///   accuracy)
   /// - On `x86`/`x86_64` with AVX only: Uses `(self * m) - s` (two roundings)
   /// - Other platforms: Delegates to [`f32x4`] (may use NEON FMA or fallback)
+  /// - With the `deterministic` feature: Uses `(self * m) - s` (two roundings)
   #[inline]
   pub fn mul_sub(self, a: Self, b: Self) -> Self {
     pick! {
-      if #[cfg(all(target_feature="avx",target_feature="fma"))] {
+      if #[cfg(feature="deterministic")] {
+        (self * a) - b
+      } else if #[cfg(all(target_feature="avx",target_feature="fma"))] {
         Self { avx: fused_mul_sub_m256(self.avx, a.avx, b.avx) }
       } else if #[cfg(target_feature="avx")] {
         // still want to use 256 bit ops
@@ -852,10 +915,13 @@ impl_simd_float! {
   ///   accuracy)
   /// - On `x86`/`x86_64` with AVX only: Uses `a - (self * m)` (two roundings)
   /// - Other platforms: Delegates to [`f32x4`] (may use NEON FMA or fallback)
+  /// - With the `deterministic` feature: Uses `a - (self * m)` (two roundings)
   #[inline]
   pub fn mul_neg_add(self, a: Self, b: Self) -> Self {
     pick! {
-      if #[cfg(all(target_feature="avx",target_feature="fma"))] {
+      if #[cfg(feature="deterministic")] {
+        b - (self * a)
+      } else if #[cfg(all(target_feature="avx",target_feature="fma"))] {
         Self { avx: fused_mul_neg_add_m256(self.avx, a.avx, b.avx) }
       } else if #[cfg(target_feature="avx")] {
         // still want to use 256 bit ops
@@ -876,10 +942,13 @@ impl_simd_float! {
   ///   accuracy)
   /// - On `x86`/`x86_64` with AVX only: Uses `-(self * m) - s` (two roundings)
   /// - Other platforms: Delegates to [`f32x4`] (may use NEON FMA or fallback)
+  /// - With the `deterministic` feature: Uses `-(self * m) - s` (two roundings)
   #[inline]
   pub fn mul_neg_sub(self, a: Self, b: Self) -> Self {
     pick! {
-      if #[cfg(all(target_feature="avx",target_feature="fma"))] {
+      if #[cfg(feature="deterministic")] {
+        -(self * a) - b
+      } else if #[cfg(all(target_feature="avx",target_feature="fma"))] {
         Self { avx: fused_mul_neg_sub_m256(self.avx, a.avx, b.avx) }
       } else if #[cfg(target_feature="avx")] {
         // still want to use 256 bit ops
@@ -953,10 +1022,10 @@ impl_simd_float! {
     let ei = cast::<_, i32x8>(ee.round_int());
     let ej = cast::<_, i32x8>(ei + (cast::<_, i32x8>(z) >> 23));
 
-    let overflow = cast::<_, f32x8>(ej.simd_gt(i32x8::splat(0x0FF)))
-      | (ee.simd_gt(f32x8::splat(300.0)));
-    let underflow = cast::<_, f32x8>(ej.simd_lt(i32x8::splat(0x000)))
-      | (ee.simd_lt(f32x8::splat(-300.0)));
+    let overflow = ej.simd_gt(i32x8::splat(0x0FF))
+      | ee.simd_gt(f32x8::splat(300.0));
+    let underflow = ej.simd_lt(i32x8::splat(0x000))
+      | ee.simd_lt(f32x8::splat(-300.0));
 
     // Add exponent by integer addition
     let z = cast::<_, f32x8>(cast::<_, i32x8>(z) + (ei << 23));
@@ -1004,7 +1073,7 @@ impl_simd_float! {
   pub fn sqrt(self) -> Self {
     pick! {
       if #[cfg(target_feature="avx")] {
-        Self { avx: sqrt_m256(self.avx) }
+        Self { avx: sqrt_m256(self.avx) }.canonicalize_nan()
       } else {
         Self {
           a : self.a.sqrt(),
@@ -1114,6 +1183,55 @@ impl_simd_float! {
     result
   }
 
+  #[inline]
+  pub fn exp10(self) -> Self {
+    const_f32_as_f32x8!(P0, 1.0 / 2.0);
+    const_f32_as_f32x8!(P1, 1.0 / 6.0);
+    const_f32_as_f32x8!(P2, 1.0 / 24.0);
+    const_f32_as_f32x8!(P3, 1.0 / 120.0);
+    const_f32_as_f32x8!(P4, 1.0 / 720.0);
+    const_f32_as_f32x8!(P5, 1.0 / 5040.0);
+    // 10^x = 2^r·e^(y·ln(10)) with r = round(x·log2(10)), where y is reduced
+    // with log10(2) split into a 12-bit upper part, so that r·LOG10_2_HI is
+    // exact, and the remainder.
+    const_f32_as_f32x8!(LOG10_2_HI, 0.301025390625);
+    const_f32_as_f32x8!(LOG10_2_LO, 4.605039e-6);
+    // max_x = log10(f32::MAX), min_x is just below log10 of the smallest
+    // subnormal.
+    let max_x = f32x8::from(38.53184);
+    let min_x = f32x8::from(-45.01);
+    let finite = self.is_finite();
+    let neg_underflow = self.simd_lt(min_x) & finite;
+    if neg_underflow.all() {
+      return Self::ZERO;
+    }
+    let max_r = f32x8::from(127.0);
+    let r = (self * Self::LOG2_10).round_ties_even();
+    let big = r.simd_gt(max_r);
+    let r_safe = big.select(max_r, r);
+    let excess = r - max_r;
+    let excess = big.select(excess, Self::ZERO);
+    let scale = Self::vm_pow2n(excess);
+    let y = r.mul_neg_add(LOG10_2_HI, self);
+    let y = r.mul_neg_add(LOG10_2_LO, y);
+    let x = y * Self::LN_10;
+    let z = polynomial_5!(x, P0, P1, P2, P3, P4, P5);
+    let x2 = x * x;
+    let z = z.mul_add(x2, x);
+    let n2 = Self::vm_pow2n(r_safe);
+    let z = (z + Self::ONE) * scale * n2;
+    let nan_mask = self.is_nan();
+    let mut result = nan_mask.select(Self::nan_pow(), z);
+    let pos_overflow = self.simd_gt(max_x) & finite;
+    result = pos_overflow.select(Self::infinity(), result);
+    result = neg_underflow.select(Self::ZERO, result);
+    let pos_inf = !finite & !self.is_sign_negative() & !nan_mask;
+    result = pos_inf.select(Self::infinity(), result);
+    let neg_inf = !finite & self.is_sign_negative() & !nan_mask;
+    result = neg_inf.select(Self::ZERO, result);
+    result
+  }
+
   #[inline]
   pub fn ln(self) -> Self {
     const_f32_as_f32x8!(HALF, 0.5);
@@ -1310,11 +1428,11 @@ impl_simd_float! {
     let notbig = t.simd_le(Self::SQRT_2 + Self::ONE);
 
     let mut s = notbig.select(Self::FRAC_PI_4, Self::FRAC_PI_2);
-    s = notsmal & s;
+    s = Self::from(notsmal) & s;
 
-    let mut a = notbig & t;
+    let mut a = Self::from(notbig) & t;
     a = notsmal.select(a - Self::ONE, a);
-    let mut b = notbig & Self::ONE;
+    let mut b = Self::from(notbig) & Self::ONE;
     b = notsmal.select(b + t, b);
     let z = a / b;
 
@@ -1366,7 +1484,7 @@ impl_simd_float! {
 
     let a = notsmal.select(t - Self::ONE, t);
     let b = notsmal.select(t + Self::ONE, Self::ONE);
-    let s = notsmal & Self::FRAC_PI_4;
+    let s = Self::from(notsmal) & Self::FRAC_PI_4;
     let z = a / b;
 
     let zz = z * z;
@@ -1420,18 +1538,18 @@ impl_simd_float! {
 
     let swap = !(q & i32x8::from(1)).simd_eq(i32x8::from(0));
 
-    let mut overflow: f32x8 = cast(q.simd_gt(i32x8::from(0x2000000)));
+    let mut overflow = q.simd_gt(i32x8::from(0x2000000));
     overflow &= xa.is_finite();
     s = overflow.select(f32x8::from(0.0), s);
     c = overflow.select(f32x8::from(1.0), c);
 
     // calc sin
-    let mut sin1 = cast::<_, f32x8>(swap).select(c, s);
+    let mut sin1 = swap.select(c, s);
     let sign_sin: i32x8 = (q << 30) ^ cast::<_, i32x8>(self);
     sin1 = sin1.flip_signs(cast(sign_sin));
 
     // calc cos
-    let mut cos1 = cast::<_, f32x8>(swap).select(s, c);
+    let mut cos1 = swap.select(s, c);
     let sign_cos: i32x8 = ((q + i32x8::from(1)) & i32x8::from(2)) << 30;
     cos1 ^= cast::<_, f32x8>(sign_cos);
 
@@ -1615,6 +1733,169 @@ impl_simd_float! {
     let result = small.select(self, exp_based);
     large.select(Self::ONE.flip_signs(self), result)
   }
+
+  #[inline]
+  pub fn erf(self) -> Self {
+    let (erf, _) = self.erf_erfc();
+    erf
+  }
+
+  #[inline]
+  pub fn erfc(self) -> Self {
+    let (_, erfc) = self.erf_erfc();
+    erfc
+  }
+
+  #[inline]
+  pub fn ln_gamma(self) -> Self {
+    // Stirling's series from x = 6 up, otherwise ln(Γ(x)) is reduced to
+    // ln(Γ(1 + t)) for 0.75 <= x < 1.5 or to ln(Γ(2 + t)) with |t| <= 0.5 by
+    // the recurrence Γ(x + 1) = x·Γ(x). Negative numbers use the reflection
+    // formula Γ(x)·Γ(-x) = -π/(x·sin(πx)).
+    const_f32_as_f32x8!(LN_PI, 1.1447299);
+    const_f32_as_f32x8!(LN_SQRT_2PI_MINUS_HALF, 0.41893855);
+    let negative = self.simd_lt(Self::ZERO);
+    let x = self.abs();
+
+    let below_half = x.simd_lt(Self::HALF);
+    let below_1_5 = x.simd_lt(Self::from(1.5));
+    let large = x.simd_ge(Self::from(6.0));
+    let mut t = below_half.select(
+      x,
+      below_1_5
+        .select(x - Self::ONE, large.select(Self::ZERO, x - Self::from(2.0))),
+    );
+    let mut product = Self::ONE;
+    loop {
+      let reduce = t.simd_ge(Self::HALF);
+      if !reduce.any() {
+        break;
+      }
+      product = reduce.select(product * (t + Self::ONE), product);
+      t = reduce.select(t - Self::ONE, t);
+    }
+    let ln = (below_half | large).select(x, product).ln();
+    let below = t.ln_1p() + below_half.select(ln, Self::ZERO);
+    let small = Self::ln_gamma_2(t) + below_1_5.select(-below, ln);
+    // Near the zero at x = 1 the difference above loses a few bits.
+    let near_one = x.simd_ge(Self::from(0.75)) & below_1_5;
+    let small = if near_one.any() {
+      near_one.select(Self::ln_gamma_1(t), small)
+    } else {
+      small
+    };
+
+    // The -1 is folded into ln(x) to not overflow just below the largest
+    // finite result.
+    let w = Self::ONE / x;
+    let stirling = (x - Self::HALF).mul_add(
+      ln - Self::ONE,
+      Self::stirling_series(w).mul_add(w, LN_SQRT_2PI_MINUS_HALF),
+    );
+    let result = large.select(stirling, small);
+
+    if negative.any() {
+      // Γ(x) is about 1/x close to zero, where x·sin(πx) would underflow.
+      let sin = ((self - self.round()) * Self::PI).sin();
+      let reflected = LN_PI - ((sin * self).abs().ln() + result);
+      let reflected =
+        x.simd_lt(Self::from(1e-20)).select(-x.ln(), reflected);
+      let result = negative.select(reflected, result);
+      self.is_inf().select(Self::INFINITY, result)
+    } else {
+      self.is_inf().select(Self::INFINITY, result)
+    }
+  }
+
+  #[inline]
+  pub fn gamma(self) -> Self {
+    // Stirling's formula from x = 6 up, otherwise Γ(x) is reduced to
+    // Γ(2 + t) with |t| <= 0.5 by the recurrence Γ(x + 1) = x·Γ(x).
+    // Negative numbers use the reflection formula Γ(x)·Γ(-x) = -π/(x·sin(πx)).
+    const_f32_as_f32x8!(MAX_X, 35.040096);
+    const_f32_as_f32x8!(TINY, 2.9387359e-39); // 2^-128
+    const_f32_as_f32x8!(P_LN2, 66.54213); // 96·ln(2)
+    const_f32_as_f32x8!(P_LN2_LO, -1.8284681e-7); // 96·ln(2) - P_LN2
+    const_f32_as_f32x8!(SCALE_DOWN, 1.26217745e-29); // 2^-96
+    let negative = self.simd_lt(Self::ZERO);
+    let x = self.abs();
+
+    let below_half = x.simd_lt(Self::HALF);
+    let below_1_5 = x.simd_lt(Self::from(1.5));
+    let large = x.simd_ge(Self::from(6.0));
+    let mut t = below_half.select(
+      x,
+      below_1_5
+        .select(x - Self::ONE, large.select(Self::ZERO, x - Self::from(2.0))),
+    );
+    let mut product = Self::ONE;
+    loop {
+      let reduce = t.simd_ge(Self::HALF);
+      if !reduce.any() {
+        break;
+      }
+      product = reduce.select(product * (t + Self::ONE), product);
+      t = reduce.select(t - Self::ONE, t);
+    }
+    let divisor = below_half
+      .select(x * (x + Self::ONE), below_1_5.select(x, Self::ONE));
+    let small = Self::ln_gamma_2(t).exp() * product / divisor;
+
+    let (high, low) =
+      Self::stirling_exponent(large.select(x, Self::from(6.0)));
+    let power = high.exp();
+    let stirling = power.mul_add(low, power);
+    let stirling = x.simd_gt(MAX_X).select(Self::INFINITY, stirling);
+    let result = large.select(stirling, small);
+
+    let result = if negative.any() {
+      // 1/Γ(-x) comes straight from the exponent, as Γ(x) is subnormal
+      // where Γ(-x) overflows. Where it would be subnormal itself, it is
+      // scaled up by 2^P until the last multiplication so that it keeps its
+      // precision. Subtracting P·ln(2) rounded from the exponent is exact
+      // there, as the exponent is at most twice as large, and the rounding
+      // error goes into `low`.
+      let scaled = high.simd_gt(P_LN2);
+      let power = scaled.select(P_LN2 - high, -high).exp();
+      let low = scaled.select(low - P_LN2_LO, low);
+      let inverse =
+        large.select(power.mul_add(-low, power), Self::ONE / small);
+      let n = self.round();
+      let half_n = n * Self::HALF;
+      let sin = ((self - n) * Self::PI).sin();
+      let sin = half_n.simd_ne(half_n.floor()).select(-sin, sin);
+      let reflected = (-Self::PI / sin) * (inverse / self);
+      let reflected = scaled.select(reflected * SCALE_DOWN, reflected);
+      // Γ has poles at the negative integers and is NaN at -∞.
+      let reflected = self.simd_eq(n).select(Self::NAN, reflected);
+      negative.select(reflected, result)
+    } else {
+      result
+    };
+    // Γ(x) is about 1/x close to zero, which overflows up to TINY, and
+    // Γ(-0.0) is -∞, like 1/x.
+    x.simd_le(TINY).select(Self::INFINITY.flip_signs(self), result)
+  }
+
+  #[inline]
+  pub fn hypot(self, y: Self) -> Self {
+    // Scales by a power of two when the squares could overflow or underflow,
+    // so the square root of the fused x² + y² is off by at most one rounding.
+    const_f32_as_f32x8!(LARGE, 1.1529215e18); // 2^60
+    const_f32_as_f32x8!(SMALL, 8.6736174e-19); // 2^-60
+    const_f32_as_f32x8!(SCALE_DOWN, 8.4703295e-22); // 2^-70
+    const_f32_as_f32x8!(SCALE_UP, 1.2379400e27); // 2^90
+    let x_abs = self.abs();
+    let y_abs = y.abs();
+    let max = x_abs.fast_max(y_abs);
+    let scale = max
+      .simd_gt(LARGE)
+      .select(SCALE_DOWN, max.simd_lt(SMALL).select(SCALE_UP, Self::ONE));
+    let x_scaled = x_abs * scale;
+    let y_scaled = y_abs * scale;
+    let result = x_scaled.mul_add(x_scaled, y_scaled * y_scaled).sqrt() / scale;
+    (self.is_inf() | y.is_inf()).select(Self::INFINITY, result)
+  }
 }
 
 /// The following functionality exists only for [`f32x8`], or only for
@@ -1634,7 +1915,7 @@ impl f32x8 {
       let valid = self.simd_ge(f32x8::from(-149.0));
       let shift_f = self + f32x8::from(149.0);
       let mut shift_i = shift_f.trunc_int();
-      shift_i = cast::<_, i32x8>(valid).select(shift_i, i32x8::ZERO);
+      shift_i = valid.select(shift_i, i32x8::ZERO);
       let mantissa = i32x8::ONE << shift_i;
       let sub_result = cast::<_, f32x8>(mantissa);
       let sub_result = valid.select(sub_result, f32x8::ZERO);
@@ -1665,11 +1946,10 @@ impl f32x8 {
     cast::<_, f32x8>(t2)
   }
   #[inline]
-  fn is_zero_or_subnormal(self) -> Self {
+  fn is_zero_or_subnormal(self) -> m32x8 {
     let t = cast::<_, i32x8>(self);
     let t = t & i32x8::splat(0x7F800000);
-    let mask = t.simd_eq(i32x8::splat(0));
-    cast::<_, f32x8>(mask)
+    t.simd_eq(i32x8::splat(0))
   }
   #[inline]
   fn infinity() -> Self {
@@ -1684,6 +1964,236 @@ impl f32x8 {
     cast::<_, f32x8>(i32x8::splat(0x7FC00000 | 0x101 & 0x003FFFFF))
   }
 
+  // Returns `(erf(x), erfc(x))` with the rational approximations of fdlibm's
+  // s_erf.c (Sun Microsystems, https://www.netlib.org/fdlibm/), which split
+  // |x| into [0, 0.84375), [0.84375, 1.25) and [1.25, ∞).
+  #[inline]
+  fn erf_erfc(self) -> (Self, Self) {
+    const_f32_as_f32x8!(ERX, 0.8450629);
+    const_f32_as_f32x8!(PP0, 0.12837917);
+    const_f32_as_f32x8!(PP1, -0.3250421);
+    const_f32_as_f32x8!(PP2, -0.02848175);
+    const_f32_as_f32x8!(PP3, -0.0057702702);
+    const_f32_as_f32x8!(PP4, -2.3763017e-05);
+    const_f32_as_f32x8!(QQ1, 0.3979172);
+    const_f32_as_f32x8!(QQ2, 0.06502225);
+    const_f32_as_f32x8!(QQ3, 0.005081306);
+    const_f32_as_f32x8!(QQ4, 0.00013249474);
+    const_f32_as_f32x8!(QQ5, -3.9602282e-06);
+    const_f32_as_f32x8!(PA0, -0.0023621186);
+    const_f32_as_f32x8!(PA1, 0.4148561);
+    const_f32_as_f32x8!(PA2, -0.37220788);
+    const_f32_as_f32x8!(PA3, 0.31834662);
+    const_f32_as_f32x8!(PA4, -0.110894695);
+    const_f32_as_f32x8!(PA5, 0.035478305);
+    const_f32_as_f32x8!(PA6, -0.0021663755);
+    const_f32_as_f32x8!(QA1, 0.10642088);
+    const_f32_as_f32x8!(QA2, 0.54039794);
+    const_f32_as_f32x8!(QA3, 0.071828656);
+    const_f32_as_f32x8!(QA4, 0.12617122);
+    const_f32_as_f32x8!(QA5, 0.013637084);
+    const_f32_as_f32x8!(QA6, 0.0119845);
+    const_f32_as_f32x8!(RA0, -0.009864944);
+    const_f32_as_f32x8!(RA1, -0.69385856);
+    const_f32_as_f32x8!(RA2, -10.558626);
+    const_f32_as_f32x8!(RA3, -62.37533);
+    const_f32_as_f32x8!(RA4, -162.39667);
+    const_f32_as_f32x8!(RA5, -184.60509);
+    const_f32_as_f32x8!(RA6, -81.28744);
+    const_f32_as_f32x8!(RA7, -9.814329);
+    const_f32_as_f32x8!(SA1, 19.651272);
+    const_f32_as_f32x8!(SA2, 137.65776);
+    const_f32_as_f32x8!(SA3, 434.5659);
+    const_f32_as_f32x8!(SA4, 645.38727);
+    const_f32_as_f32x8!(SA5, 429.00815);
+    const_f32_as_f32x8!(SA6, 108.635);
+    const_f32_as_f32x8!(SA7, 6.5702496);
+    const_f32_as_f32x8!(SA8, -0.060424414);
+    const_f32_as_f32x8!(RB0, -0.009864943);
+    const_f32_as_f32x8!(RB1, -0.79928327);
+    const_f32_as_f32x8!(RB2, -17.757956);
+    const_f32_as_f32x8!(RB3, -160.63638);
+    const_f32_as_f32x8!(RB4, -637.56647);
+    const_f32_as_f32x8!(RB5, -1025.0951);
+    const_f32_as_f32x8!(RB6, -483.5192);
+    const_f32_as_f32x8!(SB1, 30.33806);
+    const_f32_as_f32x8!(SB2, 325.7925);
+    const_f32_as_f32x8!(SB3, 1536.7296);
+    const_f32_as_f32x8!(SB4, 3199.8582);
+    const_f32_as_f32x8!(SB5, 2553.0503);
+    const_f32_as_f32x8!(SB6, 474.52853);
+    const_f32_as_f32x8!(SB7, -22.440952);
+    let x = self.abs();
+    let negative = self.is_sign_negative();
+
+    // erf(x) = x + x·P(x²)/Q(x²)
+    let z = self * self;
+    let p = polynomial_4!(z, PP0, PP1, PP2, PP3, PP4);
+    let q = polynomial_5!(z, Self::ONE, QQ1, QQ2, QQ3, QQ4, QQ5);
+    let y = self * (p / q);
+    let erf_small = self + y;
+    let erfc_small = x
+      .simd_lt(Self::from(0.25))
+      .select(Self::ONE - erf_small, Self::HALF - (y + (self - Self::HALF)));
+
+    // erf(x) = erx + P(|x| - 1)/Q(|x| - 1)
+    let s = x - Self::ONE;
+    let p = polynomial_6!(s, PA0, PA1, PA2, PA3, PA4, PA5, PA6);
+    let q = polynomial_6!(s, Self::ONE, QA1, QA2, QA3, QA4, QA5, QA6);
+    let erf_medium = ERX + p / q;
+    let erfc_medium =
+      negative.select(Self::ONE + erf_medium, (Self::ONE - ERX) - p / q);
+    let erf_medium = erf_medium.flip_signs(self);
+
+    // erfc(x) = exp(-x² - 0.5625 + R(1/x²)/S(1/x²))/x, where -x² is split
+    // into -z² - (x - z)(x + z) with the upper bits z of x, whose square is
+    // exact. erfc(x) underflows to zero from x = 10.1 up.
+    let large = x.simd_ge(Self::from(1.25));
+    let (erf_large, erfc_large) = if large.any() {
+      let x = x.fast_min(Self::from(11.0));
+      let s = Self::ONE / (x * x);
+      let r = polynomial_7!(s, RA0, RA1, RA2, RA3, RA4, RA5, RA6, RA7);
+      let q =
+        polynomial_8!(s, Self::ONE, SA1, SA2, SA3, SA4, SA5, SA6, SA7, SA8);
+      let rs_near = r / q;
+      let r = polynomial_6!(s, RB0, RB1, RB2, RB3, RB4, RB5, RB6);
+      let q = polynomial_7!(s, Self::ONE, SB1, SB2, SB3, SB4, SB5, SB6, SB7);
+      let rs = x.simd_lt(Self::from(1.0 / 0.35)).select(rs_near, r / q);
+      let z = Self::from_bits(x.to_bits() & u32x8::splat(0xffff_e000));
+      let erfc = (-z).mul_sub(z, Self::from(0.5625)).exp()
+        * (z - x).mul_add(z + x, rs).exp()
+        / x;
+      (
+        (Self::ONE - erfc).flip_signs(self),
+        negative.select(Self::from(2.0) - erfc, erfc),
+      )
+    } else {
+      (Self::ZERO, Self::ZERO)
+    };
+
+    let small = x.simd_lt(Self::from(0.84375));
+    let erf = small.select(erf_small, large.select(erf_large, erf_medium));
+    let erfc = small.select(erfc_small, large.select(erfc_large, erfc_medium));
+    let nan = self.is_nan();
+    (nan.select(self, erf), nan.select(self, erfc))
+  }
+
+  // ln(Γ(1 + t)) for -0.25 <= t <= 0.5, from a Chebyshev interpolation of
+  // ln(Γ(1 + t))/t.
+  #[inline]
+  fn ln_gamma_1(t: Self) -> Self {
+    const_f32_as_f32x8!(C0, -0.5772157);
+    const_f32_as_f32x8!(C1, 0.822467);
+    const_f32_as_f32x8!(C2, -0.4006858);
+    const_f32_as_f32x8!(C3, 0.27058387);
+    const_f32_as_f32x8!(C4, -0.20737854);
+    const_f32_as_f32x8!(C5, 0.16940622);
+    const_f32_as_f32x8!(C6, -0.14396948);
+    const_f32_as_f32x8!(C7, 0.12816949);
+    const_f32_as_f32x8!(C8, -0.11751288);
+    const_f32_as_f32x8!(C9, 0.09014059);
+    const_f32_as_f32x8!(C10, -0.036314152);
+    let low = polynomial_7!(t, C0, C1, C2, C3, C4, C5, C6, C7);
+    let high = polynomial_2!(t, C8, C9, C10);
+    let t2 = t * t;
+    let t4 = t2 * t2;
+    t * (t4 * t4).mul_add(high, low)
+  }
+
+  // ln(Γ(2 + t)) for |t| <= 0.5, from a Chebyshev interpolation of
+  // ln(Γ(2 + t))/t, which keeps the relative error small around the zero.
+  #[inline]
+  fn ln_gamma_2(t: Self) -> Self {
+    const_f32_as_f32x8!(C0, 0.42278433);
+    const_f32_as_f32x8!(C1, 0.32246703);
+    const_f32_as_f32x8!(C2, -0.067352295);
+    const_f32_as_f32x8!(C3, 0.020581625);
+    const_f32_as_f32x8!(C4, -0.0073859207);
+    const_f32_as_f32x8!(C5, 0.0028788687);
+    const_f32_as_f32x8!(C6, -0.0011874848);
+    const_f32_as_f32x8!(C7, 0.00057025126);
+    const_f32_as_f32x8!(C8, -0.00025056204);
+    t * polynomial_8!(t, C0, C1, C2, C3, C4, C5, C6, C7, C8)
+  }
+
+  // The Stirling series ln(Γ(x)) - (x - 0.5)·ln(x) + x - ln(2π)/2 divided by
+  // w = 1/x, as a polynomial in w².
+  #[inline]
+  fn stirling_series(w: Self) -> Self {
+    const_f32_as_f32x8!(S0, 1.0 / 12.0);
+    const_f32_as_f32x8!(S1, -1.0 / 360.0);
+    const_f32_as_f32x8!(S2, 1.0 / 1260.0);
+    polynomial_2!(w * w, S0, S1, S2)
+  }
+
+  // ln(Γ(x)) for x >= 6 from Stirling's series as an unevaluated sum
+  // `high + low`. The exponent of Γ(x) = e^(high + low) needs about seven
+  // more bits than a plain `ln` provides, so ln(x) is the kernel of fdlibm's
+  // e_logf.c evaluated in double-double arithmetic.
+  #[inline]
+  fn stirling_exponent(x: Self) -> (Self, Self) {
+    const_f32_as_f32x8!(LN2_HI, 6.9313812256e-01);
+    const_f32_as_f32x8!(LN2_LO, 9.0580006145e-06);
+    const_f32_as_f32x8!(LG1, 6.6666662693e-01);
+    const_f32_as_f32x8!(LG2, 4.0000972152e-01);
+    const_f32_as_f32x8!(LG3, 2.8498786688e-01);
+    const_f32_as_f32x8!(LG4, 2.4279078841e-01);
+    const_f32_as_f32x8!(LN_SQRT_2PI, 0.9189385);
+
+    // x = 2^e·m with sqrt(0.5) <= m < sqrt(2), so ln(x) = e·ln(2) + ln(1 + f)
+    // where f = m - 1 is exact.
+    let m = x.fraction_2();
+    let e = x.exponent() + Self::ONE;
+    let below = m.simd_lt(Self::FRAC_1_SQRT_2);
+    let m = below.select(m + m, m);
+    let e = below.select(e - Self::ONE, e);
+    let f = m - Self::ONE;
+    let s = f / (Self::from(2.0) + f);
+    let z = s * s;
+    let r = z * polynomial_3!(z, LG1, LG2, LG3, LG4);
+    let (hfsq, hfsq_low) = Self::two_product(Self::HALF * f, f);
+    let (difference, difference_low) = Self::two_sum(f, -hfsq);
+    let (ln_m, ln_m_low) = Self::two_sum(difference, s * (hfsq + r));
+    let (ln_x, ln_x_low) = Self::two_sum(e * LN2_HI, ln_m);
+    let ln_x_low =
+      ln_x_low + ln_m_low + (difference_low - hfsq_low + e * LN2_LO);
+
+    let v = x - Self::HALF;
+    let (product, product_low) = Self::two_product(v, ln_x);
+    let (sum, sum_low) = Self::two_sum(product, -x);
+    let w = Self::ONE / x;
+    let series = Self::stirling_series(w).mul_add(w, LN_SQRT_2PI);
+    let (high, high_low) = Self::two_sum(sum, series);
+    let low = v.mul_add(ln_x_low, product_low) + sum_low + high_low;
+    Self::two_sum(high, low)
+  }
+
+  // The exact rounding error of `a + b`.
+  #[inline]
+  fn two_sum(a: Self, b: Self) -> (Self, Self) {
+    let sum = a + b;
+    let b_part = sum - a;
+    (sum, (a - (sum - b_part)) + (b - b_part))
+  }
+
+  // The exact rounding error of `a * b` by Dekker's algorithm, which doesn't
+  // rely on `mul_add` being fused.
+  #[inline]
+  fn two_product(a: Self, b: Self) -> (Self, Self) {
+    const_f32_as_f32x8!(SPLIT, 4097.0); // 2^12 + 1
+    let split = |v: Self| {
+      let c = v * SPLIT;
+      let high = c - (c - v);
+      (high, v - high)
+    };
+    let product = a * b;
+    let (a_high, a_low) = split(a);
+    let (b_high, b_low) = split(b);
+    let error = ((a_high * b_high - product) + a_high * b_low + a_low * b_high)
+      + a_low * b_low;
+    (product, error)
+  }
+
   /// Converts each element from [`i32`] to [`f32`].
   #[inline]
   pub fn from_i32x8(v: i32x8) -> Self {
@@ -1718,6 +2228,6 @@ impl f32x8 {
   #[must_use]
   #[deprecated(since = "1.4.0", note = "renamed to `is_sign_negative`")]
   pub fn sign_bit(self) -> Self {
-    self.is_sign_negative()
+    self.is_sign_negative().into()
   }
 }
@@ -36,6 +36,7 @@ impl_simd! {
     T = f64,
     N = 8,
     Simd = f64x8,
+    Mask = m64x8,
     optional_type_x86_inner { X86Inner = __m512d },
     optional_type_arm_inner {},
     optional_type_wasm_inner {},
@@ -48,8 +49,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_m512d::<{cmp_op!(EqualOrdered)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_eq(rhs.a),
-          b : self.b.simd_eq(rhs.b),
+          a : self.a.simd_eq(rhs.a).into(),
+          b : self.b.simd_eq(rhs.b).into(),
         }
       }
     }
@@ -62,8 +63,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_m512d::<{cmp_op!(NotEqualUnordered)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_ne(rhs.a),
-          b : self.b.simd_ne(rhs.b),
+          a : self.a.simd_ne(rhs.a).into(),
+          b : self.b.simd_ne(rhs.b).into(),
         }
       }
     }
@@ -76,8 +77,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_m512d::<{cmp_op!(LessThanOrdered)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_lt(rhs.a),
-          b : self.b.simd_lt(rhs.b),
+          a : self.a.simd_lt(rhs.a).into(),
+          b : self.b.simd_lt(rhs.b).into(),
         }
       }
     }
@@ -90,8 +91,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_m512d::<{cmp_op!(GreaterThanOrdered)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_gt(rhs.a),
-          b : self.b.simd_gt(rhs.b),
+          a : self.a.simd_gt(rhs.a).into(),
+          b : self.b.simd_gt(rhs.b).into(),
         }
       }
     }
@@ -104,8 +105,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_m512d::<{cmp_op!(LessEqualOrdered)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_le(rhs.a),
-          b : self.b.simd_le(rhs.b),
+          a : self.a.simd_le(rhs.a).into(),
+          b : self.b.simd_le(rhs.b).into(),
         }
       }
     }
@@ -118,8 +119,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_m512d::<{cmp_op!(GreaterEqualOrdered)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_ge(rhs.a),
-          b : self.b.simd_ge(rhs.b),
+          a : self.a.simd_ge(rhs.a).into(),
+          b : self.b.simd_ge(rhs.b).into(),
         }
       }
     }
@@ -229,8 +230,10 @@ impl_simd_float! {
     T = f64,
     N = 8,
     Simd = f64x8,
+    Mask = m64x8,
     UnsignedT = u64,
     UnsignedSimd = u64x8,
+    SignedSimd = i64x8,
   }
   old_powf_simd_fn_name = pow_f64x8,
 
@@ -266,7 +269,7 @@ impl_simd_float! {
   fn add(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx512f")] {
-        Self { avx512: add_m512d(self.avx512, rhs.avx512) }
+        Self { avx512: add_m512d(self.avx512, rhs.avx512) }.canonicalize_nan()
       } else {
         Self {
           a : self.a.add(rhs.a),
@@ -280,7 +283,7 @@ impl_simd_float! {
   fn sub(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx512f")] {
-        Self { avx512: sub_m512d(self.avx512, rhs.avx512) }
+        Self { avx512: sub_m512d(self.avx512, rhs.avx512) }.canonicalize_nan()
       } else {
         Self {
           a : self.a.sub(rhs.a),
@@ -294,7 +297,7 @@ impl_simd_float! {
   fn mul(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx512f")] {
-        Self { avx512: mul_m512d(self.avx512, rhs.avx512) }
+        Self { avx512: mul_m512d(self.avx512, rhs.avx512) }.canonicalize_nan()
       } else {
         Self { a: self.a.mul(rhs.a), b: self.b.mul(rhs.b) }
       }
@@ -305,7 +308,7 @@ impl_simd_float! {
   fn div(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx512f")] {
-        Self { avx512: div_m512d(self.avx512, rhs.avx512) }
+        Self { avx512: div_m512d(self.avx512, rhs.avx512) }.canonicalize_nan()
       } else {
         Self { a: self.a.div(rhs.a), b: self.b.div(rhs.b) }
       }
@@ -408,55 +411,90 @@ impl_simd_float! {
   }
 
   #[inline]
-  pub fn is_nan(self) -> Self {
+  pub fn is_nan(self) -> m64x8 {
     pick! {
       if #[cfg(target_feature="avx512f")] {
-        Self { avx512: cmp_op_mask_m512d::<{cmp_op!(Unordered)}>(self.avx512, self.avx512) }
+        m64x8::from_simd_unchecked(Self { avx512: cmp_op_mask_m512d::<{cmp_op!(Unordered)}>(self.avx512, self.avx512) })
       } else {
-        Self {
-          a: self.a.is_nan(),
-          b: self.b.is_nan(),
-        }
+        m64x8::from_simd_unchecked(Self {
+          a: self.a.is_nan().into(),
+          b: self.b.is_nan().into(),
+        })
       }
     }
   }
 
   #[inline]
-  pub fn is_inf(self) -> Self {
+  pub fn is_inf(self) -> m64x8 {
     let shifted_inf = u64x8::from(0xFFE0000000000000);
     let u: u64x8 = cast(self);
     let shift_u = u << 1_u64;
     let out = (shift_u).simd_eq(shifted_inf);
-    cast(out)
+    out
   }
 
   #[inline]
-  pub fn is_finite(self) -> Self {
+  pub fn is_finite(self) -> m64x8 {
     let shifted_exp_mask = u64x8::splat(0xFFE0000000000000);
     let u: u64x8 = cast(self);
     let shift_u = u << 1_u64;
     let out = !(shift_u & shifted_exp_mask).simd_eq(shifted_exp_mask);
-    cast(out)
+    out
   }
 
   #[inline]
-  pub fn is_sign_positive(self) -> Self {
+  pub fn is_sign_positive(self) -> m64x8 {
     const SIGN_MASK: u64x8 = u64x8::splat((-0.0_f64).to_bits());
 
     let bits = cast::<f64x8, u64x8>(self);
     let sign = bits & SIGN_MASK;
     let result = sign.simd_eq(u64x8::ZERO);
-    cast::<u64x8, f64x8>(result)
+    result
   }
 
   #[inline]
-  pub fn is_sign_negative(self) -> Self {
+  pub fn is_sign_negative(self) -> m64x8 {
     const SIGN_MASK: u64x8 = u64x8::splat((-0.0_f64).to_bits());
 
     let bits = cast::<f64x8, u64x8>(self);
     let sign = bits & SIGN_MASK;
     let result = sign.simd_eq(SIGN_MASK);
-    cast::<u64x8, f64x8>(result)
+    result
+  }
+
+  #[inline]
+  pub fn logb(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512f")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm512_getexp_pd;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm512_getexp_pd;
+
+        // TODO(safe_arch): Add `_mm512_getexp_pd`.
+        Self { avx512: m512d(unsafe { _mm512_getexp_pd(self.avx512.0) }) }.canonicalize_nan()
+      } else {
+        self.logb_portable()
+      }
+    }
+  }
+
+  #[inline]
+  pub fn ldexp(self, n: i64x8) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512f")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm512_scalef_pd;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm512_scalef_pd;
+
+        let n = Self::exponent_to_float(n);
+        // TODO(safe_arch): Add `_mm512_scalef_pd`.
+        Self { avx512: m512d(unsafe { _mm512_scalef_pd(self.avx512.0, n.avx512.0) }) }.canonicalize_nan()
+      } else {
+        self.ldexp_portable(n)
+      }
+    }
   }
 
   #[inline]
@@ -476,7 +514,9 @@ impl_simd_float! {
   #[inline]
   pub fn max(self, rhs: Self) -> Self {
     pick! {
-      if #[cfg(target_feature="avx512f")] {
+      if #[cfg(feature="deterministic")] {
+        self.is_nan().select(rhs, self.simd_lt(rhs).select(rhs, self))
+      } else if #[cfg(target_feature="avx512f")] {
         rhs.is_nan().select(self, Self { avx512: max_m512d(self.avx512, rhs.avx512) })
       } else {
         Self {
@@ -490,7 +530,9 @@ impl_simd_float! {
   #[inline]
   pub fn fast_max(self, rhs: Self) -> Self {
     pick! {
-      if #[cfg(target_feature="avx512f")] {
+      if #[cfg(feature="deterministic")] {
+        self.simd_lt(rhs).select(rhs, self)
+      } else if #[cfg(target_feature="avx512f")] {
         Self { avx512: max_m512d(self.avx512, rhs.avx512) }
       } else {
         Self {
@@ -504,7 +546,9 @@ impl_simd_float! {
   #[inline]
   pub fn min(self, rhs: Self) -> Self {
     pick! {
-      if #[cfg(target_feature="avx512f")] {
+      if #[cfg(feature="deterministic")] {
+        self.is_nan().select(rhs, self.simd_gt(rhs).select(rhs, self))
+      } else if #[cfg(target_feature="avx512f")] {
         rhs.is_nan().select(self, Self { avx512: min_m512d(self.avx512, rhs.avx512) })
       } else {
         Self {
@@ -518,7 +562,9 @@ impl_simd_float! {
   #[inline]
   pub fn fast_min(self, rhs: Self) -> Self {
     pick! {
-      if #[cfg(target_feature="avx512f")] {
+      if #[cfg(feature="deterministic")] {
+        self.simd_gt(rhs).select(rhs, self)
+      } else if #[cfg(target_feature="avx512f")] {
         Self { avx512: min_m512d(self.avx512, rhs.avx512) }
       } else {
         Self {
@@ -534,7 +580,7 @@ impl_simd_float! {
     pick! {
       if #[cfg(target_feature="avx512f")] {
         // This works since all bits set is NaN.
-        self.fast_clamp(min, max) | min.is_nan() | max.is_nan()
+        self.fast_clamp(min, max) | Self::from(min.is_nan() | max.is_nan())
       } else {
         // Some targets have better implementations than the above one.
         Self {
@@ -548,7 +594,10 @@ impl_simd_float! {
   #[inline]
   pub fn fast_clamp(self, min: Self, max: Self) -> Self {
     pick! {
-      if #[cfg(target_feature="avx512f")] {
+      if #[cfg(feature="deterministic")] {
+        let result = self.simd_gt(max).select(max, self);
+        result.simd_lt(min).select(min, result)
+      } else if #[cfg(target_feature="avx512f")] {
         // For both `min_m512d` and `max_m512d` if any input is NaN, `rhs` gets
         // chosen. For `self` to be chosen, `self` must be the second argument.
         Self { avx512: max_m512d(min.avx512, min_m512d(max.avx512, self.avx512)) }
@@ -580,7 +629,7 @@ impl_simd_float! {
   pub fn floor(self) -> Self {
     pick! {
       if #[cfg(target_feature="avx512f")] {
-        Self { avx512: round_m512d::<{round_op!(NegInf)}>(self.avx512) }
+        Self { avx512: round_m512d::<{round_op!(NegInf)}>(self.avx512) }.canonicalize_nan()
       } else {
         Self {
           a : self.a.floor(),
@@ -594,7 +643,7 @@ impl_simd_float! {
   pub fn ceil(self) -> Self {
     pick! {
       if #[cfg(target_feature="avx512f")] {
-        Self { avx512: round_m512d::<{round_op!(PosInf)}>(self.avx512) }
+        Self { avx512: round_m512d::<{round_op!(PosInf)}>(self.avx512) }.canonicalize_nan()
       } else {
         Self {
           a : self.a.ceil(),
@@ -617,7 +666,7 @@ impl_simd_float! {
         let result_abs = Self { avx512: round_m512d::<{round_op!(Zero)}>(adjusted_self.avx512) };
         // The addition breaks for `0.5.next_down()` which incorrectly rounds to
         // `1.0`. This resets the result back to `0.0`.
-        let result_abs = result_abs & self_abs.simd_ne(HALF_NEXT_DOWN);
+        let result_abs = result_abs & Self::from(self_abs.simd_ne(HALF_NEXT_DOWN));
 
         // Large value, infinity and NaN need special handling.
         let bounds_mask: Self = cast(cmp_op_mask_i64_m512i::<{cmp_int_op!(Lt)}>(
@@ -626,7 +675,7 @@ impl_simd_float! {
         ));
 
         // `abs` keeps the original sign.
-        bounds_mask.abs().bitselect(result_abs, self)
+        bounds_mask.abs().bitselect(result_abs, self).canonicalize_nan()
       } else {
         Self {
           a: self.a.round(),
@@ -647,8 +696,8 @@ impl_simd_float! {
 
         // Based on: https://github.com/v8/v8/blob/210987a552a2bf2a854b0baa9588a5959ff3979d/src/codegen/shared-ia32-x64/macro-assembler-shared-ia32-x64.h#L489-L504
         let non_nan_mask = self.simd_eq(self);
-        let non_nan = self & non_nan_mask;
-        let flip_to_max: i64x8 = cast(self.simd_ge(Self::splat(9223372036854775808.0)));
+        let non_nan = self & Self::from(non_nan_mask);
+        let flip_to_max = i64x8::from(self.simd_ge(Self::splat(9223372036854775808.0)));
 
         // TODO(safe_arch): Add `_mm512_cvtpd_epi64`.
         let cast: i64x8 = cast(m512i(unsafe { _mm512_cvtpd_epi64(non_nan.avx512.0) }));
@@ -665,7 +714,9 @@ impl_simd_float! {
   #[inline]
   pub fn fast_round_int(self) -> i64x8 {
     pick! {
-      if #[cfg(target_feature="avx512dq")] {
+      if #[cfg(feature="deterministic")] {
+        self.round_int()
+      } else if #[cfg(target_feature="avx512dq")] {
         #[cfg(target_arch = "x86")]
         use core::arch::x86::_mm512_cvtpd_epi64;
         #[cfg(target_arch = "x86_64")]
@@ -686,7 +737,7 @@ impl_simd_float! {
   pub fn round_ties_even(self) -> Self {
     pick! {
       if #[cfg(target_feature="avx512f")] {
-        Self { avx512: round_m512d::<{round_op!(Nearest)}>(self.avx512) }
+        Self { avx512: round_m512d::<{round_op!(Nearest)}>(self.avx512) }.canonicalize_nan()
       } else {
         Self {
           a: self.a.round_ties_even(),
@@ -700,7 +751,7 @@ impl_simd_float! {
   pub fn trunc(self) -> Self {
     pick! {
       if #[cfg(target_feature="avx512f")] {
-        Self { avx512: round_m512d::<{round_op!(Zero)}>(self.avx512) }
+        Self { avx512: round_m512d::<{round_op!(Zero)}>(self.avx512) }.canonicalize_nan()
       } else {
         Self {
           a: self.a.trunc(),
@@ -721,8 +772,8 @@ impl_simd_float! {
 
         // Based on: https://github.com/v8/v8/blob/210987a552a2bf2a854b0baa9588a5959ff3979d/src/codegen/shared-ia32-x64/macro-assembler-shared-ia32-x64.h#L489-L504
         let non_nan_mask = self.simd_eq(self);
-        let non_nan = self & non_nan_mask;
-        let flip_to_max: i64x8 = cast(self.simd_ge(Self::splat(9223372036854775808.0)));
+        let non_nan = self & Self::from(non_nan_mask);
+        let flip_to_max = i64x8::from(self.simd_ge(Self::splat(9223372036854775808.0)));
 
         // TODO(safe_arch): Add `_mm512_cvttpd_epi64`.
         let cast: i64x8 = cast(m512i(unsafe { _mm512_cvttpd_epi64(non_nan.avx512.0) }));
@@ -739,7 +790,9 @@ impl_simd_float! {
   #[inline]
   pub fn fast_trunc_int(self) -> i64x8 {
     pick! {
-      if #[cfg(target_feature="avx512dq")] {
+      if #[cfg(feature="deterministic")] {
+        self.trunc_int()
+      } else if #[cfg(target_feature="avx512dq")] {
         #[cfg(target_arch = "x86")]
         use core::arch::x86::_mm512_cvttpd_epi64;
         #[cfg(target_arch = "x86_64")]
@@ -764,10 +817,13 @@ impl_simd_float! {
   /// - On `x86`/`x86_64` with AVX-512F only: Uses `(self * m) + a` (two
   ///   roundings)
   /// - Other platforms: Delegates to [`f64x4`] (inherits its FMA behavior)
+  /// - With the `deterministic` feature: Uses `(self * m) + a` (two roundings)
   #[inline]
   pub fn mul_add(self, a: Self, b: Self) -> Self {
     pick! {
-      if #[cfg(all(target_feature="avx512f",target_feature="fma"))] {
+      if #[cfg(feature="deterministic")] {
+        (self * a) + b
+      } else if #[cfg(all(target_feature="avx512f",target_feature="fma"))] {
         Self { avx512: fused_mul_add_m512d(self.avx512, a.avx512, b.avx512) }
       } else if #[cfg(target_feature="avx512f")] {
         // still want to use 512 bit ops
@@ -789,10 +845,13 @@ impl_simd_float! {
   /// - On `x86`/`x86_64` with AVX-512F only: Uses `(self * m) - s` (two
   ///   roundings)
   /// - Other platforms: Delegates to [`f64x4`] (inherits its FMA behavior)
+  /// - With the `deterministic` feature: Uses `(self * m) - s` (two roundings)
   #[inline]
   pub fn mul_sub(self, a: Self, b: Self) -> Self {
     pick! {
-      if #[cfg(all(target_feature="avx512f",target_feature="fma"))] {
+      if #[cfg(feature="deterministic")] {
+        (self * a) - b
+      } else if #[cfg(all(target_feature="avx512f",target_feature="fma"))] {
         Self { avx512: fused_mul_sub_m512d(self.avx512, a.avx512, b.avx512) }
       } else if #[cfg(target_feature="avx512f")] {
         // still want to use 512 bit ops
@@ -814,10 +873,13 @@ impl_simd_float! {
   /// - On `x86`/`x86_64` with AVX-512F only: Uses `a - (self * m)` (two
   ///   roundings)
   /// - Other platforms: Delegates to [`f64x4`] (inherits its FMA behavior)
+  /// - With the `deterministic` feature: Uses `a - (self * m)` (two roundings)
   #[inline]
   pub fn mul_neg_add(self, a: Self, b: Self) -> Self {
     pick! {
-      if #[cfg(all(target_feature="avx512f",target_feature="fma"))] {
+      if #[cfg(feature="deterministic")] {
+        b - (self * a)
+      } else if #[cfg(all(target_feature="avx512f",target_feature="fma"))] {
         Self { avx512: fused_mul_neg_add_m512d(self.avx512, a.avx512, b.avx512) }
       } else if #[cfg(target_feature="avx512f")] {
         // still want to use 512 bit ops
@@ -839,10 +901,13 @@ impl_simd_float! {
   /// - On `x86`/`x86_64` with AVX-512F only: Uses `-(self * m) - s` (two
   ///   roundings)
   /// - Other platforms: Delegates to [`f64x4`] (inherits its FMA behavior)
+  /// - With the `deterministic` feature: Uses `-(self * m) - s` (two roundings)
   #[inline]
   pub fn mul_neg_sub(self, a: Self, b: Self) -> Self {
     pick! {
-       if #[cfg(all(target_feature="avx512f",target_feature="fma"))] {
+       if #[cfg(feature="deterministic")] {
+         -(self * a) - b
+       } else if #[cfg(all(target_feature="avx512f",target_feature="fma"))] {
          Self { avx512: fused_mul_neg_sub_m512d(self.avx512, a.avx512, b.avx512) }
         } else if #[cfg(target_feature="avx512f")] {
           // still want to use 512 bit ops
@@ -923,9 +988,9 @@ impl_simd_float! {
     let ei = cast::<_, i64x8>(ee.round_int());
     let ej = cast::<_, i64x8>(ei + (cast::<_, i64x8>(z) >> 52));
 
-    let overflow = cast::<_, f64x8>(!ej.simd_lt(i64x8::splat(0x07FF)))
+    let overflow = !ej.simd_lt(i64x8::splat(0x07FF))
       | ee.simd_gt(f64x8::splat(3000.0));
-    let underflow = cast::<_, f64x8>(!ej.simd_gt(i64x8::splat(0x000)))
+    let underflow = !ej.simd_gt(i64x8::splat(0x000))
       | ee.simd_lt(f64x8::splat(-3000.0));
 
     // Add exponent by integer addition
@@ -979,7 +1044,7 @@ impl_simd_float! {
   pub fn sqrt(self) -> Self {
     pick! {
       if #[cfg(target_feature="avx512f")] {
-        Self { avx512: sqrt_m512d(self.avx512) }
+        Self { avx512: sqrt_m512d(self.avx512) }.canonicalize_nan()
       } else {
         Self {
           a : self.a.sqrt(),
@@ -1093,6 +1158,60 @@ impl_simd_float! {
     result
   }
 
+  #[inline]
+  pub fn exp10(self) -> Self {
+    const_f64_as_f64x8!(P2, 1.0 / 2.0);
+    const_f64_as_f64x8!(P3, 1.0 / 6.0);
+    const_f64_as_f64x8!(P4, 1.0 / 24.0);
+    const_f64_as_f64x8!(P5, 1.0 / 120.0);
+    const_f64_as_f64x8!(P6, 1.0 / 720.0);
+    const_f64_as_f64x8!(P7, 1.0 / 5040.0);
+    const_f64_as_f64x8!(P8, 1.0 / 40320.0);
+    const_f64_as_f64x8!(P9, 1.0 / 362880.0);
+    const_f64_as_f64x8!(P10, 1.0 / 3628800.0);
+    const_f64_as_f64x8!(P11, 1.0 / 39916800.0);
+    const_f64_as_f64x8!(P12, 1.0 / 479001600.0);
+    const_f64_as_f64x8!(P13, 1.0 / 6227020800.0);
+    // 10^x = 2^r·e^(y·ln(10)) with r = round(x·log2(10)), where y is reduced
+    // with the split of log10(2) from fdlibm's e_log10.c. The upper part has
+    // enough trailing zeros that r·LOG10_2_HI is exact.
+    const_f64_as_f64x8!(LOG10_2_HI, 3.01029995663611771306e-01);
+    const_f64_as_f64x8!(LOG10_2_LO, 3.69423907715893078616e-13);
+    // max_x = log10(f64::MAX), min_x is just below log10 of the smallest
+    // subnormal.
+    let max_x = f64x8::from(308.2547155599167);
+    let min_x = f64x8::from(-323.61);
+    let finite = self.is_finite();
+    let neg_underflow = self.simd_lt(min_x) & finite;
+    if neg_underflow.all() {
+      return Self::ZERO;
+    }
+    let max_r = f64x8::from(1023.0);
+    let r = (self * Self::LOG2_10).round_ties_even();
+    let big = r.simd_gt(max_r);
+    let r_safe = big.select(max_r, r);
+    let excess = r - max_r;
+    let excess = big.select(excess, Self::ZERO);
+    let scale = Self::vm_pow2n(excess);
+    let y = r.mul_neg_add(LOG10_2_HI, self);
+    let y = r.mul_neg_add(LOG10_2_LO, y);
+    let x = y * Self::LN_10;
+    let z =
+      polynomial_13!(x, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11, P12, P13);
+    let n2 = Self::vm_pow2n(r_safe);
+    let z = (z + Self::ONE) * scale * n2;
+    let nan_mask = self.is_nan();
+    let mut result = nan_mask.select(Self::nan_pow(), z);
+    let pos_overflow = self.simd_gt(max_x) & finite;
+    result = pos_overflow.select(Self::infinity(), result);
+    result = neg_underflow.select(Self::ZERO, result);
+    let pos_inf = !finite & !self.is_sign_negative() & !nan_mask;
+    result = pos_inf.select(Self::infinity(), result);
+    let neg_inf = !finite & self.is_sign_negative() & !nan_mask;
+    result = neg_inf.select(Self::ZERO, result);
+    result
+  }
+
   #[inline]
   pub fn ln(self) -> Self {
     const_f64_as_f64x8!(HALF, 0.5);
@@ -1417,16 +1536,16 @@ impl_simd_float! {
     let notsmal = t.simd_ge(Self::splat(0.66));
 
     let mut s = notbig.select(Self::FRAC_PI_4, Self::FRAC_PI_2);
-    s = notsmal & s;
+    s = Self::from(notsmal) & s;
     let mut fac = notbig.select(MORE_BITS_O2, MORE_BITS);
-    fac = notsmal & fac;
+    fac = Self::from(notsmal) & fac;
 
     // small:  z = t / 1.0;
     // medium: z = (t-1.0) / (t+1.0);
     // big:    z = -1.0 / t;
-    let mut a = notbig & t;
+    let mut a = Self::from(notbig) & t;
     a = notsmal.select(a - Self::ONE, a);
-    let mut b = notbig & Self::ONE;
+    let mut b = Self::from(notbig) & Self::ONE;
     b = notsmal.select(b + t, b);
     let z = a / b;
 
@@ -1492,16 +1611,16 @@ impl_simd_float! {
     let notsmal = t.simd_ge(Self::splat(0.66));
 
     let mut s = notbig.select(Self::FRAC_PI_4, Self::FRAC_PI_2);
-    s = notsmal & s;
+    s = Self::from(notsmal) & s;
     let mut fac = notbig.select(MORE_BITS_O2, MORE_BITS);
-    fac = notsmal & fac;
+    fac = Self::from(notsmal) & fac;
 
     // small:  z = t / 1.0;
     // medium: z = (t-1.0) / (t+1.0);
     // big:    z = -1.0 / t;
-    let mut a = notbig & t;
+    let mut a = Self::from(notbig) & t;
     a = notsmal.select(a - Self::ONE, a);
-    let mut b = notbig & Self::ONE;
+    let mut b = Self::from(notbig) & Self::ONE;
     b = notsmal.select(b + t, b);
     let z = a / b;
 
@@ -1565,18 +1684,18 @@ impl_simd_float! {
 
     let swap = !((q & i64x8::from(1)).simd_eq(i64x8::from(0)));
 
-    let mut overflow: f64x8 = cast(q.simd_gt(i64x8::from(0x80000000000000)));
+    let mut overflow = q.simd_gt(i64x8::from(0x80000000000000));
     overflow &= xa.is_finite();
     s = overflow.select(f64x8::from(0.0), s);
     c = overflow.select(f64x8::from(1.0), c);
 
     // calc sin
-    let mut sin1 = cast::<_, f64x8>(swap).select(c, s);
+    let mut sin1 = swap.select(c, s);
     let sign_sin: i64x8 = (q << 62) ^ cast::<_, i64x8>(self);
     sin1 = sin1.flip_signs(cast(sign_sin));
 
     // calc cos
-    let mut cos1 = cast::<_, f64x8>(swap).select(s, c);
+    let mut cos1 = swap.select(s, c);
     let sign_cos: i64x8 = ((q + i64x8::from(1)) & i64x8::from(2)) << 62;
     cos1 ^= cast::<_, f64x8>(sign_cos);
 
@@ -1825,6 +1944,169 @@ impl_simd_float! {
     let result = small.select(self, exp_based);
     large.select(Self::ONE.flip_signs(self), result)
   }
+
+  #[inline]
+  pub fn erf(self) -> Self {
+    let (erf, _) = self.erf_erfc();
+    erf
+  }
+
+  #[inline]
+  pub fn erfc(self) -> Self {
+    let (_, erfc) = self.erf_erfc();
+    erfc
+  }
+
+  #[inline]
+  pub fn ln_gamma(self) -> Self {
+    // Stirling's series from x = 10 up, otherwise ln(Γ(x)) is reduced to
+    // ln(Γ(1 + t)) for 0.75 <= x < 1.5 or to ln(Γ(2 + t)) with |t| <= 0.5 by
+    // the recurrence Γ(x + 1) = x·Γ(x). Negative numbers use the reflection
+    // formula Γ(x)·Γ(-x) = -π/(x·sin(πx)).
+    const_f64_as_f64x8!(LN_PI, 1.1447298858494002);
+    const_f64_as_f64x8!(LN_SQRT_2PI_MINUS_HALF, 0.4189385332046727);
+    let negative = self.simd_lt(Self::ZERO);
+    let x = self.abs();
+
+    let below_half = x.simd_lt(Self::HALF);
+    let below_1_5 = x.simd_lt(Self::from(1.5));
+    let large = x.simd_ge(Self::from(10.0));
+    let mut t = below_half.select(
+      x,
+      below_1_5
+        .select(x - Self::ONE, large.select(Self::ZERO, x - Self::from(2.0))),
+    );
+    let mut product = Self::ONE;
+    loop {
+      let reduce = t.simd_ge(Self::HALF);
+      if !reduce.any() {
+        break;
+      }
+      product = reduce.select(product * (t + Self::ONE), product);
+      t = reduce.select(t - Self::ONE, t);
+    }
+    let ln = (below_half | large).select(x, product).ln();
+    let below = t.ln_1p() + below_half.select(ln, Self::ZERO);
+    let small = Self::ln_gamma_2(t) + below_1_5.select(-below, ln);
+    // Near the zero at x = 1 the difference above loses a few bits.
+    let near_one = x.simd_ge(Self::from(0.75)) & below_1_5;
+    let small = if near_one.any() {
+      near_one.select(Self::ln_gamma_1(t), small)
+    } else {
+      small
+    };
+
+    // The -1 is folded into ln(x) to not overflow just below the largest
+    // finite result.
+    let w = Self::ONE / x;
+    let stirling = (x - Self::HALF).mul_add(
+      ln - Self::ONE,
+      Self::stirling_series(w).mul_add(w, LN_SQRT_2PI_MINUS_HALF),
+    );
+    let result = large.select(stirling, small);
+
+    if negative.any() {
+      // Γ(x) is about 1/x close to zero, where x·sin(πx) would underflow.
+      let sin = ((self - self.round()) * Self::PI).sin();
+      let reflected = LN_PI - ((sin * self).abs().ln() + result);
+      let reflected =
+        x.simd_lt(Self::from(1e-100)).select(-x.ln(), reflected);
+      let result = negative.select(reflected, result);
+      self.is_inf().select(Self::INFINITY, result)
+    } else {
+      self.is_inf().select(Self::INFINITY, result)
+    }
+  }
+
+  #[inline]
+  pub fn gamma(self) -> Self {
+    // Stirling's formula from x = 10 up, otherwise Γ(x) is reduced to
+    // Γ(2 + t) with |t| <= 0.5 by the recurrence Γ(x + 1) = x·Γ(x).
+    // Negative numbers use the reflection formula Γ(x)·Γ(-x) = -π/(x·sin(πx)).
+    const_f64_as_f64x8!(MAX_X, 171.62437695630272);
+    const_f64_as_f64x8!(TINY, 5.562684646268003e-309); // 2^-1024
+    const_f64_as_f64x8!(P_LN2, 415.88830833596717); // 600·ln(2)
+    const_f64_as_f64x8!(P_LN2_LO, 1.835517298e-14); // 600·ln(2) - P_LN2
+    const_f64_as_f64x8!(SCALE_DOWN, 2.409919865102884e-181); // 2^-600
+    let negative = self.simd_lt(Self::ZERO);
+    let x = self.abs();
+
+    let below_half = x.simd_lt(Self::HALF);
+    let below_1_5 = x.simd_lt(Self::from(1.5));
+    let large = x.simd_ge(Self::from(10.0));
+    let mut t = below_half.select(
+      x,
+      below_1_5
+        .select(x - Self::ONE, large.select(Self::ZERO, x - Self::from(2.0))),
+    );
+    let mut product = Self::ONE;
+    loop {
+      let reduce = t.simd_ge(Self::HALF);
+      if !reduce.any() {
+        break;
+      }
+      product = reduce.select(product * (t + Self::ONE), product);
+      t = reduce.select(t - Self::ONE, t);
+    }
+    let divisor = below_half
+      .select(x * (x + Self::ONE), below_1_5.select(x, Self::ONE));
+    let small = Self::ln_gamma_2(t).exp() * product / divisor;
+
+    let (high, low) =
+      Self::stirling_exponent(large.select(x, Self::from(10.0)));
+    let power = high.exp();
+    let stirling = power.mul_add(low, power);
+    let stirling = x.simd_gt(MAX_X).select(Self::INFINITY, stirling);
+    let result = large.select(stirling, small);
+
+    let result = if negative.any() {
+      // 1/Γ(-x) comes straight from the exponent, as Γ(x) is subnormal
+      // where Γ(-x) overflows. Where it would be subnormal itself, it is
+      // scaled up by 2^P until the last multiplication so that it keeps its
+      // precision. Subtracting P·ln(2) rounded from the exponent is exact
+      // there, as the exponent is at most twice as large, and the rounding
+      // error goes into `low`.
+      let scaled = high.simd_gt(P_LN2);
+      let power = scaled.select(P_LN2 - high, -high).exp();
+      let low = scaled.select(low - P_LN2_LO, low);
+      let inverse =
+        large.select(power.mul_add(-low, power), Self::ONE / small);
+      let n = self.round();
+      let half_n = n * Self::HALF;
+      let sin = ((self - n) * Self::PI).sin();
+      let sin = half_n.simd_ne(half_n.floor()).select(-sin, sin);
+      let reflected = (-Self::PI / sin) * (inverse / self);
+      let reflected = scaled.select(reflected * SCALE_DOWN, reflected);
+      // Γ has poles at the negative integers and is NaN at -∞.
+      let reflected = self.simd_eq(n).select(Self::NAN, reflected);
+      negative.select(reflected, result)
+    } else {
+      result
+    };
+    // Γ(x) is about 1/x close to zero, which overflows up to TINY, and
+    // Γ(-0.0) is -∞, like 1/x.
+    x.simd_le(TINY).select(Self::INFINITY.flip_signs(self), result)
+  }
+
+  #[inline]
+  pub fn hypot(self, y: Self) -> Self {
+    // Scales by a power of two when the squares could overflow or underflow,
+    // so the square root of the fused x² + y² is off by at most one rounding.
+    const_f64_as_f64x8!(LARGE, 3.273390607896142e150); // 2^500
+    const_f64_as_f64x8!(SMALL, 3.054936363499605e-151); // 2^-500
+    const_f64_as_f64x8!(SCALE_DOWN, 2.409919865102884e-181); // 2^-600
+    const_f64_as_f64x8!(SCALE_UP, 4.149515568880993e180); // 2^600
+    let x_abs = self.abs();
+    let y_abs = y.abs();
+    let max = x_abs.fast_max(y_abs);
+    let scale = max
+      .simd_gt(LARGE)
+      .select(SCALE_DOWN, max.simd_lt(SMALL).select(SCALE_UP, Self::ONE));
+    let x_scaled = x_abs * scale;
+    let y_scaled = y_abs * scale;
+    let result = x_scaled.mul_add(x_scaled, y_scaled * y_scaled).sqrt() / scale;
+    (self.is_inf() | y.is_inf()).select(Self::INFINITY, result)
+  }
 }
 
 /// The following functionality exists only for [`f64x8`], or only for
@@ -1844,7 +2126,7 @@ impl f64x8 {
       let valid = self.simd_ge(f64x8::from(-1074.0));
       let shift_f = self + f64x8::from(1074.0);
       let mut shift_i = shift_f.trunc_int();
-      shift_i = cast::<_, i64x8>(valid).select(shift_i, i64x8::ZERO);
+      shift_i = valid.select(shift_i, i64x8::ZERO);
       let mantissa = i64x8::ONE << shift_i;
       let sub_result = cast::<_, f64x8>(mantissa);
       let sub_result = valid.select(sub_result, f64x8::ZERO);
@@ -1875,11 +2157,10 @@ impl f64x8 {
     cast::<_, f64x8>(t2)
   }
   #[inline]
-  fn is_zero_or_subnormal(self) -> Self {
+  fn is_zero_or_subnormal(self) -> m64x8 {
     let t = cast::<_, i64x8>(self);
     let t = t & i64x8::splat(0x7FF0000000000000);
-    let mask = t.simd_eq(i64x8::splat(0));
-    cast::<_, f64x8>(mask)
+    t.simd_eq(i64x8::splat(0))
   }
   #[inline]
   fn infinity() -> Self {
@@ -1894,6 +2175,269 @@ impl f64x8 {
     cast::<_, f64x8>(i64x8::splat(0x7FF8000000000000 | 0x101 << 29))
   }
 
+  // Returns `(erf(x), erfc(x))` with the rational approximations of fdlibm's
+  // s_erf.c (Sun Microsystems, https://www.netlib.org/fdlibm/), which split
+  // |x| into [0, 0.84375), [0.84375, 1.25) and [1.25, ∞).
+  #[inline]
+  fn erf_erfc(self) -> (Self, Self) {
+    const_f64_as_f64x8!(ERX, 8.45062911510467529297e-01);
+    const_f64_as_f64x8!(PP0, 1.28379167095512558561e-01);
+    const_f64_as_f64x8!(PP1, -3.25042107247001499370e-01);
+    const_f64_as_f64x8!(PP2, -2.84817495755985104766e-02);
+    const_f64_as_f64x8!(PP3, -5.77027029648944159157e-03);
+    const_f64_as_f64x8!(PP4, -2.37630166566501626084e-05);
+    const_f64_as_f64x8!(QQ1, 3.97917223959155352819e-01);
+    const_f64_as_f64x8!(QQ2, 6.50222499887672944485e-02);
+    const_f64_as_f64x8!(QQ3, 5.08130628187576562776e-03);
+    const_f64_as_f64x8!(QQ4, 1.32494738004321644526e-04);
+    const_f64_as_f64x8!(QQ5, -3.96022827877536812320e-06);
+    const_f64_as_f64x8!(PA0, -2.36211856075265944077e-03);
+    const_f64_as_f64x8!(PA1, 4.14856118683748331666e-01);
+    const_f64_as_f64x8!(PA2, -3.72207876035701323847e-01);
+    const_f64_as_f64x8!(PA3, 3.18346619901161753674e-01);
+    const_f64_as_f64x8!(PA4, -1.10894694282396677476e-01);
+    const_f64_as_f64x8!(PA5, 3.54783043256182359371e-02);
+    const_f64_as_f64x8!(PA6, -2.16637559486879084300e-03);
+    const_f64_as_f64x8!(QA1, 1.06420880400844228286e-01);
+    const_f64_as_f64x8!(QA2, 5.40397917702171048937e-01);
+    const_f64_as_f64x8!(QA3, 7.18286544141962662868e-02);
+    const_f64_as_f64x8!(QA4, 1.26171219808761642112e-01);
+    const_f64_as_f64x8!(QA5, 1.36370839120290507362e-02);
+    const_f64_as_f64x8!(QA6, 1.19844998467991074170e-02);
+    const_f64_as_f64x8!(RA0, -9.86494403484714822705e-03);
+    const_f64_as_f64x8!(RA1, -6.93858572707181764372e-01);
+    const_f64_as_f64x8!(RA2, -1.05586262253232909814e+01);
+    const_f64_as_f64x8!(RA3, -6.23753324503260060396e+01);
+    const_f64_as_f64x8!(RA4, -1.62396669462573470355e+02);
+    const_f64_as_f64x8!(RA5, -1.84605092906711035994e+02);
+    const_f64_as_f64x8!(RA6, -8.12874355063065934246e+01);
+    const_f64_as_f64x8!(RA7, -9.81432934416914548592e+00);
+    const_f64_as_f64x8!(SA1, 1.96512716674392571292e+01);
+    const_f64_as_f64x8!(SA2, 1.37657754143519042600e+02);
+    const_f64_as_f64x8!(SA3, 4.34565877475229228821e+02);
+    const_f64_as_f64x8!(SA4, 6.45387271733267880336e+02);
+    const_f64_as_f64x8!(SA5, 4.29008140027567833386e+02);
+    const_f64_as_f64x8!(SA6, 1.08635005541779435134e+02);
+    const_f64_as_f64x8!(SA7, 6.57024977031928170135e+00);
+    const_f64_as_f64x8!(SA8, -6.04244152148580987438e-02);
+    const_f64_as_f64x8!(RB0, -9.86494292470009928597e-03);
+    const_f64_as_f64x8!(RB1, -7.99283237680523006574e-01);
+    const_f64_as_f64x8!(RB2, -1.77579549177547519889e+01);
+    const_f64_as_f64x8!(RB3, -1.60636384855821916062e+02);
+    const_f64_as_f64x8!(RB4, -6.37566443368389627722e+02);
+    const_f64_as_f64x8!(RB5, -1.02509513161107724954e+03);
+    const_f64_as_f64x8!(RB6, -4.83519191608651397019e+02);
+    const_f64_as_f64x8!(SB1, 3.03380607434824582924e+01);
+    const_f64_as_f64x8!(SB2, 3.25792512996573918826e+02);
+    const_f64_as_f64x8!(SB3, 1.53672958608443695994e+03);
+    const_f64_as_f64x8!(SB4, 3.19985821950859553908e+03);
+    const_f64_as_f64x8!(SB5, 2.55305040643316442583e+03);
+    const_f64_as_f64x8!(SB6, 4.74528541206955367215e+02);
+    const_f64_as_f64x8!(SB7, -2.24409524465858183362e+01);
+    let x = self.abs();
+    let negative = self.is_sign_negative();
+
+    // erf(x) = x + x·P(x²)/Q(x²)
+    let z = self * self;
+    let p = polynomial_4!(z, PP0, PP1, PP2, PP3, PP4);
+    let q = polynomial_5!(z, Self::ONE, QQ1, QQ2, QQ3, QQ4, QQ5);
+    let y = self * (p / q);
+    let erf_small = self + y;
+    let erfc_small = x
+      .simd_lt(Self::from(0.25))
+      .select(Self::ONE - erf_small, Self::HALF - (y + (self - Self::HALF)));
+
+    // erf(x) = erx + P(|x| - 1)/Q(|x| - 1)
+    let s = x - Self::ONE;
+    let p = polynomial_6!(s, PA0, PA1, PA2, PA3, PA4, PA5, PA6);
+    let q = polynomial_6!(s, Self::ONE, QA1, QA2, QA3, QA4, QA5, QA6);
+    let erf_medium = ERX + p / q;
+    let erfc_medium =
+      negative.select(Self::ONE + erf_medium, (Self::ONE - ERX) - p / q);
+    let erf_medium = erf_medium.flip_signs(self);
+
+    // erfc(x) = exp(-x² - 0.5625 + R(1/x²)/S(1/x²))/x, where -x² is split
+    // into -z² - (x - z)(x + z) with the upper bits z of x, whose square is
+    // exact. erfc(x) underflows to zero from x = 27.3 up.
+    let large = x.simd_ge(Self::from(1.25));
+    let (erf_large, erfc_large) = if large.any() {
+      let x = x.fast_min(Self::from(28.0));
+      let s = Self::ONE / (x * x);
+      let r = polynomial_7!(s, RA0, RA1, RA2, RA3, RA4, RA5, RA6, RA7);
+      let q =
+        polynomial_8!(s, Self::ONE, SA1, SA2, SA3, SA4, SA5, SA6, SA7, SA8);
+      let rs_near = r / q;
+      let r = polynomial_6!(s, RB0, RB1, RB2, RB3, RB4, RB5, RB6);
+      let q = polynomial_7!(s, Self::ONE, SB1, SB2, SB3, SB4, SB5, SB6, SB7);
+      let rs = x.simd_lt(Self::from(1.0 / 0.35)).select(rs_near, r / q);
+      let z =
+        Self::from_bits(x.to_bits() & u64x8::splat(0xffff_ffff_0000_0000));
+      let erfc = (-z).mul_sub(z, Self::from(0.5625)).exp()
+        * (z - x).mul_add(z + x, rs).exp()
+        / x;
+      (
+        (Self::ONE - erfc).flip_signs(self),
+        negative.select(Self::from(2.0) - erfc, erfc),
+      )
+    } else {
+      (Self::ZERO, Self::ZERO)
+    };
+
+    let small = x.simd_lt(Self::from(0.84375));
+    let erf = small.select(erf_small, large.select(erf_large, erf_medium));
+    let erfc = small.select(erfc_small, large.select(erfc_large, erfc_medium));
+    let nan = self.is_nan();
+    (nan.select(self, erf), nan.select(self, erfc))
+  }
+
+  // ln(Γ(1 + t)) for -0.25 <= t <= 0.5, from a Chebyshev interpolation of
+  // ln(Γ(1 + t))/t.
+  #[inline]
+  fn ln_gamma_1(t: Self) -> Self {
+    const_f64_as_f64x8!(C0, -0.5772156649015329);
+    const_f64_as_f64x8!(C1, 0.8224670334241129);
+    const_f64_as_f64x8!(C2, -0.4006856343865417);
+    const_f64_as_f64x8!(C3, 0.27058080842798954);
+    const_f64_as_f64x8!(C4, -0.2073855510260384);
+    const_f64_as_f64x8!(C5, 0.16955717695720057);
+    const_f64_as_f64x8!(C6, -0.14404989699480447);
+    const_f64_as_f64x8!(C7, 0.12550967314237613);
+    const_f64_as_f64x8!(C8, -0.11133425955691714);
+    const_f64_as_f64x8!(C9, 0.10009928704113542);
+    const_f64_as_f64x8!(C10, -0.0909539021382746);
+    const_f64_as_f64x8!(C11, 0.08335811853975911);
+    const_f64_as_f64x8!(C12, -0.07694329201052834);
+    const_f64_as_f64x8!(C13, 0.07138307009222658);
+    const_f64_as_f64x8!(C14, -0.06642631231818852);
+    const_f64_as_f64x8!(C15, 0.0625423359398394);
+    const_f64_as_f64x8!(C16, -0.06094838710696912);
+    const_f64_as_f64x8!(C17, 0.05965262141057934);
+    const_f64_as_f64x8!(C18, -0.05025335130400477);
+    const_f64_as_f64x8!(C19, 0.028792237448304066);
+    const_f64_as_f64x8!(C20, -0.007827903758187479);
+    let low = polynomial_7!(t, C0, C1, C2, C3, C4, C5, C6, C7);
+    let middle = polynomial_7!(t, C8, C9, C10, C11, C12, C13, C14, C15);
+    let high = polynomial_4!(t, C16, C17, C18, C19, C20);
+    let t2 = t * t;
+    let t4 = t2 * t2;
+    let t8 = t4 * t4;
+    t * (t8 * t8).mul_add(high, t8.mul_add(middle, low))
+  }
+
+  // ln(Γ(2 + t)) for |t| <= 0.5, from a Chebyshev interpolation of
+  // ln(Γ(2 + t))/t, which keeps the relative error small around the zero.
+  #[inline]
+  fn ln_gamma_2(t: Self) -> Self {
+    const_f64_as_f64x8!(C0, 0.42278433509846713);
+    const_f64_as_f64x8!(C1, 0.3224670334241132);
+    const_f64_as_f64x8!(C2, -0.06735230105319563);
+    const_f64_as_f64x8!(C3, 0.02058080842778338);
+    const_f64_as_f64x8!(C4, -0.007385551028935545);
+    const_f64_as_f64x8!(C5, 0.002890510330865901);
+    const_f64_as_f64x8!(C6, -0.0011927539009983087);
+    const_f64_as_f64x8!(C7, 0.0005096695196526673);
+    const_f64_as_f64x8!(C8, -0.00022315497746292723);
+    const_f64_as_f64x8!(C9, 9.945761692157133e-05);
+    const_f64_as_f64x8!(C10, -4.4923727564247656e-05);
+    const_f64_as_f64x8!(C11, 2.050601970174762e-05);
+    const_f64_as_f64x8!(C12, -9.45628851510172e-06);
+    const_f64_as_f64x8!(C13, 4.3828545383846665e-06);
+    const_f64_as_f64x8!(C14, -1.9743106888421067e-06);
+    const_f64_as_f64x8!(C15, 9.242852225162277e-07);
+    const_f64_as_f64x8!(C16, -5.811277768921246e-07);
+    const_f64_as_f64x8!(C17, 2.7475390051909663e-07);
+    let low = polynomial_8!(t, C0, C1, C2, C3, C4, C5, C6, C7, C8);
+    let high = polynomial_8!(t, C9, C10, C11, C12, C13, C14, C15, C16, C17);
+    let t2 = t * t;
+    let t8 = t2 * t2 * t2 * t2;
+    t * (t8 * t).mul_add(high, low)
+  }
+
+  // The Stirling series ln(Γ(x)) - (x - 0.5)·ln(x) + x - ln(2π)/2 divided by
+  // w = 1/x, as a polynomial in w².
+  #[inline]
+  fn stirling_series(w: Self) -> Self {
+    const_f64_as_f64x8!(S0, 1.0 / 12.0);
+    const_f64_as_f64x8!(S1, -1.0 / 360.0);
+    const_f64_as_f64x8!(S2, 1.0 / 1260.0);
+    const_f64_as_f64x8!(S3, -1.0 / 1680.0);
+    const_f64_as_f64x8!(S4, 1.0 / 1188.0);
+    const_f64_as_f64x8!(S5, -691.0 / 360360.0);
+    const_f64_as_f64x8!(S6, 1.0 / 156.0);
+    polynomial_6!(w * w, S0, S1, S2, S3, S4, S5, S6)
+  }
+
+  // ln(Γ(x)) for x >= 10 from Stirling's series as an unevaluated sum
+  // `high + low`. The exponent of Γ(x) = e^(high + low) needs about ten more
+  // bits than a plain `ln` provides, so ln(x) is the kernel of fdlibm's
+  // e_log.c evaluated in double-double arithmetic.
+  #[inline]
+  fn stirling_exponent(x: Self) -> (Self, Self) {
+    const_f64_as_f64x8!(LN2_HI, 6.93147180369123816490e-01);
+    const_f64_as_f64x8!(LN2_LO, 1.90821492927058770002e-10);
+    const_f64_as_f64x8!(LG1, 6.666666666666735130e-01);
+    const_f64_as_f64x8!(LG2, 3.999999999940941908e-01);
+    const_f64_as_f64x8!(LG3, 2.857142874366239149e-01);
+    const_f64_as_f64x8!(LG4, 2.222219843214978396e-01);
+    const_f64_as_f64x8!(LG5, 1.818357216161805012e-01);
+    const_f64_as_f64x8!(LG6, 1.531383769920937332e-01);
+    const_f64_as_f64x8!(LG7, 1.479819860511658591e-01);
+    const_f64_as_f64x8!(LN_SQRT_2PI, 0.9189385332046728);
+
+    // x = 2^e·m with sqrt(0.5) <= m < sqrt(2), so ln(x) = e·ln(2) + ln(1 + f)
+    // where f = m - 1 is exact.
+    let m = x.fraction_2();
+    let e = x.exponent() + Self::ONE;
+    let below = m.simd_lt(Self::FRAC_1_SQRT_2);
+    let m = below.select(m + m, m);
+    let e = below.select(e - Self::ONE, e);
+    let f = m - Self::ONE;
+    let s = f / (Self::from(2.0) + f);
+    let z = s * s;
+    let r = z * polynomial_6!(z, LG1, LG2, LG3, LG4, LG5, LG6, LG7);
+    let (hfsq, hfsq_low) = Self::two_product(Self::HALF * f, f);
+    let (difference, difference_low) = Self::two_sum(f, -hfsq);
+    let (ln_m, ln_m_low) = Self::two_sum(difference, s * (hfsq + r));
+    let (ln_x, ln_x_low) = Self::two_sum(e * LN2_HI, ln_m);
+    let ln_x_low =
+      ln_x_low + ln_m_low + (difference_low - hfsq_low + e * LN2_LO);
+
+    let v = x - Self::HALF;
+    let (product, product_low) = Self::two_product(v, ln_x);
+    let (sum, sum_low) = Self::two_sum(product, -x);
+    let w = Self::ONE / x;
+    let series = Self::stirling_series(w).mul_add(w, LN_SQRT_2PI);
+    let (high, high_low) = Self::two_sum(sum, series);
+    let low = v.mul_add(ln_x_low, product_low) + sum_low + high_low;
+    Self::two_sum(high, low)
+  }
+
+  // The exact rounding error of `a + b`.
+  #[inline]
+  fn two_sum(a: Self, b: Self) -> (Self, Self) {
+    let sum = a + b;
+    let b_part = sum - a;
+    (sum, (a - (sum - b_part)) + (b - b_part))
+  }
+
+  // The exact rounding error of `a * b` by Dekker's algorithm, which doesn't
+  // rely on `mul_add` being fused.
+  #[inline]
+  fn two_product(a: Self, b: Self) -> (Self, Self) {
+    const_f64_as_f64x8!(SPLIT, 134217729.0); // 2^27 + 1
+    let split = |v: Self| {
+      let c = v * SPLIT;
+      let high = c - (c - v);
+      (high, v - high)
+    };
+    let product = a * b;
+    let (a_high, a_low) = split(a);
+    let (b_high, b_low) = split(b);
+    let error = ((a_high * b_high - product) + a_high * b_low + a_low * b_high)
+      + a_low * b_low;
+    (product, error)
+  }
+
   /// Converts each element from [`i32`] to [`f64`].
   #[inline]
   pub fn from_i32x8(v: i32x8) -> Self {
@@ -0,0 +1,569 @@
+use super::*;
+
+/// Implements gathers and scatters for SIMD vectors with 32-bit or 64-bit
+/// elements, indexed by the signed integer SIMD vector of the same shape.
+///
+/// `gather` names the plain and masked gather intrinsics, either the `avx2`
+/// forms (`base, indices`) or the `avx512` forms (`indices, base`). `scatter`
+/// names the AVX-512 scatter intrinsics.
+macro_rules! impl_gather_scatter {
+  (
+    Simd = $Simd:ident,
+    T = $T:ident,
+    Mask = $Mask:ident,
+    Index = $Index:ident,
+    IndexBits = $IndexBits:ident,
+    gather = #[cfg($($gather_cfg:meta),+)]
+      $gather_kind:ident($gather:ident, $masked_gather:ident);
+    scatter = #[cfg($($scatter_cfg:meta),+)]
+      ($scatter:ident, $masked_scatter:ident);
+  ) => {
+    /// Gathers and scatters.
+    impl $Simd {
+      /// Returns a [mask] that is `true` for each element of `indices` that is
+      /// a valid index into a slice of length `len`.
+      ///
+      /// [mask]: crate#masks
+      #[inline]
+      fn indices_in_bounds(indices: $Index, len: usize) -> $Mask {
+        // Negative indices are larger than any valid length after casting to
+        // unsigned, so a single comparison covers both bounds.
+        let len = usize::try_from($IndexBits::MAX >> 1)
+          .map_or(len, |max| len.min(max + 1));
+
+        indices.cast_unsigned().simd_lt(len as $IndexBits)
+      }
+
+      /// Loads the element of `slice` at each index of `indices`.
+      ///
+      /// Lane `i` of the result is `slice[indices[i]]`.
+      ///
+      /// Currently this function is accelerated with `vgather` on `avx2`
+      /// (`avx512f` for 512-bit vectors).
+      ///
+      /// # Panics
+      ///
+      /// Panics if any index is negative or not less than `slice.len()`.
+      #[inline]
+      #[must_use]
+      pub fn gather(slice: &[$T], indices: $Index) -> Self {
+        assert!(
+          Self::indices_in_bounds(indices, slice.len()).all(),
+          "gather index out of bounds",
+        );
+        // SAFETY: All indices were checked to be in bounds.
+        unsafe { Self::gather_unchecked(slice, indices) }
+      }
+
+      /// Loads the element of `slice` at each index of `indices` for the lanes
+      /// that are `true` in `mask`, and takes the other lanes from `fill`.
+      ///
+      /// The indices of lanes that are `false` in `mask` are ignored, and are
+      /// not bounds checked.
+      ///
+      /// Currently this function is accelerated with `vgather` on `avx2`
+      /// (`avx512f` for 512-bit vectors).
+      ///
+      /// # Panics
+      ///
+      /// Panics if the index of any lane that is `true` in `mask` is negative
+      /// or not less than `slice.len()`.
+      #[inline]
+      #[must_use]
+      pub fn masked_gather(
+        slice: &[$T],
+        indices: $Index,
+        mask: $Mask,
+        fill: Self,
+      ) -> Self {
+        assert!(
+          (Self::indices_in_bounds(indices, slice.len()) | !mask).all(),
+          "gather index out of bounds",
+        );
+        // SAFETY: All indices of enabled lanes were checked to be in bounds.
+        unsafe { Self::masked_gather_unchecked(slice, indices, mask, fill) }
+      }
+
+      /// Loads the element of `slice` at each index of `indices`, without
+      /// bounds checking.
+      ///
+      /// See [`gather`](Self::gather) for the safe version.
+      ///
+      /// # Safety
+      ///
+      /// Every index must be non-negative and less than `slice.len()`.
+      #[inline]
+      #[must_use]
+      pub unsafe fn gather_unchecked(slice: &[$T], indices: $Index) -> Self {
+        pick! {
+          if #[cfg($($gather_cfg),+)] {
+            // SAFETY: The caller guarantees that every index is in bounds.
+            unsafe {
+              impl_gather_scatter!(
+                @gather $gather_kind $gather, $T, slice.as_ptr(), indices
+              )
+            }
+          } else {
+            Self::new(core::array::from_fn(|i| {
+              let index = indices.as_array()[i] as usize;
+              // SAFETY: The caller guarantees that every index is in bounds.
+              unsafe { *slice.get_unchecked(index) }
+            }))
+          }
+        }
+      }
+
+      /// Loads the element of `slice` at each index of `indices` for the lanes
+      /// that are `true` in `mask`, and takes the other lanes from `fill`,
+      /// without bounds checking.
+      ///
+      /// See [`masked_gather`](Self::masked_gather) for the safe version.
+      ///
+      /// # Safety
+      ///
+      /// The index of every lane that is `true` in `mask` must be
+      /// non-negative and less than `slice.len()`.
+      #[inline]
+      #[must_use]
+      pub unsafe fn masked_gather_unchecked(
+        slice: &[$T],
+        indices: $Index,
+        mask: $Mask,
+        fill: Self,
+      ) -> Self {
+        pick! {
+          if #[cfg($($gather_cfg),+)] {
+            // SAFETY: The caller guarantees that every index of an enabled
+            // lane is in bounds, and disabled lanes are not accessed.
+            unsafe {
+              impl_gather_scatter!(
+                @masked_gather $gather_kind $masked_gather, $T,
+                slice.as_ptr(), indices, mask, fill
+              )
+            }
+          } else {
+            let mut result = fill;
+            let mut bits = mask.to_bitmask();
+            while bits != 0 {
+              let lane = bits.trailing_zeros() as usize;
+              let index = indices.as_array()[lane] as usize;
+              // SAFETY: The caller guarantees that the index of every enabled
+              // lane is in bounds.
+              let elem = unsafe { *slice.get_unchecked(index) };
+              result.as_mut_array()[lane] = elem;
+              bits &= bits - 1;
+            }
+            result
+          }
+        }
+      }
+
+      /// Stores each element of `self` to `slice` at the corresponding index
+      /// of `indices`.
+      ///
+      /// Lane `i` is stored to `slice[indices[i]]`. If several lanes have the
+      /// same index, the last of those lanes is stored.
+      ///
+      /// Currently this function is accelerated with `vscatter` on `avx512vl`
+      /// (`avx512f` for 512-bit vectors).
+      ///
+      /// # Panics
+      ///
+      /// Panics if any index is negative or not less than `slice.len()`.
+      #[inline]
+      pub fn scatter(self, slice: &mut [$T], indices: $Index) {
+        assert!(
+          Self::indices_in_bounds(indices, slice.len()).all(),
+          "scatter index out of bounds",
+        );
+        // SAFETY: All indices were checked to be in bounds.
+        unsafe { self.scatter_unchecked(slice, indices) }
+      }
+
+      /// Stores each element of `self` to `slice` at the corresponding index
+      /// of `indices` for the lanes that are `true` in `mask`.
+      ///
+      /// The indices of lanes that are `false` in `mask` are ignored, and are
+      /// not bounds checked. If several enabled lanes have the same index, the
+      /// last of those lanes is stored.
+      ///
+      /// Currently this function is accelerated with `vscatter` on `avx512vl`
+      /// (`avx512f` for 512-bit vectors).
+      ///
+      /// # Panics
+      ///
+      /// Panics if the index of any lane that is `true` in `mask` is negative
+      /// or not less than `slice.len()`.
+      #[inline]
+      pub fn masked_scatter(
+        self,
+        slice: &mut [$T],
+        indices: $Index,
+        mask: $Mask,
+      ) {
+        assert!(
+          (Self::indices_in_bounds(indices, slice.len()) | !mask).all(),
+          "scatter index out of bounds",
+        );
+        // SAFETY: All indices of enabled lanes were checked to be in bounds.
+        unsafe { self.masked_scatter_unchecked(slice, indices, mask) }
+      }
+
+      /// Stores each element of `self` to `slice` at the corresponding index
+      /// of `indices`, without bounds checking.
+      ///
+      /// See [`scatter`](Self::scatter) for the safe version.
+      ///
+      /// # Safety
+      ///
+      /// Every index must be non-negative and less than `slice.len()`.
+      #[inline]
+      pub unsafe fn scatter_unchecked(self, slice: &mut [$T], indices: $Index) {
+        pick! {
+          if #[cfg($($scatter_cfg),+)] {
+            #[cfg(target_arch = "x86")]
+            use core::arch::x86::$scatter;
+            #[cfg(target_arch = "x86_64")]
+            use core::arch::x86_64::$scatter;
+
+            // SAFETY: The caller guarantees that every index is in bounds.
+            unsafe {
+              $scatter::<{ size_of::<$T>() as i32 }>(
+                slice.as_mut_ptr().cast(),
+                indices.into(),
+                self.into(),
+              )
+            }
+          } else {
+            let lanes = indices.as_array().iter().zip(self.as_array());
+            for (&index, &elem) in lanes {
+              // SAFETY: The caller guarantees that every index is in bounds.
+              unsafe { *slice.get_unchecked_mut(index as usize) = elem };
+            }
+          }
+        }
+      }
+
+      /// Stores each element of `self` to `slice` at the corresponding index
+      /// of `indices` for the lanes that are `true` in `mask`, without bounds
+      /// checking.
+      ///
+      /// See [`masked_scatter`](Self::masked_scatter) for the safe version.
+      ///
+      /// # Safety
+      ///
+      /// The index of every lane that is `true` in `mask` must be
+      /// non-negative and less than `slice.len()`.
+      #[inline]
+      pub unsafe fn masked_scatter_unchecked(
+        self,
+        slice: &mut [$T],
+        indices: $Index,
+        mask: $Mask,
+      ) {
+        pick! {
+          if #[cfg($($scatter_cfg),+)] {
+            #[cfg(target_arch = "x86")]
+            use core::arch::x86::$masked_scatter;
+            #[cfg(target_arch = "x86_64")]
+            use core::arch::x86_64::$masked_scatter;
+
+            // SAFETY: The caller guarantees that every index of an enabled
+            // lane is in bounds, and disabled lanes are not accessed.
+            unsafe {
+              $masked_scatter::<{ size_of::<$T>() as i32 }>(
+                slice.as_mut_ptr().cast(),
+                mask.to_bitmask() as _,
+                indices.into(),
+                self.into(),
+              )
+            }
+          } else {
+            let mut bits = mask.to_bitmask();
+            while bits != 0 {
+              let lane = bits.trailing_zeros() as usize;
+              let index = indices.as_array()[lane] as usize;
+              let elem = self.as_array()[lane];
+              // SAFETY: The caller guarantees that the index of every enabled
+              // lane is in bounds.
+              unsafe { *slice.get_unchecked_mut(index) = elem };
+              bits &= bits - 1;
+            }
+          }
+        }
+      }
+    }
+  };
+  (@gather avx2 $gather:ident, $T:ident, $ptr:expr, $indices:expr) => {{
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::$gather;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::$gather;
+
+    Self::from($gather::<{ size_of::<$T>() as i32 }>(
+      $ptr.cast(),
+      $indices.into(),
+    ))
+  }};
+  (@gather avx512 $gather:ident, $T:ident, $ptr:expr, $indices:expr) => {{
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::$gather;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::$gather;
+
+    Self::from($gather::<{ size_of::<$T>() as i32 }>(
+      $indices.into(),
+      $ptr.cast(),
+    ))
+  }};
+  (
+    @masked_gather avx2 $gather:ident, $T:ident,
+    $ptr:expr, $indices:expr, $mask:expr, $fill:expr
+  ) => {{
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::$gather;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::$gather;
+
+    // The mask has the type of the gathered vector, and only its sign bits
+    // are used.
+    Self::from($gather::<{ size_of::<$T>() as i32 }>(
+      $fill.into(),
+      $ptr.cast(),
+      $indices.into(),
+      Self::from($mask).into(),
+    ))
+  }};
+  (
+    @masked_gather avx512 $gather:ident, $T:ident,
+    $ptr:expr, $indices:expr, $mask:expr, $fill:expr
+  ) => {{
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::$gather;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::$gather;
+
+    Self::from($gather::<{ size_of::<$T>() as i32 }>(
+      $fill.into(),
+      $mask.to_bitmask() as _,
+      $indices.into(),
+      $ptr.cast(),
+    ))
+  }};
+}
+
+impl_gather_scatter! {
+  Simd = f32x4,
+  T = f32,
+  Mask = m32x4,
+  Index = i32x4,
+  IndexBits = u32,
+  gather = #[cfg(target_feature = "avx2")]
+    avx2(_mm_i32gather_ps, _mm_mask_i32gather_ps);
+  scatter = #[cfg(target_feature = "avx512vl")]
+    (_mm_i32scatter_ps, _mm_mask_i32scatter_ps);
+}
+
+impl_gather_scatter! {
+  Simd = f32x8,
+  T = f32,
+  Mask = m32x8,
+  Index = i32x8,
+  IndexBits = u32,
+  gather = #[cfg(target_feature = "avx2")]
+    avx2(_mm256_i32gather_ps, _mm256_mask_i32gather_ps);
+  scatter = #[cfg(target_feature = "avx512vl")]
+    (_mm256_i32scatter_ps, _mm256_mask_i32scatter_ps);
+}
+
+impl_gather_scatter! {
+  Simd = f32x16,
+  T = f32,
+  Mask = m32x16,
+  Index = i32x16,
+  IndexBits = u32,
+  gather = #[cfg(target_feature = "avx512f")]
+    avx512(_mm512_i32gather_ps, _mm512_mask_i32gather_ps);
+  scatter = #[cfg(target_feature = "avx512f")]
+    (_mm512_i32scatter_ps, _mm512_mask_i32scatter_ps);
+}
+
+impl_gather_scatter! {
+  Simd = f64x2,
+  T = f64,
+  Mask = m64x2,
+  Index = i64x2,
+  IndexBits = u64,
+  gather = #[cfg(target_feature = "avx2")]
+    avx2(_mm_i64gather_pd, _mm_mask_i64gather_pd);
+  scatter = #[cfg(target_feature = "avx512vl")]
+    (_mm_i64scatter_pd, _mm_mask_i64scatter_pd);
+}
+
+impl_gather_scatter! {
+  Simd = f64x4,
+  T = f64,
+  Mask = m64x4,
+  Index = i64x4,
+  IndexBits = u64,
+  gather = #[cfg(target_feature = "avx2")]
+    avx2(_mm256_i64gather_pd, _mm256_mask_i64gather_pd);
+  scatter = #[cfg(target_feature = "avx512vl")]
+    (_mm256_i64scatter_pd, _mm256_mask_i64scatter_pd);
+}
+
+impl_gather_scatter! {
+  Simd = f64x8,
+  T = f64,
+  Mask = m64x8,
+  Index = i64x8,
+  IndexBits = u64,
+  gather = #[cfg(target_feature = "avx512f")]
+    avx512(_mm512_i64gather_pd, _mm512_mask_i64gather_pd);
+  scatter = #[cfg(target_feature = "avx512f")]
+    (_mm512_i64scatter_pd, _mm512_mask_i64scatter_pd);
+}
+
+impl_gather_scatter! {
+  Simd = i32x4,
+  T = i32,
+  Mask = m32x4,
+  Index = i32x4,
+  IndexBits = u32,
+  gather = #[cfg(target_feature = "avx2")]
+    avx2(_mm_i32gather_epi32, _mm_mask_i32gather_epi32);
+  scatter = #[cfg(target_feature = "avx512vl")]
+    (_mm_i32scatter_epi32, _mm_mask_i32scatter_epi32);
+}
+
+impl_gather_scatter! {
+  Simd = u32x4,
+  T = u32,
+  Mask = m32x4,
+  Index = i32x4,
+  IndexBits = u32,
+  gather = #[cfg(target_feature = "avx2")]
+    avx2(_mm_i32gather_epi32, _mm_mask_i32gather_epi32);
+  scatter = #[cfg(target_feature = "avx512vl")]
+    (_mm_i32scatter_epi32, _mm_mask_i32scatter_epi32);
+}
+
+impl_gather_scatter! {
+  Simd = i32x8,
+  T = i32,
+  Mask = m32x8,
+  Index = i32x8,
+  IndexBits = u32,
+  gather = #[cfg(target_feature = "avx2")]
+    avx2(_mm256_i32gather_epi32, _mm256_mask_i32gather_epi32);
+  scatter = #[cfg(target_feature = "avx512vl")]
+    (_mm256_i32scatter_epi32, _mm256_mask_i32scatter_epi32);
+}
+
+impl_gather_scatter! {
+  Simd = u32x8,
+  T = u32,
+  Mask = m32x8,
+  Index = i32x8,
+  IndexBits = u32,
+  gather = #[cfg(target_feature = "avx2")]
+    avx2(_mm256_i32gather_epi32, _mm256_mask_i32gather_epi32);
+  scatter = #[cfg(target_feature = "avx512vl")]
+    (_mm256_i32scatter_epi32, _mm256_mask_i32scatter_epi32);
+}
+
+impl_gather_scatter! {
+  Simd = i32x16,
+  T = i32,
+  Mask = m32x16,
+  Index = i32x16,
+  IndexBits = u32,
+  gather = #[cfg(target_feature = "avx512f")]
+    avx512(_mm512_i32gather_epi32, _mm512_mask_i32gather_epi32);
+  scatter = #[cfg(target_feature = "avx512f")]
+    (_mm512_i32scatter_epi32, _mm512_mask_i32scatter_epi32);
+}
+
+impl_gather_scatter! {
+  Simd = u32x16,
+  T = u32,
+  Mask = m32x16,
+  Index = i32x16,
+  IndexBits = u32,
+  gather = #[cfg(target_feature = "avx512f")]
+    avx512(_mm512_i32gather_epi32, _mm512_mask_i32gather_epi32);
+  scatter = #[cfg(target_feature = "avx512f")]
+    (_mm512_i32scatter_epi32, _mm512_mask_i32scatter_epi32);
+}
+
+impl_gather_scatter! {
+  Simd = i64x2,
+  T = i64,
+  Mask = m64x2,
+  Index = i64x2,
+  IndexBits = u64,
+  gather = #[cfg(target_feature = "avx2")]
+    avx2(_mm_i64gather_epi64, _mm_mask_i64gather_epi64);
+  scatter = #[cfg(target_feature = "avx512vl")]
+    (_mm_i64scatter_epi64, _mm_mask_i64scatter_epi64);
+}
+
+impl_gather_scatter! {
+  Simd = u64x2,
+  T = u64,
+  Mask = m64x2,
+  Index = i64x2,
+  IndexBits = u64,
+  gather = #[cfg(target_feature = "avx2")]
+    avx2(_mm_i64gather_epi64, _mm_mask_i64gather_epi64);
+  scatter = #[cfg(target_feature = "avx512vl")]
+    (_mm_i64scatter_epi64, _mm_mask_i64scatter_epi64);
+}
+
+impl_gather_scatter! {
+  Simd = i64x4,
+  T = i64,
+  Mask = m64x4,
+  Index = i64x4,
+  IndexBits = u64,
+  gather = #[cfg(target_feature = "avx2")]
+    avx2(_mm256_i64gather_epi64, _mm256_mask_i64gather_epi64);
+  scatter = #[cfg(target_feature = "avx512vl")]
+    (_mm256_i64scatter_epi64, _mm256_mask_i64scatter_epi64);
+}
+
+impl_gather_scatter! {
+  Simd = u64x4,
+  T = u64,
+  Mask = m64x4,
+  Index = i64x4,
+  IndexBits = u64,
+  gather = #[cfg(target_feature = "avx2")]
+    avx2(_mm256_i64gather_epi64, _mm256_mask_i64gather_epi64);
+  scatter = #[cfg(target_feature = "avx512vl")]
+    (_mm256_i64scatter_epi64, _mm256_mask_i64scatter_epi64);
+}
+
+impl_gather_scatter! {
+  Simd = i64x8,
+  T = i64,
+  Mask = m64x8,
+  Index = i64x8,
+  IndexBits = u64,
+  gather = #[cfg(target_feature = "avx512f")]
+    avx512(_mm512_i64gather_epi64, _mm512_mask_i64gather_epi64);
+  scatter = #[cfg(target_feature = "avx512f")]
+    (_mm512_i64scatter_epi64, _mm512_mask_i64scatter_epi64);
+}
+
+impl_gather_scatter! {
+  Simd = u64x8,
+  T = u64,
+  Mask = m64x8,
+  Index = i64x8,
+  IndexBits = u64,
+  gather = #[cfg(target_feature = "avx512f")]
+    avx512(_mm512_i64gather_epi64, _mm512_mask_i64gather_epi64);
+  scatter = #[cfg(target_feature = "avx512f")]
+    (_mm512_i64scatter_epi64, _mm512_mask_i64scatter_epi64);
+}
@@ -0,0 +1,379 @@
+use super::*;
+
+/// Implements a SIMD vector of 16-bit floats, which is stored as `Bits` and
+/// converts to and from `Float` for anything but storage.
+macro_rules! impl_half {
+  (
+    Half = $Half:ident,
+    Bits = $Bits:ident,
+    Float = $Float:ident,
+    N = $N:literal,
+    Format = $format:literal,
+    to_float = $to_float:ident,
+    from_float = $from_float:ident,
+  ) => {
+    #[doc = concat!("A SIMD vector with ", stringify!($N), " elements in the ", $format, " format.")]
+    ///
+    /// This is a storage type: it only converts to and from
+    #[doc = concat!("[`", stringify!($Float), "`], where the actual math happens. The arithmetic")]
+    /// operators are provided for convenience and do the same, with the
+    /// result rounded once, which is the correctly rounded result.
+    ///
+    /// Like the other SIMD vectors, this implements [`Pod`], so slices of
+    /// 16-bit floats stored as [`u16`] can be cast to slices of this type.
+    #[derive(Clone, Copy, Default)]
+    #[repr(transparent)]
+    pub struct $Half($Bits);
+
+    // SAFETY: `$Half` is a transparent wrapper of `$Bits`, which is `Pod`,
+    // and every bit pattern is a valid 16-bit float.
+    unsafe impl Zeroable for $Half {}
+    // SAFETY: See above.
+    unsafe impl Pod for $Half {}
+
+    impl $Half {
+      /// Constructs a SIMD vector from the raw bits of each element.
+      #[inline]
+      #[must_use]
+      pub fn from_bits(bits: $Bits) -> Self {
+        Self(bits)
+      }
+
+      /// Returns the raw bits of each element.
+      #[inline]
+      #[must_use]
+      pub fn to_bits(self) -> $Bits {
+        self.0
+      }
+    }
+
+    impl From<$Half> for $Float {
+      /// Converts each element to `f32` without losing information.
+      #[inline]
+      fn from(half: $Half) -> Self {
+        half.$to_float()
+      }
+    }
+
+    impl PartialEq for $Half {
+      /// Compares the elements as floats, like
+      #[doc = concat!("[`", stringify!($Float), "`], so that `0.0 == -0.0` and `NaN` is not equal to")]
+      /// itself.
+      #[inline]
+      fn eq(&self, other: &Self) -> bool {
+        self.$to_float() == other.$to_float()
+      }
+    }
+
+    impl core::fmt::Debug for $Half {
+      #[allow(clippy::missing_inline_in_public_items)]
+      fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.$to_float(), f)
+      }
+    }
+
+    /// Parses the [`Debug`](core::fmt::Debug) output, an array or a single
+    #[doc = concat!("element used for every lane, like [`", stringify!($Float), "`], then rounds")]
+    /// each element to the nearest 16-bit float.
+    impl core::str::FromStr for $Half {
+      type Err = ParseSimdError;
+
+      #[inline]
+      fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self::$from_float)
+      }
+    }
+
+    impl Neg for $Half {
+      type Output = Self;
+
+      #[inline]
+      fn neg(self) -> Self {
+        Self(self.0 ^ $Bits::splat(0x8000))
+      }
+    }
+
+    impl_half!(@op $Half, $to_float, $from_float, Add, add);
+    impl_half!(@op $Half, $to_float, $from_float, Sub, sub);
+    impl_half!(@op $Half, $to_float, $from_float, Mul, mul);
+    impl_half!(@op $Half, $to_float, $from_float, Div, div);
+  };
+  (@op $Half:ident, $to_float:ident, $from_float:ident, $Op:ident, $op:ident) => {
+    impl $Op for $Half {
+      type Output = Self;
+
+      #[inline]
+      fn $op(self, rhs: Self) -> Self {
+        Self::$from_float(self.$to_float().$op(rhs.$to_float()))
+      }
+    }
+  };
+}
+
+impl_half! {
+  Half = f16x8,
+  Bits = u16x8,
+  Float = f32x8,
+  N = 8,
+  Format = "IEEE 754 half-precision (`f16`)",
+  to_float = to_f32x8,
+  from_float = from_f32x8,
+}
+impl_half! {
+  Half = f16x16,
+  Bits = u16x16,
+  Float = f32x16,
+  N = 16,
+  Format = "IEEE 754 half-precision (`f16`)",
+  to_float = to_f32x16,
+  from_float = from_f32x16,
+}
+impl_half! {
+  Half = bf16x8,
+  Bits = u16x8,
+  Float = f32x8,
+  N = 8,
+  Format = "bfloat16 (`bf16`)",
+  to_float = to_f32x8,
+  from_float = from_f32x8,
+}
+impl_half! {
+  Half = bf16x16,
+  Bits = u16x16,
+  Float = f32x16,
+  N = 16,
+  Format = "bfloat16 (`bf16`)",
+  to_float = to_f32x16,
+  from_float = from_f32x16,
+}
+
+impl f16x8 {
+  /// Converts each element to `f32` without losing information.
+  ///
+  /// Uses `vcvtph2ps` with F16C and `fcvtl` on NEON.
+  #[inline]
+  #[must_use]
+  pub fn to_f32x8(self) -> f32x8 {
+    pick! {
+      if #[cfg(target_feature = "f16c")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::*;
+
+        f32x8::from(unsafe { _mm256_cvtph_ps(self.0.into()) })
+      } else if #[cfg(all(target_feature = "neon", target_arch = "aarch64"))] {
+        use core::arch::aarch64::*;
+
+        // The `f16` intrinsics need a newer compiler, so this uses the
+        // instructions directly.
+        let bits: uint16x8_t = self.0.into();
+        let low: float32x4_t;
+        let high: float32x4_t;
+        unsafe {
+          core::arch::asm!(
+            "fcvtl {low:v}.4s, {bits:v}.4h",
+            "fcvtl2 {high:v}.4s, {bits:v}.8h",
+            bits = in(vreg) bits,
+            low = out(vreg) low,
+            high = out(vreg) high,
+            options(pure, nomem, nostack, preserves_flags),
+          );
+        }
+        cast([f32x4::from(low), f32x4::from(high)])
+      } else {
+        let bits = u32x8::from(self.0);
+        let sign = (bits & u32x8::splat(0x8000)) << 16;
+        let unsigned = bits & u32x8::splat(0x7fff);
+        let exponent = bits & u32x8::splat(0x7c00);
+
+        // Moving the exponent and mantissa into place and adding the
+        // difference of the exponent biases works for normal numbers.
+        let shifted = unsigned << 13;
+        let normal = shifted + u32x8::splat(112 << 23);
+
+        // Infinity and NaN get the maximum exponent, and NaN is made quiet.
+        let is_nan = u32x8::from(unsigned.simd_gt(u32x8::splat(0x7c00)));
+        let inf_nan = (normal + u32x8::splat(112 << 23))
+          | (is_nan & u32x8::splat(0x0040_0000));
+
+        // Zero and subnormals are normalized by a float subtraction.
+        let magic = f32x8::from_bits(u32x8::splat(113 << 23));
+        let subnormal = (f32x8::from_bits(shifted + magic.to_bits()) - magic).to_bits();
+
+        let unsigned = exponent
+          .simd_eq(u32x8::splat(0x7c00))
+          .select(inf_nan, exponent.simd_eq(u32x8::ZERO).select(subnormal, normal));
+        f32x8::from_bits(unsigned | sign)
+      }
+    }
+  }
+
+  /// Converts each element from `f32`, rounding to the nearest value with
+  /// ties to even.
+  ///
+  /// Values too large for `f16` become infinity. `NaN` stays `NaN`, but keeps
+  /// only the top bits of its payload.
+  ///
+  /// Uses `vcvtps2ph` with F16C and `fcvtn` on NEON.
+  #[inline]
+  #[must_use]
+  pub fn from_f32x8(v: f32x8) -> Self {
+    pick! {
+      if #[cfg(target_feature = "f16c")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::*;
+
+        Self(u16x8::from(unsafe {
+          _mm256_cvtps_ph::<_MM_FROUND_TO_NEAREST_INT>(v.into())
+        }))
+      } else if #[cfg(all(target_feature = "neon", target_arch = "aarch64"))] {
+        use core::arch::aarch64::*;
+
+        // See `to_f32x8`. `fcvtn` rounds to nearest even by default.
+        let [low, high]: [f32x4; 2] = cast(v);
+        let low: float32x4_t = low.into();
+        let high: float32x4_t = high.into();
+        let bits: uint16x8_t;
+        unsafe {
+          core::arch::asm!(
+            "fcvtn {bits:v}.4h, {low:v}.4s",
+            "fcvtn2 {bits:v}.8h, {high:v}.4s",
+            low = in(vreg) low,
+            high = in(vreg) high,
+            bits = out(vreg) bits,
+            options(pure, nomem, nostack, preserves_flags),
+          );
+        }
+        Self(u16x8::from(bits))
+      } else {
+        let bits = v.to_bits();
+        let sign = (bits >> 16) & u32x8::splat(0x8000);
+        let unsigned = bits & u32x8::splat(0x7fff_ffff);
+
+        // Values from 2^16 up are too large and become infinity, and NaN
+        // keeps the top bits of its payload and is made quiet.
+        let is_nan = unsigned.simd_gt(u32x8::splat(0x7f80_0000));
+        let inf_nan = is_nan.select(
+          u32x8::splat(0x7e00) | ((unsigned >> 13) & u32x8::splat(0x01ff)),
+          u32x8::splat(0x7c00),
+        );
+
+        // Adding a float with a large exponent rounds the mantissa of values
+        // below 2^-14 to the bits of subnormals.
+        let magic = f32x8::from_bits(u32x8::splat(126 << 23));
+        let subnormal = (f32x8::from_bits(unsigned) + magic).to_bits() - magic.to_bits();
+
+        // Normal numbers are rounded to nearest even by adding just below half
+        // of the dropped bits, plus the lowest kept bit.
+        let odd = (unsigned >> 13) & u32x8::splat(1);
+        let normal = (unsigned + u32x8::splat(0xc800_0fff) + odd) >> 13;
+
+        let half: u32x8 = unsigned.simd_ge(u32x8::splat(0x4780_0000)).select(
+          inf_nan,
+          unsigned.simd_lt(u32x8::splat(0x3880_0000)).select(subnormal, normal),
+        );
+        let half: u32x8 = half | sign;
+        Self(half.cast_lossy())
+      }
+    }
+  }
+}
+
+impl f16x16 {
+  /// Converts each element to `f32` without losing information.
+  ///
+  /// Uses `vcvtph2ps` with F16C or AVX-512 and `fcvtl` on NEON.
+  #[inline]
+  #[must_use]
+  pub fn to_f32x16(self) -> f32x16 {
+    pick! {
+      if #[cfg(target_feature = "avx512f")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::*;
+
+        f32x16::from(unsafe { _mm512_cvtph_ps(self.0.into()) })
+      } else {
+        let [low, high]: [f16x8; 2] = cast(self);
+        cast([low.to_f32x8(), high.to_f32x8()])
+      }
+    }
+  }
+
+  /// Converts each element from `f32`, see
+  /// [`f16x8::from_f32x8`].
+  #[inline]
+  #[must_use]
+  pub fn from_f32x16(v: f32x16) -> Self {
+    pick! {
+      if #[cfg(target_feature = "avx512f")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::*;
+
+        Self(u16x16::from(unsafe {
+          _mm512_cvtps_ph::<_MM_FROUND_TO_NEAREST_INT>(v.into())
+        }))
+      } else {
+        let [low, high]: [f32x8; 2] = cast(v);
+        cast([f16x8::from_f32x8(low), f16x8::from_f32x8(high)])
+      }
+    }
+  }
+}
+
+/// Implements the conversions of `bf16`, which is the upper half of an `f32`.
+macro_rules! impl_bf16_conversions {
+  (
+    Half = $Half:ident,
+    Float = $Float:ident,
+    U32 = $U32:ident,
+    to_float = $to_float:ident,
+    from_float = $from_float:ident,
+  ) => {
+    impl $Half {
+      /// Converts each element to `f32` without losing information.
+      #[inline]
+      #[must_use]
+      pub fn $to_float(self) -> $Float {
+        $Float::from_bits($U32::from(self.0) << 16)
+      }
+
+      /// Converts each element from `f32`, rounding to the nearest value with
+      /// ties to even.
+      ///
+      /// Values too large for `bf16` become infinity. `NaN` stays `NaN`, but
+      /// keeps only the top bits of its payload.
+      #[inline]
+      #[must_use]
+      pub fn $from_float(v: $Float) -> Self {
+        let bits = v.to_bits();
+        let odd = (bits >> 16) & $U32::splat(1);
+        let rounded = (bits + $U32::splat(0x7fff) + odd) >> 16;
+        let nan = (bits >> 16) | $U32::splat(0x0040);
+        let half: $U32 = v.is_nan().select(nan, rounded);
+        Self(half.cast_lossy())
+      }
+    }
+  };
+}
+
+impl_bf16_conversions! {
+  Half = bf16x8,
+  Float = f32x8,
+  U32 = u32x8,
+  to_float = to_f32x8,
+  from_float = from_f32x8,
+}
+impl_bf16_conversions! {
+  Half = bf16x16,
+  Float = f32x16,
+  U32 = u32x16,
+  to_float = to_f32x16,
+  from_float = from_f32x16,
+}
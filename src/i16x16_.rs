@@ -29,6 +29,7 @@ impl_simd! {
     T = i16,
     N = 16,
     Simd = i16x16,
+    Mask = m16x16,
     optional_type_x86_inner { X86Inner = __m256i },
     optional_type_arm_inner {},
     optional_type_wasm_inner {},
@@ -41,8 +42,8 @@ impl_simd! {
         Self { avx2: cmp_eq_mask_i16_m256i(self.avx2, rhs.avx2) }
       } else {
         Self {
-          a : self.a.simd_eq(rhs.a),
-          b : self.b.simd_eq(rhs.b),
+          a : self.a.simd_eq(rhs.a).into(),
+          b : self.b.simd_eq(rhs.b).into(),
         }
       }
     }
@@ -52,11 +53,11 @@ impl_simd! {
   fn simd_ne(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else {
         Self {
-          a : self.a.simd_ne(rhs.a),
-          b : self.b.simd_ne(rhs.b),
+          a : self.a.simd_ne(rhs.a).into(),
+          b : self.b.simd_ne(rhs.b).into(),
         }
       }
     }
@@ -69,8 +70,8 @@ impl_simd! {
         Self { avx2: !cmp_gt_mask_i16_m256i(self.avx2, rhs.avx2) ^ cmp_eq_mask_i16_m256i(self.avx2,rhs.avx2) }
       } else {
         Self {
-          a : self.a.simd_lt(rhs.a),
-          b : self.b.simd_lt(rhs.b),
+          a : self.a.simd_lt(rhs.a).into(),
+          b : self.b.simd_lt(rhs.b).into(),
         }
       }
     }
@@ -83,8 +84,8 @@ impl_simd! {
         Self { avx2: cmp_gt_mask_i16_m256i(self.avx2, rhs.avx2) }
       } else {
         Self {
-          a : self.a.simd_gt(rhs.a),
-          b : self.b.simd_gt(rhs.b),
+          a : self.a.simd_gt(rhs.a).into(),
+          b : self.b.simd_gt(rhs.b).into(),
         }
       }
     }
@@ -94,11 +95,11 @@ impl_simd! {
   fn simd_le(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        !self.simd_gt(rhs)
+        Self::from(!self.simd_gt(rhs))
       } else {
         Self {
-          a : self.a.simd_le(rhs.a),
-          b : self.b.simd_le(rhs.b),
+          a : self.a.simd_le(rhs.a).into(),
+          b : self.b.simd_le(rhs.b).into(),
         }
       }
     }
@@ -108,11 +109,11 @@ impl_simd! {
   fn simd_ge(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        !self.simd_lt(rhs)
+        Self::from(!self.simd_lt(rhs))
       } else {
         Self {
-          a : self.a.simd_ge(rhs.a),
-          b : self.b.simd_ge(rhs.b),
+          a : self.a.simd_ge(rhs.a).into(),
+          b : self.b.simd_ge(rhs.b).into(),
         }
       }
     }
@@ -239,6 +240,7 @@ impl_simd_int! {
     T = i16,
     N = 16,
     Simd = i16x16,
+    Mask = m16x16,
     UnsignedSimd = u16x16,
     T_BITS = 16,
     T_BITS_MUL_2 = 32,
@@ -389,11 +391,11 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn overflowing_mul(self, rhs: Self) -> (Self, Self) {
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m16x16) {
     let (low, high) = self.mul_keep_low_high(rhs);
     let low = cast::<u16x16, i16x16>(low);
 
-    let overflow = high.simd_ne(low.is_negative());
+    let overflow = high.simd_ne(Self::from(low.is_negative()));
     (low, overflow)
   }
 
@@ -449,14 +451,14 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn is_positive(self) -> Self {
+  pub fn is_positive(self) -> m16x16 {
     pick! {
       if #[cfg(all(target_feature="neon", target_arch="aarch64"))] {
         // `neon` has dedicated greater-than-zero intrinsics.
-        Self {
-          a: self.a.is_positive(),
-          b: self.b.is_positive(),
-        }
+        m16x16::from_simd_unchecked(Self {
+          a: self.a.is_positive().into(),
+          b: self.b.is_positive().into(),
+        })
       } else {
         self.simd_gt(Self::ZERO)
       }
@@ -464,14 +466,14 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn is_negative(self) -> Self {
+  pub fn is_negative(self) -> m16x16 {
     pick! {
       if #[cfg(all(target_feature="neon", target_arch="aarch64"))] {
         // `neon` has dedicated less-than-zero intrinsics.
-        Self {
-          a: self.a.is_negative(),
-          b: self.b.is_negative(),
-        }
+        m16x16::from_simd_unchecked(Self {
+          a: self.a.is_negative().into(),
+          b: self.b.is_negative().into(),
+        })
       } else {
         self.simd_lt(Self::ZERO)
       }
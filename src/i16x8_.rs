@@ -83,6 +83,7 @@ impl_simd! {
     T = i16,
     N = 8,
     Simd = i16x8,
+    Mask = m16x8,
     optional_type_x86_inner { X86Inner = __m128i },
     optional_type_arm_inner { ArmInner = int16x8_t },
     optional_type_wasm_inner { WasmInner = v128 },
@@ -116,11 +117,11 @@ impl_simd! {
   fn simd_ne(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: i16x8_ne(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else {
         Self { arr: [
           if self.arr[0] != rhs.arr[0] { -1 } else { 0 },
@@ -188,11 +189,11 @@ impl_simd! {
   fn simd_le(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
-        !self.simd_gt(rhs)
+        Self::from(!self.simd_gt(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: i16x8_le(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_gt(rhs)
+        Self::from(!self.simd_gt(rhs))
       } else {
         Self { arr: [
           if self.arr[0] <= rhs.arr[0] { -1 } else { 0 },
@@ -212,11 +213,11 @@ impl_simd! {
   fn simd_ge(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
-        !self.simd_lt(rhs)
+        Self::from(!self.simd_lt(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: i16x8_ge(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_lt(rhs)
+        Self::from(!self.simd_lt(rhs))
       } else {
         Self { arr: [
           if self.arr[0] >= rhs.arr[0] { -1 } else { 0 },
@@ -477,6 +478,7 @@ impl_simd_int! {
     T = i16,
     N = 8,
     Simd = i16x8,
+    Mask = m16x8,
     UnsignedSimd = u16x8,
     T_BITS = 16,
     T_BITS_MUL_2 = 32,
@@ -679,7 +681,7 @@ impl_simd_int! {
       if #[cfg(target_feature="sse2")] {
         Self { sse: shr_all_i16_m128i(self.sse, cast([rhs as u64, 0])) }
       } else if #[cfg(target_feature="simd128")] {
-        if rhs < 16 { Self { simd: i16x8_shr(self.simd, rhs) } } else { self.is_negative() }
+        if rhs < 16 { Self { simd: i16x8_shr(self.simd, rhs) } } else { Self::from(self.is_negative()) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
         unsafe {
           // Negate `rhs` because there is no direct shift-right intrinsic, and
@@ -752,11 +754,11 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn overflowing_mul(self, rhs: Self) -> (Self, Self) {
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m16x8) {
     let (low, high) = self.mul_keep_low_high(rhs);
     let low = cast::<u16x8, i16x8>(low);
 
-    let overflow = high.simd_ne(low.is_negative());
+    let overflow = high.simd_ne(Self::from(low.is_negative()));
     (low, overflow)
   }
 
@@ -946,10 +948,10 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn is_positive(self) -> Self {
+  pub fn is_positive(self) -> m16x8 {
     pick! {
       if #[cfg(all(target_feature="neon", target_arch="aarch64"))] {
-        Self { neon: unsafe { vreinterpretq_s16_u16(vcgtzq_s16(self.neon)) } }
+        m16x8::from_simd_unchecked(Self { neon: unsafe { vreinterpretq_s16_u16(vcgtzq_s16(self.neon)) } })
       } else {
         self.simd_gt(Self::ZERO)
       }
@@ -957,10 +959,10 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn is_negative(self) -> Self {
+  pub fn is_negative(self) -> m16x8 {
     pick! {
       if #[cfg(all(target_feature="neon", target_arch="aarch64"))] {
-        Self { neon: unsafe { vreinterpretq_s16_u16(vcltzq_s16(self.neon)) } }
+        m16x8::from_simd_unchecked(Self { neon: unsafe { vreinterpretq_s16_u16(vcltzq_s16(self.neon)) } })
       } else {
         self.simd_lt(Self::ZERO)
       }
@@ -29,6 +29,7 @@ impl_simd! {
     T = i32,
     N = 16,
     Simd = i32x16,
+    Mask = m32x16,
     optional_type_x86_inner { X86Inner = __m512i },
     optional_type_arm_inner {},
     optional_type_wasm_inner {},
@@ -41,8 +42,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_i32_m512i::<{cmp_int_op!(Eq)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_eq(rhs.a),
-          b : self.b.simd_eq(rhs.b),
+          a : self.a.simd_eq(rhs.a).into(),
+          b : self.b.simd_eq(rhs.b).into(),
         }
       }
     }
@@ -55,8 +56,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_i32_m512i::<{cmp_int_op!(Ne)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_ne(rhs.a),
-          b : self.b.simd_ne(rhs.b),
+          a : self.a.simd_ne(rhs.a).into(),
+          b : self.b.simd_ne(rhs.b).into(),
         }
       }
     }
@@ -69,8 +70,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_i32_m512i::<{cmp_int_op!(Lt)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : rhs.a.simd_gt(self.a),
-          b : rhs.b.simd_gt(self.b),
+          a : rhs.a.simd_gt(self.a).into(),
+          b : rhs.b.simd_gt(self.b).into(),
         }
       }
     }
@@ -83,8 +84,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_i32_m512i::<{cmp_int_op!(Nle)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_gt(rhs.a),
-          b : self.b.simd_gt(rhs.b),
+          a : self.a.simd_gt(rhs.a).into(),
+          b : self.b.simd_gt(rhs.b).into(),
         }
       }
     }
@@ -97,8 +98,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_i32_m512i::<{cmp_int_op!(Le)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_le(rhs.a),
-          b : self.b.simd_le(rhs.b),
+          a : self.a.simd_le(rhs.a).into(),
+          b : self.b.simd_le(rhs.b).into(),
         }
       }
     }
@@ -111,8 +112,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_i32_m512i::<{cmp_int_op!(Nlt)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_ge(rhs.a),
-          b : self.b.simd_ge(rhs.b),
+          a : self.a.simd_ge(rhs.a).into(),
+          b : self.b.simd_ge(rhs.b).into(),
         }
       }
     }
@@ -240,6 +241,7 @@ impl_simd_int! {
     T = i32,
     N = 16,
     Simd = i32x16,
+    Mask = m32x16,
     UnsignedSimd = u32x16,
     T_BITS = 32,
     T_BITS_MUL_2 = 64,
@@ -368,7 +370,7 @@ impl_simd_int! {
         let negative = self.is_negative();
 
         // If overflow occurs return `MAX` if positive or `MIN` if negative.
-        overflow.select(Self::MAX ^ negative, result)
+        overflow.select(Self::MAX ^ Self::from(negative), result)
       } else {
         Self {
           a: self.a.saturating_add(rhs.a),
@@ -387,7 +389,7 @@ impl_simd_int! {
         let negative = self.is_negative();
 
         // If overflow occurs return `MAX` if positive or `MIN` if negative.
-        overflow.select(Self::MAX ^ negative, result)
+        overflow.select(Self::MAX ^ Self::from(negative), result)
       } else {
         Self {
           a: self.a.saturating_sub(rhs.a),
@@ -398,11 +400,11 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn overflowing_mul(self, rhs: Self) -> (Self, Self) {
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m32x16) {
     let (low, high) = self.mul_keep_low_high(rhs);
     let low = cast::<u32x16, i32x16>(low);
 
-    let overflow = high.simd_ne(low.is_negative());
+    let overflow = high.simd_ne(Self::from(low.is_negative()));
     (low, overflow)
   }
 
@@ -493,14 +495,14 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn is_positive(self) -> Self {
+  pub fn is_positive(self) -> m32x16 {
     pick! {
       if #[cfg(all(target_feature="neon", target_arch="aarch64"))] {
         // `neon` has dedicated greater-than-zero intrinsics.
-        Self {
-          a: self.a.is_positive(),
-          b: self.b.is_positive(),
-        }
+        m32x16::from_simd_unchecked(Self {
+          a: self.a.is_positive().into(),
+          b: self.b.is_positive().into(),
+        })
       } else {
         self.simd_gt(Self::ZERO)
       }
@@ -508,14 +510,14 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn is_negative(self) -> Self {
+  pub fn is_negative(self) -> m32x16 {
     pick! {
       if #[cfg(all(target_feature="neon", target_arch="aarch64"))] {
         // `neon` has dedicated less-than-zero intrinsics.
-        Self {
-          a: self.a.is_negative(),
-          b: self.b.is_negative(),
-        }
+        m32x16::from_simd_unchecked(Self {
+          a: self.a.is_negative().into(),
+          b: self.b.is_negative().into(),
+        })
       } else {
         self.simd_lt(Self::ZERO)
       }
@@ -83,6 +83,7 @@ impl_simd! {
     T = i32,
     N = 4,
     Simd = i32x4,
+    Mask = m32x4,
     optional_type_x86_inner { X86Inner = __m128i },
     optional_type_arm_inner { ArmInner = int32x4_t },
     optional_type_wasm_inner { WasmInner = v128 },
@@ -112,11 +113,11 @@ impl_simd! {
   fn simd_ne(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: i32x4_ne(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else {
         Self { arr: [
           if self.arr[0] != rhs.arr[0] { -1 } else { 0 },
@@ -172,11 +173,11 @@ impl_simd! {
   fn simd_le(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
-        !self.simd_gt(rhs)
+        Self::from(!self.simd_gt(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: i32x4_le(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_gt(rhs)
+        Self::from(!self.simd_gt(rhs))
       } else {
         Self { arr: [
           if self.arr[0] <= rhs.arr[0] { -1 } else { 0 },
@@ -192,11 +193,11 @@ impl_simd! {
   fn simd_ge(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
-        !self.simd_lt(rhs)
+        Self::from(!self.simd_lt(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: i32x4_ge(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_lt(rhs)
+        Self::from(!self.simd_lt(rhs))
       } else {
         Self { arr: [
           if self.arr[0] >= rhs.arr[0] { -1 } else { 0 },
@@ -359,6 +360,7 @@ impl_simd_int! {
     T = i32,
     N = 4,
     Simd = i32x4,
+    Mask = m32x4,
     UnsignedSimd = u32x4,
     T_BITS = 32,
     T_BITS_MUL_2 = 64,
@@ -490,7 +492,7 @@ impl_simd_int! {
       if #[cfg(target_feature="sse2")] {
         Self { sse: shr_all_i32_m128i(self.sse, cast([rhs as u64, 0])) }
       } else if #[cfg(target_feature="simd128")] {
-        if rhs < 32 { Self { simd: i32x4_shr(self.simd, rhs) } } else { self.is_negative() }
+        if rhs < 32 { Self { simd: i32x4_shr(self.simd, rhs) } } else { Self::from(self.is_negative()) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
         unsafe {
           // Negate `rhs` because there is no direct shift-right intrinsic, and
@@ -519,7 +521,7 @@ impl_simd_int! {
         let negative = self.is_negative();
 
         // If overflow occurs return `MAX` if positive or `MIN` if negative.
-        overflow.select(Self::MAX ^ negative, result)
+        overflow.select(Self::MAX ^ Self::from(negative), result)
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
         unsafe { Self { neon: vqaddq_s32(self.neon, rhs.neon) } }
       } else {
@@ -544,7 +546,7 @@ impl_simd_int! {
         let negative = self.is_negative();
 
         // If overflow occurs return `MAX` if positive or `MIN` if negative.
-        overflow.select(Self::MAX ^ negative, result)
+        overflow.select(Self::MAX ^ Self::from(negative), result)
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
         unsafe { Self { neon: vqsubq_s32(self.neon, rhs.neon) } }
       } else {
@@ -561,11 +563,11 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn overflowing_mul(self, rhs: Self) -> (Self, Self) {
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m32x4) {
     let (low, high) = self.mul_keep_low_high(rhs);
     let low = cast::<u32x4, i32x4>(low);
 
-    let overflow = high.simd_ne(low.is_negative());
+    let overflow = high.simd_ne(Self::from(low.is_negative()));
     (low, overflow)
   }
 
@@ -749,10 +751,10 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn is_positive(self) -> Self {
+  pub fn is_positive(self) -> m32x4 {
     pick! {
       if #[cfg(all(target_feature="neon", target_arch="aarch64"))] {
-        Self { neon: unsafe { vreinterpretq_s32_u32(vcgtzq_s32(self.neon)) } }
+        m32x4::from_simd_unchecked(Self { neon: unsafe { vreinterpretq_s32_u32(vcgtzq_s32(self.neon)) } })
       } else {
         self.simd_gt(Self::ZERO)
       }
@@ -760,10 +762,10 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn is_negative(self) -> Self {
+  pub fn is_negative(self) -> m32x4 {
     pick! {
       if #[cfg(all(target_feature="neon", target_arch="aarch64"))] {
-        Self { neon: unsafe { vreinterpretq_s32_u32(vcltzq_s32(self.neon)) } }
+        m32x4::from_simd_unchecked(Self { neon: unsafe { vreinterpretq_s32_u32(vcltzq_s32(self.neon)) } })
       } else {
         self.simd_lt(Self::ZERO)
       }
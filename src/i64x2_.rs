@@ -85,6 +85,7 @@ impl_simd! {
     T = i64,
     N = 2,
     Simd = i64x2,
+    Mask = m64x2,
     optional_type_x86_inner { X86Inner = __m128i },
     optional_type_arm_inner { ArmInner = int64x2_t },
     optional_type_wasm_inner { WasmInner = v128 },
@@ -114,11 +115,11 @@ impl_simd! {
   fn simd_ne(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse4.1")] {
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: i64x2_ne(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else {
         let s: [i64;2] = cast(self);
         let r: [i64;2] = cast(rhs);
@@ -175,11 +176,11 @@ impl_simd! {
   fn simd_le(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse4.1")] {
-        !self.simd_gt(rhs)
+        Self::from(!self.simd_gt(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: i64x2_le(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_gt(rhs)
+        Self::from(!self.simd_gt(rhs))
       } else {
         let s: [i64;2] = cast(self);
         let r: [i64;2] = cast(rhs);
@@ -195,11 +196,11 @@ impl_simd! {
   fn simd_ge(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse4.1")] {
-        !self.simd_lt(rhs)
+        Self::from(!self.simd_lt(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: i64x2_ge(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_lt(rhs)
+        Self::from(!self.simd_lt(rhs))
       } else {
         let s: [i64;2] = cast(self);
         let r: [i64;2] = cast(rhs);
@@ -320,6 +321,7 @@ impl_simd_int! {
     T = i64,
     N = 2,
     Simd = i64x2,
+    Mask = m64x2,
     UnsignedSimd = u64x2,
     T_BITS = 64,
     T_BITS_MUL_2 = 128,
@@ -412,9 +414,9 @@ impl_simd_int! {
       } else {
         // Cannot use scalar `unbounded_shl` because it takes `u32`, which is
         // smaller than `u64`.
-        cast::<u64x2, i64x2>(rhs.simd_lt(64)).select(
+        rhs.simd_lt(64).select(
           self >> cast::<u64x2, i64x2>(rhs),
-          self.is_negative(),
+          Self::from(self.is_negative()),
         )
       }
     }
@@ -424,7 +426,7 @@ impl_simd_int! {
   pub fn unbounded_shr_scalar(self, rhs: u32) -> Self {
     pick! {
       if #[cfg(target_feature="simd128")] {
-        if rhs < 64 { Self { simd: i64x2_shr(self.simd, rhs) } } else { self.is_negative() }
+        if rhs < 64 { Self { simd: i64x2_shr(self.simd, rhs) } } else { Self::from(self.is_negative()) }
       } else {
         let self_array = self.to_array();
 
@@ -445,7 +447,7 @@ impl_simd_int! {
         let negative = self.is_negative();
 
         // If overflow occurs return `MAX` if positive or `MIN` if negative.
-        overflow.select(Self::MAX ^ negative, result)
+        overflow.select(Self::MAX ^ Self::from(negative), result)
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
         unsafe { Self { neon: vqaddq_s64(self.neon, rhs.neon) } }
       } else {
@@ -468,7 +470,7 @@ impl_simd_int! {
         let negative = self.is_negative();
 
         // If overflow occurs return `MAX` if positive or `MIN` if negative.
-        overflow.select(Self::MAX ^ negative, result)
+        overflow.select(Self::MAX ^ Self::from(negative), result)
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
         unsafe { Self { neon: vqsubq_s64(self.neon, rhs.neon) } }
       } else {
@@ -483,7 +485,7 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn overflowing_mul(self, rhs: Self) -> (Self, Self) {
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m64x2) {
     // TODO(perf): This implementation looks quite bad. Is there a better
     // one? This intentionally avoids `mul_keep_low_high` because getting the
     // high bits of 64-bit multiplication could be slow.
@@ -497,7 +499,7 @@ impl_simd_int! {
     ];
     (
       Self::new([result[0].0, result[1].0]),
-      Self::new([-(result[0].1 as i64), -(result[1].1 as i64)]),
+      m64x2::new([result[0].1, result[1].1]),
     )
   }
 
@@ -561,10 +563,10 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn is_positive(self) -> Self {
+  pub fn is_positive(self) -> m64x2 {
     pick! {
       if #[cfg(all(target_feature="neon", target_arch="aarch64"))] {
-        Self { neon: unsafe { vreinterpretq_s64_u64(vcgtzq_s64(self.neon)) } }
+        m64x2::from_simd_unchecked(Self { neon: unsafe { vreinterpretq_s64_u64(vcgtzq_s64(self.neon)) } })
       } else {
         self.simd_gt(Self::ZERO)
       }
@@ -572,10 +574,10 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn is_negative(self) -> Self {
+  pub fn is_negative(self) -> m64x2 {
     pick! {
       if #[cfg(all(target_feature="neon", target_arch="aarch64"))] {
-        Self { neon: unsafe { vreinterpretq_s64_u64(vcltzq_s64(self.neon)) } }
+        m64x2::from_simd_unchecked(Self { neon: unsafe { vreinterpretq_s64_u64(vcltzq_s64(self.neon)) } })
       } else {
         self.simd_lt(Self::ZERO)
       }
@@ -29,6 +29,7 @@ impl_simd! {
     T = i64,
     N = 4,
     Simd = i64x4,
+    Mask = m64x4,
     optional_type_x86_inner { X86Inner = __m256i },
     optional_type_arm_inner {},
     optional_type_wasm_inner {},
@@ -41,8 +42,8 @@ impl_simd! {
         Self { avx2: cmp_eq_mask_i64_m256i(self.avx2, rhs.avx2) }
       } else {
         Self {
-          a : self.a.simd_eq(rhs.a),
-          b : self.b.simd_eq(rhs.b),
+          a : self.a.simd_eq(rhs.a).into(),
+          b : self.b.simd_eq(rhs.b).into(),
         }
       }
     }
@@ -52,11 +53,11 @@ impl_simd! {
   fn simd_ne(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else {
         Self {
-          a : self.a.simd_ne(rhs.a),
-          b : self.b.simd_ne(rhs.b),
+          a : self.a.simd_ne(rhs.a).into(),
+          b : self.b.simd_ne(rhs.b).into(),
         }
       }
     }
@@ -69,8 +70,8 @@ impl_simd! {
         Self { avx2: !(cmp_gt_mask_i64_m256i(self.avx2, rhs.avx2) ^ cmp_eq_mask_i64_m256i(self.avx2, rhs.avx2)) }
       } else {
         Self {
-          a : self.a.simd_lt(rhs.a),
-          b : self.b.simd_lt(rhs.b),
+          a : self.a.simd_lt(rhs.a).into(),
+          b : self.b.simd_lt(rhs.b).into(),
         }
       }
     }
@@ -83,8 +84,8 @@ impl_simd! {
         Self { avx2: cmp_gt_mask_i64_m256i(self.avx2, rhs.avx2) }
       } else {
         Self {
-          a : self.a.simd_gt(rhs.a),
-          b : self.b.simd_gt(rhs.b),
+          a : self.a.simd_gt(rhs.a).into(),
+          b : self.b.simd_gt(rhs.b).into(),
         }
       }
     }
@@ -94,11 +95,11 @@ impl_simd! {
   fn simd_le(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        !self.simd_gt(rhs)
+        Self::from(!self.simd_gt(rhs))
       } else {
         Self {
-          a : self.a.simd_le(rhs.a),
-          b : self.b.simd_le(rhs.b),
+          a : self.a.simd_le(rhs.a).into(),
+          b : self.b.simd_le(rhs.b).into(),
         }
       }
     }
@@ -108,11 +109,11 @@ impl_simd! {
   fn simd_ge(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        !self.simd_lt(rhs)
+        Self::from(!self.simd_lt(rhs))
       } else {
         Self {
-          a : self.a.simd_ge(rhs.a),
-          b : self.b.simd_ge(rhs.b),
+          a : self.a.simd_ge(rhs.a).into(),
+          b : self.b.simd_ge(rhs.b).into(),
         }
       }
     }
@@ -233,6 +234,7 @@ impl_simd_int! {
     T = i64,
     N = 4,
     Simd = i64x4,
+    Mask = m64x4,
     UnsignedSimd = u64x4,
     T_BITS = 64,
     T_BITS_MUL_2 = 128,
@@ -313,7 +315,7 @@ impl_simd_int! {
         let negative = self.is_negative();
 
         // If overflow occurs return `MAX` if positive or `MIN` if negative.
-        overflow.select(Self::MAX ^ negative, result)
+        overflow.select(Self::MAX ^ Self::from(negative), result)
       } else {
         Self {
           a: self.a.saturating_add(rhs.a),
@@ -332,7 +334,7 @@ impl_simd_int! {
         let negative = self.is_negative();
 
         // If overflow occurs return `MAX` if positive or `MIN` if negative.
-        overflow.select(Self::MAX ^ negative, result)
+        overflow.select(Self::MAX ^ Self::from(negative), result)
       } else {
         Self {
           a: self.a.saturating_sub(rhs.a),
@@ -343,7 +345,7 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn overflowing_mul(self, rhs: Self) -> (Self, Self) {
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m64x4) {
     // TODO(perf): This implementation looks quite bad. Is there a better
     // one? This intentionally avoids `mul_keep_low_high` because getting the
     // high bits of 64-bit multiplication could be slow.
@@ -359,11 +361,11 @@ impl_simd_int! {
     ];
     (
       Self::new([result[0].0, result[1].0, result[2].0, result[3].0]),
-      Self::new([
-        -(result[0].1 as i64),
-        -(result[1].1 as i64),
-        -(result[2].1 as i64),
-        -(result[3].1 as i64),
+      m64x4::new([
+        result[0].1,
+        result[1].1,
+        result[2].1,
+        result[3].1,
       ]),
     )
   }
@@ -439,14 +441,14 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn is_positive(self) -> Self {
+  pub fn is_positive(self) -> m64x4 {
     pick! {
       if #[cfg(all(target_feature="neon", target_arch="aarch64"))] {
         // `neon` has dedicated greater-than-zero intrinsics.
-        Self {
-          a: self.a.is_positive(),
-          b: self.b.is_positive(),
-        }
+        m64x4::from_simd_unchecked(Self {
+          a: self.a.is_positive().into(),
+          b: self.b.is_positive().into(),
+        })
       } else {
         self.simd_gt(Self::ZERO)
       }
@@ -454,14 +456,14 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn is_negative(self) -> Self {
+  pub fn is_negative(self) -> m64x4 {
     pick! {
       if #[cfg(all(target_feature="neon", target_arch="aarch64"))] {
         // `neon` has dedicated less-than-zero intrinsics.
-        Self {
-          a: self.a.is_negative(),
-          b: self.b.is_negative(),
-        }
+        m64x4::from_simd_unchecked(Self {
+          a: self.a.is_negative().into(),
+          b: self.b.is_negative().into(),
+        })
       } else {
         self.simd_lt(Self::ZERO)
       }
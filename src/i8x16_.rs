@@ -83,6 +83,7 @@ impl_simd! {
     T = i8,
     N = 16,
     Simd = i8x16,
+    Mask = m8x16,
     optional_type_x86_inner { X86Inner = __m128i },
     optional_type_arm_inner { ArmInner = int8x16_t },
     optional_type_wasm_inner { WasmInner = v128 },
@@ -124,11 +125,11 @@ impl_simd! {
   fn simd_ne(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: i8x16_ne(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else {
         Self { arr: [
           if self.arr[0] != rhs.arr[0] { -1 } else { 0 },
@@ -220,11 +221,11 @@ impl_simd! {
   fn simd_le(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
-        !self.simd_gt(rhs)
+        Self::from(!self.simd_gt(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: i8x16_le(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_gt(rhs)
+        Self::from(!self.simd_gt(rhs))
       } else {
         Self { arr: [
           if self.arr[0] <= rhs.arr[0] { -1 } else { 0 },
@@ -252,11 +253,11 @@ impl_simd! {
   fn simd_ge(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
-        !self.simd_lt(rhs)
+        Self::from(!self.simd_lt(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: i8x16_ge(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_lt(rhs)
+        Self::from(!self.simd_lt(rhs))
       } else {
         Self { arr: [
           if self.arr[0] >= rhs.arr[0] { -1 } else { 0 },
@@ -450,6 +451,7 @@ impl_simd_int! {
     T = i8,
     N = 16,
     Simd = i8x16,
+    Mask = m8x16,
     UnsignedSimd = u8x16,
     T_BITS = 8,
     T_BITS_MUL_2 = 16,
@@ -723,7 +725,7 @@ impl_simd_int! {
     // may not actually be faster than auto-vectorization.
     pick! {
       if #[cfg(target_feature="simd128")] {
-        if rhs < 8 { Self { simd: i8x16_shr(self.simd, rhs) } } else { self.is_negative() }
+        if rhs < 8 { Self { simd: i8x16_shr(self.simd, rhs) } } else { Self::from(self.is_negative()) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
         unsafe {
           // Negate `rhs` because there is no direct shift-right intrinsic, and
@@ -820,11 +822,11 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn overflowing_mul(self, rhs: Self) -> (Self, Self) {
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m8x16) {
     let (low, high) = self.mul_keep_low_high(rhs);
     let low = cast::<u8x16, i8x16>(low);
 
-    let overflow = high.simd_ne(low.is_negative());
+    let overflow = high.simd_ne(Self::from(low.is_negative()));
     (low, overflow)
   }
 
@@ -1029,10 +1031,10 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn is_positive(self) -> Self {
+  pub fn is_positive(self) -> m8x16 {
     pick! {
       if #[cfg(all(target_feature="neon", target_arch="aarch64"))] {
-        Self { neon: unsafe { vreinterpretq_s8_u8(vcgtzq_s8(self.neon)) } }
+        m8x16::from_simd_unchecked(Self { neon: unsafe { vreinterpretq_s8_u8(vcgtzq_s8(self.neon)) } })
       } else {
         self.simd_gt(Self::ZERO)
       }
@@ -1040,10 +1042,10 @@ impl_simd_int! {
   }
 
   #[inline]
-  pub fn is_negative(self) -> Self {
+  pub fn is_negative(self) -> m8x16 {
     pick! {
       if #[cfg(all(target_feature="neon", target_arch="aarch64"))] {
-        Self { neon: unsafe { vreinterpretq_s8_u8(vcltzq_s8(self.neon)) } }
+        m8x16::from_simd_unchecked(Self { neon: unsafe { vreinterpretq_s8_u8(vcltzq_s8(self.neon)) } })
       } else {
         self.simd_lt(Self::ZERO)
       }
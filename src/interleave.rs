@@ -0,0 +1,180 @@
+use super::*;
+use crate::shuffle::shuffle2_const;
+
+/// Implements conversions between interleaved data ("array of structs") and
+/// planar SIMD vectors ("struct of arrays"), like the NEON `vld2`/`vst2` to
+/// `vld4`/`vst4` instructions.
+///
+/// These are built from shuffles of two vectors with constant indices, see
+/// `shuffle2_const!`. Three vectors are combined with two shuffles, four
+/// vectors by splitting or merging pairs twice.
+macro_rules! impl_interleave {
+  (
+    Simd = $Simd:ident,
+    T = $T:ident,
+    N = $N:literal,
+  ) => {
+    impl_interleave!(@k $Simd, $T, $N, 2, deinterleave2, interleave2, load_deinterleaved2, store_interleaved2, "[x0, y0, x1, y1, ...]", "`[xs, ys]`");
+    impl_interleave!(@k $Simd, $T, $N, 3, deinterleave3, interleave3, load_deinterleaved3, store_interleaved3, "[x0, y0, z0, x1, y1, z1, ...]", "`[xs, ys, zs]`");
+    impl_interleave!(@k $Simd, $T, $N, 4, deinterleave4, interleave4, load_deinterleaved4, store_interleaved4, "[x0, y0, z0, w0, x1, y1, z1, w1, ...]", "`[xs, ys, zs, ws]`");
+  };
+  (@deinterleave 2, $Simd:ident, $N:literal, $data:ident) => {{
+    let [a, b] = $data;
+    [
+      shuffle2_const!($Simd, a, b, |i| 2 * i),
+      shuffle2_const!($Simd, a, b, |i| 2 * i + 1),
+    ]
+  }};
+  (@deinterleave 3, $Simd:ident, $N:literal, $data:ident) => {{
+    let [a, b, c] = $data;
+    [
+      impl_interleave!(@select3 $Simd, $N, a, b, c, |i| 3 * i),
+      impl_interleave!(@select3 $Simd, $N, a, b, c, |i| 3 * i + 1),
+      impl_interleave!(@select3 $Simd, $N, a, b, c, |i| 3 * i + 2),
+    ]
+  }};
+  (@deinterleave 4, $Simd:ident, $N:literal, $data:ident) => {{
+    let [a, b, c, d] = $data;
+    let [ab_even, ab_odd] = Self::deinterleave2([a, b]);
+    let [cd_even, cd_odd] = Self::deinterleave2([c, d]);
+    let [x, z] = Self::deinterleave2([ab_even, cd_even]);
+    let [y, w] = Self::deinterleave2([ab_odd, cd_odd]);
+    [x, y, z, w]
+  }};
+  (@interleave 2, $Simd:ident, $N:literal, $planes:ident) => {{
+    let [x, y] = $planes;
+    [x.interleave_low(y), x.interleave_high(y)]
+  }};
+  (@interleave 3, $Simd:ident, $N:literal, $planes:ident) => {{
+    let [x, y, z] = $planes;
+    [
+      impl_interleave!(@select3 $Simd, $N, x, y, z, |i| {
+        let j = i;
+        j % 3 * $N + j / 3
+      }),
+      impl_interleave!(@select3 $Simd, $N, x, y, z, |i| {
+        let j = $N + i;
+        j % 3 * $N + j / 3
+      }),
+      impl_interleave!(@select3 $Simd, $N, x, y, z, |i| {
+        let j = 2 * $N + i;
+        j % 3 * $N + j / 3
+      }),
+    ]
+  }};
+  (@interleave 4, $Simd:ident, $N:literal, $planes:ident) => {{
+    let [x, y, z, w] = $planes;
+    let [xz_low, xz_high] = Self::interleave2([x, z]);
+    let [yw_low, yw_high] = Self::interleave2([y, w]);
+    let [a, b] = Self::interleave2([xz_low, yw_low]);
+    let [c, d] = Self::interleave2([xz_high, yw_high]);
+    [a, b, c, d]
+  }};
+  // Selects element `index % N` of vector `index / N` of `[a, b, c]` for
+  // each `i`: first from `a` and `b`, then from that and `c`.
+  (
+    @select3 $Simd:ident, $N:literal, $a:ident, $b:ident, $c:ident,
+    |$i:ident| $index:expr
+  ) => {{
+    let ab = shuffle2_const!($Simd, $a, $b, |$i| {
+      let index = $index;
+      if index < 2 * $N { index } else { 0 }
+    });
+    shuffle2_const!($Simd, ab, $c, |$i| {
+      let index = $index;
+      if index < 2 * $N { $i } else { index - $N }
+    })
+  }};
+  (
+    @k $Simd:ident, $T:ident, $N:literal, $K:tt,
+    $deinterleave:ident, $interleave:ident,
+    $load:ident, $store:ident,
+    $layout:literal, $planes:literal
+  ) => {
+    impl $Simd {
+      #[doc = concat!("Splits ", $K, " vectors of interleaved elements `", $layout, "` into")]
+      #[doc = concat!("the ", $K, " planar vectors ", $planes, ".")]
+      ///
+      #[doc = concat!("This is the inverse of [`", stringify!($interleave), "`](Self::", stringify!($interleave), ").")]
+      #[inline]
+      #[must_use]
+      pub fn $deinterleave(data: [Self; $K]) -> [Self; $K] {
+        impl_interleave!(@deinterleave $K, $Simd, $N, data)
+      }
+
+      #[doc = concat!("Merges the ", $K, " planar vectors ", $planes, " into ", $K, " vectors")]
+      #[doc = concat!("of interleaved elements `", $layout, "`.")]
+      ///
+      #[doc = concat!("This is the inverse of [`", stringify!($deinterleave), "`](Self::", stringify!($deinterleave), ").")]
+      #[inline]
+      #[must_use]
+      pub fn $interleave(planes: [Self; $K]) -> [Self; $K] {
+        impl_interleave!(@interleave $K, $Simd, $N, planes)
+      }
+
+      #[doc = concat!("Loads ", $K, " * ", $N, " interleaved elements `", $layout, "`")]
+      #[doc = concat!("from `slice` into the ", $K, " planar vectors ", $planes, ".")]
+      ///
+      /// Extra elements of `slice` are ignored.
+      ///
+      /// # Panics
+      ///
+      #[doc = concat!("Panics if `slice` has less than ", $K, " * ", $N, " elements.")]
+      #[inline]
+      #[must_use]
+      pub fn $load(slice: &[$T]) -> [Self; $K] {
+        let Some(data) = slice.first_chunk::<{ $K * $N }>() else {
+          panic!("slice is too short");
+        };
+        Self::$deinterleave(cast(*data))
+      }
+
+      #[doc = concat!("Stores the ", $K, " planar vectors ", $planes, " into `slice` as")]
+      #[doc = concat!($K, " * ", $N, " interleaved elements `", $layout, "`.")]
+      ///
+      /// Elements of `slice` past the stored elements are left unchanged.
+      ///
+      /// # Panics
+      ///
+      #[doc = concat!("Panics if `slice` has less than ", $K, " * ", $N, " elements.")]
+      #[inline]
+      pub fn $store(planes: [Self; $K], slice: &mut [$T]) {
+        let Some(data) = slice.first_chunk_mut::<{ $K * $N }>() else {
+          panic!("slice is too short");
+        };
+        *data = cast(Self::$interleave(planes));
+      }
+    }
+  };
+}
+
+impl_interleave! { Simd = f32x4, T = f32, N = 4, }
+impl_interleave! { Simd = f32x8, T = f32, N = 8, }
+impl_interleave! { Simd = f32x16, T = f32, N = 16, }
+impl_interleave! { Simd = f64x2, T = f64, N = 2, }
+impl_interleave! { Simd = f64x4, T = f64, N = 4, }
+impl_interleave! { Simd = f64x8, T = f64, N = 8, }
+impl_interleave! { Simd = i8x16, T = i8, N = 16, }
+impl_interleave! { Simd = i8x32, T = i8, N = 32, }
+impl_interleave! { Simd = i8x64, T = i8, N = 64, }
+impl_interleave! { Simd = i16x8, T = i16, N = 8, }
+impl_interleave! { Simd = i16x16, T = i16, N = 16, }
+impl_interleave! { Simd = i16x32, T = i16, N = 32, }
+impl_interleave! { Simd = i32x4, T = i32, N = 4, }
+impl_interleave! { Simd = i32x8, T = i32, N = 8, }
+impl_interleave! { Simd = i32x16, T = i32, N = 16, }
+impl_interleave! { Simd = i64x2, T = i64, N = 2, }
+impl_interleave! { Simd = i64x4, T = i64, N = 4, }
+impl_interleave! { Simd = i64x8, T = i64, N = 8, }
+impl_interleave! { Simd = u8x16, T = u8, N = 16, }
+impl_interleave! { Simd = u8x32, T = u8, N = 32, }
+impl_interleave! { Simd = u8x64, T = u8, N = 64, }
+impl_interleave! { Simd = u16x8, T = u16, N = 8, }
+impl_interleave! { Simd = u16x16, T = u16, N = 16, }
+impl_interleave! { Simd = u16x32, T = u16, N = 32, }
+impl_interleave! { Simd = u32x4, T = u32, N = 4, }
+impl_interleave! { Simd = u32x8, T = u32, N = 8, }
+impl_interleave! { Simd = u32x16, T = u32, N = 16, }
+impl_interleave! { Simd = u64x2, T = u64, N = 2, }
+impl_interleave! { Simd = u64x4, T = u64, N = 4, }
+impl_interleave! { Simd = u64x8, T = u64, N = 8, }
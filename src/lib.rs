@@ -22,15 +22,21 @@
 //!
 //! # Masks
 //!
-//! SIMD vector masks are per-element booleans, and are represented by SIMD
-//! vectors where each element's value in bits is either all zeros (`false`) or
-//! all ones (`true`).
+//! SIMD vector masks are per-element booleans. Each lane width has its own
+//! mask type, such as [`m32x4`] for [`f32x4`], [`i32x4`] and [`u32x4`]. In
+//! memory, every element of a mask is either all zeros (`false`) or all ones
+//! (`true`).
 //!
 //! SIMD versions of functions that regularly return `bool` return masks. For
 //! example, [`f32::is_sign_positive`] returns `bool`, and
-//! [`f32x4::is_sign_positive`] returns an [`f32x4`] that represents a mask.
-//! The [`select`] method can be used to perform a per-element `if` statement
-//! over a mask. For example, for this simple scalar code:
+//! [`f32x4::is_sign_positive`] returns an [`m32x4`]. Masks can be combined
+//! with `&`, `|`, `^` and `!`, queried with [`any`](m32x4::any),
+//! [`all`](m32x4::all) and [`none`](m32x4::none), and converted to and from
+//! integer bitmasks. Masks of the same lane count but different lane widths
+//! convert into each other with [`From`].
+//!
+//! The [`select`] method can be used to perform a per-element
+//! `if` statement over a mask. For example, for this simple scalar code:
 //!
 //! ```
 //! let x = 1.0_f32;
@@ -70,6 +76,61 @@
 //! The reason for this is that enforcing guarantees would add substantial
 //! overhead to operations, and is generally not worth it.
 //!
+//! # Deterministic results
+//!
+//! By default, float operations may return different results on different
+//! platforms or with different target features. For example `mul_add` is only
+//! fused when FMA is available, `recip` is an approximation on x86, and the
+//! order of additions in `reduce_add` depends on the vector backend.
+//!
+//! The `deterministic` feature makes every float operation return the same
+//! bits on every platform, including SSE, AVX, AVX-512, NEON, WASM `simd128`
+//! and the scalar fallback:
+//!
+//! * `mul_add` and its variants round after the multiplication and after the
+//!   addition, as if they were written with separate operations.
+//! * `recip` and `recip_sqrt` are computed with a division.
+//! * `reduce_add` and `reduce_mul` combine the elements in order, starting
+//!   with the first.
+//! * `max`, `min` and their `fast_*` versions return `self` if both elements
+//!   are zero, and `fast_round_int`/`fast_trunc_int` behave like `round_int`
+//!   and `trunc_int`.
+//! * NaN results of arithmetic, `sqrt`, rounding and float to float casts are
+//!   always [`f32::NAN`] or [`f64::NAN`]. Operations that only move bits, like
+//!   `neg`, `abs`, `copysign` and `select`, keep the bits of their input.
+//!
+//! The math functions are built from these operations, so they are
+//! deterministic too. This costs speed, especially for `mul_add` on targets
+//! with FMA. Conversions between `f32` and the 16-bit float types are not
+//! covered, and neither are targets whose scalar floats do not follow IEEE
+//! 754, like 32-bit x86 without SSE2.
+//!
+//! # Accuracy tiers
+//!
+//! The math functions of SIMD vectors of floats, such as [`f32x4::exp`] and
+//! [`f32x4::sin`], use a single polynomial approximation each and have
+//! unspecified precision. For `f32` vectors, many of them come in two more
+//! tiers with stated error bounds:
+//!
+//! - The `fast_*` functions, such as [`f32x4::fast_exp`] and
+//!   [`f32x4::fast_sin`], use shorter polynomials and skip the handling of
+//!   special cases. They are within 64 to 256 ULP of the exact value, see the
+//!   documentation of each function.
+//! - The `precise_*` functions, such as [`f32x4::precise_exp`] and
+//!   [`f32x4::precise_sin`], are within 1 ULP of the exact value for all
+//!   inputs. They are computed with `f64` vectors and are several times slower
+//!   than the default tier.
+//!
+//! [`recip`](f32x4::recip) and [`recip_sqrt`](f32x4::recip_sqrt) use the
+//! approximate `rcpps` and `rsqrtps` instructions on x86, which have about 12
+//! bits of precision, but are exact divisions on other platforms. Use
+//! [`precise_recip`](f32x4::precise_recip) and
+//! [`precise_recip_sqrt`](f32x4::precise_recip_sqrt) when the result needs to
+//! be accurate everywhere.
+//!
+//! The bounds of both tiers are tested over all `f32` inputs. Results within a
+//! bound can still differ between platforms.
+//!
 //! # Wrapping semantics
 //!
 //! SIMD vectors of integers treat operators as wrapping, as if [`Wrapping<T>`]
@@ -97,13 +158,30 @@
 //! This typically does not have much, if any, runtime overhead in optimized
 //! builds.
 //!
+//! Mask types only implement [`bytemuck::Zeroable`], because not every bit
+//! pattern is a valid mask. Use `From` to turn a mask into a vector, and
+//! `from_int` to turn a vector into a mask.
+//!
+//! # Generic code
+//!
+//! The [`SimdVector`], [`SimdFloat`], [`SimdInt`] and [`SimdUint`] traits
+//! expose the functionality shared by the SIMD types, so that a function can be
+//! written once and used with several lane counts, such as [`f32x4`] and
+//! [`f32x8`]. The [`SimdMask`] trait does the same for the mask types.
+//!
+//! The [`dispatch`] module builds on these traits to pick the widest SIMD
+//! vectors supported by the CPU at runtime.
+//!
 //! # Feature flags
 //!
 //! * `std`: This causes the feature to link to `std`.
-//!   * Currently this just improves the performance of `sqrt` when an explicit
-//!     SIMD `sqrt` isn't available.
+//!   * This improves the performance of `sqrt` when an explicit SIMD `sqrt`
+//!     isn't available.
+//!   * This enables runtime CPU feature detection in [`dispatch`].
+//! * `deterministic`: This makes float operations return bit-identical results
+//!   on every platform, see [Deterministic results](#deterministic-results).
 //!
-//! [`select`]: f32x4::select
+//! [`select`]: m32x4::select
 //! [`Wrapping<T>`]: core::num::Wrapping
 
 // Note(Lokathor): Due to standard library magic, the std-only methods for f32
@@ -113,7 +191,6 @@ extern crate std;
 
 // TODO
 // Add/Sub/Mul/Div with constant
-// Shuffle left/right/by index
 
 use core::ops::*;
 
@@ -126,6 +203,10 @@ use bytemuck::*;
 // Re-export so that users don't need to add a bytemuck dependency of their own
 pub use bytemuck;
 
+#[macro_use]
+mod traits;
+pub use traits::*;
+
 #[macro_use]
 mod simd;
 #[macro_use]
@@ -164,7 +245,7 @@ macro_rules! pick {
   (@__forests [$($not:meta,)*]; [{$($m:meta),*} {$($tokens:tt)*}], $($rest:tt)*) => {
     #[cfg(all( $($m,)* not(any($($not),*)) ))]
     pick!{ @__identity $($tokens)* }
-    pick!{ @__forests [ $($not,)* $($m,)* ] ; $($rest)* }
+    pick!{ @__forests [ $($not,)* all($($m),*), ] ; $($rest)* }
   };
   (@__identity $($tokens:tt)*) => {
     $($tokens)*
@@ -287,6 +368,38 @@ macro_rules! polynomial_13 {
   }};
 }
 
+mod mask;
+pub use mask::*;
+
+mod masked_memory;
+
+mod gather_scatter;
+
+mod shuffle;
+#[doc(hidden)]
+pub use shuffle::ShuffleIndices;
+
+mod interleave;
+
+mod reduce;
+
+mod scan;
+
+mod compress;
+
+mod convert;
+pub use convert::*;
+
+mod parse;
+pub use parse::*;
+
+mod accuracy;
+
+mod half;
+pub use half::*;
+
+pub mod dispatch;
+
 mod f32x16_;
 pub use f32x16_::*;
 
@@ -317,6 +430,9 @@ pub use i16x32_::*;
 mod i8x32_;
 pub use i8x32_::*;
 
+mod i8x64_;
+pub use i8x64_::*;
+
 mod i16x8_;
 pub use i16x8_::*;
 
@@ -344,6 +460,9 @@ pub use u8x16_::*;
 mod u8x32_;
 pub use u8x32_::*;
 
+mod u8x64_;
+pub use u8x64_::*;
+
 mod u16x8_;
 pub use u16x8_::*;
 
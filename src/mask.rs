@@ -0,0 +1,505 @@
+use super::*;
+
+macro_rules! impl_mask {
+  (
+    // SAFETY: The contents of this macro assume that:
+    //
+    // - `Int` is the signed integer SIMD vector with `N` lanes of `T`
+    // - `Blend` has the same size and lane count as `Int`
+    unsafe {
+      T = $T:ident,
+      N = $N:literal,
+      Mask = $Mask:ident,
+      Int = $Int:ident,
+      Blend = $Blend:ident,
+      Vectors = [$($Vector:ident),+],
+    }
+  ) => {
+    #[doc = concat!(
+      "A [mask] of ", stringify!($N), " lanes, returned by comparisons of SIMD ",
+      "vectors with ", stringify!($N), " lanes of ", stringify!($T),
+      "-sized elements.\n\n",
+      "Each lane is either `true` or `false`. Internally each lane is stored ",
+      "with all bits set or all bits clear, so converting to and from the ",
+      "matching SIMD vectors is free.\n\n",
+      "[mask]: crate#masks",
+    )]
+    #[derive(Clone, Copy, Default, PartialEq)]
+    #[repr(transparent)]
+    #[allow(non_camel_case_types)]
+    pub struct $Mask($Int);
+
+    impl Eq for $Mask {}
+
+    impl core::hash::Hash for $Mask {
+      #[inline]
+      fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.to_bitmask().hash(state);
+      }
+    }
+
+    // SAFETY: The all-zeros bit pattern is the all-`false` mask.
+    unsafe impl Zeroable for $Mask {}
+
+    impl core::fmt::Debug for $Mask {
+      #[allow(clippy::missing_inline_in_public_items)]
+      fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "(")?;
+        for (i, x) in self.to_array().iter().enumerate() {
+          if i > 0 {
+            write!(f, ", ")?;
+          }
+          core::fmt::Debug::fmt(x, f)?;
+        }
+        write!(f, ")")
+      }
+    }
+
+    impl From<[bool; $N]> for $Mask {
+      /// Converts an array of `bool` to a mask.
+      #[inline]
+      fn from(arr: [bool; $N]) -> Self {
+        Self::new(arr)
+      }
+    }
+
+    impl From<$Mask> for [bool; $N] {
+      /// Converts a mask to an array of `bool`.
+      #[inline]
+      fn from(mask: $Mask) -> Self {
+        mask.to_array()
+      }
+    }
+
+    impl From<bool> for $Mask {
+      /// Converts a single `bool` to a mask by setting all lanes to that value.
+      #[inline]
+      fn from(value: bool) -> Self {
+        Self::splat(value)
+      }
+    }
+
+    $(
+      impl From<$Mask> for $Vector {
+        /// Converts a mask to a SIMD vector where each element has all bits
+        /// set if the lane is `true` and all bits clear if it is `false`.
+        #[inline]
+        fn from(mask: $Mask) -> Self {
+          cast(mask.0)
+        }
+      }
+    )+
+
+    impl Not for $Mask {
+      type Output = Self;
+
+      #[inline]
+      fn not(self) -> Self {
+        Self(!self.0)
+      }
+    }
+
+    impl BitAnd for $Mask {
+      type Output = Self;
+
+      #[inline]
+      fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+      }
+    }
+
+    impl BitOr for $Mask {
+      type Output = Self;
+
+      #[inline]
+      fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+      }
+    }
+
+    impl BitXor for $Mask {
+      type Output = Self;
+
+      #[inline]
+      fn bitxor(self, rhs: Self) -> Self {
+        Self(self.0 ^ rhs.0)
+      }
+    }
+
+    impl BitAndAssign for $Mask {
+      #[inline]
+      fn bitand_assign(&mut self, rhs: Self) {
+        *self = *self & rhs;
+      }
+    }
+
+    impl BitOrAssign for $Mask {
+      #[inline]
+      fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+      }
+    }
+
+    impl BitXorAssign for $Mask {
+      #[inline]
+      fn bitxor_assign(&mut self, rhs: Self) {
+        *self = *self ^ rhs;
+      }
+    }
+
+    impl $Mask {
+      /// Converts an array of `bool` to a mask.
+      #[inline]
+      #[must_use]
+      pub fn new(array: [bool; $N]) -> Self {
+        Self($Int::new(array.map(|b| -(b as $T))))
+      }
+
+      /// Constructs a new mask with all lanes set to the given value.
+      #[inline]
+      #[must_use]
+      pub const fn splat(value: bool) -> Self {
+        Self($Int::splat(-(value as $T)))
+      }
+
+      /// Converts a mask to an array of `bool`.
+      #[inline]
+      #[must_use]
+      pub fn to_array(self) -> [bool; $N] {
+        self.0.to_array().map(|x| x != 0)
+      }
+
+      /// Returns the value of the lane at `index`.
+      ///
+      /// # Panics
+      ///
+      #[doc = concat!("Panics if `index` is not less than ", stringify!($N), ".")]
+      #[inline]
+      #[must_use]
+      pub fn test(self, index: usize) -> bool {
+        self.0.as_array()[index] != 0
+      }
+
+      /// Sets the lane at `index` to `value`.
+      ///
+      /// # Panics
+      ///
+      #[doc = concat!("Panics if `index` is not less than ", stringify!($N), ".")]
+      #[inline]
+      pub fn set(&mut self, index: usize, value: bool) {
+        self.0.as_mut_array()[index] = -(value as $T);
+      }
+
+      /// Converts an integer SIMD vector to a mask, where each lane is `true`
+      /// if the corresponding element is non-zero.
+      #[inline]
+      #[must_use]
+      pub fn from_int(int: $Int) -> Self {
+        int.simd_ne($Int::ZERO)
+      }
+
+      /// Converts a mask to an integer SIMD vector, where each element is `-1`
+      /// if the lane is `true` and `0` if it is `false`.
+      #[inline]
+      #[must_use]
+      pub fn to_int(self) -> $Int {
+        self.0
+      }
+
+      /// Constructs a mask from a SIMD vector that is known to have all bits
+      /// set or all bits clear in every element.
+      #[inline]
+      #[must_use]
+      pub(crate) fn from_simd_unchecked<V: Pod>(simd: V) -> Self {
+        Self(cast(simd))
+      }
+
+      /// Constructs a mask where the first `count` lanes are `true` and the
+      /// remaining lanes are `false`.
+      #[inline]
+      #[must_use]
+      pub(crate) fn first_lanes(count: usize) -> Self {
+        Self::new(core::array::from_fn(|i| i < count))
+      }
+
+      /// Converts a bitmask to a mask, where each lane is `true` if the
+      /// corresponding bit of `bitmask` is `1`.
+      ///
+      /// The least significant bit corresponds to the lowest lane. Remaining
+      /// bits are ignored.
+      #[inline]
+      #[must_use]
+      pub fn from_bitmask(bitmask: u64) -> Self {
+        Self::new(core::array::from_fn(|i| bitmask & (1 << i) != 0))
+      }
+
+      /// Converts to a bitmask, where each bit is `1` if the lane of `self` is
+      /// `true` or `0` if the lane of `self` is `false`.
+      ///
+      /// The least significant bit corresponds to the lowest lane. Remaining
+      /// bits are `0`.
+      #[inline]
+      #[must_use]
+      #[doc(alias("movemask", "move_mask"))]
+      pub fn to_bitmask(self) -> u64 {
+        #[expect(deprecated)]
+        u64::from(self.0.to_bitmask())
+      }
+
+      /// Returns `true` if any lane of `self` is `true`.
+      #[inline]
+      #[must_use]
+      pub fn any(self) -> bool {
+        #[expect(deprecated)]
+        self.0.any()
+      }
+
+      /// Returns `true` if all lanes of `self` are `true`.
+      #[inline]
+      #[must_use]
+      pub fn all(self) -> bool {
+        #[expect(deprecated)]
+        self.0.all()
+      }
+
+      /// Returns `true` if none of the lanes of `self` are `true`.
+      #[inline]
+      #[must_use]
+      pub fn none(self) -> bool {
+        !self.any()
+      }
+
+      /// Elementwise selection.
+      ///
+      /// For each lane of `self`:
+      ///
+      /// - If the lane is `true`, return the corresponding element of
+      ///   `if_true`
+      /// - If the lane is `false`, return the corresponding element of
+      ///   `if_false`
+      #[inline]
+      #[must_use]
+      pub fn select<V>(self, if_true: V, if_false: V) -> V
+      where
+        V: SimdVector<Mask = Self>,
+      {
+        #[expect(deprecated)]
+        cast(cast::<$Int, $Blend>(self.0).select(cast(if_true), cast(if_false)))
+      }
+    }
+
+    impl SimdMask for $Mask {
+      const LANES: usize = $N;
+
+      #[inline]
+      fn splat(value: bool) -> Self {
+        Self::splat(value)
+      }
+
+      #[inline]
+      fn test(self, index: usize) -> bool {
+        Self::test(self, index)
+      }
+
+      #[inline]
+      fn from_bitmask(bitmask: u64) -> Self {
+        Self::from_bitmask(bitmask)
+      }
+
+      #[inline]
+      fn to_bitmask(self) -> u64 {
+        Self::to_bitmask(self)
+      }
+
+      #[inline]
+      fn any(self) -> bool {
+        Self::any(self)
+      }
+
+      #[inline]
+      fn all(self) -> bool {
+        Self::all(self)
+      }
+
+      #[inline]
+      fn none(self) -> bool {
+        Self::none(self)
+      }
+
+      #[inline]
+      fn select<V>(self, if_true: V, if_false: V) -> V
+      where
+        V: SimdVector<Mask = Self>,
+      {
+        Self::select(self, if_true, if_false)
+      }
+    }
+  };
+}
+
+macro_rules! impl_mask_conversions {
+  ($($From:ident <=> $To:ident),+ $(,)?) => {
+    $(
+      impl From<$From> for $To {
+        /// Converts a mask to a mask of a different lane width with the same
+        /// lane count.
+        #[inline]
+        fn from(mask: $From) -> Self {
+          Self::from_bitmask(mask.to_bitmask())
+        }
+      }
+
+      impl From<$To> for $From {
+        /// Converts a mask to a mask of a different lane width with the same
+        /// lane count.
+        #[inline]
+        fn from(mask: $To) -> Self {
+          Self::from_bitmask(mask.to_bitmask())
+        }
+      }
+    )+
+  };
+}
+
+impl_mask! {
+  unsafe {
+    T = i8,
+    N = 16,
+    Mask = m8x16,
+    Int = i8x16,
+    Blend = i8x16,
+    Vectors = [i8x16, u8x16],
+  }
+}
+
+impl_mask! {
+  unsafe {
+    T = i8,
+    N = 32,
+    Mask = m8x32,
+    Int = i8x32,
+    Blend = i8x32,
+    Vectors = [i8x32, u8x32],
+  }
+}
+
+impl_mask! {
+  unsafe {
+    T = i8,
+    N = 64,
+    Mask = m8x64,
+    Int = i8x64,
+    Blend = i8x64,
+    Vectors = [i8x64, u8x64],
+  }
+}
+
+impl_mask! {
+  unsafe {
+    T = i16,
+    N = 8,
+    Mask = m16x8,
+    Int = i16x8,
+    Blend = i16x8,
+    Vectors = [i16x8, u16x8],
+  }
+}
+
+impl_mask! {
+  unsafe {
+    T = i16,
+    N = 16,
+    Mask = m16x16,
+    Int = i16x16,
+    Blend = i16x16,
+    Vectors = [i16x16, u16x16],
+  }
+}
+
+impl_mask! {
+  unsafe {
+    T = i16,
+    N = 32,
+    Mask = m16x32,
+    Int = i16x32,
+    Blend = i16x32,
+    Vectors = [i16x32, u16x32],
+  }
+}
+
+impl_mask! {
+  unsafe {
+    T = i32,
+    N = 4,
+    Mask = m32x4,
+    Int = i32x4,
+    Blend = f32x4,
+    Vectors = [f32x4, i32x4, u32x4],
+  }
+}
+
+impl_mask! {
+  unsafe {
+    T = i32,
+    N = 8,
+    Mask = m32x8,
+    Int = i32x8,
+    Blend = f32x8,
+    Vectors = [f32x8, i32x8, u32x8],
+  }
+}
+
+impl_mask! {
+  unsafe {
+    T = i32,
+    N = 16,
+    Mask = m32x16,
+    Int = i32x16,
+    Blend = f32x16,
+    Vectors = [f32x16, i32x16, u32x16],
+  }
+}
+
+impl_mask! {
+  unsafe {
+    T = i64,
+    N = 2,
+    Mask = m64x2,
+    Int = i64x2,
+    Blend = f64x2,
+    Vectors = [f64x2, i64x2, u64x2],
+  }
+}
+
+impl_mask! {
+  unsafe {
+    T = i64,
+    N = 4,
+    Mask = m64x4,
+    Int = i64x4,
+    Blend = f64x4,
+    Vectors = [f64x4, i64x4, u64x4],
+  }
+}
+
+impl_mask! {
+  unsafe {
+    T = i64,
+    N = 8,
+    Mask = m64x8,
+    Int = i64x8,
+    Blend = f64x8,
+    Vectors = [f64x8, i64x8, u64x8],
+  }
+}
+
+impl_mask_conversions! {
+  m32x4 <=> m64x4,
+  m16x8 <=> m32x8,
+  m16x8 <=> m64x8,
+  m32x8 <=> m64x8,
+  m8x16 <=> m16x16,
+  m8x16 <=> m32x16,
+  m16x16 <=> m32x16,
+  m8x32 <=> m16x32,
+}
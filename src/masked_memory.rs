@@ -0,0 +1,375 @@
+use super::*;
+
+/// Implements the raw masked loads and stores that the slice functions of
+/// `impl_simd!` are built on.
+///
+/// `avx512` names the AVX-512 masked move intrinsics, which take a bitmask.
+/// `avx` optionally names the `vmaskmov` intrinsics, which take a vector
+/// mask and zero the lanes that are not loaded.
+macro_rules! impl_masked_memory {
+  (
+    Simd = $Simd:ident,
+    T = $T:ident,
+    Mask = $Mask:ident,
+    avx512 = #[cfg($($avx512:meta),+)] $load512:ident, $store512:ident;
+    $(avx = #[cfg($($avx:meta),+)] $load:ident, $store:ident;)?
+  ) => {
+    impl $Simd {
+      /// Loads the lanes that are `true` in `mask` from `ptr`, and takes the
+      /// other lanes from `fill`.
+      ///
+      /// # Safety
+      ///
+      /// `ptr` must be valid for reads of every lane that is `true` in `mask`.
+      /// Lanes that are `false` are never accessed.
+      #[inline]
+      #[must_use]
+      pub(crate) unsafe fn masked_load_ptr(
+        ptr: *const $T,
+        mask: $Mask,
+        fill: Self,
+      ) -> Self {
+        pick! {
+          if #[cfg($($avx512),+)] {
+            #[cfg(target_arch = "x86")]
+            use core::arch::x86::$load512;
+            #[cfg(target_arch = "x86_64")]
+            use core::arch::x86_64::$load512;
+
+            // SAFETY: Masked moves do not access, or fault on, the lanes that
+            // are `false` in `mask`.
+            Self::from(unsafe {
+              $load512(fill.into(), mask.to_bitmask() as _, ptr.cast())
+            })
+          } $(else if #[cfg($($avx),+)] {
+            #[cfg(target_arch = "x86")]
+            use core::arch::x86::$load;
+            #[cfg(target_arch = "x86_64")]
+            use core::arch::x86_64::$load;
+
+            // SAFETY: `vmaskmov` does not access, or fault on, the lanes that
+            // are `false` in `mask`.
+            let loaded =
+              Self::from(unsafe { $load(ptr.cast(), mask.to_int().into()) });
+            mask.select(loaded, fill)
+          })? else {
+            let mut result = fill;
+            let mut bits = mask.to_bitmask();
+            while bits != 0 {
+              let index = bits.trailing_zeros() as usize;
+              // SAFETY: The caller guarantees that `ptr` is valid for reads of
+              // lane `index` because it is `true` in `mask`.
+              result.as_mut_array()[index] = unsafe { ptr.add(index).read() };
+              bits &= bits - 1;
+            }
+            result
+          }
+        }
+      }
+
+      /// Stores the lanes that are `true` in `mask` to `ptr`.
+      ///
+      /// # Safety
+      ///
+      /// `ptr` must be valid for writes of every lane that is `true` in `mask`.
+      /// Lanes that are `false` are never accessed.
+      #[inline]
+      pub(crate) unsafe fn masked_store_ptr(self, ptr: *mut $T, mask: $Mask) {
+        pick! {
+          if #[cfg($($avx512),+)] {
+            #[cfg(target_arch = "x86")]
+            use core::arch::x86::$store512;
+            #[cfg(target_arch = "x86_64")]
+            use core::arch::x86_64::$store512;
+
+            // SAFETY: Masked moves do not access, or fault on, the lanes that
+            // are `false` in `mask`.
+            unsafe {
+              $store512(ptr.cast(), mask.to_bitmask() as _, self.into())
+            }
+          } $(else if #[cfg($($avx),+)] {
+            #[cfg(target_arch = "x86")]
+            use core::arch::x86::$store;
+            #[cfg(target_arch = "x86_64")]
+            use core::arch::x86_64::$store;
+
+            // SAFETY: `vmaskmov` does not access, or fault on, the lanes that
+            // are `false` in `mask`.
+            unsafe { $store(ptr.cast(), mask.to_int().into(), self.into()) }
+          })? else {
+            let mut bits = mask.to_bitmask();
+            while bits != 0 {
+              let index = bits.trailing_zeros() as usize;
+              // SAFETY: The caller guarantees that `ptr` is valid for writes of
+              // lane `index` because it is `true` in `mask`.
+              unsafe { ptr.add(index).write(self.as_array()[index]) };
+              bits &= bits - 1;
+            }
+          }
+        }
+      }
+    }
+  };
+}
+
+impl_masked_memory! {
+  Simd = f32x4,
+  T = f32,
+  Mask = m32x4,
+  avx512 = #[cfg(target_feature = "avx512vl")]
+    _mm_mask_loadu_ps, _mm_mask_storeu_ps;
+  avx = #[cfg(target_feature = "avx")] _mm_maskload_ps, _mm_maskstore_ps;
+}
+
+impl_masked_memory! {
+  Simd = f32x8,
+  T = f32,
+  Mask = m32x8,
+  avx512 = #[cfg(target_feature = "avx512vl")]
+    _mm256_mask_loadu_ps, _mm256_mask_storeu_ps;
+  avx = #[cfg(target_feature = "avx")]
+    _mm256_maskload_ps, _mm256_maskstore_ps;
+}
+
+impl_masked_memory! {
+  Simd = f32x16,
+  T = f32,
+  Mask = m32x16,
+  avx512 = #[cfg(target_feature = "avx512f")]
+    _mm512_mask_loadu_ps, _mm512_mask_storeu_ps;
+}
+
+impl_masked_memory! {
+  Simd = f64x2,
+  T = f64,
+  Mask = m64x2,
+  avx512 = #[cfg(target_feature = "avx512vl")]
+    _mm_mask_loadu_pd, _mm_mask_storeu_pd;
+  avx = #[cfg(target_feature = "avx")] _mm_maskload_pd, _mm_maskstore_pd;
+}
+
+impl_masked_memory! {
+  Simd = f64x4,
+  T = f64,
+  Mask = m64x4,
+  avx512 = #[cfg(target_feature = "avx512vl")]
+    _mm256_mask_loadu_pd, _mm256_mask_storeu_pd;
+  avx = #[cfg(target_feature = "avx")]
+    _mm256_maskload_pd, _mm256_maskstore_pd;
+}
+
+impl_masked_memory! {
+  Simd = f64x8,
+  T = f64,
+  Mask = m64x8,
+  avx512 = #[cfg(target_feature = "avx512f")]
+    _mm512_mask_loadu_pd, _mm512_mask_storeu_pd;
+}
+
+impl_masked_memory! {
+  Simd = i8x16,
+  T = i8,
+  Mask = m8x16,
+  avx512 = #[cfg(target_feature = "avx512bw", target_feature = "avx512vl")]
+    _mm_mask_loadu_epi8, _mm_mask_storeu_epi8;
+}
+
+impl_masked_memory! {
+  Simd = u8x16,
+  T = u8,
+  Mask = m8x16,
+  avx512 = #[cfg(target_feature = "avx512bw", target_feature = "avx512vl")]
+    _mm_mask_loadu_epi8, _mm_mask_storeu_epi8;
+}
+
+impl_masked_memory! {
+  Simd = i8x32,
+  T = i8,
+  Mask = m8x32,
+  avx512 = #[cfg(target_feature = "avx512bw", target_feature = "avx512vl")]
+    _mm256_mask_loadu_epi8, _mm256_mask_storeu_epi8;
+}
+
+impl_masked_memory! {
+  Simd = u8x32,
+  T = u8,
+  Mask = m8x32,
+  avx512 = #[cfg(target_feature = "avx512bw", target_feature = "avx512vl")]
+    _mm256_mask_loadu_epi8, _mm256_mask_storeu_epi8;
+}
+
+impl_masked_memory! {
+  Simd = i8x64,
+  T = i8,
+  Mask = m8x64,
+  avx512 = #[cfg(target_feature = "avx512bw")]
+    _mm512_mask_loadu_epi8, _mm512_mask_storeu_epi8;
+}
+
+impl_masked_memory! {
+  Simd = u8x64,
+  T = u8,
+  Mask = m8x64,
+  avx512 = #[cfg(target_feature = "avx512bw")]
+    _mm512_mask_loadu_epi8, _mm512_mask_storeu_epi8;
+}
+
+impl_masked_memory! {
+  Simd = i16x8,
+  T = i16,
+  Mask = m16x8,
+  avx512 = #[cfg(target_feature = "avx512bw", target_feature = "avx512vl")]
+    _mm_mask_loadu_epi16, _mm_mask_storeu_epi16;
+}
+
+impl_masked_memory! {
+  Simd = u16x8,
+  T = u16,
+  Mask = m16x8,
+  avx512 = #[cfg(target_feature = "avx512bw", target_feature = "avx512vl")]
+    _mm_mask_loadu_epi16, _mm_mask_storeu_epi16;
+}
+
+impl_masked_memory! {
+  Simd = i16x16,
+  T = i16,
+  Mask = m16x16,
+  avx512 = #[cfg(target_feature = "avx512bw", target_feature = "avx512vl")]
+    _mm256_mask_loadu_epi16, _mm256_mask_storeu_epi16;
+}
+
+impl_masked_memory! {
+  Simd = u16x16,
+  T = u16,
+  Mask = m16x16,
+  avx512 = #[cfg(target_feature = "avx512bw", target_feature = "avx512vl")]
+    _mm256_mask_loadu_epi16, _mm256_mask_storeu_epi16;
+}
+
+impl_masked_memory! {
+  Simd = i16x32,
+  T = i16,
+  Mask = m16x32,
+  avx512 = #[cfg(target_feature = "avx512bw")]
+    _mm512_mask_loadu_epi16, _mm512_mask_storeu_epi16;
+}
+
+impl_masked_memory! {
+  Simd = u16x32,
+  T = u16,
+  Mask = m16x32,
+  avx512 = #[cfg(target_feature = "avx512bw")]
+    _mm512_mask_loadu_epi16, _mm512_mask_storeu_epi16;
+}
+
+impl_masked_memory! {
+  Simd = i32x4,
+  T = i32,
+  Mask = m32x4,
+  avx512 = #[cfg(target_feature = "avx512vl")]
+    _mm_mask_loadu_epi32, _mm_mask_storeu_epi32;
+  avx = #[cfg(target_feature = "avx2")]
+    _mm_maskload_epi32, _mm_maskstore_epi32;
+}
+
+impl_masked_memory! {
+  Simd = u32x4,
+  T = u32,
+  Mask = m32x4,
+  avx512 = #[cfg(target_feature = "avx512vl")]
+    _mm_mask_loadu_epi32, _mm_mask_storeu_epi32;
+  avx = #[cfg(target_feature = "avx2")]
+    _mm_maskload_epi32, _mm_maskstore_epi32;
+}
+
+impl_masked_memory! {
+  Simd = i32x8,
+  T = i32,
+  Mask = m32x8,
+  avx512 = #[cfg(target_feature = "avx512vl")]
+    _mm256_mask_loadu_epi32, _mm256_mask_storeu_epi32;
+  avx = #[cfg(target_feature = "avx2")]
+    _mm256_maskload_epi32, _mm256_maskstore_epi32;
+}
+
+impl_masked_memory! {
+  Simd = u32x8,
+  T = u32,
+  Mask = m32x8,
+  avx512 = #[cfg(target_feature = "avx512vl")]
+    _mm256_mask_loadu_epi32, _mm256_mask_storeu_epi32;
+  avx = #[cfg(target_feature = "avx2")]
+    _mm256_maskload_epi32, _mm256_maskstore_epi32;
+}
+
+impl_masked_memory! {
+  Simd = i32x16,
+  T = i32,
+  Mask = m32x16,
+  avx512 = #[cfg(target_feature = "avx512f")]
+    _mm512_mask_loadu_epi32, _mm512_mask_storeu_epi32;
+}
+
+impl_masked_memory! {
+  Simd = u32x16,
+  T = u32,
+  Mask = m32x16,
+  avx512 = #[cfg(target_feature = "avx512f")]
+    _mm512_mask_loadu_epi32, _mm512_mask_storeu_epi32;
+}
+
+impl_masked_memory! {
+  Simd = i64x2,
+  T = i64,
+  Mask = m64x2,
+  avx512 = #[cfg(target_feature = "avx512vl")]
+    _mm_mask_loadu_epi64, _mm_mask_storeu_epi64;
+  avx = #[cfg(target_feature = "avx2")]
+    _mm_maskload_epi64, _mm_maskstore_epi64;
+}
+
+impl_masked_memory! {
+  Simd = u64x2,
+  T = u64,
+  Mask = m64x2,
+  avx512 = #[cfg(target_feature = "avx512vl")]
+    _mm_mask_loadu_epi64, _mm_mask_storeu_epi64;
+  avx = #[cfg(target_feature = "avx2")]
+    _mm_maskload_epi64, _mm_maskstore_epi64;
+}
+
+impl_masked_memory! {
+  Simd = i64x4,
+  T = i64,
+  Mask = m64x4,
+  avx512 = #[cfg(target_feature = "avx512vl")]
+    _mm256_mask_loadu_epi64, _mm256_mask_storeu_epi64;
+  avx = #[cfg(target_feature = "avx2")]
+    _mm256_maskload_epi64, _mm256_maskstore_epi64;
+}
+
+impl_masked_memory! {
+  Simd = u64x4,
+  T = u64,
+  Mask = m64x4,
+  avx512 = #[cfg(target_feature = "avx512vl")]
+    _mm256_mask_loadu_epi64, _mm256_mask_storeu_epi64;
+  avx = #[cfg(target_feature = "avx2")]
+    _mm256_maskload_epi64, _mm256_maskstore_epi64;
+}
+
+impl_masked_memory! {
+  Simd = i64x8,
+  T = i64,
+  Mask = m64x8,
+  avx512 = #[cfg(target_feature = "avx512f")]
+    _mm512_mask_loadu_epi64, _mm512_mask_storeu_epi64;
+}
+
+impl_masked_memory! {
+  Simd = u64x8,
+  T = u64,
+  Mask = m64x8,
+  avx512 = #[cfg(target_feature = "avx512f")]
+    _mm512_mask_loadu_epi64, _mm512_mask_storeu_epi64;
+}
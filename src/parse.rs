@@ -0,0 +1,87 @@
+use core::{fmt, str::FromStr};
+
+/// An error returned when parsing a SIMD vector from a string fails.
+///
+/// SIMD vectors implement [`FromStr`] for the formats below. Whitespace
+/// around the elements is ignored.
+///
+/// * The [`Display`](fmt::Display) and [`Debug`](fmt::Debug) output, with the
+///   elements in parentheses: `(1, 2, 3, 4)`.
+/// * The elements in brackets, like an array: `[1, 2, 3, 4]`.
+/// * A single element without parentheses or brackets, which is used for every
+///   lane: `1`.
+///
+/// Each element is parsed with the [`FromStr`] implementation of the element
+/// type, so floats accept `NaN` and `inf`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseSimdError {
+  /// The opening parenthesis or bracket has no matching closing one, or the
+  /// other way around.
+  UnmatchedBracket,
+  /// The number of elements is not the number of lanes of the SIMD vector.
+  LaneCount {
+    /// The number of lanes of the SIMD vector.
+    expected: usize,
+    /// The number of elements in the string.
+    found: usize,
+  },
+  /// An element could not be parsed. For a single element that is used for
+  /// every lane, the lane is `0`.
+  InvalidLane {
+    /// The index of the element that could not be parsed.
+    lane: usize,
+  },
+}
+
+impl fmt::Display for ParseSimdError {
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Self::UnmatchedBracket => write!(f, "unmatched parenthesis or bracket"),
+      Self::LaneCount { expected, found } => {
+        write!(f, "expected {expected} elements, found {found}")
+      }
+      Self::InvalidLane { lane } => write!(f, "invalid element in lane {lane}"),
+    }
+  }
+}
+
+impl core::error::Error for ParseSimdError {}
+
+/// Parses the elements of a SIMD vector in one of the formats described in
+/// [`ParseSimdError`].
+pub(crate) fn parse_lanes<T, const N: usize>(
+  s: &str,
+) -> Result<[T; N], ParseSimdError>
+where
+  T: FromStr + Default + Copy,
+{
+  let s = s.trim();
+  let list = match (s.chars().next(), s.chars().next_back()) {
+    (Some('('), Some(')')) | (Some('['), Some(']')) => &s[1..s.len() - 1],
+    (Some('(' | '['), _) | (_, Some(')' | ']')) => {
+      return Err(ParseSimdError::UnmatchedBracket);
+    }
+    _ => {
+      let value =
+        s.parse().map_err(|_| ParseSimdError::InvalidLane { lane: 0 })?;
+      return Ok([value; N]);
+    }
+  };
+
+  let found = if list.trim().is_empty() { 0 } else { list.split(',').count() };
+  if found != N {
+    return Err(ParseSimdError::LaneCount { expected: N, found });
+  }
+  let mut lanes = [T::default(); N];
+  for (lane, (value, element)) in
+    lanes.iter_mut().zip(list.split(',')).enumerate()
+  {
+    *value = element
+      .trim()
+      .parse()
+      .map_err(|_| ParseSimdError::InvalidLane { lane })?;
+  }
+  Ok(lanes)
+}
@@ -0,0 +1,434 @@
+use super::*;
+
+#[cfg(all(target_feature = "neon", target_arch = "aarch64"))]
+use core::arch::aarch64::*;
+#[cfg(target_feature = "simd128")]
+use core::arch::wasm32::*;
+
+/// Implements the bitwise reductions, the widening sum and the sum of
+/// absolute differences for an integer SIMD vector.
+///
+/// The widening sum is named after the type it sums into, like
+/// `reduce_add_u32`. Unless `sad` is given, it is computed as the widening sum
+/// of `max - min` reinterpreted as `$Unsigned`.
+macro_rules! impl_reduce {
+  (
+    Simd = $Simd:ident,
+    T = $T:ident,
+    Wide = $Wide:ident,
+    Unsigned = $Unsigned:ident,
+    UWide = $UWide:ident,
+    reduce_add_unsigned = $reduce_add_unsigned:ident,
+    $fn_reduce_add_wide:item
+  ) => {
+    impl_reduce! {
+      Simd = $Simd,
+      T = $T,
+      Wide = $Wide,
+      UWide = $UWide,
+      $fn_reduce_add_wide
+      #[inline]
+      pub fn sad(self, rhs: Self) -> $UWide {
+        cast::<Self, $Unsigned>(self.max(rhs) - self.min(rhs))
+          .$reduce_add_unsigned()
+      }
+    }
+  };
+  (
+    Simd = $Simd:ident,
+    T = $T:ident,
+    Wide = $Wide:ident,
+    UWide = $UWide:ident,
+    $fn_reduce_add_wide:item
+    $fn_sad:item
+  ) => {
+    impl $Simd {
+      /// Reducing bitwise and. Returns the bitwise and of the vector's
+      /// elements.
+      ///
+      /// Equivalent to `self[0] & self[1] & ...`.
+      #[inline]
+      #[must_use]
+      pub fn reduce_and(self) -> $T {
+        self.to_array().into_iter().fold(!0, |acc, x| acc & x)
+      }
+
+      /// Reducing bitwise or. Returns the bitwise or of the vector's elements.
+      ///
+      /// Equivalent to `self[0] | self[1] | ...`.
+      #[inline]
+      #[must_use]
+      pub fn reduce_or(self) -> $T {
+        self.to_array().into_iter().fold(0, |acc, x| acc | x)
+      }
+
+      /// Reducing bitwise xor. Returns the bitwise xor of the vector's
+      /// elements.
+      ///
+      /// Equivalent to `self[0] ^ self[1] ^ ...`.
+      #[inline]
+      #[must_use]
+      pub fn reduce_xor(self) -> $T {
+        self.to_array().into_iter().fold(0, |acc, x| acc ^ x)
+      }
+
+      #[doc = concat!("Widening reducing addition. Returns the sum of the vector's elements as [`", stringify!($Wide), "`].")]
+      ///
+      /// Unlike [`reduce_add`](Self::reduce_add), this cannot overflow.
+      #[must_use]
+      $fn_reduce_add_wide
+
+      /// Sum of absolute differences. Returns the sum of the absolute
+      /// differences between the elements of `self` and `rhs` without
+      /// overflowing.
+      ///
+      /// Equivalent to `self[0].abs_diff(rhs[0]) + self[1].abs_diff(rhs[1]) + ...`.
+      #[must_use]
+      $fn_sad
+    }
+  };
+}
+
+impl_reduce! {
+  Simd = u8x16,
+  T = u8,
+  Wide = u32,
+  UWide = u32,
+  #[inline]
+  pub fn reduce_add_u32(self) -> u32 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        // `psadbw` against zero adds up each half of the bytes.
+        let [low, high]: [u64; 2] =
+          cast(sum_of_u8_abs_diff_m128i(self.sse, m128i::default()));
+        (low + high) as u32
+      } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
+        u32::from(unsafe { vaddlvq_u8(self.neon) })
+      } else if #[cfg(target_feature="simd128")] {
+        let pairs = u16x8_extadd_pairwise_u8x16(self.simd);
+        u32x4 { simd: u32x4_extadd_pairwise_u16x8(pairs) }.reduce_add()
+      } else {
+        self.to_array().into_iter().map(u32::from).sum()
+      }
+    }
+  }
+  #[inline]
+  pub fn sad(self, rhs: Self) -> u32 {
+    pick! {
+      if #[cfg(target_feature="sse2")] {
+        let [low, high]: [u64; 2] =
+          cast(sum_of_u8_abs_diff_m128i(self.sse, rhs.sse));
+        (low + high) as u32
+      } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
+        u32::from(unsafe { vaddlvq_u8(vabdq_u8(self.neon, rhs.neon)) })
+      } else {
+        (self.max(rhs) - self.min(rhs)).reduce_add_u32()
+      }
+    }
+  }
+}
+
+impl_reduce! {
+  Simd = u8x32,
+  T = u8,
+  Wide = u32,
+  UWide = u32,
+  #[inline]
+  pub fn reduce_add_u32(self) -> u32 {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        let sums: [u64; 4] =
+          cast(sum_of_u8_abs_diff_m256i(self.avx, m256i::default()));
+        sums.into_iter().sum::<u64>() as u32
+      } else {
+        let [a, b]: [u8x16; 2] = cast(self);
+        a.reduce_add_u32() + b.reduce_add_u32()
+      }
+    }
+  }
+  #[inline]
+  pub fn sad(self, rhs: Self) -> u32 {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        let sums: [u64; 4] = cast(sum_of_u8_abs_diff_m256i(self.avx, rhs.avx));
+        sums.into_iter().sum::<u64>() as u32
+      } else {
+        let [self_a, self_b]: [u8x16; 2] = cast(self);
+        let [rhs_a, rhs_b]: [u8x16; 2] = cast(rhs);
+        self_a.sad(rhs_a) + self_b.sad(rhs_b)
+      }
+    }
+  }
+}
+
+impl_reduce! {
+  Simd = u8x64,
+  T = u8,
+  Wide = u32,
+  UWide = u32,
+  #[inline]
+  pub fn reduce_add_u32(self) -> u32 {
+    self.sad(Self::default())
+  }
+  #[inline]
+  pub fn sad(self, rhs: Self) -> u32 {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm512_sad_epu8;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm512_sad_epu8;
+
+        // TODO(safe_arch): Add `sum_of_u8_abs_diff_m512i`.
+        let sums: [u64; 8] =
+          cast(m512i(unsafe { _mm512_sad_epu8(self.avx512.0, rhs.avx512.0) }));
+        sums.into_iter().sum::<u64>() as u32
+      } else {
+        let [self_a, self_b]: [u8x32; 2] = cast(self);
+        let [rhs_a, rhs_b]: [u8x32; 2] = cast(rhs);
+        self_a.sad(rhs_a) + self_b.sad(rhs_b)
+      }
+    }
+  }
+}
+
+macro_rules! impl_reduce_i8 {
+  ($($Simd:ident: $Unsigned:ident, $N:literal;)*) => {$(
+    impl_reduce! {
+      Simd = $Simd,
+      T = i8,
+      Wide = i32,
+      UWide = u32,
+      #[inline]
+      pub fn reduce_add_i32(self) -> i32 {
+        // Flipping the sign bit adds 128 to each element as a `u8`.
+        let offset: $Unsigned = cast(self ^ Self::splat(i8::MIN));
+        offset.reduce_add_u32() as i32 - 128 * $N
+      }
+      #[inline]
+      pub fn sad(self, rhs: Self) -> u32 {
+        // Flipping the sign bit keeps the differences.
+        let offset = Self::splat(i8::MIN);
+        cast::<Self, $Unsigned>(self ^ offset).sad(cast(rhs ^ offset))
+      }
+    }
+  )*};
+}
+
+impl_reduce_i8! {
+  i8x16: u8x16, 16;
+  i8x32: u8x32, 32;
+  i8x64: u8x64, 64;
+}
+
+impl_reduce! {
+  Simd = i16x8,
+  T = i16,
+  Wide = i32,
+  Unsigned = u16x8,
+  UWide = u32,
+  reduce_add_unsigned = reduce_add_u32,
+  #[inline]
+  pub fn reduce_add_i32(self) -> i32 {
+    pick! {
+      if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
+        unsafe { vaddlvq_s16(self.neon) }
+      } else {
+        self.dot(Self::splat(1)).reduce_add()
+      }
+    }
+  }
+}
+
+impl_reduce! {
+  Simd = i16x16,
+  T = i16,
+  Wide = i32,
+  Unsigned = u16x16,
+  UWide = u32,
+  reduce_add_unsigned = reduce_add_u32,
+  #[inline]
+  pub fn reduce_add_i32(self) -> i32 {
+    self.dot(Self::splat(1)).reduce_add()
+  }
+}
+
+impl_reduce! {
+  Simd = i16x32,
+  T = i16,
+  Wide = i32,
+  Unsigned = u16x32,
+  UWide = u32,
+  reduce_add_unsigned = reduce_add_u32,
+  #[inline]
+  pub fn reduce_add_i32(self) -> i32 {
+    self.dot(Self::splat(1)).reduce_add()
+  }
+}
+
+impl_reduce! {
+  Simd = u16x8,
+  T = u16,
+  Wide = u32,
+  Unsigned = u16x8,
+  UWide = u32,
+  reduce_add_unsigned = reduce_add_u32,
+  #[inline]
+  pub fn reduce_add_u32(self) -> u32 {
+    pick! {
+      if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
+        unsafe { vaddlvq_u16(self.neon) }
+      } else if #[cfg(target_feature="simd128")] {
+        u32x4 { simd: u32x4_extadd_pairwise_u16x8(self.simd) }.reduce_add()
+      } else {
+        // Flipping the sign bit subtracts 32768 from each element as an
+        // `i16`, whose sum can use `pmaddwd`.
+        let offset: i16x8 = cast(self ^ Self::splat(0x8000));
+        (offset.reduce_add_i32() + 0x8000 * 8) as u32
+      }
+    }
+  }
+}
+
+macro_rules! impl_reduce_u16 {
+  ($($Simd:ident: $Signed:ident, $N:literal;)*) => {$(
+    impl_reduce! {
+      Simd = $Simd,
+      T = u16,
+      Wide = u32,
+      Unsigned = $Simd,
+      UWide = u32,
+      reduce_add_unsigned = reduce_add_u32,
+      #[inline]
+      pub fn reduce_add_u32(self) -> u32 {
+        // Flipping the sign bit subtracts 32768 from each element as an
+        // `i16`, whose sum can use `pmaddwd`.
+        let offset: $Signed = cast(self ^ Self::splat(0x8000));
+        (offset.reduce_add_i32() + 0x8000 * $N) as u32
+      }
+    }
+  )*};
+}
+
+impl_reduce_u16! {
+  u16x16: i16x16, 16;
+  u16x32: i16x32, 32;
+}
+
+impl_reduce! {
+  Simd = i32x4,
+  T = i32,
+  Wide = i64,
+  Unsigned = u32x4,
+  UWide = u64,
+  reduce_add_unsigned = reduce_add_u64,
+  #[inline]
+  pub fn reduce_add_i64(self) -> i64 {
+    pick! {
+      if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
+        unsafe { vaddlvq_s32(self.neon) }
+      } else {
+        i64x4::from(self).reduce_add()
+      }
+    }
+  }
+}
+
+impl_reduce! {
+  Simd = i32x8,
+  T = i32,
+  Wide = i64,
+  Unsigned = u32x8,
+  UWide = u64,
+  reduce_add_unsigned = reduce_add_u64,
+  #[inline]
+  pub fn reduce_add_i64(self) -> i64 {
+    i64x8::from(self).reduce_add()
+  }
+}
+
+impl_reduce! {
+  Simd = i32x16,
+  T = i32,
+  Wide = i64,
+  Unsigned = u32x16,
+  UWide = u64,
+  reduce_add_unsigned = reduce_add_u64,
+  #[inline]
+  pub fn reduce_add_i64(self) -> i64 {
+    let [a, b]: [i32x8; 2] = cast(self);
+    a.reduce_add_i64() + b.reduce_add_i64()
+  }
+}
+
+impl_reduce! {
+  Simd = u32x4,
+  T = u32,
+  Wide = u64,
+  Unsigned = u32x4,
+  UWide = u64,
+  reduce_add_unsigned = reduce_add_u64,
+  #[inline]
+  pub fn reduce_add_u64(self) -> u64 {
+    pick! {
+      if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
+        unsafe { vaddlvq_u32(self.neon) }
+      } else {
+        u64x4::from(self).reduce_add()
+      }
+    }
+  }
+}
+
+impl_reduce! {
+  Simd = u32x8,
+  T = u32,
+  Wide = u64,
+  Unsigned = u32x8,
+  UWide = u64,
+  reduce_add_unsigned = reduce_add_u64,
+  #[inline]
+  pub fn reduce_add_u64(self) -> u64 {
+    u64x8::from(self).reduce_add()
+  }
+}
+
+impl_reduce! {
+  Simd = u32x16,
+  T = u32,
+  Wide = u64,
+  Unsigned = u32x16,
+  UWide = u64,
+  reduce_add_unsigned = reduce_add_u64,
+  #[inline]
+  pub fn reduce_add_u64(self) -> u64 {
+    let [a, b]: [u32x8; 2] = cast(self);
+    a.reduce_add_u64() + b.reduce_add_u64()
+  }
+}
+
+macro_rules! impl_reduce_64 {
+  ($($Simd:ident: $T:ident => $Wide:ident, $Unsigned:ident, $reduce_add_wide:ident;)*) => {$(
+    impl_reduce! {
+      Simd = $Simd,
+      T = $T,
+      Wide = $Wide,
+      Unsigned = $Unsigned,
+      UWide = u128,
+      reduce_add_unsigned = reduce_add_u128,
+      #[inline]
+      pub fn $reduce_add_wide(self) -> $Wide {
+        self.to_array().into_iter().map($Wide::from).sum()
+      }
+    }
+  )*};
+}
+
+impl_reduce_64! {
+  i64x2: i64 => i128, u64x2, reduce_add_i128;
+  i64x4: i64 => i128, u64x4, reduce_add_i128;
+  i64x8: i64 => i128, u64x8, reduce_add_i128;
+  u64x2: u64 => u128, u64x2, reduce_add_u128;
+  u64x4: u64 => u128, u64x4, reduce_add_u128;
+  u64x8: u64 => u128, u64x8, reduce_add_u128;
+}
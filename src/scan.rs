@@ -0,0 +1,165 @@
+use super::*;
+use crate::shuffle::shuffle2_const;
+
+/// Implements prefix sums and other scans of a SIMD vector.
+///
+/// The scans take `log2(N)` steps. Each step combines every element with the
+/// element `K` lanes before it, with `K` doubling every step, so that after the
+/// last step every element has combined all elements up to it. The lanes are
+/// moved with `shuffle2_const!`, like the other shuffles.
+///
+/// `Zero` is the identity of addition, which is `-0.0` for floats so that a
+/// sum of `-0.0` stays `-0.0`, like in a sequential loop.
+macro_rules! impl_scan {
+  (
+    Simd = $Simd:ident,
+    T = $T:ident,
+    N = $N:literal,
+    Zero = $zero:literal,
+  ) => {
+    impl $Simd {
+      /// Shifts the elements of `self` up by `K` lanes, taking the first `K`
+      /// elements from `fill`.
+      #[inline]
+      fn shift_lanes_up<const K: usize>(self, fill: Self) -> Self {
+        shuffle2_const!($Simd, self, fill, |i| if i < K { $N + i } else { i - K })
+      }
+
+      /// Combines each element with all elements before it using `op`.
+      ///
+      /// `fill` returns the elements combined with the first lanes, which
+      /// have nothing before them. For idempotent `op`s, that can be the
+      /// elements themselves.
+      #[inline]
+      fn scan(
+        self,
+        fill: impl Fn(Self) -> Self,
+        op: impl Fn(Self, Self) -> Self,
+      ) -> Self {
+        let mut x = self;
+        x = op(x, x.shift_lanes_up::<1>(fill(x)));
+        if $N > 2 {
+          x = op(x, x.shift_lanes_up::<2>(fill(x)));
+        }
+        if $N > 4 {
+          x = op(x, x.shift_lanes_up::<4>(fill(x)));
+        }
+        if $N > 8 {
+          x = op(x, x.shift_lanes_up::<8>(fill(x)));
+        }
+        if $N > 16 {
+          x = op(x, x.shift_lanes_up::<16>(fill(x)));
+        }
+        if $N > 32 {
+          x = op(x, x.shift_lanes_up::<32>(fill(x)));
+        }
+        x
+      }
+
+      /// Inclusive prefix sum. Returns the running totals of the vector's
+      /// elements.
+      ///
+      /// Equivalent to `[self[0], self[0] + self[1], self[0] + self[1] + self[2], ...]`.
+      ///
+      /// Integer additions wrap on overflow. Float additions are grouped
+      /// differently than in a sequential loop, so they can round differently.
+      #[inline]
+      #[must_use]
+      pub fn scan_add(self) -> Self {
+        self.scan(|_| Self::splat($zero), |a, b| a + b)
+      }
+
+      /// Exclusive prefix sum. Returns the running totals of the elements
+      /// before each element.
+      ///
+      /// Equivalent to `[0, self[0], self[0] + self[1], ...]`, which is
+      /// [`scan_add`](Self::scan_add) shifted up by one lane. This gives the
+      /// offsets to write each element's output at, e.g. for stream
+      /// compaction.
+      #[inline]
+      #[must_use]
+      pub fn scan_add_exclusive(self) -> Self {
+        self.scan_add().shift_lanes_up::<1>(Self::default())
+      }
+
+      /// Inclusive prefix maximum. Returns the running maximum of the vector's
+      /// elements.
+      ///
+      /// Equivalent to `[self[0], self[0].max(self[1]), self[0].max(self[1]).max(self[2]), ...]`.
+      #[inline]
+      #[must_use]
+      pub fn scan_max(self) -> Self {
+        self.scan(|x| x, Self::max)
+      }
+
+      /// Inclusive prefix minimum. Returns the running minimum of the vector's
+      /// elements.
+      ///
+      /// Equivalent to `[self[0], self[0].min(self[1]), self[0].min(self[1]).min(self[2]), ...]`.
+      #[inline]
+      #[must_use]
+      pub fn scan_min(self) -> Self {
+        self.scan(|x| x, Self::min)
+      }
+
+      /// Inclusive prefix bitwise or. Returns the running bitwise or of the
+      /// vector's elements.
+      ///
+      /// Equivalent to `[self[0], self[0] | self[1], self[0] | self[1] | self[2], ...]`.
+      /// For floats, this combines their bits, e.g. to find whether any
+      /// element so far had its sign bit set.
+      #[inline]
+      #[must_use]
+      pub fn scan_or(self) -> Self {
+        self.scan(|x| x, |a, b| a | b)
+      }
+
+      /// Replaces each element of `values` with the sum of all elements up to
+      /// and including it, like [`scan_add`](Self::scan_add) over the whole
+      /// slice.
+      ///
+      #[doc = concat!("This scans ", $N, " elements at a time and carries the running total")]
+      /// from one SIMD vector to the next.
+      #[inline]
+      pub fn prefix_sum(values: &mut [$T]) {
+        let mut total = Self::splat($zero);
+        for chunk in values.chunks_mut($N) {
+          let sums = Self::load_or(chunk, <$T>::default()).scan_add() + total;
+          sums.store_partial(chunk);
+          total = sums.broadcast_lane::<{ $N - 1 }>();
+        }
+      }
+    }
+  };
+}
+
+impl_scan! { Simd = f32x4, T = f32, N = 4, Zero = -0.0, }
+impl_scan! { Simd = f32x8, T = f32, N = 8, Zero = -0.0, }
+impl_scan! { Simd = f32x16, T = f32, N = 16, Zero = -0.0, }
+impl_scan! { Simd = f64x2, T = f64, N = 2, Zero = -0.0, }
+impl_scan! { Simd = f64x4, T = f64, N = 4, Zero = -0.0, }
+impl_scan! { Simd = f64x8, T = f64, N = 8, Zero = -0.0, }
+impl_scan! { Simd = i8x16, T = i8, N = 16, Zero = 0, }
+impl_scan! { Simd = i8x32, T = i8, N = 32, Zero = 0, }
+impl_scan! { Simd = i8x64, T = i8, N = 64, Zero = 0, }
+impl_scan! { Simd = i16x8, T = i16, N = 8, Zero = 0, }
+impl_scan! { Simd = i16x16, T = i16, N = 16, Zero = 0, }
+impl_scan! { Simd = i16x32, T = i16, N = 32, Zero = 0, }
+impl_scan! { Simd = i32x4, T = i32, N = 4, Zero = 0, }
+impl_scan! { Simd = i32x8, T = i32, N = 8, Zero = 0, }
+impl_scan! { Simd = i32x16, T = i32, N = 16, Zero = 0, }
+impl_scan! { Simd = i64x2, T = i64, N = 2, Zero = 0, }
+impl_scan! { Simd = i64x4, T = i64, N = 4, Zero = 0, }
+impl_scan! { Simd = i64x8, T = i64, N = 8, Zero = 0, }
+impl_scan! { Simd = u8x16, T = u8, N = 16, Zero = 0, }
+impl_scan! { Simd = u8x32, T = u8, N = 32, Zero = 0, }
+impl_scan! { Simd = u8x64, T = u8, N = 64, Zero = 0, }
+impl_scan! { Simd = u16x8, T = u16, N = 8, Zero = 0, }
+impl_scan! { Simd = u16x16, T = u16, N = 16, Zero = 0, }
+impl_scan! { Simd = u16x32, T = u16, N = 32, Zero = 0, }
+impl_scan! { Simd = u32x4, T = u32, N = 4, Zero = 0, }
+impl_scan! { Simd = u32x8, T = u32, N = 8, Zero = 0, }
+impl_scan! { Simd = u32x16, T = u32, N = 16, Zero = 0, }
+impl_scan! { Simd = u64x2, T = u64, N = 2, Zero = 0, }
+impl_scan! { Simd = u64x4, T = u64, N = 4, Zero = 0, }
+impl_scan! { Simd = u64x8, T = u64, N = 8, Zero = 0, }
@@ -0,0 +1,757 @@
+use super::*;
+
+/// How to shuffle two 128-bit SIMD vectors `self` and `other`.
+#[derive(Clone, Copy)]
+pub(crate) struct ShufflePlan128 {
+  /// The byte indices for [`i8x16::swizzle`] of `self` and of `other`.
+  /// Negative indices select `0`.
+  bytes: [[i8; 16]; 2],
+  /// The element indices into `[self, other]`, for targets without a byte
+  /// swizzle. Only the first `N` are used.
+  idx: [u8; 16],
+}
+
+/// The plans to shuffle two 256-bit SIMD vectors. For each half of the result,
+/// the halves of `self`, the halves of `other`, and then both of those are
+/// shuffled.
+pub(crate) type ShufflePlan256 = [[ShufflePlan128; 3]; 2];
+
+/// The plans to shuffle two 512-bit SIMD vectors, like [`ShufflePlan256`].
+pub(crate) type ShufflePlan512 = [[ShufflePlan256; 3]; 2];
+
+/// Shuffles `$a` and `$b` of type `$Simd` with the indices `$index` computed
+/// for each `$i` in `0..N`, see `shuffle2`.
+///
+/// The indices and the shuffle plan are computed at compile time, so that
+/// LLVM always sees constant byte indices. Computing them at runtime only
+/// works for vectors with few elements, because larger loops are vectorized
+/// before the constant indices are propagated.
+macro_rules! shuffle2_const {
+  ($Simd:ident, $a:expr, $b:expr, |$i:ident| $index:expr) => {
+    $a.shuffle2_planned(
+      $b,
+      const {
+        const N: usize = <$Simd as SimdVector>::LANES;
+        let mut indices = [0; N];
+        let mut $i = 0;
+        while $i < N {
+          indices[$i] = $index;
+          $i += 1;
+        }
+        &$Simd::shuffle2_plan(indices)
+      },
+    )
+  };
+}
+pub(crate) use shuffle2_const;
+
+/// The indices of a shuffle by [`shuffle!`] or [`shuffle2!`], as a type so
+/// that the shuffle plan can be computed at compile time.
+#[doc(hidden)]
+pub trait ShuffleIndices {
+  /// The element indices.
+  const INDICES: &'static [usize];
+}
+
+/// Returns the indices of `I` as an array, checking that there are `N` that
+/// are all below `limit`.
+pub(crate) const fn shuffle_indices<I: ShuffleIndices, const N: usize>(
+  limit: usize,
+) -> [usize; N] {
+  assert!(I::INDICES.len() == N, "wrong number of shuffle indices");
+  let mut indices = [0; N];
+  let mut i = 0;
+  while i < N {
+    assert!(I::INDICES[i] < limit, "shuffle index out of range");
+    indices[i] = I::INDICES[i];
+    i += 1;
+  }
+  indices
+}
+
+/// Shuffles the elements of a SIMD vector by indices known at compile time.
+///
+/// `shuffle!(v, idx)` returns the same as `v.shuffle(idx)`, but the shuffle
+/// is planned at compile time, so that it always compiles to one or a few
+/// shuffle instructions, also for vectors with many elements like `u8x64`.
+/// `idx` must be a constant expression, and indices that are out of range
+/// fail to compile.
+///
+/// ```
+/// # use wide::*;
+/// let v = f32x4::new([1.0, 2.0, 3.0, 4.0]);
+/// assert_eq!(shuffle!(v, [3, 2, 2, 0]), f32x4::new([4.0, 3.0, 3.0, 1.0]));
+/// ```
+///
+/// ```compile_fail
+/// # use wide::*;
+/// let v = shuffle!(f32x4::ONE, [0, 1, 2, 4]);
+/// ```
+#[macro_export]
+macro_rules! shuffle {
+  ($v:expr, $idx:expr $(,)?) => {{
+    struct Indices;
+    impl $crate::ShuffleIndices for Indices {
+      const INDICES: &'static [usize] = &$idx;
+    }
+    $v.__shuffle_const::<Indices>()
+  }};
+}
+
+/// Shuffles the elements of two SIMD vectors by indices known at compile
+/// time.
+///
+/// `shuffle2!(a, b, idx)` returns the same as `a.shuffle2(b, idx)`, planned
+/// at compile time like [`shuffle!`].
+///
+/// ```
+/// # use wide::*;
+/// let a = i32x4::new([1, 2, 3, 4]);
+/// let b = i32x4::new([5, 6, 7, 8]);
+/// assert_eq!(shuffle2!(a, b, [0, 4, 3, 7]), i32x4::new([1, 5, 4, 8]));
+/// ```
+#[macro_export]
+macro_rules! shuffle2 {
+  ($a:expr, $b:expr, $idx:expr $(,)?) => {{
+    struct Indices;
+    impl $crate::ShuffleIndices for Indices {
+      const INDICES: &'static [usize] = &$idx;
+    }
+    $a.__shuffle2_const::<Indices>($b)
+  }};
+}
+
+/// Implements shuffles that move whole elements of a SIMD vector.
+///
+/// `shuffle` and `shuffle2` pick elements from arrays, which LLVM only turns
+/// into shuffle instructions for constant indices into vectors with few
+/// elements. `shuffle!`, `shuffle2!` and the other shuffles use
+/// `shuffle2_const!`, which turns them into byte swizzles of 128-bit vectors.
+/// LLVM turns those into the best shuffle instructions of the target
+/// (`shufps`, `punpcklbw`, `vpermilps`, `vperm2f128`, NEON `ext`/`zip`/`tbl`,
+/// ...). Wider vectors are shuffled as `Half` vectors.
+macro_rules! impl_shuffle {
+  (
+    Simd = $Simd:ident,
+    N = $N:literal,
+    Plan = $Plan:ident,
+    $(Half = $Half:ident,)?
+  ) => {
+    /// The following functionality exists for all SIMD vectors.
+    impl $Simd {
+      /// Returns the elements of `self` in the order given by `idx`, so that
+      /// element `i` of the result is `self[idx[i]]`.
+      ///
+      /// The elements are picked one by one. LLVM turns that into one or a
+      /// few shuffle instructions for constant indices into vectors with few
+      /// elements, but into many element moves for wide byte vectors like
+      /// `u8x64`, and for indices only known at runtime. For indices known at
+      /// compile time, [`shuffle!`](crate::shuffle!) always compiles to
+      /// shuffle instructions.
+      ///
+      /// # Panics
+      ///
+      #[doc = concat!("Panics if any index is `", $N, "` or greater.")]
+      #[inline(always)]
+      #[must_use]
+      pub fn shuffle(self, idx: [usize; $N]) -> Self {
+        let array = self.to_array();
+        let mut out = array;
+        let mut i = 0;
+        while i < $N {
+          assert!(idx[i] < $N, "shuffle index out of range");
+          out[i] = array[idx[i]];
+          i += 1;
+        }
+        Self::new(out)
+      }
+
+      /// Returns elements of `self` and `other` in the order given by `idx`.
+      ///
+      #[doc = concat!("Indices below `", $N, "` select elements of `self`, and indices from `", $N, "`")]
+      #[doc = concat!("up to `", $N, " * 2` select elements of `other`, as if both vectors")]
+      /// were concatenated.
+      ///
+      /// The elements are picked one by one, see [`shuffle`](Self::shuffle).
+      /// For indices known at compile time, use
+      /// [`shuffle2!`](crate::shuffle2!).
+      ///
+      /// # Panics
+      ///
+      #[doc = concat!("Panics if any index is `", $N, " * 2` or greater.")]
+      #[inline(always)]
+      #[must_use]
+      pub fn shuffle2(self, other: Self, idx: [usize; $N]) -> Self {
+        let both = [self.to_array(), other.to_array()];
+        let mut out = both[0];
+        let mut i = 0;
+        while i < $N {
+          assert!(idx[i] < $N * 2, "shuffle index out of range");
+          out[i] = both[idx[i] / $N][idx[i] % $N];
+          i += 1;
+        }
+        Self::new(out)
+      }
+
+      /// Shuffles `self` with the indices of `I`, see
+      /// [`shuffle!`](crate::shuffle!).
+      #[doc(hidden)]
+      #[inline(always)]
+      #[must_use]
+      pub fn __shuffle_const<I: ShuffleIndices>(self) -> Self {
+        self.shuffle2_planned(
+          self,
+          const { &Self::shuffle2_plan(shuffle_indices::<I, $N>($N)) },
+        )
+      }
+
+      /// Shuffles `self` and `other` with the indices of `I`, see
+      /// [`shuffle2!`](crate::shuffle2!).
+      #[doc(hidden)]
+      #[inline(always)]
+      #[must_use]
+      pub fn __shuffle2_const<I: ShuffleIndices>(self, other: Self) -> Self {
+        self.shuffle2_planned(
+          other,
+          const { &Self::shuffle2_plan(shuffle_indices::<I, $N>($N * 2)) },
+        )
+      }
+
+      impl_shuffle!(@plan $N, $Plan, $($Half)?);
+
+      /// Reverses the order of the elements of `self`.
+      #[inline]
+      #[must_use]
+      pub fn reverse(self) -> Self {
+        shuffle2_const!($Simd, self, self, |i| $N - 1 - i)
+      }
+
+      /// Returns a SIMD vector with all elements set to element `I` of `self`.
+      ///
+      #[doc = concat!("Fails to compile if `I` is `", $N, "` or greater.")]
+      #[inline]
+      #[must_use]
+      pub fn broadcast_lane<const I: usize>(self) -> Self {
+        const { assert!(I < $N, "lane index out of range") };
+        Self::splat(self.to_array()[I])
+      }
+
+      /// Returns `[self[0], other[0], self[1], other[1], ...]`, interleaving
+      /// the low halves of `self` and `other`.
+      ///
+      /// Unlike the AVX unpack instructions, this interleaves the low halves
+      /// of the whole vectors, not of each 128-bit lane.
+      #[inline]
+      #[must_use]
+      pub fn interleave_low(self, other: Self) -> Self {
+        shuffle2_const!($Simd, self, other, |i| i % 2 * $N + i / 2)
+      }
+
+      #[doc = concat!("Returns `[self[", $N, " / 2], other[", $N, " / 2], ...]`, interleaving the high")]
+      /// halves of `self` and `other`.
+      ///
+      /// Unlike the AVX unpack instructions, this interleaves the high halves
+      /// of the whole vectors, not of each 128-bit lane.
+      #[inline]
+      #[must_use]
+      pub fn interleave_high(self, other: Self) -> Self {
+        shuffle2_const!($Simd, self, other, |i| i % 2 * $N + $N / 2 + i / 2)
+      }
+    }
+  };
+  (@plan $N:literal, $Plan:ident,) => {
+    /// Computes the byte indices to shuffle `self` and `other` with `idx`,
+    /// see [`shuffle2`](Self::shuffle2).
+    #[inline(always)]
+    pub(crate) const fn shuffle2_plan(idx: [usize; $N]) -> $Plan {
+      const SIZE: usize = 16 / $N;
+
+      let mut plan = $Plan { bytes: [[-1; 16]; 2], idx: [0; 16] };
+      let mut j = 0;
+      while j < $N {
+        let i = idx[j];
+        assert!(i < $N * 2, "shuffle index out of range");
+        plan.idx[j] = i as u8;
+        let mut b = 0;
+        while b < SIZE {
+          let byte = i * SIZE + b;
+          plan.bytes[byte / 16][j * SIZE + b] = (byte % 16) as i8;
+          b += 1;
+        }
+        j += 1;
+      }
+      plan
+    }
+
+    /// Shuffles `self` and `other` with a plan from `shuffle2_plan`.
+    #[inline(always)]
+    pub(crate) fn shuffle2_planned(self, other: Self, plan: &$Plan) -> Self {
+      pick! {
+        if #[cfg(any(
+          target_feature = "ssse3",
+          target_feature = "simd128",
+          all(target_feature = "neon", target_arch = "aarch64")
+        ))] {
+          let [self_bytes, other_bytes] = plan.bytes.map(i8x16::new);
+          let from_self = cast::<Self, i8x16>(self).swizzle(self_bytes);
+          let from_other = cast::<Self, i8x16>(other).swizzle(other_bytes);
+          cast(from_self | from_other)
+        } else {
+          let both: [<Self as SimdVector>::Elem; $N * 2] = cast([self, other]);
+          let mut out = [both[0]; $N];
+          for (out, i) in out.iter_mut().zip(plan.idx) {
+            *out = both[i as usize];
+          }
+          Self::new(out)
+        }
+      }
+    }
+  };
+  (@plan $N:literal, $Plan:ident, $Half:ident) => {
+    /// Computes the plans to shuffle `self` and `other` with `idx`, see
+    /// [`shuffle2`](Self::shuffle2).
+    #[inline(always)]
+    pub(crate) const fn shuffle2_plan(idx: [usize; $N]) -> $Plan {
+      const HALF: usize = $N / 2;
+
+      let empty = $Half::shuffle2_plan([0; HALF]);
+      let mut plan = [[empty; 3]; 2];
+      let mut half = 0;
+      while half < 2 {
+        let mut self_idx = [0; HALF];
+        let mut other_idx = [0; HALF];
+        let mut both_idx = [0; HALF];
+        let mut j = 0;
+        while j < HALF {
+          let i = idx[half * HALF + j];
+          assert!(i < $N * 2, "shuffle index out of range");
+          if i < $N {
+            self_idx[j] = i;
+            both_idx[j] = j;
+          } else {
+            other_idx[j] = i - $N;
+            both_idx[j] = HALF + j;
+          }
+          j += 1;
+        }
+        plan[half] = [
+          $Half::shuffle2_plan(self_idx),
+          $Half::shuffle2_plan(other_idx),
+          $Half::shuffle2_plan(both_idx),
+        ];
+        half += 1;
+      }
+      plan
+    }
+
+    /// Shuffles `self` and `other` with a plan from `shuffle2_plan`.
+    #[inline(always)]
+    pub(crate) fn shuffle2_planned(self, other: Self, plan: &$Plan) -> Self {
+      let [self_low, self_high]: [$Half; 2] = cast(self);
+      let [other_low, other_high]: [$Half; 2] = cast(other);
+      let [[low_self, low_other, low_both], [high_self, high_other, high_both]] =
+        plan;
+
+      let low = self_low
+        .shuffle2_planned(self_high, low_self)
+        .shuffle2_planned(other_low.shuffle2_planned(other_high, low_other), low_both);
+      let high = self_low
+        .shuffle2_planned(self_high, high_self)
+        .shuffle2_planned(other_low.shuffle2_planned(other_high, high_other), high_both);
+      cast([low, high])
+    }
+  };
+}
+
+/// Implements `concat` and `split` between SIMD vectors of the same element
+/// type, where `Full` has twice the elements of `Half`.
+macro_rules! impl_concat_split {
+  (
+    Half = $Half:ident,
+    Full = $Full:ident,
+  ) => {
+    impl $Half {
+      #[doc = concat!("Concatenates `self` and `high` into a [`", stringify!($Full), "`], with `self` as")]
+      /// the low half.
+      #[inline]
+      #[must_use]
+      pub fn concat(self, high: Self) -> $Full {
+        cast([self, high])
+      }
+    }
+
+    impl $Full {
+      #[doc = concat!("Splits `self` into its low and high halves, which are [`", stringify!($Half), "`]s.")]
+      #[inline]
+      #[must_use]
+      pub fn split(self) -> ($Half, $Half) {
+        let [low, high]: [$Half; 2] = cast(self);
+        (low, high)
+      }
+    }
+  };
+}
+
+/// Implements `permute`, a shuffle of whole elements by runtime indices.
+///
+/// `native` computes the permutation from the raw `table` and `index`
+/// vectors, with an instruction that takes the index modulo the number of
+/// elements. Otherwise, the lane indices are turned into byte indices for the
+/// `Bytes` swizzle, or the vector is split into `Half` vectors.
+macro_rules! impl_permute {
+  (
+    Simd = $Simd:ident,
+    N = $N:literal,
+    Index = $Index:ident,
+    native = #[cfg($($native:meta),+)] |$table:ident, $index:ident| $permute:expr,
+    $(Bytes = $Bytes:ident,)?
+    $(Half = $Half:ident, HalfIndex = $HalfIndex:ident,)?
+  ) => {
+    impl $Simd {
+      /// Returns a new vector where each element is selected from `self` by
+      /// the corresponding element of `idx`, which can be computed at
+      /// runtime.
+      ///
+      #[doc = concat!("* Index values in the range `[0, ", $N, ")` select the i-th element of `self`.")]
+      /// * Index values that are out of range, including negative values,
+      ///   cause that output lane to be `0`.
+      ///
+      /// If the indices are known at compile time, [`shuffle`](Self::shuffle)
+      /// is faster.
+      #[inline]
+      #[must_use]
+      pub fn permute(self, idx: $Index) -> Self {
+        let in_range = idx.cast_unsigned().simd_lt($N);
+        pick! {
+          if #[cfg($($native),+)] {
+            #[cfg(target_arch = "x86")]
+            use core::arch::x86::*;
+            #[cfg(target_arch = "x86_64")]
+            use core::arch::x86_64::*;
+
+            let $table = self.into();
+            let $index = idx.into();
+            let permuted = Self::from(unsafe { $permute });
+            permuted & Self::from(in_range)
+          } else {
+            $(
+              const SIZE: usize = size_of::<$Simd>() / $N;
+
+              // Turn the index of each element into the indices of its bytes,
+              // and set all bits of the out-of-range elements so that the
+              // byte swizzle zeroes them.
+              let first_byte: $Bytes = cast(idx << SIZE.trailing_zeros());
+              let bytes = first_byte
+                .shuffle(core::array::from_fn(|i| i / SIZE * SIZE))
+                + $Bytes::new(core::array::from_fn(|i| (i % SIZE) as i8));
+              let out_of_range = cast::<$Index, $Bytes>($Index::from(!in_range));
+
+              cast(cast::<Self, $Bytes>(self).swizzle(bytes | out_of_range))
+            )?
+            $(
+              // Each half of the result looks up both halves of `self`. The
+              // lookups zero the out-of-range lanes, so only the half that
+              // holds the element is non-zero.
+              let _ = in_range;
+              let [low, high]: [$Half; 2] = cast(self);
+              let offset = $HalfIndex::splat($N / 2);
+              let [idx_low, idx_high]: [$HalfIndex; 2] = cast(idx);
+              cast([
+                low.permute(idx_low) | high.permute(idx_low - offset),
+                low.permute(idx_high) | high.permute(idx_high - offset),
+              ])
+            )?
+          }
+        }
+      }
+    }
+  };
+}
+
+impl_shuffle! { Simd = f32x4, N = 4, Plan = ShufflePlan128, }
+impl_shuffle! { Simd = f32x8, N = 8, Plan = ShufflePlan256, Half = f32x4, }
+impl_shuffle! { Simd = f32x16, N = 16, Plan = ShufflePlan512, Half = f32x8, }
+impl_shuffle! { Simd = f64x2, N = 2, Plan = ShufflePlan128, }
+impl_shuffle! { Simd = f64x4, N = 4, Plan = ShufflePlan256, Half = f64x2, }
+impl_shuffle! { Simd = f64x8, N = 8, Plan = ShufflePlan512, Half = f64x4, }
+impl_shuffle! { Simd = i8x16, N = 16, Plan = ShufflePlan128, }
+impl_shuffle! { Simd = i8x32, N = 32, Plan = ShufflePlan256, Half = i8x16, }
+impl_shuffle! { Simd = i8x64, N = 64, Plan = ShufflePlan512, Half = i8x32, }
+impl_shuffle! { Simd = i16x8, N = 8, Plan = ShufflePlan128, }
+impl_shuffle! { Simd = i16x16, N = 16, Plan = ShufflePlan256, Half = i16x8, }
+impl_shuffle! { Simd = i16x32, N = 32, Plan = ShufflePlan512, Half = i16x16, }
+impl_shuffle! { Simd = i32x4, N = 4, Plan = ShufflePlan128, }
+impl_shuffle! { Simd = i32x8, N = 8, Plan = ShufflePlan256, Half = i32x4, }
+impl_shuffle! { Simd = i32x16, N = 16, Plan = ShufflePlan512, Half = i32x8, }
+impl_shuffle! { Simd = i64x2, N = 2, Plan = ShufflePlan128, }
+impl_shuffle! { Simd = i64x4, N = 4, Plan = ShufflePlan256, Half = i64x2, }
+impl_shuffle! { Simd = i64x8, N = 8, Plan = ShufflePlan512, Half = i64x4, }
+impl_shuffle! { Simd = u8x16, N = 16, Plan = ShufflePlan128, }
+impl_shuffle! { Simd = u8x32, N = 32, Plan = ShufflePlan256, Half = u8x16, }
+impl_shuffle! { Simd = u8x64, N = 64, Plan = ShufflePlan512, Half = u8x32, }
+impl_shuffle! { Simd = u16x8, N = 8, Plan = ShufflePlan128, }
+impl_shuffle! { Simd = u16x16, N = 16, Plan = ShufflePlan256, Half = u16x8, }
+impl_shuffle! { Simd = u16x32, N = 32, Plan = ShufflePlan512, Half = u16x16, }
+impl_shuffle! { Simd = u32x4, N = 4, Plan = ShufflePlan128, }
+impl_shuffle! { Simd = u32x8, N = 8, Plan = ShufflePlan256, Half = u32x4, }
+impl_shuffle! { Simd = u32x16, N = 16, Plan = ShufflePlan512, Half = u32x8, }
+impl_shuffle! { Simd = u64x2, N = 2, Plan = ShufflePlan128, }
+impl_shuffle! { Simd = u64x4, N = 4, Plan = ShufflePlan256, Half = u64x2, }
+impl_shuffle! { Simd = u64x8, N = 8, Plan = ShufflePlan512, Half = u64x4, }
+
+impl_concat_split! { Half = f32x4, Full = f32x8, }
+impl_concat_split! { Half = f32x8, Full = f32x16, }
+impl_concat_split! { Half = f64x2, Full = f64x4, }
+impl_concat_split! { Half = f64x4, Full = f64x8, }
+impl_concat_split! { Half = i8x16, Full = i8x32, }
+impl_concat_split! { Half = i8x32, Full = i8x64, }
+impl_concat_split! { Half = i16x8, Full = i16x16, }
+impl_concat_split! { Half = i16x16, Full = i16x32, }
+impl_concat_split! { Half = i32x4, Full = i32x8, }
+impl_concat_split! { Half = i32x8, Full = i32x16, }
+impl_concat_split! { Half = i64x2, Full = i64x4, }
+impl_concat_split! { Half = i64x4, Full = i64x8, }
+impl_concat_split! { Half = u8x16, Full = u8x32, }
+impl_concat_split! { Half = u8x32, Full = u8x64, }
+impl_concat_split! { Half = u16x8, Full = u16x16, }
+impl_concat_split! { Half = u16x16, Full = u16x32, }
+impl_concat_split! { Half = u32x4, Full = u32x8, }
+impl_concat_split! { Half = u32x8, Full = u32x16, }
+impl_concat_split! { Half = u64x2, Full = u64x4, }
+impl_concat_split! { Half = u64x4, Full = u64x8, }
+
+impl_permute! {
+  Simd = i16x8,
+  N = 8,
+  Index = i16x8,
+  native = #[cfg(target_feature = "avx512bw", target_feature = "avx512vl")]
+    |table, index| _mm_permutexvar_epi16(index, table),
+  Bytes = i8x16,
+}
+
+impl_permute! {
+  Simd = u16x8,
+  N = 8,
+  Index = i16x8,
+  native = #[cfg(target_feature = "avx512bw", target_feature = "avx512vl")]
+    |table, index| _mm_permutexvar_epi16(index, table),
+  Bytes = i8x16,
+}
+
+impl_permute! {
+  Simd = i16x16,
+  N = 16,
+  Index = i16x16,
+  native = #[cfg(target_feature = "avx512bw", target_feature = "avx512vl")]
+    |table, index| _mm256_permutexvar_epi16(index, table),
+  Bytes = i8x32,
+}
+
+impl_permute! {
+  Simd = u16x16,
+  N = 16,
+  Index = i16x16,
+  native = #[cfg(target_feature = "avx512bw", target_feature = "avx512vl")]
+    |table, index| _mm256_permutexvar_epi16(index, table),
+  Bytes = i8x32,
+}
+
+impl_permute! {
+  Simd = i16x32,
+  N = 32,
+  Index = i16x32,
+  native = #[cfg(target_feature = "avx512bw")]
+    |table, index| _mm512_permutexvar_epi16(index, table),
+  Half = i16x16,
+  HalfIndex = i16x16,
+}
+
+impl_permute! {
+  Simd = u16x32,
+  N = 32,
+  Index = i16x32,
+  native = #[cfg(target_feature = "avx512bw")]
+    |table, index| _mm512_permutexvar_epi16(index, table),
+  Half = u16x16,
+  HalfIndex = i16x16,
+}
+
+impl_permute! {
+  Simd = f32x4,
+  N = 4,
+  Index = i32x4,
+  native = #[cfg(target_feature = "avx")]
+    |table, index| _mm_permutevar_ps(table, index),
+  Bytes = i8x16,
+}
+
+impl_permute! {
+  Simd = i32x4,
+  N = 4,
+  Index = i32x4,
+  native = #[cfg(target_feature = "avx")]
+    |table, index| _mm_castps_si128(_mm_permutevar_ps(
+      _mm_castsi128_ps(table),
+      index,
+    )),
+  Bytes = i8x16,
+}
+
+impl_permute! {
+  Simd = u32x4,
+  N = 4,
+  Index = i32x4,
+  native = #[cfg(target_feature = "avx")]
+    |table, index| _mm_castps_si128(_mm_permutevar_ps(
+      _mm_castsi128_ps(table),
+      index,
+    )),
+  Bytes = i8x16,
+}
+
+impl_permute! {
+  Simd = f32x8,
+  N = 8,
+  Index = i32x8,
+  native = #[cfg(target_feature = "avx2")]
+    |table, index| _mm256_permutevar8x32_ps(table, index),
+  Bytes = i8x32,
+}
+
+impl_permute! {
+  Simd = i32x8,
+  N = 8,
+  Index = i32x8,
+  native = #[cfg(target_feature = "avx2")]
+    |table, index| _mm256_permutevar8x32_epi32(table, index),
+  Bytes = i8x32,
+}
+
+impl_permute! {
+  Simd = u32x8,
+  N = 8,
+  Index = i32x8,
+  native = #[cfg(target_feature = "avx2")]
+    |table, index| _mm256_permutevar8x32_epi32(table, index),
+  Bytes = i8x32,
+}
+
+impl_permute! {
+  Simd = f32x16,
+  N = 16,
+  Index = i32x16,
+  native = #[cfg(target_feature = "avx512f")]
+    |table, index| _mm512_permutexvar_ps(index, table),
+  Half = f32x8,
+  HalfIndex = i32x8,
+}
+
+impl_permute! {
+  Simd = i32x16,
+  N = 16,
+  Index = i32x16,
+  native = #[cfg(target_feature = "avx512f")]
+    |table, index| _mm512_permutexvar_epi32(index, table),
+  Half = i32x8,
+  HalfIndex = i32x8,
+}
+
+impl_permute! {
+  Simd = u32x16,
+  N = 16,
+  Index = i32x16,
+  native = #[cfg(target_feature = "avx512f")]
+    |table, index| _mm512_permutexvar_epi32(index, table),
+  Half = u32x8,
+  HalfIndex = i32x8,
+}
+
+impl_permute! {
+  Simd = f64x2,
+  N = 2,
+  Index = i64x2,
+  native = #[cfg(target_feature = "avx")]
+    |table, index| _mm_permutevar_pd(table, _mm_slli_epi64::<1>(index)),
+  Bytes = i8x16,
+}
+
+impl_permute! {
+  Simd = i64x2,
+  N = 2,
+  Index = i64x2,
+  native = #[cfg(target_feature = "avx")]
+    |table, index| _mm_castpd_si128(_mm_permutevar_pd(
+      _mm_castsi128_pd(table),
+      _mm_slli_epi64::<1>(index),
+    )),
+  Bytes = i8x16,
+}
+
+impl_permute! {
+  Simd = u64x2,
+  N = 2,
+  Index = i64x2,
+  native = #[cfg(target_feature = "avx")]
+    |table, index| _mm_castpd_si128(_mm_permutevar_pd(
+      _mm_castsi128_pd(table),
+      _mm_slli_epi64::<1>(index),
+    )),
+  Bytes = i8x16,
+}
+
+impl_permute! {
+  Simd = f64x4,
+  N = 4,
+  Index = i64x4,
+  native = #[cfg(target_feature = "avx512f", target_feature = "avx512vl")]
+    |table, index| _mm256_permutexvar_pd(index, table),
+  Bytes = i8x32,
+}
+
+impl_permute! {
+  Simd = i64x4,
+  N = 4,
+  Index = i64x4,
+  native = #[cfg(target_feature = "avx512f", target_feature = "avx512vl")]
+    |table, index| _mm256_permutexvar_epi64(index, table),
+  Bytes = i8x32,
+}
+
+impl_permute! {
+  Simd = u64x4,
+  N = 4,
+  Index = i64x4,
+  native = #[cfg(target_feature = "avx512f", target_feature = "avx512vl")]
+    |table, index| _mm256_permutexvar_epi64(index, table),
+  Bytes = i8x32,
+}
+
+impl_permute! {
+  Simd = f64x8,
+  N = 8,
+  Index = i64x8,
+  native = #[cfg(target_feature = "avx512f")]
+    |table, index| _mm512_permutexvar_pd(index, table),
+  Half = f64x4,
+  HalfIndex = i64x4,
+}
+
+impl_permute! {
+  Simd = i64x8,
+  N = 8,
+  Index = i64x8,
+  native = #[cfg(target_feature = "avx512f")]
+    |table, index| _mm512_permutexvar_epi64(index, table),
+  Half = i64x4,
+  HalfIndex = i64x4,
+}
+
+impl_permute! {
+  Simd = u64x8,
+  N = 8,
+  Index = i64x8,
+  native = #[cfg(target_feature = "avx512f")]
+    |table, index| _mm512_permutexvar_epi64(index, table),
+  Half = u64x4,
+  HalfIndex = i64x4,
+}
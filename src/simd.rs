@@ -11,6 +11,7 @@ macro_rules! impl_simd {
       T = $T:ident,
       N = $N:literal,
       Simd = $Simd:ident,
+      Mask = $Mask:ident,
       optional_type_x86_inner { $(X86Inner = $X86Inner:ident)? },
       optional_type_arm_inner { $(ArmInner = $ArmInner:ident)? },
       optional_type_wasm_inner { $(WasmInner = $WasmInner:ident)? },
@@ -70,24 +71,7 @@ macro_rules! impl_simd {
           ),
         );
 
-        // SAFETY: `$Simd` accepts all bit-patterns, including all zeros.
-        let mut result = unsafe { core::mem::zeroed::<$Simd>() };
-
-        // SAFETY: `value` is valid for its own length, and `result` is valid
-        // because its length is checked to be less than or equal to
-        // `value.len()`. Both pointers are properly aligned because they
-        // originate from a slice of `$T`. The regions of memory do not overlap
-        // because they originate from a shared reference and a mutable
-        // reference.
-        unsafe {
-          core::ptr::copy_nonoverlapping::<$T>(
-            value.as_ptr(),
-            result.as_mut_array().as_mut_ptr(),
-            value.len()
-          );
-        }
-
-        result
+        Self::load_or(value, 0 as $T)
       }
     }
 
@@ -205,6 +189,17 @@ macro_rules! impl_simd {
     impl_formatting_trait!(core::fmt::LowerExp);
     impl_formatting_trait!(core::fmt::UpperExp);
 
+    /// Parses the [`Display`](core::fmt::Display) output, an array or a single
+    /// element used for every lane. See [`ParseSimdError`] for the details.
+    impl core::str::FromStr for $Simd {
+      type Err = ParseSimdError;
+
+      #[inline]
+      fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse_lanes::<$T, $N>(s).map(Self::new)
+      }
+    }
+
     #[expect(deprecated)]
     impl CmpEq for $Simd {
       type Output = Self;
@@ -218,7 +213,7 @@ macro_rules! impl_simd {
 
       #[inline]
       fn simd_eq(self, rhs: $T) -> Self::Output {
-        self.simd_eq(Self::splat(rhs))
+        CmpEq::simd_eq(self, Self::splat(rhs))
       }
     }
 
@@ -235,7 +230,7 @@ macro_rules! impl_simd {
 
       #[inline]
       fn simd_ne(self, rhs: $T) -> Self::Output {
-        self.simd_ne(Self::splat(rhs))
+        CmpNe::simd_ne(self, Self::splat(rhs))
       }
     }
 
@@ -252,7 +247,7 @@ macro_rules! impl_simd {
 
       #[inline]
       fn simd_lt(self, rhs: $T) -> Self::Output {
-        self.simd_lt(Self::splat(rhs))
+        CmpLt::simd_lt(self, Self::splat(rhs))
       }
     }
 
@@ -269,7 +264,7 @@ macro_rules! impl_simd {
 
       #[inline]
       fn simd_gt(self, rhs: $T) -> Self::Output {
-        self.simd_gt(Self::splat(rhs))
+        CmpGt::simd_gt(self, Self::splat(rhs))
       }
     }
 
@@ -286,7 +281,7 @@ macro_rules! impl_simd {
 
       #[inline]
       fn simd_le(self, rhs: $T) -> Self::Output {
-        self.simd_le(Self::splat(rhs))
+        CmpLe::simd_le(self, Self::splat(rhs))
       }
     }
 
@@ -303,7 +298,7 @@ macro_rules! impl_simd {
 
       #[inline]
       fn simd_ge(self, rhs: $T) -> Self::Output {
-        self.simd_ge(Self::splat(rhs))
+        CmpGe::simd_ge(self, Self::splat(rhs))
       }
     }
 
@@ -356,17 +351,136 @@ macro_rules! impl_simd {
         unsafe { core::mem::transmute::<&mut $Simd, &mut [$T; $N]>(self) }
       }
 
+      #[doc = concat!(
+        "Loads the first ", stringify!($N), " elements of `slice`, setting ",
+        "the remaining elements to `fill` if `slice` is shorter.",
+      )]
+      ///
+      /// This is meant for the remainder of a slice that does not fill a whole
+      /// SIMD vector. Extra elements of `slice` are ignored.
+      #[inline]
+      #[must_use]
+      pub fn load_or(slice: &[$T], fill: $T) -> Self {
+        match slice.first_chunk::<$N>() {
+          Some(array) => Self::new(*array),
+          None => {
+            Self::masked_load(slice, $Mask::splat(true), Self::splat(fill))
+          }
+        }
+      }
+
+      /// Stores as many elements of `self` as fit in `slice`, starting with
+      /// the first element.
+      ///
+      /// Elements of `slice` past the length of the SIMD vector are left
+      /// unchanged.
+      #[inline]
+      pub fn store_partial(self, slice: &mut [$T]) {
+        match slice.first_chunk_mut::<$N>() {
+          Some(array) => *array = self.to_array(),
+          None => self.masked_store(slice, $Mask::splat(true)),
+        }
+      }
+
+      /// Loads the elements of `slice` for the lanes that are `true` in
+      /// `mask`, and takes the other elements from `fill`.
+      ///
+      /// Lanes past the end of `slice` are always taken from `fill`, so this
+      /// never reads out of bounds.
+      ///
+      /// Currently this function is accelerated with AVX-512 masked moves, and
+      /// with `vmaskmov` on `avx`/`avx2` for 32-bit and 64-bit elements.
+      #[inline]
+      #[must_use]
+      pub fn masked_load(slice: &[$T], mask: $Mask, fill: Self) -> Self {
+        let mask = mask & $Mask::first_lanes(slice.len());
+        // SAFETY: `slice` is valid for reads of its own length, and every lane
+        // past its end is `false` in `mask`.
+        unsafe { Self::masked_load_ptr(slice.as_ptr(), mask, fill) }
+      }
+
+      /// Stores the elements of `self` to `slice` for the lanes that are
+      /// `true` in `mask`, leaving the other elements of `slice` unchanged.
+      ///
+      /// Lanes past the end of `slice` are never stored, so this never writes
+      /// out of bounds.
+      ///
+      /// Currently this function is accelerated with AVX-512 masked moves, and
+      /// with `vmaskmov` on `avx`/`avx2` for 32-bit and 64-bit elements.
+      #[inline]
+      pub fn masked_store(self, slice: &mut [$T], mask: $Mask) {
+        let mask = mask & $Mask::first_lanes(slice.len());
+        // SAFETY: `slice` is valid for writes of its own length, and every
+        // lane past its end is `false` in `mask`.
+        unsafe { self.masked_store_ptr(slice.as_mut_ptr(), mask) }
+      }
+
+      /// Rotates the elements of `self` to the left by `OFFSET` lanes. The
+      /// element at index `OFFSET` becomes the first element, and the first
+      /// `OFFSET` elements wrap around to the end.
+      ///
+      #[doc = concat!("`OFFSET` is taken modulo ", $N, ".")]
+      ///
+      /// This moves whole elements, unlike `rotate_left` of integer vectors,
+      /// which rotates the bits within each element.
+      #[inline]
+      #[must_use]
+      pub fn rotate_lanes_left<const OFFSET: usize>(self) -> Self {
+        let array = self.to_array();
+        Self::new(core::array::from_fn(|i| array[(i + OFFSET) % $N]))
+      }
+
+      /// Rotates the elements of `self` to the right by `OFFSET` lanes. The
+      /// last `OFFSET` elements wrap around to the beginning.
+      ///
+      #[doc = concat!("`OFFSET` is taken modulo ", $N, ".")]
+      ///
+      /// This moves whole elements, unlike `rotate_right` of integer vectors,
+      /// which rotates the bits within each element.
+      #[inline]
+      #[must_use]
+      pub fn rotate_lanes_right<const OFFSET: usize>(self) -> Self {
+        let array = self.to_array();
+        Self::new(core::array::from_fn(|i| array[(i + $N - OFFSET % $N) % $N]))
+      }
+
+      /// Returns the index of the maximum element, which is the first one if
+      /// several elements are equal to the maximum.
+      ///
+      /// For floats, this is the index of [`reduce_max`](Self::reduce_max), so
+      /// NaN elements are ignored and `+0.0` and `-0.0` are equal. If all
+      /// elements are NaN, `0` is returned.
+      #[inline]
+      #[must_use]
+      pub fn argmax(self) -> usize {
+        let bitmask = self.simd_eq(Self::splat(self.reduce_max())).to_bitmask();
+        if bitmask == 0 { 0 } else { bitmask.trailing_zeros() as usize }
+      }
+
+      /// Returns the index of the minimum element, which is the first one if
+      /// several elements are equal to the minimum.
+      ///
+      /// For floats, this is the index of [`reduce_min`](Self::reduce_min), so
+      /// NaN elements are ignored and `+0.0` and `-0.0` are equal. If all
+      /// elements are NaN, `0` is returned.
+      #[inline]
+      #[must_use]
+      pub fn argmin(self) -> usize {
+        let bitmask = self.simd_eq(Self::splat(self.reduce_min())).to_bitmask();
+        if bitmask == 0 { 0 } else { bitmask.trailing_zeros() as usize }
+      }
+
       /// Returns a [mask] that checks if each element of `self` is equal to the
       /// corresponding element of `other`.
       ///
       /// [mask]: crate#masks
       #[inline]
       #[expect(deprecated)]
-      pub fn simd_eq<Rhs>(self, other: Rhs) -> <Self as CmpEq<Rhs>>::Output
+      pub fn simd_eq<Rhs>(self, other: Rhs) -> $Mask
       where
-        Self: CmpEq<Rhs>,
+        Self: CmpEq<Rhs, Output = Self>,
       {
-        CmpEq::simd_eq(self, other)
+        $Mask::from_simd_unchecked(CmpEq::simd_eq(self, other))
       }
 
       /// Returns a [mask] that checks if each element of `self` is not equal to
@@ -375,11 +489,11 @@ macro_rules! impl_simd {
       /// [mask]: crate#masks
       #[inline]
       #[expect(deprecated)]
-      pub fn simd_ne<Rhs>(self, other: Rhs) -> <Self as CmpNe<Rhs>>::Output
+      pub fn simd_ne<Rhs>(self, other: Rhs) -> $Mask
       where
-        Self: CmpNe<Rhs>,
+        Self: CmpNe<Rhs, Output = Self>,
       {
-        CmpNe::simd_ne(self, other)
+        $Mask::from_simd_unchecked(CmpNe::simd_ne(self, other))
       }
 
       /// Returns a [mask] that checks if each element of `self` is less than
@@ -388,11 +502,11 @@ macro_rules! impl_simd {
       /// [mask]: crate#masks
       #[inline]
       #[expect(deprecated)]
-      pub fn simd_lt<Rhs>(self, other: Rhs) -> <Self as CmpLt<Rhs>>::Output
+      pub fn simd_lt<Rhs>(self, other: Rhs) -> $Mask
       where
-        Self: CmpLt<Rhs>,
+        Self: CmpLt<Rhs, Output = Self>,
       {
-        CmpLt::simd_lt(self, other)
+        $Mask::from_simd_unchecked(CmpLt::simd_lt(self, other))
       }
 
       /// Returns a [mask] that checks if each element of `self` is greater than
@@ -401,11 +515,11 @@ macro_rules! impl_simd {
       /// [mask]: crate#masks
       #[inline]
       #[expect(deprecated)]
-      pub fn simd_gt<Rhs>(self, other: Rhs) -> <Self as CmpGt<Rhs>>::Output
+      pub fn simd_gt<Rhs>(self, other: Rhs) -> $Mask
       where
-        Self: CmpGt<Rhs>,
+        Self: CmpGt<Rhs, Output = Self>,
       {
-        CmpGt::simd_gt(self, other)
+        $Mask::from_simd_unchecked(CmpGt::simd_gt(self, other))
       }
 
       /// Returns a [mask] that checks if each element of `self` is less than or
@@ -414,11 +528,11 @@ macro_rules! impl_simd {
       /// [mask]: crate#masks
       #[inline]
       #[expect(deprecated)]
-      pub fn simd_le<Rhs>(self, other: Rhs) -> <Self as CmpLe<Rhs>>::Output
+      pub fn simd_le<Rhs>(self, other: Rhs) -> $Mask
       where
-        Self: CmpLe<Rhs>,
+        Self: CmpLe<Rhs, Output = Self>,
       {
-        CmpLe::simd_le(self, other)
+        $Mask::from_simd_unchecked(CmpLe::simd_le(self, other))
       }
 
       /// Returns a [mask] that checks if each element of `self` is greater than
@@ -427,11 +541,11 @@ macro_rules! impl_simd {
       /// [mask]: crate#masks
       #[inline]
       #[expect(deprecated)]
-      pub fn simd_ge<Rhs>(self, other: Rhs) -> <Self as CmpGe<Rhs>>::Output
+      pub fn simd_ge<Rhs>(self, other: Rhs) -> $Mask
       where
-        Self: CmpGe<Rhs>,
+        Self: CmpGe<Rhs, Output = Self>,
       {
-        CmpGe::simd_ge(self, other)
+        $Mask::from_simd_unchecked(CmpGe::simd_ge(self, other))
       }
 
       /// Bitwise selection.
@@ -441,11 +555,12 @@ macro_rules! impl_simd {
       /// - If the bit is one, return the corresponding bit of `if_one`
       /// - If the bit is zero, return the corresponding bit of `if_zero`
       ///
-      /// If you know `self` is a [mask], meaning each element is either all
-      /// zeros or all ones, consider using [`select`] which is faster.
+      #[doc = concat!(
+        "For elementwise selection over a [mask] use [`", stringify!($Mask),
+        "::select`] which is faster.",
+      )]
       ///
       /// [mask]: crate#masks
-      /// [`select`]: Self::select
       #[must_use]
       $fn_bitselect
 
@@ -456,57 +571,72 @@ macro_rules! impl_simd {
       /// - If all bits are one, return the corresponding element of `if_true`
       /// - If all bits are zero, return the corresponding element of `if_false`
       ///
-      /// This function assumes `self` is a [mask], meaning each element is
-      /// either all zeros or all ones. For bitwise selection use [`bitselect`].
+      /// This function assumes `self` is a [mask] stored in a SIMD vector of
+      /// the same type, meaning each element is either all zeros or all ones.
       ///
       /// [mask]: crate#masks
-      /// [`bitselect`]: Self::bitselect
+      #[deprecated(
+        since = "1.6.0",
+        note = "use `select` of the dedicated mask types instead"
+      )]
+      #[allow(deprecated)]
       #[must_use]
       $fn_select
 
       /// Converts to a bitmask, where each bit is `1` if the element of `self`
       /// is true or `0` if the element of `self` is false.
       ///
-      /// Each bit of the output corresponds to an element of `self`. The least
-      /// significant bit corresponds to the lowest element. Remaining bits are
-      /// `0`.
-      ///
-      /// This function assumes `self` is a [mask], meaning each element is
-      /// either all zeros or all ones. If the input is not a mask, the result
-      /// is unspecified.
+      /// This function assumes `self` is a [mask] stored in a SIMD vector of
+      /// the same type. If the input is not a mask, the result is unspecified.
       ///
       /// [mask]: crate#masks
+      #[deprecated(
+        since = "1.6.0",
+        note = "use `to_bitmask` of the dedicated mask types instead"
+      )]
+      #[allow(deprecated)]
       #[must_use]
-      #[doc(alias("movemask", "move_mask"))]
       $fn_to_bitmask
 
       /// Returns `true` if any element of `self` is true.
       ///
-      /// This function assumes `self` is a [mask], meaning each element is
-      /// either all zeros or all ones. If the input is not a mask, the result
-      /// is unspecified.
+      /// This function assumes `self` is a [mask] stored in a SIMD vector of
+      /// the same type. If the input is not a mask, the result is unspecified.
       ///
       /// [mask]: crate#masks
+      #[deprecated(
+        since = "1.6.0",
+        note = "use `any` of the dedicated mask types instead"
+      )]
+      #[allow(deprecated)]
       #[must_use]
       $fn_any
 
       /// Returns `true` if all elements of `self` are true.
       ///
-      /// This function assumes `self` is a [mask], meaning each element is
-      /// either all zeros or all ones. If the input is not a mask, the result
-      /// is unspecified.
+      /// This function assumes `self` is a [mask] stored in a SIMD vector of
+      /// the same type. If the input is not a mask, the result is unspecified.
       ///
       /// [mask]: crate#masks
+      #[deprecated(
+        since = "1.6.0",
+        note = "use `all` of the dedicated mask types instead"
+      )]
+      #[allow(deprecated)]
       #[must_use]
       $fn_all
 
       /// Returns `true` if none of the elements of `self` are true.
       ///
-      /// This function assumes `self` is a [mask], meaning each element is
-      /// either all zeros or all ones. If the input is not a mask, the result
-      /// is unspecified.
+      /// This function assumes `self` is a [mask] stored in a SIMD vector of
+      /// the same type. If the input is not a mask, the result is unspecified.
       ///
       /// [mask]: crate#masks
+      #[deprecated(
+        since = "1.6.0",
+        note = "use `none` of the dedicated mask types instead"
+      )]
+      #[allow(deprecated)]
       #[inline]
       #[must_use]
       pub fn none(self) -> bool {
@@ -532,7 +662,7 @@ macro_rules! impl_simd {
       ///
       /// [mask]: crate#masks
       /// [`blend`]: Self::blend
-      /// [`select`]: Self::select
+      #[doc = concat!("[`select`]: ", stringify!($Mask), "::select")]
       /// [`bitselect`]: Self::bitselect
       #[deprecated(
         since = "1.6.0",
@@ -541,6 +671,7 @@ macro_rules! impl_simd {
       #[inline]
       #[must_use]
       pub fn blend(self, if_true: Self, if_false: Self) -> Self {
+        #[expect(deprecated)]
         self.select(if_true, if_false)
       }
     }
@@ -561,7 +692,7 @@ macro_rules! impl_simd {
 
     #[cfg(feature = "serde")]
     mod serde {
-      use serde_core::{Deserialize, Serialize, ser::SerializeTuple};
+      use serde_core::{Deserialize, Serialize, de, ser::SerializeTuple};
 
       use crate::$Simd;
 
@@ -586,7 +717,35 @@ macro_rules! impl_simd {
         where
           D: serde_core::Deserializer<'de>,
         {
-          Ok(<[$T; $N]>::deserialize(deserializer)?.into())
+          // serde only implements `Deserialize` for arrays of up to 32
+          // elements, so this reads the same tuple format by hand.
+          struct Visitor;
+
+          impl<'de> de::Visitor<'de> for Visitor {
+            type Value = $Simd;
+
+            fn expecting(
+              &self,
+              f: &mut core::fmt::Formatter,
+            ) -> core::fmt::Result {
+              write!(f, "an array of length {}", $N)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<$Simd, A::Error>
+            where
+              A: de::SeqAccess<'de>,
+            {
+              let mut array = [<$T>::default(); $N];
+              for (i, e) in array.iter_mut().enumerate() {
+                *e = seq
+                  .next_element()?
+                  .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+              }
+              Ok(array.into())
+            }
+          }
+
+          deserializer.deserialize_tuple($N, Visitor)
         }
       }
     }
@@ -10,8 +10,10 @@ macro_rules! impl_simd_float {
       T = $T:ident,
       N = $N:literal,
       Simd = $Simd:ident,
+      Mask = $Mask:ident,
       UnsignedT = $UnsignedT:ident,
       UnsignedSimd = $UnsignedSimd:ident,
+      SignedSimd = $SignedSimd:ident,
     }
     old_powf_simd_fn_name = $old_powf_simd_fn_name:ident,
 
@@ -32,6 +34,8 @@ macro_rules! impl_simd_float {
     $fn_is_finite:item
     $fn_is_sign_positive:item
     $fn_is_sign_negative:item
+    $fn_logb:item
+    $fn_ldexp:item
     $fn_recip:item
     $fn_recip_sqrt:item
     $fn_max:item
@@ -58,6 +62,7 @@ macro_rules! impl_simd_float {
     $fn_sqrt:item
     $fn_exp:item
     $fn_exp2:item
+    $fn_exp10:item
     $fn_ln:item
     $fn_cbrt:item
     $fn_asin:item
@@ -71,6 +76,11 @@ macro_rules! impl_simd_float {
     $fn_sinh:item
     $fn_cosh:item
     $fn_tanh:item
+    $fn_erf:item
+    $fn_erfc:item
+    $fn_ln_gamma:item
+    $fn_gamma:item
+    $fn_hypot:item
   ) => {
     impl_unary_operator!(
       $Simd,
@@ -481,10 +491,25 @@ macro_rules! impl_simd_float {
       ///
       /// The order of addition is non-deterministic. This means it varies by
       /// platform, version, and can even differ within the same execution from
-      /// one invocation to the next.
+      /// one invocation to the next. With the
+      /// [`deterministic`](crate#deterministic-results) feature, the elements
+      /// are added from first to last.
+      #[cfg(not(feature = "deterministic"))]
       #[must_use]
       $fn_reduce_add
 
+      /// Reducing addition. Returns the sum of the vector's elements.
+      ///
+      /// Equivalent to `self[0] + self[1] + ...`, adding the elements from
+      /// first to last.
+      #[cfg(feature = "deterministic")]
+      #[inline]
+      #[must_use]
+      pub fn reduce_add(self) -> $T {
+        let sum = self.to_array().into_iter().fold(-0.0, |sum, x| sum + x);
+        if sum.is_nan() { $T::NAN } else { sum }
+      }
+
       /// Reducing multiplication. Returns the product of the vector's elements.
       ///
       /// Equivalent to `self[0] * self[1] * ...`.
@@ -493,10 +518,101 @@ macro_rules! impl_simd_float {
       ///
       /// The order of multiplication is non-deterministic. This means it varies
       /// by platform, version, and can even differ within the same execution
-      /// from one invocation to the next.
+      /// from one invocation to the next. With the
+      /// [`deterministic`](crate#deterministic-results) feature, the elements
+      /// are multiplied from first to last.
+      #[cfg(not(feature = "deterministic"))]
       #[must_use]
       $fn_reduce_mul
 
+      /// Reducing multiplication. Returns the product of the vector's elements.
+      ///
+      /// Equivalent to `self[0] * self[1] * ...`, multiplying the elements from
+      /// first to last.
+      #[cfg(feature = "deterministic")]
+      #[inline]
+      #[must_use]
+      pub fn reduce_mul(self) -> $T {
+        let product =
+          self.to_array().into_iter().fold(1.0, |product, x| product * x);
+        if product.is_nan() { $T::NAN } else { product }
+      }
+
+      /// Combines the elements with `op`, by combining each element with the
+      /// element half of the remaining lanes away until one lane is left.
+      #[inline]
+      fn reduce_halves(self, op: impl Fn(Self, Self) -> Self) -> $T {
+        let mut x = self;
+        if $N > 8 {
+          x = op(x, x.rotate_lanes_left::<8>());
+        }
+        if $N > 4 {
+          x = op(x, x.rotate_lanes_left::<4>());
+        }
+        if $N > 2 {
+          x = op(x, x.rotate_lanes_left::<2>());
+        }
+        op(x, x.rotate_lanes_left::<1>()).as_array()[0]
+      }
+
+      /// Reducing maximum. Returns the maximum of the vector's elements,
+      /// ignoring NaN.
+      ///
+      /// Equivalent to `self[0].max(self[1].max(...))`, so NaN is only
+      /// returned if all elements are NaN. If the maximum is zero, either
+      /// `+0.0` or `-0.0` may be returned.
+      ///
+      /// See [`reduce_maximum`](Self::reduce_maximum) for a variant that
+      /// propagates NaN.
+      #[inline]
+      #[must_use]
+      pub fn reduce_max(self) -> $T {
+        self.reduce_halves(Self::max)
+      }
+
+      /// Reducing minimum. Returns the minimum of the vector's elements,
+      /// ignoring NaN.
+      ///
+      /// Equivalent to `self[0].min(self[1].min(...))`, so NaN is only
+      /// returned if all elements are NaN. If the minimum is zero, either
+      /// `+0.0` or `-0.0` may be returned.
+      ///
+      /// See [`reduce_minimum`](Self::reduce_minimum) for a variant that
+      /// propagates NaN.
+      #[inline]
+      #[must_use]
+      pub fn reduce_min(self) -> $T {
+        self.reduce_halves(Self::min)
+      }
+
+      /// Reducing maximum that propagates NaN. Returns NaN if any element is
+      /// NaN, and otherwise the maximum of the vector's elements, where `+0.0`
+      /// is greater than `-0.0`.
+      #[inline]
+      #[must_use]
+      pub fn reduce_maximum(self) -> $T {
+        if self.is_nan().any() {
+          return $T::NAN;
+        }
+        let max = self.reduce_max();
+        let positive_zero = self.simd_eq(Self::ZERO) & self.is_sign_positive();
+        if max == 0.0 && positive_zero.any() { 0.0 } else { max }
+      }
+
+      /// Reducing minimum that propagates NaN. Returns NaN if any element is
+      /// NaN, and otherwise the minimum of the vector's elements, where `-0.0`
+      /// is less than `+0.0`.
+      #[inline]
+      #[must_use]
+      pub fn reduce_minimum(self) -> $T {
+        if self.is_nan().any() {
+          return $T::NAN;
+        }
+        let min = self.reduce_min();
+        let negative_zero = self.simd_eq(Self::ZERO) & self.is_sign_negative();
+        if min == 0.0 && negative_zero.any() { -0.0 } else { min }
+      }
+
       /// Returns a [mask] that checks if each element is NaN.
       ///
       /// [mask]: crate#masks
@@ -531,6 +647,191 @@ macro_rules! impl_simd_float {
       #[must_use]
       $fn_is_sign_negative
 
+      /// Returns a [mask] that checks if each element is neither zero,
+      /// infinite, subnormal nor NaN.
+      ///
+      /// [mask]: crate#masks
+      #[inline]
+      #[must_use]
+      pub fn is_normal(self) -> $Mask {
+        self.abs().simd_ge(Self::MIN_POSITIVE) & self.is_finite()
+      }
+
+      /// Returns a [mask] that checks if each element is subnormal.
+      ///
+      /// [mask]: crate#masks
+      #[inline]
+      #[must_use]
+      pub fn is_subnormal(self) -> $Mask {
+        self.abs().simd_lt(Self::MIN_POSITIVE) & self.simd_ne(Self::ZERO)
+      }
+
+      #[doc = concat!(
+        "Returns the [`", stringify!($T), "::classify`] category of each element."
+      )]
+      #[inline]
+      #[must_use]
+      pub fn classify(self) -> [core::num::FpCategory; $N] {
+        self.to_array().map($T::classify)
+      }
+
+      /// Returns the exponent of each element as a float, `floor(log2(|x|))`.
+      ///
+      /// Subnormal numbers return their exact exponent. Zero returns negative
+      /// infinity, infinity returns positive infinity and NaN returns NaN.
+      #[must_use]
+      $fn_logb
+
+      /// Returns the exponent of each element as an integer,
+      /// `floor(log2(|x|))`.
+      ///
+      #[doc = concat!(
+        "Zero and NaN return [`", stringify!($SignedSimd), "::MIN`], and ",
+        "infinity returns [`", stringify!($SignedSimd), "::MAX`]."
+      )]
+      #[inline]
+      #[must_use]
+      pub fn ilogb(self) -> $SignedSimd {
+        self.is_nan().select($SignedSimd::MIN, self.logb().trunc_int())
+      }
+
+      /// Splits each element into a mantissa and an exponent, such that
+      /// `self = mantissa · 2^exponent` and the absolute value of the mantissa
+      /// is in `[0.5, 1)`.
+      ///
+      /// Zero, infinity and NaN are returned as the mantissa, with an exponent
+      /// of `0`.
+      #[inline]
+      #[must_use]
+      pub fn frexp(self) -> (Self, $SignedSimd) {
+        let special = self.simd_eq(Self::ZERO) | !self.is_finite();
+        let exponent = special.select($SignedSimd::ZERO, self.ilogb() + 1);
+        (self.ldexp(-exponent), exponent)
+      }
+
+      /// Multiplies each element by two raised to the power of the
+      /// corresponding element of `n`, `self · 2^n`.
+      ///
+      /// The result is rounded once, so it is exact unless it overflows or is
+      /// subnormal.
+      #[must_use]
+      $fn_ldexp
+
+      /// Returns the least number greater than each element.
+      ///
+      #[doc = concat!("This is [`", stringify!($T), "::next_up`] for every element: NaN and")]
+      /// positive infinity are returned unchanged, and both zeros return the
+      /// smallest positive subnormal number.
+      #[inline]
+      #[must_use]
+      pub fn next_up(self) -> Self {
+        let bits = cast::<Self, $SignedSimd>(self);
+        let step = self
+          .is_sign_negative()
+          .select($SignedSimd::splat(-1), $SignedSimd::ONE);
+        let next = cast::<$SignedSimd, Self>(bits + step);
+        let tiny = cast::<$SignedSimd, Self>($SignedSimd::ONE);
+        let next = self.simd_eq(Self::ZERO).select(tiny, next);
+        (self.is_nan() | self.simd_eq(Self::INFINITY)).select(self, next)
+      }
+
+      /// Returns the greatest number less than each element.
+      ///
+      #[doc = concat!("This is [`", stringify!($T), "::next_down`] for every element: NaN and")]
+      /// negative infinity are returned unchanged, and both zeros return the
+      /// largest negative subnormal number.
+      #[inline]
+      #[must_use]
+      pub fn next_down(self) -> Self {
+        -(-self).next_up()
+      }
+
+      /// Converts exponents to floats exactly, clamping them to a range where
+      /// [`ldexp`](Self::ldexp) gives the same results.
+      #[inline]
+      fn exponent_to_float(n: $SignedSimd) -> Self {
+        // `1.5 · 2^(MANTISSA_DIGITS - 1)` has a unit in the last place of `1`,
+        // so adding `n` to its bits adds `n` to the float.
+        const MAGIC: $Simd = $Simd::splat(1.5 / $T::EPSILON);
+        const LIMIT: i32 = 2 * ($T::MAX_EXP + $T::MANTISSA_DIGITS as i32);
+        let limit = $SignedSimd::splat(LIMIT as _);
+        let n = n.max(-limit).min(limit);
+        cast::<$SignedSimd, Self>(cast::<Self, $SignedSimd>(MAGIC) + n) - MAGIC
+      }
+
+      /// Computes [`logb`](Self::logb) from the bits of each element.
+      // Vectors with AVX-512 use `vgetexp` instead.
+      #[allow(dead_code)]
+      #[inline]
+      fn logb_portable(self) -> Self {
+        const MANTISSA_BITS: u32 = $T::MANTISSA_DIGITS - 1;
+        const BIAS: i32 = $T::MAX_EXP - 1;
+        // Subnormal numbers are scaled by `2^MANTISSA_DIGITS` first, to make
+        // them normal.
+        const SCALE: $Simd = $Simd::splat(2.0 / $T::EPSILON);
+        let tiny = self.abs().simd_lt(Self::MIN_POSITIVE);
+        let x = tiny.select(self * SCALE, self).abs();
+        let biased = cast::<Self, $SignedSimd>(x) >> MANTISSA_BITS;
+        let bias = tiny.select(
+          $SignedSimd::splat((BIAS + $T::MANTISSA_DIGITS as i32) as _),
+          $SignedSimd::splat(BIAS as _),
+        );
+        let exponent = Self::exponent_to_float(biased - bias);
+        let exponent =
+          self.simd_eq(Self::ZERO).select(Self::NEG_INFINITY, exponent);
+        // This returns infinity for both infinities, and NaN for NaN.
+        self.is_finite().select(exponent, self * self)
+      }
+
+      /// Computes [`ldexp`](Self::ldexp) by multiplying with powers of two,
+      /// like musl's `scalbn`.
+      // Vectors with AVX-512 use `vscalef` instead.
+      #[allow(dead_code)]
+      #[inline]
+      fn ldexp_portable(self, n: $SignedSimd) -> Self {
+        const MANTISSA_BITS: u32 = $T::MANTISSA_DIGITS - 1;
+        const BIAS: i32 = $T::MAX_EXP - 1;
+        // `2^BIAS`, the largest power of two.
+        const UP: $Simd =
+          $Simd::splat($T::from_bits(((2 * BIAS) as $UnsignedT) << MANTISSA_BITS));
+        // `2^(1 - BIAS + MANTISSA_BITS)`. Scaling down by `MANTISSA_BITS` less
+        // than the smallest normal exponent keeps the final multiplication
+        // from rounding a second time when the result is subnormal.
+        const DOWN_EXP: i32 = 1 - BIAS + MANTISSA_BITS as i32;
+        const DOWN: $Simd = $Simd::splat($T::from_bits(
+          ((1 + MANTISSA_BITS) as $UnsignedT) << MANTISSA_BITS,
+        ));
+        let max = $SignedSimd::splat(BIAS as _);
+        let min = $SignedSimd::splat((1 - BIAS) as _);
+        let mut x = self;
+        let mut n = n;
+        // Exponents out of the normal range are applied in up to three steps.
+        for _ in 0..2 {
+          let up = n.simd_gt(max);
+          x = up.select(x * UP, x);
+          n = up.select(n - max, n);
+          let down = n.simd_lt(min);
+          x = down.select(x * DOWN, x);
+          n = down.select(n - $SignedSimd::splat(DOWN_EXP as _), n);
+        }
+        let n = n.max(min).min(max);
+        x * cast::<$SignedSimd, Self>((n + max) << MANTISSA_BITS)
+      }
+
+      /// Replaces each NaN element with [`NAN`](Self::NAN) if the
+      /// `deterministic` feature is enabled, so that NaN results have the same
+      /// bits on every platform.
+      // Vectors made of two halves only call this on the widest backend.
+      #[allow(dead_code)]
+      #[inline(always)]
+      fn canonicalize_nan(self) -> Self {
+        if cfg!(feature = "deterministic") {
+          self.is_nan().select(Self::NAN, self)
+        } else {
+          self
+        }
+      }
+
       /// Returns the reciprocal (inverse) of a number, `1/x`.
       ///
       /// # Unspecified precision
@@ -542,7 +843,7 @@ macro_rules! impl_simd_float {
       #[doc = concat!(
         "To compute the reciprocal deterministically, use `",
         stringify!($Simd),
-        "::ONE / x`."
+        "::ONE / x`, or enable the [`deterministic`](crate#deterministic-results) feature."
       )]
       #[must_use]
       $fn_recip
@@ -555,9 +856,43 @@ macro_rules! impl_simd_float {
       /// The precision of this function is non-deterministic. This means it
       /// varies by platform, version, and can even differ within the same
       /// execution from one invocation to the next.
+      ///
+      /// With the [`deterministic`](crate#deterministic-results) feature, this
+      /// computes `1 / self.sqrt()` on every platform.
       #[must_use]
       $fn_recip_sqrt
 
+      /// Returns `1/sqrt(self)` for each input element, refining the estimate
+      /// of [`recip_sqrt`](Self::recip_sqrt) with one step of Newton's method.
+      ///
+      /// Unlike `recip_sqrt`, this is accurate for subnormal numbers too.
+      ///
+      /// # Unspecified precision
+      ///
+      /// The precision of this function is non-deterministic. This means it
+      /// varies by platform, version, and can even differ within the same
+      /// execution from one invocation to the next.
+      #[inline]
+      #[must_use]
+      pub fn rsqrt(self) -> Self {
+        const THREE_HALVES: $Simd = $Simd::splat(1.5);
+        // Subnormal numbers are scaled by `2^(2·p)` first, as the hardware
+        // estimate treats them as zero.
+        const SCALE: $Simd = $Simd::splat(1.0 / ($T::EPSILON * $T::EPSILON));
+        const SCALE_SQRT: $Simd = $Simd::splat(1.0 / $T::EPSILON);
+        // Negative subnormal numbers are scaled too, so that they give NaN.
+        let abs = self.abs();
+        let tiny = abs.simd_gt(Self::ZERO) & abs.simd_lt(Self::MIN_POSITIVE);
+        let x = tiny.select(self * SCALE, self);
+        let y = x.recip_sqrt();
+        let half_x = x * Self::HALF;
+        let refined = y * (half_x * y).mul_neg_add(y, THREE_HALVES);
+        // `0·inf` is NaN, so keep the estimate for zero and infinity.
+        let exact = y.simd_eq(Self::ZERO) | y.is_inf();
+        let result = exact.select(y, refined);
+        tiny.select(result * SCALE_SQRT, result)
+      }
+
       /// Converts radians to degrees.
       ///
       /// # Unspecified precision
@@ -592,7 +927,9 @@ macro_rules! impl_simd_float {
       /// For each lane, if exactly one of the arguments is NaN, then the other
       /// argument is returned. If both arguments are NaN, the return value is
       /// NaN. If the inputs compare equal (such as for the case of `+0.0` and
-      /// `-0.0`), either input may be returned non-deterministically.
+      /// `-0.0`), either input may be returned non-deterministically. With the
+      /// [`deterministic`](crate#deterministic-results) feature, `self` is
+      /// returned.
       ///
       /// See [`fast_max`] for a faster variant that does not handle NaNs.
       ///
@@ -606,7 +943,9 @@ macro_rules! impl_simd_float {
       /// For each lane, if both arguments are NaN, the return value is NaN. If
       /// the inputs compare equal (such as for the case of `+0.0` and `-0.0`),
       /// or if exactly one of the arguments is NaN, either input may be
-      /// returned non-deterministically.
+      /// returned non-deterministically. With the
+      /// [`deterministic`](crate#deterministic-results) feature, `self` is
+      /// returned.
       ///
       /// See [`max`] for a slower variant that does handle NaNs.
       ///
@@ -620,7 +959,9 @@ macro_rules! impl_simd_float {
       /// For each lane, if exactly one of the arguments is NaN, then the other
       /// argument is returned. If both arguments are NaN, the return value is
       /// NaN. If the inputs compare equal (such as for the case of `+0.0` and
-      /// `-0.0`), either input may be returned non-deterministically.
+      /// `-0.0`), either input may be returned non-deterministically. With the
+      /// [`deterministic`](crate#deterministic-results) feature, `self` is
+      /// returned.
       ///
       /// See [`fast_min`] for a faster variant that does not handle NaNs.
       ///
@@ -634,7 +975,9 @@ macro_rules! impl_simd_float {
       /// For each lane, if both arguments are NaN, the return value is NaN. If
       /// the inputs compare equal (such as for the case of `+0.0` and `-0.0`),
       /// or if exactly one of the arguments is NaN, either input may be
-      /// returned non-deterministically.
+      /// returned non-deterministically. With the
+      /// [`deterministic`](crate#deterministic-results) feature, `self` is
+      /// returned.
       ///
       /// See [`min`] for a slower variant that does handle NaNs.
       ///
@@ -642,6 +985,66 @@ macro_rules! impl_simd_float {
       #[must_use]
       $fn_fast_min
 
+      /// Returns the maximum between each element of `self` and the
+      /// corresponding element of `other`, propagating NaN.
+      ///
+      /// This is the IEEE 754-2019 `maximum` operation: if either argument is
+      /// NaN, the result is NaN, and `+0.0` is greater than `-0.0`.
+      ///
+      /// See [`maximum_number`](Self::maximum_number) for a variant that
+      /// ignores NaN.
+      #[inline]
+      #[must_use]
+      pub fn maximum(self, other: Self) -> Self {
+        let max = other.total_lt(self).select(self, other);
+        (self.is_nan() | other.is_nan()).select(self + other, max)
+      }
+
+      /// Returns the minimum between each element of `self` and the
+      /// corresponding element of `other`, propagating NaN.
+      ///
+      /// This is the IEEE 754-2019 `minimum` operation: if either argument is
+      /// NaN, the result is NaN, and `-0.0` is less than `+0.0`.
+      ///
+      /// See [`minimum_number`](Self::minimum_number) for a variant that
+      /// ignores NaN.
+      #[inline]
+      #[must_use]
+      pub fn minimum(self, other: Self) -> Self {
+        let min = self.total_lt(other).select(self, other);
+        (self.is_nan() | other.is_nan()).select(self + other, min)
+      }
+
+      /// Returns the maximum between each element of `self` and the
+      /// corresponding element of `other`, ignoring NaN.
+      ///
+      /// This is the IEEE 754-2019 `maximumNumber` operation: if exactly one of
+      /// the arguments is NaN, the other argument is returned, and `+0.0` is
+      /// greater than `-0.0`. Unlike [`max`](Self::max), the result for `+0.0`
+      /// and `-0.0` is always `+0.0`.
+      #[inline]
+      #[must_use]
+      pub fn maximum_number(self, other: Self) -> Self {
+        let a = self.is_nan().select(other, self);
+        let b = other.is_nan().select(a, other);
+        b.total_lt(a).select(a, b).canonicalize_nan()
+      }
+
+      /// Returns the minimum between each element of `self` and the
+      /// corresponding element of `other`, ignoring NaN.
+      ///
+      /// This is the IEEE 754-2019 `minimumNumber` operation: if exactly one of
+      /// the arguments is NaN, the other argument is returned, and `-0.0` is
+      /// less than `+0.0`. Unlike [`min`](Self::min), the result for `+0.0`
+      /// and `-0.0` is always `-0.0`.
+      #[inline]
+      #[must_use]
+      pub fn minimum_number(self, other: Self) -> Self {
+        let a = self.is_nan().select(other, self);
+        let b = other.is_nan().select(a, other);
+        a.total_lt(b).select(a, b).canonicalize_nan()
+      }
+
       /// Calculates the midpoint (average) between `self` and `other`.
       ///
       /// This returns NaN when *either* argument is NaN or if a combination of
@@ -686,6 +1089,105 @@ macro_rules! impl_simd_float {
         unsafe { core::mem::transmute::<$UnsignedSimd, $Simd>(bits) }
       }
 
+      /// Returns the bits of each element as signed integers, ordered like
+      #[doc = concat!("[`", stringify!($T), "::total_cmp`].")]
+      ///
+      /// Comparing the integers orders negative NaN first, then negative
+      /// infinity, the negative numbers, `-0.0`, `+0.0`, the positive numbers,
+      /// positive infinity and positive NaN last. This can be used as a sort
+      /// key, or to compare floats as integers.
+      #[inline]
+      #[must_use]
+      pub fn to_ordered_bits(self) -> $SignedSimd {
+        // Negative numbers are ordered backwards, so all bits but the sign bit
+        // are flipped.
+        let flip = self
+          .is_sign_negative()
+          .select($SignedSimd::MAX, $SignedSimd::ZERO);
+        cast::<Self, $SignedSimd>(self) ^ flip
+      }
+
+      #[doc = concat!(
+        "Returns a [mask] that checks if each element is equal to the ",
+        "corresponding element of `other`, according to [`", stringify!($T),
+        "::total_cmp`]."
+      )]
+      ///
+      /// Unlike [`simd_eq`](Self::simd_eq), `-0.0` and `+0.0` are not equal,
+      /// and NaNs are equal if their bits are the same.
+      ///
+      /// [mask]: crate#masks
+      #[inline]
+      #[must_use]
+      pub fn total_eq(self, other: Self) -> $Mask {
+        self.to_ordered_bits().simd_eq(other.to_ordered_bits())
+      }
+
+      #[doc = concat!(
+        "Returns a [mask] that checks if each element is not equal to the ",
+        "corresponding element of `other`, according to [`", stringify!($T),
+        "::total_cmp`]."
+      )]
+      ///
+      /// [mask]: crate#masks
+      #[inline]
+      #[must_use]
+      pub fn total_ne(self, other: Self) -> $Mask {
+        self.to_ordered_bits().simd_ne(other.to_ordered_bits())
+      }
+
+      #[doc = concat!(
+        "Returns a [mask] that checks if each element is less than the ",
+        "corresponding element of `other`, according to [`", stringify!($T),
+        "::total_cmp`]."
+      )]
+      ///
+      /// [mask]: crate#masks
+      #[inline]
+      #[must_use]
+      pub fn total_lt(self, other: Self) -> $Mask {
+        self.to_ordered_bits().simd_lt(other.to_ordered_bits())
+      }
+
+      #[doc = concat!(
+        "Returns a [mask] that checks if each element is less than or equal ",
+        "to the corresponding element of `other`, according to [`",
+        stringify!($T), "::total_cmp`]."
+      )]
+      ///
+      /// [mask]: crate#masks
+      #[inline]
+      #[must_use]
+      pub fn total_le(self, other: Self) -> $Mask {
+        self.to_ordered_bits().simd_le(other.to_ordered_bits())
+      }
+
+      #[doc = concat!(
+        "Returns a [mask] that checks if each element is greater than the ",
+        "corresponding element of `other`, according to [`", stringify!($T),
+        "::total_cmp`]."
+      )]
+      ///
+      /// [mask]: crate#masks
+      #[inline]
+      #[must_use]
+      pub fn total_gt(self, other: Self) -> $Mask {
+        self.to_ordered_bits().simd_gt(other.to_ordered_bits())
+      }
+
+      #[doc = concat!(
+        "Returns a [mask] that checks if each element is greater than or ",
+        "equal to the corresponding element of `other`, according to [`",
+        stringify!($T), "::total_cmp`]."
+      )]
+      ///
+      /// [mask]: crate#masks
+      #[inline]
+      #[must_use]
+      pub fn total_ge(self, other: Self) -> $Mask {
+        self.to_ordered_bits().simd_ge(other.to_ordered_bits())
+      }
+
       /// Restrict a value to a certain interval unless it is NaN.
       ///
       /// If `self`, `min` or `max` are NaN, the result is NaN. If `min > max`,
@@ -788,7 +1290,8 @@ macro_rules! impl_simd_float {
       /// Returns the nearest integer to each input element.
       ///
       /// The result for values half-way between two integers is currently not
-      /// specified.
+      /// specified. With the [`deterministic`](crate#deterministic-results)
+      /// feature, they are rounded to the even integer.
       ///
       /// This saturates out of range values and turns NaNs to `0`. See
       /// [`fast_round_int`] for a faster variant that does not handle out of
@@ -805,7 +1308,9 @@ macro_rules! impl_simd_float {
       ///
       /// This function does not handle out of range values or NaNs. See
       /// [`round_int`] for a slower variant that does handle out of range
-      /// values and NaNs.
+      /// values and NaNs. With the
+      /// [`deterministic`](crate#deterministic-results) feature, this is the
+      /// same as `round_int`.
       ///
       /// [`round_int`]: Self::round_int
       #[must_use]
@@ -841,7 +1346,9 @@ macro_rules! impl_simd_float {
       ///
       /// This function does not handle out of range values or NaNs. See
       /// [`trunc_int`] for a slower variant that does handle out of range
-      /// values and NaNs.
+      /// values and NaNs. With the
+      /// [`deterministic`](crate#deterministic-results) feature, this is the
+      /// same as `trunc_int`.
       ///
       /// [`trunc_int`]: Self::trunc_int
       #[must_use]
@@ -863,6 +1370,10 @@ macro_rules! impl_simd_float {
       /// add operations, resulting in two rounding errors. Note that in the
       /// future, this function may change to always having one rounding error,
       /// at the cost of worse performance.
+      ///
+      /// With the [`deterministic`](crate#deterministic-results) feature, this
+      /// always uses separate operations, so that the result is the same on
+      /// every platform.
       #[must_use]
       $fn_mul_add
 
@@ -873,6 +1384,10 @@ macro_rules! impl_simd_float {
       /// add operations, resulting in two rounding errors. Note that in the
       /// future, this function may change to always having one rounding error,
       /// at the cost of worse performance.
+      ///
+      /// With the [`deterministic`](crate#deterministic-results) feature, this
+      /// always uses separate operations, so that the result is the same on
+      /// every platform.
       #[must_use]
       $fn_mul_sub
 
@@ -883,6 +1398,10 @@ macro_rules! impl_simd_float {
       /// add operations, resulting in two rounding errors. Note that in the
       /// future, this function may change to always having one rounding error,
       /// at the cost of worse performance.
+      ///
+      /// With the [`deterministic`](crate#deterministic-results) feature, this
+      /// always uses separate operations, so that the result is the same on
+      /// every platform.
       #[must_use]
       $fn_mul_neg_add
 
@@ -893,6 +1412,10 @@ macro_rules! impl_simd_float {
       /// add operations, resulting in two rounding errors. Note that in the
       /// future, this function may change to always having one rounding error,
       /// at the cost of worse performance.
+      ///
+      /// With the [`deterministic`](crate#deterministic-results) feature, this
+      /// always uses separate operations, so that the result is the same on
+      /// every platform.
       #[must_use]
       $fn_mul_neg_sub
 
@@ -979,6 +1502,16 @@ macro_rules! impl_simd_float {
       #[must_use]
       $fn_exp2
 
+      /// Returns `10^(self)` for each input element.
+      ///
+      /// # Unspecified precision
+      ///
+      /// The precision of this function is non-deterministic. This means it
+      /// varies by platform, version, and can even differ within the same
+      /// execution from one invocation to the next.
+      #[must_use]
+      $fn_exp10
+
       /// Returns the natural logarithm of a number for each input element.
       ///
       /// # Unspecified precision
@@ -1015,6 +1548,20 @@ macro_rules! impl_simd_float {
         Self::ln(self) * Self::LOG10_E
       }
 
+      /// Returns the logarithm of each element of `self` with respect to the
+      /// corresponding element of `base`.
+      ///
+      /// # Unspecified precision
+      ///
+      /// The precision of this function is non-deterministic. This means it
+      /// varies by platform, version, and can even differ within the same
+      /// execution from one invocation to the next.
+      #[inline]
+      #[must_use]
+      pub fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+      }
+
       /// Returns the cube root of a number for each input element.
       ///
       /// # Unspecified precision
@@ -1067,6 +1614,100 @@ macro_rules! impl_simd_float {
         s / c
       }
 
+      /// Simultaneously computes `sin(π·self)` and `cos(π·self)` for each
+      /// input element. Returns `(sin_pi(x), cos_pi(x))`.
+      ///
+      /// Unlike `(x * PI).sin_cos()`, the argument is reduced exactly, so the
+      /// results are exact at integers and half-integers, and stay accurate
+      /// for large numbers. The signs of zero results follow IEEE 754:
+      /// `sin_pi` returns zero with the sign of `self` at integers, and
+      /// `cos_pi` returns `+0.0` at half-integers.
+      ///
+      /// # Unspecified precision
+      ///
+      /// The precision of this function is non-deterministic. This means it
+      /// varies by platform, version, and can even differ within the same
+      /// execution from one invocation to the next.
+      #[inline]
+      #[must_use]
+      pub fn sin_cos_pi(self) -> (Self, Self) {
+        const TWO: $Simd = $Simd::splat(2.0);
+        const THREE: $Simd = $Simd::splat(3.0);
+        const QUARTER: $Simd = $Simd::splat(0.25);
+        const FOUR: $Simd = $Simd::splat(4.0);
+        // Every number from `2/EPSILON` upwards is an even integer.
+        const EVEN: $Simd = $Simd::splat(2.0 / $T::EPSILON);
+        let even = self.abs().simd_ge(EVEN) & self.is_finite();
+        let x = even.select(Self::ZERO, self);
+        // x = n/2 + f with |f| <= 1/4, both exact
+        let n = (x * TWO).round_ties_even();
+        let f = n.mul_neg_add(Self::HALF, x);
+        let (s, c) = (f * Self::PI).sin_cos();
+        // quadrant = n mod 4
+        let q = n - (n * QUARTER).floor() * FOUR;
+        let swap = q.simd_eq(Self::ONE) | q.simd_eq(THREE);
+        let s_swapped = swap.select(c, s);
+        let c_swapped = swap.select(s, c);
+        let sin = q.simd_ge(TWO).select(-s_swapped, s_swapped);
+        let cos = (q.simd_eq(Self::ONE) | q.simd_eq(TWO))
+          .select(-c_swapped, c_swapped);
+        let signed_zero = Self::ZERO.flip_signs(self);
+        let sin = sin.simd_eq(Self::ZERO).select(signed_zero, sin);
+        let cos = cos.simd_eq(Self::ZERO).select(Self::ZERO, cos);
+        (sin, cos)
+      }
+
+      /// Computes `sin(π·self)` for each input element.
+      ///
+      /// See [`sin_cos_pi`](Self::sin_cos_pi) for how this differs from
+      /// `(x * PI).sin()`.
+      ///
+      /// # Unspecified precision
+      ///
+      /// The precision of this function is non-deterministic. This means it
+      /// varies by platform, version, and can even differ within the same
+      /// execution from one invocation to the next.
+      #[inline]
+      #[must_use]
+      pub fn sin_pi(self) -> Self {
+        let (s, _) = self.sin_cos_pi();
+        s
+      }
+
+      /// Computes `cos(π·self)` for each input element.
+      ///
+      /// See [`sin_cos_pi`](Self::sin_cos_pi) for how this differs from
+      /// `(x * PI).cos()`.
+      ///
+      /// # Unspecified precision
+      ///
+      /// The precision of this function is non-deterministic. This means it
+      /// varies by platform, version, and can even differ within the same
+      /// execution from one invocation to the next.
+      #[inline]
+      #[must_use]
+      pub fn cos_pi(self) -> Self {
+        let (_, c) = self.sin_cos_pi();
+        c
+      }
+
+      /// Computes `tan(π·self)` for each input element.
+      ///
+      /// Like IEEE 754 `tanPi`, this is `+inf` at `n + 0.5` for even `n` and
+      /// `-inf` for odd `n`, e.g. `+inf` at `0.5` and `-inf` at `1.5`.
+      ///
+      /// # Unspecified precision
+      ///
+      /// The precision of this function is non-deterministic. This means it
+      /// varies by platform, version, and can even differ within the same
+      /// execution from one invocation to the next.
+      #[inline]
+      #[must_use]
+      pub fn tan_pi(self) -> Self {
+        let (s, c) = self.sin_cos_pi();
+        s / c
+      }
+
       /// Computes the arcsine of a number for each input element. Return value
       /// is in radians in the range [-pi/2, pi/2] or NaN if the number is
       /// outside the range [-1, 1].
@@ -1197,6 +1838,131 @@ macro_rules! impl_simd_float {
       #[must_use]
       $fn_tanh
 
+      /// Returns the inverse hyperbolic sine for each input element.
+      ///
+      /// # Unspecified precision
+      ///
+      /// The precision of this function is non-deterministic. This means it
+      /// varies by platform, version, and can even differ within the same
+      /// execution from one invocation to the next.
+      #[inline]
+      #[must_use]
+      pub fn asinh(self) -> Self {
+        const BIG: $Simd = $Simd::splat(1.0 / $T::EPSILON);
+        let a = self.abs();
+        // asinh(a) = ln(2a) once 1/a² is negligible, which also avoids
+        // overflowing a².
+        let big = a.ln() + Self::LN_2;
+        let a2 = a * a;
+        let small = (a + a2 / (Self::ONE + (Self::ONE + a2).sqrt())).ln_1p();
+        a.simd_ge(BIG).select(big, small).flip_signs(self)
+      }
+
+      /// Returns the inverse hyperbolic cosine for each input element, or NaN
+      /// if the number is less than `1.0`.
+      ///
+      /// # Unspecified precision
+      ///
+      /// The precision of this function is non-deterministic. This means it
+      /// varies by platform, version, and can even differ within the same
+      /// execution from one invocation to the next.
+      #[inline]
+      #[must_use]
+      pub fn acosh(self) -> Self {
+        const BIG: $Simd = $Simd::splat(1.0 / $T::EPSILON);
+        let big = self.ln() + Self::LN_2;
+        let t = self - Self::ONE;
+        let small = (t + t.mul_add(t, t + t).sqrt()).ln_1p();
+        let result = self.simd_ge(BIG).select(big, small);
+        self.simd_lt(Self::ONE).select(Self::NAN, result)
+      }
+
+      /// Returns the inverse hyperbolic tangent for each input element, or NaN
+      /// if the number is outside the range [-1, 1].
+      ///
+      /// # Unspecified precision
+      ///
+      /// The precision of this function is non-deterministic. This means it
+      /// varies by platform, version, and can even differ within the same
+      /// execution from one invocation to the next.
+      #[inline]
+      #[must_use]
+      pub fn atanh(self) -> Self {
+        let a = self.abs();
+        let t = a + a;
+        let one_minus_a = Self::ONE - a;
+        // atanh(a) = ln_1p(2a/(1-a))/2, rearranged for small a so that the
+        // argument of ln_1p keeps full precision.
+        let small = t.mul_add(a / one_minus_a, t);
+        let large = t / one_minus_a;
+        let result =
+          a.simd_lt(Self::HALF).select(small, large).ln_1p() * Self::HALF;
+        let result = a.simd_gt(Self::ONE).select(Self::NAN, result);
+        result.flip_signs(self)
+      }
+
+      /// Returns the error function
+      /// (`2/sqrt(pi) * integral of e^(-t²) from 0 to self`) for each input
+      /// element.
+      ///
+      /// # Precision
+      ///
+      /// The result is within 1.5 ULP of the exact value. It can differ
+      /// between platforms within that bound.
+      #[must_use]
+      $fn_erf
+
+      /// Returns the complementary error function (`1 - erf(self)`) for each
+      /// input element, which is accurate even if the result is close to zero.
+      ///
+      /// # Precision
+      ///
+      /// The result is within 4 ULP of the exact value. It can differ
+      /// between platforms within that bound.
+      #[must_use]
+      $fn_erfc
+
+      /// Returns the natural logarithm of the absolute value of the gamma
+      /// function for each input element.
+      ///
+      /// This is `+inf` at zero and the negative integers, where the gamma
+      /// function has poles.
+      ///
+      /// # Precision
+      ///
+      /// For positive numbers, the result is within 6 ULP of the exact value.
+      /// Negative numbers use the reflection formula and lose precision close
+      /// to the zeros of the result, like most implementations. The result
+      /// can differ between platforms within that bound.
+      #[must_use]
+      $fn_ln_gamma
+
+      /// Returns the gamma function for each input element.
+      ///
+      /// This is `±inf` at `±0.0` and NaN at the negative integers and
+      /// negative infinity.
+      ///
+      /// # Precision
+      ///
+      /// The result is within 8 ULP of the exact value for `f32` and within 10
+      /// ULP for `f64`. It can differ between platforms within that bound.
+      #[must_use]
+      $fn_gamma
+
+      /// Returns the length of the hypotenuse of a right-angle triangle with
+      /// legs of length `self` and `y` for each input element, that is
+      /// `sqrt(self² + y²)`, without overflow or underflow in the
+      /// intermediate results.
+      ///
+      /// This is `+inf` if either input is infinite, even if the other is NaN.
+      ///
+      /// # Precision
+      ///
+      /// The result is within 2 ULP of the exact value. It can differ between
+      /// platforms within that bound.
+      #[must_use]
+      $fn_hypot
+
       /// Raises each element of the number `self` to the corresponding element
       /// of the floating point power `n`.
       ///
@@ -1234,6 +2000,157 @@ macro_rules! impl_simd_float {
       pub fn powf(self, n: $T) -> Self {
         self.powf_simd(Self::splat(n))
       }
+
+      /// Raises each element of the number `self` to the integer power `n`.
+      ///
+      /// This uses repeated multiplication like the scalar function, and
+      /// computes the reciprocal at the end for negative powers. `x.powi(0)`
+      /// is `1.0` for all `x`, even NaN.
+      ///
+      /// # Unspecified precision
+      ///
+      /// The precision of this function is non-deterministic. This means it
+      /// varies by platform, version, and can even differ within the same
+      /// execution from one invocation to the next.
+      #[inline]
+      #[must_use]
+      pub fn powi(self, n: i32) -> Self {
+        let mut exp = n.unsigned_abs();
+        let mut base = self;
+        let mut result = Self::ONE;
+        loop {
+          if exp & 1 != 0 {
+            result *= base;
+          }
+          exp >>= 1;
+          if exp == 0 {
+            break;
+          }
+          base *= base;
+        }
+        if n < 0 { Self::ONE / result } else { result }
+      }
+    }
+
+    impl_simd_vector_trait! {
+      T = $T,
+      N = $N,
+      Simd = $Simd,
+      Mask = $Mask,
+      Bits = $UnsignedSimd,
+    }
+
+    impl SimdFloat for $Simd {
+      type Int = $SignedSimd;
+
+      const ZERO: Self = Self::ZERO;
+      const HALF: Self = Self::HALF;
+      const ONE: Self = Self::ONE;
+      const EPSILON: Self = Self::EPSILON;
+      const MIN: Self = Self::MIN;
+      const MIN_POSITIVE: Self = Self::MIN_POSITIVE;
+      const MAX: Self = Self::MAX;
+      const NAN: Self = Self::NAN;
+      const INFINITY: Self = Self::INFINITY;
+      const NEG_INFINITY: Self = Self::NEG_INFINITY;
+      const PI: Self = Self::PI;
+
+      forward_to_inherent! {
+        fn is_nan(self) -> $Mask;
+        fn is_inf(self) -> $Mask;
+        fn is_finite(self) -> $Mask;
+        fn is_sign_positive(self) -> $Mask;
+        fn is_sign_negative(self) -> $Mask;
+        fn is_normal(self) -> $Mask;
+        fn is_subnormal(self) -> $Mask;
+        fn recip(self) -> Self;
+        fn recip_sqrt(self) -> Self;
+        fn rsqrt(self) -> Self;
+        fn to_degrees(self) -> Self;
+        fn to_radians(self) -> Self;
+        fn logb(self) -> Self;
+        fn ilogb(self) -> $SignedSimd;
+        fn frexp(self) -> (Self, $SignedSimd);
+        fn ldexp(self, n: $SignedSimd) -> Self;
+        fn next_up(self) -> Self;
+        fn next_down(self) -> Self;
+        fn reduce_max(self) -> $T;
+        fn reduce_min(self) -> $T;
+        fn reduce_maximum(self) -> $T;
+        fn reduce_minimum(self) -> $T;
+        fn fast_max(self, rhs: Self) -> Self;
+        fn fast_min(self, rhs: Self) -> Self;
+        fn fast_clamp(self, min: Self, max: Self) -> Self;
+        fn maximum(self, other: Self) -> Self;
+        fn minimum(self, other: Self) -> Self;
+        fn maximum_number(self, other: Self) -> Self;
+        fn minimum_number(self, other: Self) -> Self;
+        fn to_ordered_bits(self) -> $SignedSimd;
+        fn total_eq(self, other: Self) -> $Mask;
+        fn total_ne(self, other: Self) -> $Mask;
+        fn total_lt(self, other: Self) -> $Mask;
+        fn total_le(self, other: Self) -> $Mask;
+        fn total_gt(self, other: Self) -> $Mask;
+        fn total_ge(self, other: Self) -> $Mask;
+        fn midpoint(self, other: Self) -> Self;
+        fn abs(self) -> Self;
+        fn signum(self) -> Self;
+        fn copysign(self, sign: Self) -> Self;
+        fn flip_signs(self, sign: Self) -> Self;
+        fn floor(self) -> Self;
+        fn ceil(self) -> Self;
+        fn round(self) -> Self;
+        fn round_ties_even(self) -> Self;
+        fn trunc(self) -> Self;
+        fn fract(self) -> Self;
+        fn round_int(self) -> $SignedSimd;
+        fn fast_round_int(self) -> $SignedSimd;
+        fn trunc_int(self) -> $SignedSimd;
+        fn fast_trunc_int(self) -> $SignedSimd;
+        fn mul_add(self, a: Self, b: Self) -> Self;
+        fn mul_sub(self, a: Self, b: Self) -> Self;
+        fn mul_neg_add(self, a: Self, b: Self) -> Self;
+        fn mul_neg_sub(self, a: Self, b: Self) -> Self;
+        fn div_euclid(self, rhs: Self) -> Self;
+        fn rem_euclid(self, rhs: Self) -> Self;
+        fn powf_simd(self, n: Self) -> Self;
+        fn powi(self, n: i32) -> Self;
+        fn sqrt(self) -> Self;
+        fn exp(self) -> Self;
+        fn exp2(self) -> Self;
+        fn exp10(self) -> Self;
+        fn exp_m1(self) -> Self;
+        fn ln(self) -> Self;
+        fn ln_1p(self) -> Self;
+        fn log2(self) -> Self;
+        fn log10(self) -> Self;
+        fn log(self, base: Self) -> Self;
+        fn cbrt(self) -> Self;
+        fn sin(self) -> Self;
+        fn cos(self) -> Self;
+        fn tan(self) -> Self;
+        fn sin_cos(self) -> (Self, Self);
+        fn sin_pi(self) -> Self;
+        fn cos_pi(self) -> Self;
+        fn tan_pi(self) -> Self;
+        fn sin_cos_pi(self) -> (Self, Self);
+        fn asin(self) -> Self;
+        fn acos(self) -> Self;
+        fn asin_acos(self) -> (Self, Self);
+        fn atan(self) -> Self;
+        fn atan2(self, x: Self) -> Self;
+        fn sinh(self) -> Self;
+        fn cosh(self) -> Self;
+        fn tanh(self) -> Self;
+        fn asinh(self) -> Self;
+        fn acosh(self) -> Self;
+        fn atanh(self) -> Self;
+        fn erf(self) -> Self;
+        fn erfc(self) -> Self;
+        fn ln_gamma(self) -> Self;
+        fn gamma(self) -> Self;
+        fn hypot(self, y: Self) -> Self;
+      }
     }
   };
 }
@@ -10,6 +10,7 @@ macro_rules! impl_simd_int {
       T = $T:ident,
       N = $N:literal,
       Simd = $Simd:ident,
+      Mask = $Mask:ident,
       UnsignedSimd = $UnsignedSimd:ident,
       T_BITS = $T_BITS:literal,
       T_BITS_MUL_2 = $T_BITS_MUL_2:literal,
@@ -366,6 +367,13 @@ macro_rules! impl_simd_int {
     impl_formatting_trait!(core::fmt::Octal);
     impl_formatting_trait!(core::fmt::UpperHex);
 
+    impl core::hash::Hash for $Simd {
+      #[inline]
+      fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.to_array().hash(state);
+      }
+    }
+
     /// The following functionality exists for all SIMD vectors of signed
     /// integers.
     impl $Simd {
@@ -546,7 +554,7 @@ macro_rules! impl_simd_int {
       #[must_use]
       pub fn saturating_mul(self, rhs: Self) -> Self {
         let (result, overflow) = self.overflowing_mul(rhs);
-        let limit = Self::MAX ^ (self ^ rhs).is_negative();
+        let limit = Self::MAX ^ Self::from((self ^ rhs).is_negative());
         overflow.select(limit, result)
       }
 
@@ -584,7 +592,7 @@ macro_rules! impl_simd_int {
       /// - A mask indicating whether an overflow occured
       #[inline]
       #[must_use]
-      pub fn overflowing_add(self, rhs: Self) -> (Self, Self) {
+      pub fn overflowing_add(self, rhs: Self) -> (Self, $Mask) {
         let result = self + rhs;
         let overflow = (!(self ^ rhs) & (self ^ result)).is_negative();
 
@@ -599,7 +607,7 @@ macro_rules! impl_simd_int {
       /// - A mask indicating whether an overflow occured
       #[inline]
       #[must_use]
-      pub fn overflowing_sub(self, rhs: Self) -> (Self, Self) {
+      pub fn overflowing_sub(self, rhs: Self) -> (Self, $Mask) {
         let result = self - rhs;
         let overflow = ((self ^ rhs) & (self ^ result)).is_negative();
 
@@ -627,7 +635,7 @@ macro_rules! impl_simd_int {
       /// very slow and should be avoided if possible.
       #[inline]
       #[must_use]
-      pub fn overflowing_div(self, rhs: Self) -> (Self, Self) {
+      pub fn overflowing_div(self, rhs: Self) -> (Self, $Mask) {
         // The second field is equivalent to
         // `self.simd_eq(Self::MIN) & rhs.simd_eq(-1)` but may be cheaper.
         (self / rhs, ((self ^ Self::MAX) & rhs).simd_eq(!Self::ZERO))
@@ -644,7 +652,7 @@ macro_rules! impl_simd_int {
       /// very slow and should be avoided if possible.
       #[inline]
       #[must_use]
-      pub fn overflowing_rem(self, rhs: Self) -> (Self, Self) {
+      pub fn overflowing_rem(self, rhs: Self) -> (Self, $Mask) {
         // The second field is equivalent to
         // `self.simd_eq(Self::MIN) & rhs.simd_eq(-1)` but may be cheaper.
         (self % rhs, ((self ^ Self::MAX) & rhs).simd_eq(!Self::ZERO))
@@ -711,7 +719,7 @@ macro_rules! impl_simd_int {
       pub fn signum(self) -> Self {
         // Flip signs because the result for true in `is_positive/negative` is
         // `-1` (all bits set).
-        self.is_negative() - self.is_positive()
+        Self::from(self.is_negative()) - Self::from(self.is_positive())
       }
 
       /// Returns a [mask] that is true for each positive element, and false if
@@ -727,6 +735,142 @@ macro_rules! impl_simd_int {
       /// [mask]: crate#masks
       #[must_use]
       $fn_is_negative
+
+      /// Returns the number of ones in the binary representation of each
+      /// element.
+      #[inline]
+      #[must_use]
+      pub fn count_ones(self) -> $UnsignedSimd {
+        self.cast_unsigned().count_ones()
+      }
+
+      /// Returns the number of leading zeros in the binary representation of
+      /// each element.
+      #[inline]
+      #[must_use]
+      pub fn leading_zeros(self) -> $UnsignedSimd {
+        self.cast_unsigned().leading_zeros()
+      }
+
+      /// Returns the number of trailing zeros in the binary representation of
+      /// each element.
+      #[inline]
+      #[must_use]
+      pub fn trailing_zeros(self) -> $UnsignedSimd {
+        self.cast_unsigned().trailing_zeros()
+      }
+
+      /// Reverses the order of bits in each element. The least significant
+      /// bit becomes the most significant bit, the second least significant
+      /// bit becomes the second most significant bit, etc.
+      #[inline]
+      #[must_use]
+      pub fn reverse_bits(self) -> Self {
+        self.cast_unsigned().reverse_bits().cast_signed()
+      }
+
+      /// Rotates the bits of each element of `self` to the left by the
+      /// corresponding element of `rhs`, wrapping the truncated bits to the
+      /// end of the element.
+      ///
+      #[doc = concat!("The rotation amount is taken modulo the number of bits in [`", stringify!($T), "`].")]
+      ///
+      /// If you intend to rotate all elements by the same amount, consider
+      /// using [`rotate_left_scalar`] which is faster.
+      ///
+      /// [`rotate_left_scalar`]: Self::rotate_left_scalar
+      #[inline]
+      #[must_use]
+      pub fn rotate_left(self, rhs: $UnsignedSimd) -> Self {
+        self.cast_unsigned().rotate_left(rhs).cast_signed()
+      }
+
+      /// Rotates the bits of each element of `self` to the left by the
+      /// uniform scalar `rhs`, wrapping the truncated bits to the end of the
+      /// element.
+      ///
+      #[doc = concat!("The rotation amount is taken modulo the number of bits in [`", stringify!($T), "`].")]
+      #[inline]
+      #[must_use]
+      pub fn rotate_left_scalar(self, rhs: u32) -> Self {
+        self.cast_unsigned().rotate_left_scalar(rhs).cast_signed()
+      }
+
+      /// Rotates the bits of each element of `self` to the right by the
+      /// corresponding element of `rhs`, wrapping the truncated bits to the
+      /// beginning of the element.
+      ///
+      #[doc = concat!("The rotation amount is taken modulo the number of bits in [`", stringify!($T), "`].")]
+      ///
+      /// If you intend to rotate all elements by the same amount, consider
+      /// using [`rotate_right_scalar`] which is faster.
+      ///
+      /// [`rotate_right_scalar`]: Self::rotate_right_scalar
+      #[inline]
+      #[must_use]
+      pub fn rotate_right(self, rhs: $UnsignedSimd) -> Self {
+        self.cast_unsigned().rotate_right(rhs).cast_signed()
+      }
+
+      /// Rotates the bits of each element of `self` to the right by the
+      /// uniform scalar `rhs`, wrapping the truncated bits to the beginning
+      /// of the element.
+      ///
+      #[doc = concat!("The rotation amount is taken modulo the number of bits in [`", stringify!($T), "`].")]
+      #[inline]
+      #[must_use]
+      pub fn rotate_right_scalar(self, rhs: u32) -> Self {
+        self.cast_unsigned().rotate_right_scalar(rhs).cast_signed()
+      }
+    }
+
+    impl_simd_vector_trait! {
+      T = $T,
+      N = $N,
+      Simd = $Simd,
+      Mask = $Mask,
+      Bits = $UnsignedSimd,
+    }
+
+    impl SimdInt for $Simd {
+      const ZERO: Self = Self::ZERO;
+      const ONE: Self = Self::ONE;
+      const MIN: Self = Self::MIN;
+      const MAX: Self = Self::MAX;
+
+      forward_to_inherent! {
+        fn reduce_max(self) -> $T;
+        fn reduce_min(self) -> $T;
+        fn reduce_and(self) -> $T;
+        fn reduce_or(self) -> $T;
+        fn reduce_xor(self) -> $T;
+        fn unbounded_shl(self, rhs: $UnsignedSimd) -> Self;
+        fn unbounded_shl_scalar(self, rhs: u32) -> Self;
+        fn unbounded_shr(self, rhs: $UnsignedSimd) -> Self;
+        fn unbounded_shr_scalar(self, rhs: u32) -> Self;
+        fn saturating_add(self, rhs: Self) -> Self;
+        fn saturating_sub(self, rhs: Self) -> Self;
+        fn saturating_mul(self, rhs: Self) -> Self;
+        fn saturating_div(self, rhs: Self) -> Self;
+        fn overflowing_add(self, rhs: Self) -> (Self, $Mask);
+        fn overflowing_sub(self, rhs: Self) -> (Self, $Mask);
+        fn overflowing_mul(self, rhs: Self) -> (Self, $Mask);
+        fn mul_keep_low_high(self, rhs: Self) -> ($UnsignedSimd, Self);
+        fn mul_keep_high(self, rhs: Self) -> Self;
+        fn abs(self) -> Self;
+        fn unsigned_abs(self) -> $UnsignedSimd;
+        fn signum(self) -> Self;
+        fn is_positive(self) -> $Mask;
+        fn is_negative(self) -> $Mask;
+        fn count_ones(self) -> $UnsignedSimd;
+        fn leading_zeros(self) -> $UnsignedSimd;
+        fn trailing_zeros(self) -> $UnsignedSimd;
+        fn reverse_bits(self) -> Self;
+        fn rotate_left(self, rhs: $UnsignedSimd) -> Self;
+        fn rotate_left_scalar(self, rhs: u32) -> Self;
+        fn rotate_right(self, rhs: $UnsignedSimd) -> Self;
+        fn rotate_right_scalar(self, rhs: u32) -> Self;
+      }
     }
   };
 }
@@ -10,6 +10,7 @@ macro_rules! impl_simd_uint {
       T = $T:ident,
       N = $N:literal,
       Simd = $Simd:ident,
+      Mask = $Mask:ident,
       SignedSimd = $SignedSimd:ident,
       T_BITS = $T_BITS:literal,
       T_BITS_MUL_2 = $T_BITS_MUL_2:literal,
@@ -43,6 +44,10 @@ macro_rules! impl_simd_uint {
     optional_fn_widening_mul { $($fn_widening_mul:item)? }
     $fn_mul_keep_low_high:item
     $fn_mul_keep_high:item
+    $fn_count_ones:item
+    $fn_leading_zeros:item
+    $fn_rotate_left:item
+    $fn_rotate_left_scalar:item
   ) => {
     impl_unary_operator!(
       $Simd,
@@ -305,6 +310,13 @@ macro_rules! impl_simd_uint {
     impl_formatting_trait!(core::fmt::Octal);
     impl_formatting_trait!(core::fmt::UpperHex);
 
+    impl core::hash::Hash for $Simd {
+      #[inline]
+      fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.to_array().hash(state);
+      }
+    }
+
     /// The following functionality exists for all SIMD vectors of unsigned
     /// integers.
     impl $Simd {
@@ -467,7 +479,7 @@ macro_rules! impl_simd_uint {
       #[must_use]
       pub fn saturating_mul(self, rhs: Self) -> Self {
         let (low, high) = self.mul_keep_low_high(rhs);
-        low | high.simd_ne(Self::ZERO)
+        low | Self::from(high.simd_ne(Self::ZERO))
       }
 
       /// Saturating integer division. Computes `self / rhs`, saturating at the
@@ -500,7 +512,7 @@ macro_rules! impl_simd_uint {
       /// - A mask indicating whether an overflow occured
       #[inline]
       #[must_use]
-      pub fn overflowing_add(self, rhs: Self) -> (Self, Self) {
+      pub fn overflowing_add(self, rhs: Self) -> (Self, $Mask) {
         let result = self + rhs;
         let overflow = result.simd_lt(self);
 
@@ -515,7 +527,7 @@ macro_rules! impl_simd_uint {
       /// - A mask indicating whether an overflow occured
       #[inline]
       #[must_use]
-      pub fn overflowing_sub(self, rhs: Self) -> (Self, Self) {
+      pub fn overflowing_sub(self, rhs: Self) -> (Self, $Mask) {
         let result = self - rhs;
         let overflow = result.simd_gt(self);
 
@@ -546,8 +558,8 @@ macro_rules! impl_simd_uint {
       /// very slow and should be avoided if possible.
       #[inline]
       #[must_use]
-      pub fn overflowing_div(self, rhs: Self) -> (Self, Self) {
-        (self / rhs, Self::ZERO)
+      pub fn overflowing_div(self, rhs: Self) -> (Self, $Mask) {
+        (self / rhs, $Mask::splat(false))
       }
 
       /// Returns `self % rhs` and whether an overflow occured.
@@ -565,8 +577,8 @@ macro_rules! impl_simd_uint {
       /// very slow and should be avoided if possible.
       #[inline]
       #[must_use]
-      pub fn overflowing_rem(self, rhs: Self) -> (Self, Self) {
-        (self % rhs, Self::ZERO)
+      pub fn overflowing_rem(self, rhs: Self) -> (Self, $Mask) {
+        (self % rhs, $Mask::splat(false))
       }
 
       $(
@@ -615,6 +627,139 @@ macro_rules! impl_simd_uint {
       )]
       #[must_use]
       $fn_mul_keep_high
+
+      /// Returns the number of ones in the binary representation of each
+      /// element.
+      #[must_use]
+      $fn_count_ones
+
+      /// Returns the number of leading zeros in the binary representation of
+      /// each element.
+      #[must_use]
+      $fn_leading_zeros
+
+      /// Returns the number of trailing zeros in the binary representation of
+      /// each element.
+      #[inline]
+      #[must_use]
+      pub fn trailing_zeros(self) -> Self {
+        // The bits set in `!self & (self - 1)` are exactly the trailing zeros.
+        (!self & (self - Self::ONE)).count_ones()
+      }
+
+      /// Reverses the order of bits in each element. The least significant
+      /// bit becomes the most significant bit, the second least significant
+      /// bit becomes the second most significant bit, etc.
+      #[inline]
+      #[must_use]
+      pub fn reverse_bits(self) -> Self {
+        // Swap adjacent bits, then pairs of bits, nibbles, bytes, etc.
+        let mut x = self;
+        let mut shift = 1;
+        while shift < $T::BITS {
+          let mask = Self::splat($T::MAX / ((1 << shift) + 1));
+          x = ((x >> shift) & mask) | ((x & mask) << shift);
+          shift *= 2;
+        }
+        x
+      }
+
+      /// Rotates the bits of each element of `self` to the left by the
+      /// corresponding element of `rhs`, wrapping the truncated bits to the
+      /// end of the element.
+      ///
+      #[doc = concat!("The rotation amount is taken modulo the number of bits in [`", stringify!($T), "`].")]
+      ///
+      /// If you intend to rotate all elements by the same amount, consider
+      /// using [`rotate_left_scalar`] which is faster.
+      ///
+      /// [`rotate_left_scalar`]: Self::rotate_left_scalar
+      #[must_use]
+      $fn_rotate_left
+
+      /// Rotates the bits of each element of `self` to the left by the
+      /// uniform scalar `rhs`, wrapping the truncated bits to the end of the
+      /// element.
+      ///
+      #[doc = concat!("The rotation amount is taken modulo the number of bits in [`", stringify!($T), "`].")]
+      #[must_use]
+      $fn_rotate_left_scalar
+
+      /// Rotates the bits of each element of `self` to the right by the
+      /// corresponding element of `rhs`, wrapping the truncated bits to the
+      /// beginning of the element.
+      ///
+      #[doc = concat!("The rotation amount is taken modulo the number of bits in [`", stringify!($T), "`].")]
+      ///
+      /// If you intend to rotate all elements by the same amount, consider
+      /// using [`rotate_right_scalar`] which is faster.
+      ///
+      /// [`rotate_right_scalar`]: Self::rotate_right_scalar
+      #[inline]
+      #[must_use]
+      pub fn rotate_right(self, rhs: Self) -> Self {
+        // Rotating right is rotating left by the negated amount, because the
+        // amount is taken modulo the number of bits.
+        self.rotate_left(Self::ZERO - rhs)
+      }
+
+      /// Rotates the bits of each element of `self` to the right by the
+      /// uniform scalar `rhs`, wrapping the truncated bits to the beginning
+      /// of the element.
+      ///
+      #[doc = concat!("The rotation amount is taken modulo the number of bits in [`", stringify!($T), "`].")]
+      #[inline]
+      #[must_use]
+      pub fn rotate_right_scalar(self, rhs: u32) -> Self {
+        self.rotate_left_scalar(rhs.wrapping_neg())
+      }
+    }
+
+    impl_simd_vector_trait! {
+      T = $T,
+      N = $N,
+      Simd = $Simd,
+      Mask = $Mask,
+      Bits = $Simd,
+    }
+
+    impl SimdUint for $Simd {
+      type Signed = $SignedSimd;
+
+      const ZERO: Self = Self::ZERO;
+      const ONE: Self = Self::ONE;
+      const MIN: Self = Self::MIN;
+      const MAX: Self = Self::MAX;
+
+      forward_to_inherent! {
+        fn reduce_max(self) -> $T;
+        fn reduce_min(self) -> $T;
+        fn reduce_and(self) -> $T;
+        fn reduce_or(self) -> $T;
+        fn reduce_xor(self) -> $T;
+        fn cast_signed(self) -> $SignedSimd;
+        fn unbounded_shl(self, rhs: Self) -> Self;
+        fn unbounded_shl_scalar(self, rhs: u32) -> Self;
+        fn unbounded_shr(self, rhs: Self) -> Self;
+        fn unbounded_shr_scalar(self, rhs: u32) -> Self;
+        fn saturating_add(self, rhs: Self) -> Self;
+        fn saturating_sub(self, rhs: Self) -> Self;
+        fn saturating_mul(self, rhs: Self) -> Self;
+        fn saturating_div(self, rhs: Self) -> Self;
+        fn overflowing_add(self, rhs: Self) -> (Self, $Mask);
+        fn overflowing_sub(self, rhs: Self) -> (Self, $Mask);
+        fn overflowing_mul(self, rhs: Self) -> (Self, $Mask);
+        fn mul_keep_low_high(self, rhs: Self) -> (Self, Self);
+        fn mul_keep_high(self, rhs: Self) -> Self;
+        fn count_ones(self) -> Self;
+        fn leading_zeros(self) -> Self;
+        fn trailing_zeros(self) -> Self;
+        fn reverse_bits(self) -> Self;
+        fn rotate_left(self, rhs: Self) -> Self;
+        fn rotate_left_scalar(self, rhs: u32) -> Self;
+        fn rotate_right(self, rhs: Self) -> Self;
+        fn rotate_right_scalar(self, rhs: u32) -> Self;
+      }
     }
   };
 }
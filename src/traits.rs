@@ -0,0 +1,806 @@
+use super::*;
+
+use core::{
+  fmt::Debug,
+  iter::{Product, Sum},
+};
+
+/// Functionality shared by all SIMD vectors, for writing code that is generic
+/// over the element type and lane count.
+///
+/// Every method here forwards to the inherent method of the same name, so
+/// the documentation of e.g. [`f32x4`] applies. Inherent methods take priority
+/// over trait methods, so importing this trait never changes what
+/// `x.method()` does on a concrete type.
+///
+/// ```
+/// use wide::{SimdFloat, f32x4, f32x8};
+///
+/// fn sum_of_squares<V: SimdFloat>(values: &[V]) -> V::Elem {
+///   values.iter().map(|&v| v * v).sum::<V>().reduce_add()
+/// }
+///
+/// assert_eq!(sum_of_squares(&[f32x4::splat(2.0); 3]), 48.0);
+/// assert_eq!(sum_of_squares(&[f32x8::splat(2.0); 3]), 96.0);
+/// ```
+pub trait SimdVector:
+  Copy
+  + Default
+  + PartialEq
+  + Debug
+  + Pod
+  + Send
+  + Sync
+  + 'static
+  + Add<Output = Self>
+  + Sub<Output = Self>
+  + Mul<Output = Self>
+  + Div<Output = Self>
+  + Rem<Output = Self>
+  + Neg<Output = Self>
+  + Not<Output = Self>
+  + BitAnd<Output = Self>
+  + BitOr<Output = Self>
+  + BitXor<Output = Self>
+  + AddAssign
+  + SubAssign
+  + MulAssign
+  + DivAssign
+  + RemAssign
+  + BitAndAssign
+  + BitOrAssign
+  + BitXorAssign
+  + Sum
+  + Product
+{
+  /// The element type of this SIMD vector.
+  type Elem: Copy + Default + PartialEq + Debug + Pod;
+
+  /// The [mask] type returned by comparisons.
+  ///
+  /// [mask]: crate#masks
+  type Mask: SimdMask;
+
+  /// The unsigned integer SIMD vector with the same lane count and lane size,
+  /// used to access the raw bits of each element.
+  type Bits: Copy;
+
+  /// The number of elements in this SIMD vector.
+  const LANES: usize;
+
+  /// Constructs a new SIMD vector with all elements set to the given value.
+  fn splat(elem: Self::Elem) -> Self;
+
+  /// Converts a slice to a SIMD vector, filling in zeros if there are not
+  /// enough elements.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `slice` has more than [`LANES`](Self::LANES) elements.
+  fn from_slice(slice: &[Self::Elem]) -> Self;
+
+  /// Loads a slice that may be shorter than the SIMD vector, see
+  /// [`f32x4::load_or`].
+  fn load_or(slice: &[Self::Elem], fill: Self::Elem) -> Self;
+
+  /// Stores to a slice that may be shorter than the SIMD vector, see
+  /// [`f32x4::store_partial`].
+  fn store_partial(self, slice: &mut [Self::Elem]);
+
+  /// Loads the lanes of a slice selected by a [mask], see
+  /// [`f32x4::masked_load`].
+  ///
+  /// [mask]: crate#masks
+  fn masked_load(slice: &[Self::Elem], mask: Self::Mask, fill: Self) -> Self;
+
+  /// Stores the lanes selected by a [mask] to a slice, see
+  /// [`f32x4::masked_store`].
+  ///
+  /// [mask]: crate#masks
+  fn masked_store(self, slice: &mut [Self::Elem], mask: Self::Mask);
+
+  /// Moves the lanes selected by a [mask] to the front, see
+  /// [`f32x4::compress`].
+  ///
+  /// [mask]: crate#masks
+  fn compress(self, mask: Self::Mask) -> (Self, usize);
+
+  /// Stores the lanes selected by a [mask] to the start of a slice, see
+  /// [`f32x4::compress_store`].
+  ///
+  /// [mask]: crate#masks
+  fn compress_store(self, mask: Self::Mask, slice: &mut [Self::Elem]) -> usize;
+
+  /// Moves the first elements to the lanes selected by a [mask], see
+  /// [`f32x4::expand`].
+  ///
+  /// [mask]: crate#masks
+  fn expand(self, mask: Self::Mask, src: Self) -> Self;
+
+  /// Rotates whole elements to the left, see [`f32x4::rotate_lanes_left`].
+  fn rotate_lanes_left<const OFFSET: usize>(self) -> Self;
+
+  /// Rotates whole elements to the right, see [`f32x4::rotate_lanes_right`].
+  fn rotate_lanes_right<const OFFSET: usize>(self) -> Self;
+
+  /// See [`f32x4::reverse`].
+  fn reverse(self) -> Self;
+
+  /// See [`f32x4::broadcast_lane`].
+  fn broadcast_lane<const I: usize>(self) -> Self;
+
+  /// Returns the index of the maximum element, see [`f32x4::argmax`].
+  fn argmax(self) -> usize;
+
+  /// Returns the index of the minimum element, see [`f32x4::argmin`].
+  fn argmin(self) -> usize;
+
+  /// See [`f32x4::interleave_low`].
+  fn interleave_low(self, other: Self) -> Self;
+
+  /// See [`f32x4::interleave_high`].
+  fn interleave_high(self, other: Self) -> Self;
+
+  /// Returns a slice containing the entire SIMD vector.
+  fn as_slice(&self) -> &[Self::Elem];
+
+  /// Returns a mutable slice containing the entire SIMD vector.
+  fn as_mut_slice(&mut self) -> &mut [Self::Elem];
+
+  /// Raw transmutation to the unsigned integer vector [`Bits`](Self::Bits).
+  fn to_bits(self) -> Self::Bits;
+
+  /// Raw transmutation from the unsigned integer vector [`Bits`](Self::Bits).
+  fn from_bits(bits: Self::Bits) -> Self;
+
+  /// Returns a [mask] that checks if each element of `self` is equal to the
+  /// corresponding element of `rhs`.
+  ///
+  /// [mask]: crate#masks
+  fn simd_eq(self, rhs: Self) -> Self::Mask;
+
+  /// Returns a [mask] that checks if each element of `self` is not equal to
+  /// the corresponding element of `rhs`.
+  ///
+  /// [mask]: crate#masks
+  fn simd_ne(self, rhs: Self) -> Self::Mask;
+
+  /// Returns a [mask] that checks if each element of `self` is less than the
+  /// corresponding element of `rhs`.
+  ///
+  /// [mask]: crate#masks
+  fn simd_lt(self, rhs: Self) -> Self::Mask;
+
+  /// Returns a [mask] that checks if each element of `self` is greater than
+  /// the corresponding element of `rhs`.
+  ///
+  /// [mask]: crate#masks
+  fn simd_gt(self, rhs: Self) -> Self::Mask;
+
+  /// Returns a [mask] that checks if each element of `self` is less than or
+  /// equal to the corresponding element of `rhs`.
+  ///
+  /// [mask]: crate#masks
+  fn simd_le(self, rhs: Self) -> Self::Mask;
+
+  /// Returns a [mask] that checks if each element of `self` is greater than
+  /// or equal to the corresponding element of `rhs`.
+  ///
+  /// [mask]: crate#masks
+  fn simd_ge(self, rhs: Self) -> Self::Mask;
+
+  /// Bitwise selection, see [`f32x4::bitselect`].
+  fn bitselect(self, if_one: Self, if_zero: Self) -> Self;
+
+  /// Returns the maximum between each element of `self` and the
+  /// corresponding element of `rhs`.
+  fn max(self, rhs: Self) -> Self;
+
+  /// Returns the minimum between each element of `self` and the
+  /// corresponding element of `rhs`.
+  fn min(self, rhs: Self) -> Self;
+
+  /// Clamps each element of `self` between the corresponding elements of
+  /// `min` and `max`.
+  fn clamp(self, min: Self, max: Self) -> Self;
+
+  /// Reducing addition. Returns the sum of the vector's elements.
+  fn reduce_add(self) -> Self::Elem;
+
+  /// Reducing multiplication. Returns the product of the vector's elements.
+  fn reduce_mul(self) -> Self::Elem;
+
+  /// See [`f32x4::scan_add`].
+  fn scan_add(self) -> Self;
+
+  /// See [`f32x4::scan_add_exclusive`].
+  fn scan_add_exclusive(self) -> Self;
+
+  /// See [`f32x4::scan_max`].
+  fn scan_max(self) -> Self;
+
+  /// See [`f32x4::scan_min`].
+  fn scan_min(self) -> Self;
+
+  /// See [`f32x4::scan_or`].
+  fn scan_or(self) -> Self;
+
+  /// See [`f32x4::prefix_sum`].
+  fn prefix_sum(values: &mut [Self::Elem]);
+}
+
+/// Functionality shared by all [mask] types, such as [`m32x4`].
+///
+/// See [`SimdVector`] for more information.
+///
+/// [mask]: crate#masks
+pub trait SimdMask:
+  Copy
+  + Default
+  + PartialEq
+  + Eq
+  + Debug
+  + Send
+  + Sync
+  + 'static
+  + Not<Output = Self>
+  + BitAnd<Output = Self>
+  + BitOr<Output = Self>
+  + BitXor<Output = Self>
+  + BitAndAssign
+  + BitOrAssign
+  + BitXorAssign
+{
+  /// The number of lanes in this mask.
+  const LANES: usize;
+
+  /// Constructs a new mask with all lanes set to the given value.
+  fn splat(value: bool) -> Self;
+
+  /// Returns the value of the lane at `index`, see [`m32x4::test`].
+  fn test(self, index: usize) -> bool;
+
+  /// Converts a bitmask to a mask, see [`m32x4::from_bitmask`].
+  fn from_bitmask(bitmask: u64) -> Self;
+
+  /// Converts a mask to a bitmask, see [`m32x4::to_bitmask`].
+  fn to_bitmask(self) -> u64;
+
+  /// Returns `true` if any lane of `self` is `true`.
+  fn any(self) -> bool;
+
+  /// Returns `true` if all lanes of `self` are `true`.
+  fn all(self) -> bool;
+
+  /// Returns `true` if none of the lanes of `self` are `true`.
+  fn none(self) -> bool;
+
+  /// Elementwise selection, see [`m32x4::select`].
+  fn select<V>(self, if_true: V, if_false: V) -> V
+  where
+    V: SimdVector<Mask = Self>;
+}
+
+/// Functionality shared by all SIMD vectors of floats.
+///
+/// See [`SimdVector`] for more information.
+pub trait SimdFloat: SimdVector {
+  /// The signed integer SIMD vector with the same lane count and lane size,
+  /// returned by functions such as [`round_int`](Self::round_int).
+  type Int: Copy;
+
+  /// A SIMD vector with all elements set to `0.0`.
+  const ZERO: Self;
+  /// A SIMD vector with all elements set to `0.5`.
+  const HALF: Self;
+  /// A SIMD vector with all elements set to `1.0`.
+  const ONE: Self;
+  /// A SIMD vector with all elements set to the machine epsilon.
+  const EPSILON: Self;
+  /// A SIMD vector with all elements set to the smallest finite value.
+  const MIN: Self;
+  /// A SIMD vector with all elements set to the smallest positive normal
+  /// value.
+  const MIN_POSITIVE: Self;
+  /// A SIMD vector with all elements set to the largest finite value.
+  const MAX: Self;
+  /// A SIMD vector with all elements set to NaN.
+  const NAN: Self;
+  /// A SIMD vector with all elements set to positive infinity.
+  const INFINITY: Self;
+  /// A SIMD vector with all elements set to negative infinity.
+  const NEG_INFINITY: Self;
+  /// A SIMD vector with all elements set to π.
+  const PI: Self;
+
+  /// Returns a [mask] that checks if each element is NaN.
+  ///
+  /// [mask]: crate#masks
+  fn is_nan(self) -> Self::Mask;
+  /// Returns a [mask] that checks if each element is infinity.
+  ///
+  /// [mask]: crate#masks
+  fn is_inf(self) -> Self::Mask;
+  /// Returns a [mask] that checks if each element is neither infinite nor
+  /// NaN.
+  ///
+  /// [mask]: crate#masks
+  fn is_finite(self) -> Self::Mask;
+  /// Returns a [mask] that checks if each element has a positive sign.
+  ///
+  /// [mask]: crate#masks
+  fn is_sign_positive(self) -> Self::Mask;
+  /// Returns a [mask] that checks if each element has a negative sign.
+  ///
+  /// [mask]: crate#masks
+  fn is_sign_negative(self) -> Self::Mask;
+  /// See [`f32x4::is_normal`].
+  fn is_normal(self) -> Self::Mask;
+  /// See [`f32x4::is_subnormal`].
+  fn is_subnormal(self) -> Self::Mask;
+
+  /// See [`f32x4::recip`].
+  fn recip(self) -> Self;
+  /// See [`f32x4::recip_sqrt`].
+  fn recip_sqrt(self) -> Self;
+  /// See [`f32x4::rsqrt`].
+  fn rsqrt(self) -> Self;
+  /// See [`f32x4::to_degrees`].
+  fn to_degrees(self) -> Self;
+  /// See [`f32x4::to_radians`].
+  fn to_radians(self) -> Self;
+  /// See [`f32x4::logb`].
+  fn logb(self) -> Self;
+  /// See [`f32x4::ilogb`].
+  fn ilogb(self) -> Self::Int;
+  /// See [`f32x4::frexp`].
+  fn frexp(self) -> (Self, Self::Int);
+  /// See [`f32x4::ldexp`].
+  fn ldexp(self, n: Self::Int) -> Self;
+  /// See [`f32x4::next_up`].
+  fn next_up(self) -> Self;
+  /// See [`f32x4::next_down`].
+  fn next_down(self) -> Self;
+  /// See [`f32x4::reduce_max`].
+  fn reduce_max(self) -> Self::Elem;
+  /// See [`f32x4::reduce_min`].
+  fn reduce_min(self) -> Self::Elem;
+  /// See [`f32x4::reduce_maximum`].
+  fn reduce_maximum(self) -> Self::Elem;
+  /// See [`f32x4::reduce_minimum`].
+  fn reduce_minimum(self) -> Self::Elem;
+  /// See [`f32x4::fast_max`].
+  fn fast_max(self, rhs: Self) -> Self;
+  /// See [`f32x4::fast_min`].
+  fn fast_min(self, rhs: Self) -> Self;
+  /// See [`f32x4::fast_clamp`].
+  fn fast_clamp(self, min: Self, max: Self) -> Self;
+  /// See [`f32x4::maximum`].
+  fn maximum(self, other: Self) -> Self;
+  /// See [`f32x4::minimum`].
+  fn minimum(self, other: Self) -> Self;
+  /// See [`f32x4::maximum_number`].
+  fn maximum_number(self, other: Self) -> Self;
+  /// See [`f32x4::minimum_number`].
+  fn minimum_number(self, other: Self) -> Self;
+  /// See [`f32x4::to_ordered_bits`].
+  fn to_ordered_bits(self) -> Self::Int;
+  /// See [`f32x4::total_eq`].
+  fn total_eq(self, other: Self) -> Self::Mask;
+  /// See [`f32x4::total_ne`].
+  fn total_ne(self, other: Self) -> Self::Mask;
+  /// See [`f32x4::total_lt`].
+  fn total_lt(self, other: Self) -> Self::Mask;
+  /// See [`f32x4::total_le`].
+  fn total_le(self, other: Self) -> Self::Mask;
+  /// See [`f32x4::total_gt`].
+  fn total_gt(self, other: Self) -> Self::Mask;
+  /// See [`f32x4::total_ge`].
+  fn total_ge(self, other: Self) -> Self::Mask;
+  /// See [`f32x4::midpoint`].
+  fn midpoint(self, other: Self) -> Self;
+  /// See [`f32x4::abs`].
+  fn abs(self) -> Self;
+  /// See [`f32x4::signum`].
+  fn signum(self) -> Self;
+  /// See [`f32x4::copysign`].
+  fn copysign(self, sign: Self) -> Self;
+  /// See [`f32x4::flip_signs`].
+  fn flip_signs(self, sign: Self) -> Self;
+
+  /// See [`f32x4::floor`].
+  fn floor(self) -> Self;
+  /// See [`f32x4::ceil`].
+  fn ceil(self) -> Self;
+  /// See [`f32x4::round`].
+  fn round(self) -> Self;
+  /// See [`f32x4::round_ties_even`].
+  fn round_ties_even(self) -> Self;
+  /// See [`f32x4::trunc`].
+  fn trunc(self) -> Self;
+  /// See [`f32x4::fract`].
+  fn fract(self) -> Self;
+  /// See [`f32x4::round_int`].
+  fn round_int(self) -> Self::Int;
+  /// See [`f32x4::fast_round_int`].
+  fn fast_round_int(self) -> Self::Int;
+  /// See [`f32x4::trunc_int`].
+  fn trunc_int(self) -> Self::Int;
+  /// See [`f32x4::fast_trunc_int`].
+  fn fast_trunc_int(self) -> Self::Int;
+
+  /// See [`f32x4::mul_add`].
+  fn mul_add(self, a: Self, b: Self) -> Self;
+  /// See [`f32x4::mul_sub`].
+  fn mul_sub(self, a: Self, b: Self) -> Self;
+  /// See [`f32x4::mul_neg_add`].
+  fn mul_neg_add(self, a: Self, b: Self) -> Self;
+  /// See [`f32x4::mul_neg_sub`].
+  fn mul_neg_sub(self, a: Self, b: Self) -> Self;
+  /// See [`f32x4::div_euclid`].
+  fn div_euclid(self, rhs: Self) -> Self;
+  /// See [`f32x4::rem_euclid`].
+  fn rem_euclid(self, rhs: Self) -> Self;
+
+  /// See [`f32x4::powf_simd`].
+  fn powf_simd(self, n: Self) -> Self;
+  /// See [`f32x4::powi`].
+  fn powi(self, n: i32) -> Self;
+  /// See [`f32x4::sqrt`].
+  fn sqrt(self) -> Self;
+  /// See [`f32x4::exp`].
+  fn exp(self) -> Self;
+  /// See [`f32x4::exp2`].
+  fn exp2(self) -> Self;
+  /// See [`f32x4::exp10`].
+  fn exp10(self) -> Self;
+  /// See [`f32x4::exp_m1`].
+  fn exp_m1(self) -> Self;
+  /// See [`f32x4::ln`].
+  fn ln(self) -> Self;
+  /// See [`f32x4::ln_1p`].
+  fn ln_1p(self) -> Self;
+  /// See [`f32x4::log2`].
+  fn log2(self) -> Self;
+  /// See [`f32x4::log10`].
+  fn log10(self) -> Self;
+  /// See [`f32x4::log`].
+  fn log(self, base: Self) -> Self;
+  /// See [`f32x4::cbrt`].
+  fn cbrt(self) -> Self;
+  /// See [`f32x4::sin`].
+  fn sin(self) -> Self;
+  /// See [`f32x4::cos`].
+  fn cos(self) -> Self;
+  /// See [`f32x4::tan`].
+  fn tan(self) -> Self;
+  /// See [`f32x4::sin_cos`].
+  fn sin_cos(self) -> (Self, Self);
+  /// See [`f32x4::sin_pi`].
+  fn sin_pi(self) -> Self;
+  /// See [`f32x4::cos_pi`].
+  fn cos_pi(self) -> Self;
+  /// See [`f32x4::tan_pi`].
+  fn tan_pi(self) -> Self;
+  /// See [`f32x4::sin_cos_pi`].
+  fn sin_cos_pi(self) -> (Self, Self);
+  /// See [`f32x4::asin`].
+  fn asin(self) -> Self;
+  /// See [`f32x4::acos`].
+  fn acos(self) -> Self;
+  /// See [`f32x4::asin_acos`].
+  fn asin_acos(self) -> (Self, Self);
+  /// See [`f32x4::atan`].
+  fn atan(self) -> Self;
+  /// See [`f32x4::atan2`].
+  fn atan2(self, x: Self) -> Self;
+  /// See [`f32x4::sinh`].
+  fn sinh(self) -> Self;
+  /// See [`f32x4::cosh`].
+  fn cosh(self) -> Self;
+  /// See [`f32x4::tanh`].
+  fn tanh(self) -> Self;
+  /// See [`f32x4::asinh`].
+  fn asinh(self) -> Self;
+  /// See [`f32x4::acosh`].
+  fn acosh(self) -> Self;
+  /// See [`f32x4::atanh`].
+  fn atanh(self) -> Self;
+  /// See [`f32x4::erf`].
+  fn erf(self) -> Self;
+  /// See [`f32x4::erfc`].
+  fn erfc(self) -> Self;
+  /// See [`f32x4::ln_gamma`].
+  fn ln_gamma(self) -> Self;
+  /// See [`f32x4::gamma`].
+  fn gamma(self) -> Self;
+  /// See [`f32x4::hypot`].
+  fn hypot(self, y: Self) -> Self;
+}
+
+/// Functionality shared by all SIMD vectors of signed integers.
+///
+/// See [`SimdVector`] for more information.
+pub trait SimdInt:
+  SimdVector + Shl<u32, Output = Self> + Shr<u32, Output = Self>
+{
+  /// A SIMD vector with all elements set to `0`.
+  const ZERO: Self;
+  /// A SIMD vector with all elements set to `1`.
+  const ONE: Self;
+  /// A SIMD vector with all elements set to the smallest value.
+  const MIN: Self;
+  /// A SIMD vector with all elements set to the largest value.
+  const MAX: Self;
+
+  /// See [`i32x4::reduce_max`].
+  fn reduce_max(self) -> Self::Elem;
+  /// See [`i32x4::reduce_min`].
+  fn reduce_min(self) -> Self::Elem;
+  /// See [`i32x4::reduce_and`].
+  fn reduce_and(self) -> Self::Elem;
+  /// See [`i32x4::reduce_or`].
+  fn reduce_or(self) -> Self::Elem;
+  /// See [`i32x4::reduce_xor`].
+  fn reduce_xor(self) -> Self::Elem;
+  /// See [`i32x4::unbounded_shl`].
+  fn unbounded_shl(self, rhs: Self::Bits) -> Self;
+  /// See [`i32x4::unbounded_shl_scalar`].
+  fn unbounded_shl_scalar(self, rhs: u32) -> Self;
+  /// See [`i32x4::unbounded_shr`].
+  fn unbounded_shr(self, rhs: Self::Bits) -> Self;
+  /// See [`i32x4::unbounded_shr_scalar`].
+  fn unbounded_shr_scalar(self, rhs: u32) -> Self;
+  /// See [`i32x4::saturating_add`].
+  fn saturating_add(self, rhs: Self) -> Self;
+  /// See [`i32x4::saturating_sub`].
+  fn saturating_sub(self, rhs: Self) -> Self;
+  /// See [`i32x4::saturating_mul`].
+  fn saturating_mul(self, rhs: Self) -> Self;
+  /// See [`i32x4::saturating_div`].
+  fn saturating_div(self, rhs: Self) -> Self;
+  /// See [`i32x4::overflowing_add`].
+  fn overflowing_add(self, rhs: Self) -> (Self, Self::Mask);
+  /// See [`i32x4::overflowing_sub`].
+  fn overflowing_sub(self, rhs: Self) -> (Self, Self::Mask);
+  /// See [`i32x4::overflowing_mul`].
+  fn overflowing_mul(self, rhs: Self) -> (Self, Self::Mask);
+  /// See [`i32x4::mul_keep_low_high`].
+  fn mul_keep_low_high(self, rhs: Self) -> (Self::Bits, Self);
+  /// See [`i32x4::mul_keep_high`].
+  fn mul_keep_high(self, rhs: Self) -> Self;
+  /// See [`i32x4::abs`].
+  fn abs(self) -> Self;
+  /// See [`i32x4::unsigned_abs`].
+  fn unsigned_abs(self) -> Self::Bits;
+  /// See [`i32x4::signum`].
+  fn signum(self) -> Self;
+  /// Returns a [mask] that is true for each positive element.
+  ///
+  /// [mask]: crate#masks
+  fn is_positive(self) -> Self::Mask;
+  /// Returns a [mask] that is true for each negative element.
+  ///
+  /// [mask]: crate#masks
+  fn is_negative(self) -> Self::Mask;
+  /// See [`i32x4::count_ones`].
+  fn count_ones(self) -> Self::Bits;
+  /// See [`i32x4::leading_zeros`].
+  fn leading_zeros(self) -> Self::Bits;
+  /// See [`i32x4::trailing_zeros`].
+  fn trailing_zeros(self) -> Self::Bits;
+  /// See [`i32x4::reverse_bits`].
+  fn reverse_bits(self) -> Self;
+  /// See [`i32x4::rotate_left`].
+  fn rotate_left(self, rhs: Self::Bits) -> Self;
+  /// See [`i32x4::rotate_left_scalar`].
+  fn rotate_left_scalar(self, rhs: u32) -> Self;
+  /// See [`i32x4::rotate_right`].
+  fn rotate_right(self, rhs: Self::Bits) -> Self;
+  /// See [`i32x4::rotate_right_scalar`].
+  fn rotate_right_scalar(self, rhs: u32) -> Self;
+}
+
+/// Functionality shared by all SIMD vectors of unsigned integers.
+///
+/// See [`SimdVector`] for more information.
+pub trait SimdUint:
+  SimdVector<Bits = Self> + Shl<u32, Output = Self> + Shr<u32, Output = Self>
+{
+  /// The signed integer SIMD vector with the same lane count and lane size.
+  type Signed: Copy;
+
+  /// A SIMD vector with all elements set to `0`.
+  const ZERO: Self;
+  /// A SIMD vector with all elements set to `1`.
+  const ONE: Self;
+  /// A SIMD vector with all elements set to the smallest value.
+  const MIN: Self;
+  /// A SIMD vector with all elements set to the largest value.
+  const MAX: Self;
+
+  /// See [`u32x4::reduce_max`].
+  fn reduce_max(self) -> Self::Elem;
+  /// See [`u32x4::reduce_min`].
+  fn reduce_min(self) -> Self::Elem;
+  /// See [`u32x4::reduce_and`].
+  fn reduce_and(self) -> Self::Elem;
+  /// See [`u32x4::reduce_or`].
+  fn reduce_or(self) -> Self::Elem;
+  /// See [`u32x4::reduce_xor`].
+  fn reduce_xor(self) -> Self::Elem;
+  /// See [`u32x4::cast_signed`].
+  fn cast_signed(self) -> Self::Signed;
+  /// See [`u32x4::unbounded_shl`].
+  fn unbounded_shl(self, rhs: Self) -> Self;
+  /// See [`u32x4::unbounded_shl_scalar`].
+  fn unbounded_shl_scalar(self, rhs: u32) -> Self;
+  /// See [`u32x4::unbounded_shr`].
+  fn unbounded_shr(self, rhs: Self) -> Self;
+  /// See [`u32x4::unbounded_shr_scalar`].
+  fn unbounded_shr_scalar(self, rhs: u32) -> Self;
+  /// See [`u32x4::saturating_add`].
+  fn saturating_add(self, rhs: Self) -> Self;
+  /// See [`u32x4::saturating_sub`].
+  fn saturating_sub(self, rhs: Self) -> Self;
+  /// See [`u32x4::saturating_mul`].
+  fn saturating_mul(self, rhs: Self) -> Self;
+  /// See [`u32x4::saturating_div`].
+  fn saturating_div(self, rhs: Self) -> Self;
+  /// See [`u32x4::overflowing_add`].
+  fn overflowing_add(self, rhs: Self) -> (Self, Self::Mask);
+  /// See [`u32x4::overflowing_sub`].
+  fn overflowing_sub(self, rhs: Self) -> (Self, Self::Mask);
+  /// See [`u32x4::overflowing_mul`].
+  fn overflowing_mul(self, rhs: Self) -> (Self, Self::Mask);
+  /// See [`u32x4::mul_keep_low_high`].
+  fn mul_keep_low_high(self, rhs: Self) -> (Self, Self);
+  /// See [`u32x4::mul_keep_high`].
+  fn mul_keep_high(self, rhs: Self) -> Self;
+  /// See [`u32x4::count_ones`].
+  fn count_ones(self) -> Self;
+  /// See [`u32x4::leading_zeros`].
+  fn leading_zeros(self) -> Self;
+  /// See [`u32x4::trailing_zeros`].
+  fn trailing_zeros(self) -> Self;
+  /// See [`u32x4::reverse_bits`].
+  fn reverse_bits(self) -> Self;
+  /// See [`u32x4::rotate_left`].
+  fn rotate_left(self, rhs: Self) -> Self;
+  /// See [`u32x4::rotate_left_scalar`].
+  fn rotate_left_scalar(self, rhs: u32) -> Self;
+  /// See [`u32x4::rotate_right`].
+  fn rotate_right(self, rhs: Self) -> Self;
+  /// See [`u32x4::rotate_right_scalar`].
+  fn rotate_right_scalar(self, rhs: u32) -> Self;
+}
+
+/// Forwards trait functions with the given signatures to the inherent
+/// functions of the same name.
+macro_rules! forward_to_inherent {
+  ($(fn $name:ident(self $(, $arg:ident: $Arg:ty)*) -> $Ret:ty;)*) => {
+    $(
+      #[inline]
+      fn $name(self $(, $arg: $Arg)*) -> $Ret {
+        Self::$name(self $(, $arg)*)
+      }
+    )*
+  };
+}
+
+/// Implements [`SimdVector`] by forwarding to the inherent functions.
+macro_rules! impl_simd_vector_trait {
+  (
+    T = $T:ident,
+    N = $N:literal,
+    Simd = $Simd:ident,
+    Mask = $Mask:ident,
+    Bits = $Bits:ident,
+  ) => {
+    impl SimdVector for $Simd {
+      type Elem = $T;
+      type Mask = $Mask;
+      type Bits = $Bits;
+
+      const LANES: usize = $N;
+
+      #[inline]
+      fn splat(elem: $T) -> Self {
+        Self::splat(elem)
+      }
+
+      #[inline]
+      fn from_slice(slice: &[$T]) -> Self {
+        Self::from(slice)
+      }
+
+      #[inline]
+      fn load_or(slice: &[$T], fill: $T) -> Self {
+        Self::load_or(slice, fill)
+      }
+
+      #[inline]
+      fn store_partial(self, slice: &mut [$T]) {
+        Self::store_partial(self, slice)
+      }
+
+      #[inline]
+      fn masked_load(slice: &[$T], mask: $Mask, fill: Self) -> Self {
+        Self::masked_load(slice, mask, fill)
+      }
+
+      #[inline]
+      fn masked_store(self, slice: &mut [$T], mask: $Mask) {
+        Self::masked_store(self, slice, mask)
+      }
+
+      #[inline]
+      fn rotate_lanes_left<const OFFSET: usize>(self) -> Self {
+        Self::rotate_lanes_left::<OFFSET>(self)
+      }
+
+      #[inline]
+      fn rotate_lanes_right<const OFFSET: usize>(self) -> Self {
+        Self::rotate_lanes_right::<OFFSET>(self)
+      }
+
+      #[inline]
+      fn broadcast_lane<const I: usize>(self) -> Self {
+        Self::broadcast_lane::<I>(self)
+      }
+
+      #[inline]
+      fn as_slice(&self) -> &[$T] {
+        self.as_array()
+      }
+
+      #[inline]
+      fn as_mut_slice(&mut self) -> &mut [$T] {
+        self.as_mut_array()
+      }
+
+      #[inline]
+      fn to_bits(self) -> $Bits {
+        cast(self)
+      }
+
+      #[inline]
+      fn from_bits(bits: $Bits) -> Self {
+        cast(bits)
+      }
+
+      forward_to_inherent! {
+        fn simd_eq(self, rhs: Self) -> $Mask;
+        fn simd_ne(self, rhs: Self) -> $Mask;
+        fn simd_lt(self, rhs: Self) -> $Mask;
+        fn simd_gt(self, rhs: Self) -> $Mask;
+        fn simd_le(self, rhs: Self) -> $Mask;
+        fn simd_ge(self, rhs: Self) -> $Mask;
+        fn bitselect(self, if_one: Self, if_zero: Self) -> Self;
+        fn reverse(self) -> Self;
+        fn interleave_low(self, other: Self) -> Self;
+        fn interleave_high(self, other: Self) -> Self;
+        fn max(self, rhs: Self) -> Self;
+        fn min(self, rhs: Self) -> Self;
+        fn clamp(self, min: Self, max: Self) -> Self;
+        fn reduce_add(self) -> $T;
+        fn reduce_mul(self) -> $T;
+        fn argmax(self) -> usize;
+        fn argmin(self) -> usize;
+        fn scan_add(self) -> Self;
+        fn scan_add_exclusive(self) -> Self;
+        fn scan_max(self) -> Self;
+        fn scan_min(self) -> Self;
+        fn scan_or(self) -> Self;
+        fn compress(self, mask: $Mask) -> (Self, usize);
+        fn compress_store(self, mask: $Mask, slice: &mut [$T]) -> usize;
+        fn expand(self, mask: $Mask, src: Self) -> Self;
+      }
+
+      #[inline]
+      fn prefix_sum(values: &mut [$T]) {
+        Self::prefix_sum(values)
+      }
+    }
+  };
+}
@@ -29,6 +29,7 @@ impl_simd! {
     T = u16,
     N = 16,
     Simd = u16x16,
+    Mask = m16x16,
     optional_type_x86_inner { X86Inner = __m256i },
     optional_type_arm_inner {},
     optional_type_wasm_inner {},
@@ -41,8 +42,8 @@ impl_simd! {
         Self { avx2: cmp_eq_mask_i16_m256i(self.avx2, rhs.avx2) }
       } else {
         Self {
-          a : self.a.simd_eq(rhs.a),
-          b : self.b.simd_eq(rhs.b),
+          a : self.a.simd_eq(rhs.a).into(),
+          b : self.b.simd_eq(rhs.b).into(),
         }
       }
     }
@@ -52,11 +53,11 @@ impl_simd! {
   fn simd_ne(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else {
         Self {
-          a : self.a.simd_ne(rhs.a),
-          b : self.b.simd_ne(rhs.b),
+          a : self.a.simd_ne(rhs.a).into(),
+          b : self.b.simd_ne(rhs.b).into(),
         }
       }
     }
@@ -65,7 +66,7 @@ impl_simd! {
   #[inline]
   fn simd_lt(self, rhs: Self) -> Self::Output {
     // no gt, so just reverse to get same answer
-    Self::simd_gt(rhs, self)
+    Self::from(rhs.simd_gt(self))
   }
 
   #[inline]
@@ -80,8 +81,8 @@ impl_simd! {
         Self { avx2: mask }
       } else {
         Self {
-          a: self.a.simd_gt(rhs.a),
-          b: self.b.simd_gt(rhs.b),
+          a: self.a.simd_gt(rhs.a).into(),
+          b: self.b.simd_gt(rhs.b).into(),
         }
       }
     }
@@ -91,11 +92,11 @@ impl_simd! {
   fn simd_le(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        !self.simd_gt(rhs)
+        Self::from(!self.simd_gt(rhs))
       } else {
         Self {
-          a : self.a.simd_le(rhs.a),
-          b : self.b.simd_le(rhs.b),
+          a : self.a.simd_le(rhs.a).into(),
+          b : self.b.simd_le(rhs.b).into(),
         }
       }
     }
@@ -105,11 +106,11 @@ impl_simd! {
   fn simd_ge(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        !self.simd_lt(rhs)
+        Self::from(!self.simd_lt(rhs))
       } else {
         Self {
-          a : self.a.simd_ge(rhs.a),
-          b : self.b.simd_ge(rhs.b),
+          a : self.a.simd_ge(rhs.a).into(),
+          b : self.b.simd_ge(rhs.b).into(),
         }
       }
     }
@@ -176,6 +177,7 @@ impl_simd_uint! {
     T = u16,
     N = 16,
     Simd = u16x16,
+    Mask = m16x16,
     SignedSimd = i16x16,
     T_BITS = 16,
     T_BITS_MUL_2 = 32,
@@ -508,7 +510,7 @@ impl_simd_uint! {
   }
 
   #[inline]
-  pub fn overflowing_mul(self, rhs: Self) -> (Self, Self) {
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m16x16) {
     let (low, high) = self.mul_keep_low_high(rhs);
     let overflow = high.simd_ne(Self::ZERO);
     (low, overflow)
@@ -550,6 +552,59 @@ impl_simd_uint! {
 
     cast([self_a.mul_keep_high(rhs_a), self_b.mul_keep_high(rhs_b)])
   }
+
+  #[inline]
+  pub fn count_ones(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512bitalg", target_feature="avx512vl")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm256_popcnt_epi16;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm256_popcnt_epi16;
+
+        Self { avx2: m256i(unsafe { _mm256_popcnt_epi16(self.avx2.0) }) }
+      } else if #[cfg(target_feature="avx2")] {
+        // Add the counts of both bytes.
+        let bytes: Self = cast(u8x32::count_ones(cast(self)));
+        (bytes & Self::splat(0xFF)) + (bytes >> 8)
+      } else {
+        Self {
+          a : self.a.count_ones(),
+          b : self.b.count_ones(),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  pub fn leading_zeros(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        // Only count the zeros of the low byte if the high byte is zero.
+        let bytes: Self = cast(u8x32::leading_zeros(cast(self)));
+        let high: Self = bytes >> 8;
+        let high_is_zero = Self::from(high.simd_eq(Self::splat(8)));
+        high + (bytes & Self::splat(0xFF) & high_is_zero)
+      } else {
+        Self {
+          a : self.a.leading_zeros(),
+          b : self.b.leading_zeros(),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  pub fn rotate_left(self, rhs: Self) -> Self {
+    let rhs = rhs & Self::splat(15);
+    self.unbounded_shl(rhs) | self.unbounded_shr(Self::splat(16) - rhs)
+  }
+
+  #[inline]
+  pub fn rotate_left_scalar(self, rhs: u32) -> Self {
+    let rhs = rhs % 16;
+    self.unbounded_shl_scalar(rhs) | self.unbounded_shr_scalar(16 - rhs)
+  }
 }
 
 impl From<u8x16> for u16x16 {
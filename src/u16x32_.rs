@@ -29,6 +29,7 @@ impl_simd! {
     T = u16,
     N = 32,
     Simd = u16x32,
+    Mask = m16x32,
     optional_type_x86_inner { X86Inner = __m512i },
     optional_type_arm_inner {},
     optional_type_wasm_inner {},
@@ -41,8 +42,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_u16_m512i::<{cmp_int_op!(Eq)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_eq(rhs.a),
-          b : self.b.simd_eq(rhs.b),
+          a : self.a.simd_eq(rhs.a).into(),
+          b : self.b.simd_eq(rhs.b).into(),
         }
       }
     }
@@ -55,8 +56,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_u16_m512i::<{cmp_int_op!(Ne)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_ne(rhs.a),
-          b : self.b.simd_ne(rhs.b),
+          a : self.a.simd_ne(rhs.a).into(),
+          b : self.b.simd_ne(rhs.b).into(),
         }
       }
     }
@@ -69,8 +70,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_u16_m512i::<{cmp_int_op!(Lt)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : rhs.a.simd_gt(self.a),
-          b : rhs.b.simd_gt(self.b),
+          a : rhs.a.simd_gt(self.a).into(),
+          b : rhs.b.simd_gt(self.b).into(),
         }
       }
     }
@@ -83,8 +84,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_u16_m512i::<{cmp_int_op!(Nle)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_gt(rhs.a),
-          b : self.b.simd_gt(rhs.b),
+          a : self.a.simd_gt(rhs.a).into(),
+          b : self.b.simd_gt(rhs.b).into(),
         }
       }
     }
@@ -97,8 +98,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_u16_m512i::<{cmp_int_op!(Le)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_le(rhs.a),
-          b : self.b.simd_le(rhs.b),
+          a : self.a.simd_le(rhs.a).into(),
+          b : self.b.simd_le(rhs.b).into(),
         }
       }
     }
@@ -111,8 +112,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_u16_m512i::<{cmp_int_op!(Nlt)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_ge(rhs.a),
-          b : self.b.simd_ge(rhs.b),
+          a : self.a.simd_ge(rhs.a).into(),
+          b : self.b.simd_ge(rhs.b).into(),
         }
       }
     }
@@ -179,6 +180,7 @@ impl_simd_uint! {
     T = u16,
     N = 32,
     Simd = u16x32,
+    Mask = m16x32,
     SignedSimd = i16x32,
     T_BITS = 16,
     T_BITS_MUL_2 = 32,
@@ -490,7 +492,7 @@ impl_simd_uint! {
   }
 
   #[inline]
-  pub fn overflowing_mul(self, rhs: Self) -> (Self, Self) {
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m16x32) {
     let (low, high) = self.mul_keep_low_high(rhs);
     let overflow = high.simd_ne(Self::ZERO);
     (low, overflow)
@@ -523,4 +525,39 @@ impl_simd_uint! {
 
     cast([self_a.mul_keep_high(rhs_a), self_b.mul_keep_high(rhs_b)])
   }
+
+  #[inline]
+  pub fn count_ones(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512bitalg")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm512_popcnt_epi16;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm512_popcnt_epi16;
+
+        Self { avx512: m512i(unsafe { _mm512_popcnt_epi16(self.avx512.0) }) }
+      } else {
+        let [a, b]: [u16x16; 2] = cast(self);
+        cast([a.count_ones(), b.count_ones()])
+      }
+    }
+  }
+
+  #[inline]
+  pub fn leading_zeros(self) -> Self {
+    let [a, b]: [u16x16; 2] = cast(self);
+    cast([a.leading_zeros(), b.leading_zeros()])
+  }
+
+  #[inline]
+  pub fn rotate_left(self, rhs: Self) -> Self {
+    let rhs = rhs & Self::splat(15);
+    self.unbounded_shl(rhs) | self.unbounded_shr(Self::splat(16) - rhs)
+  }
+
+  #[inline]
+  pub fn rotate_left_scalar(self, rhs: u32) -> Self {
+    let rhs = rhs % 16;
+    self.unbounded_shl_scalar(rhs) | self.unbounded_shr_scalar(16 - rhs)
+  }
 }
@@ -83,6 +83,7 @@ impl_simd! {
     T = u16,
     N = 8,
     Simd = u16x8,
+    Mask = m16x8,
     optional_type_x86_inner { X86Inner = __m128i },
     optional_type_arm_inner { ArmInner = uint16x8_t },
     optional_type_wasm_inner { WasmInner = v128 },
@@ -116,11 +117,11 @@ impl_simd! {
   fn simd_ne(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: u16x8_ne(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else {
         Self { arr: [
           if self.arr[0] != rhs.arr[0] { u16::MAX } else { 0 },
@@ -139,7 +140,7 @@ impl_simd! {
   #[inline]
   fn simd_lt(self, rhs: Self) -> Self::Output {
     // no lt, so reverse gt
-    Self::simd_gt(rhs, self)
+    Self::from(rhs.simd_gt(self))
   }
 
   #[inline]
@@ -185,11 +186,11 @@ impl_simd! {
   fn simd_le(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
-        !self.simd_gt(rhs)
+        Self::from(!self.simd_gt(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: u16x8_le(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_gt(rhs)
+        Self::from(!self.simd_gt(rhs))
       } else {
         Self { arr: [
           if self.arr[0] <= rhs.arr[0] { u16::MAX } else { 0 },
@@ -209,11 +210,11 @@ impl_simd! {
   fn simd_ge(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
-        !self.simd_lt(rhs)
+        Self::from(!self.simd_lt(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: u16x8_ge(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_lt(rhs)
+        Self::from(!self.simd_lt(rhs))
       } else {
         Self { arr: [
           if self.arr[0] >= rhs.arr[0] { u16::MAX } else { 0 },
@@ -292,6 +293,7 @@ impl_simd_uint! {
     T = u16,
     N = 8,
     Simd = u16x8,
+    Mask = m16x8,
     SignedSimd = i16x8,
     T_BITS = 16,
     T_BITS_MUL_2 = 32,
@@ -779,7 +781,7 @@ impl_simd_uint! {
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))] {
         unsafe {
           // The intrinsic has different semantics so we need to mask ourselves.
-          Self { neon: vshlq_u16(self.neon, vreinterpretq_s16_u16(rhs.neon)) } & rhs.simd_lt(16)
+          Self { neon: vshlq_u16(self.neon, vreinterpretq_s16_u16(rhs.neon)) } & Self::from(rhs.simd_lt(16))
         }
       } else {
         let self_array = self.to_array();
@@ -840,7 +842,7 @@ impl_simd_uint! {
         unsafe {
           // Negate `rhs` because there is no direct shift-right intrinsic, and
           // mask to hide `rhs` overflow.
-          Self { neon: vshlq_u16(self.neon, vnegq_s16(vreinterpretq_s16_u16(rhs.neon))) } & rhs.simd_lt(16)
+          Self { neon: vshlq_u16(self.neon, vnegq_s16(vreinterpretq_s16_u16(rhs.neon))) } & Self::from(rhs.simd_lt(16))
         }
       } else {
         let self_array = self.to_array();
@@ -939,7 +941,7 @@ impl_simd_uint! {
   }
 
   #[inline]
-  pub fn overflowing_mul(self, rhs: Self) -> (Self, Self) {
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m16x8) {
     let (low, high) = self.mul_keep_low_high(rhs);
     let overflow = high.simd_ne(Self::ZERO);
     (low, overflow)
@@ -1092,6 +1094,56 @@ impl_simd_uint! {
       }
     }
   }
+
+  #[inline]
+  pub fn count_ones(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512bitalg", target_feature="avx512vl")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm_popcnt_epi16;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm_popcnt_epi16;
+
+        Self { sse: m128i(unsafe { _mm_popcnt_epi16(self.sse.0) }) }
+      } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
+        unsafe {
+          let bytes = vcntq_u8(vreinterpretq_u8_u16(self.neon));
+          Self { neon: vpaddlq_u8(bytes) }
+        }
+      } else {
+        // Add the counts of both bytes.
+        let bytes: Self = cast(u8x16::count_ones(cast(self)));
+        (bytes & Self::splat(0xFF)) + (bytes >> 8)
+      }
+    }
+  }
+
+  #[inline]
+  pub fn leading_zeros(self) -> Self {
+    pick! {
+      if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
+        unsafe { Self { neon: vclzq_u16(self.neon) } }
+      } else {
+        // Only count the zeros of the low byte if the high byte is zero.
+        let bytes: Self = cast(u8x16::leading_zeros(cast(self)));
+        let high: Self = bytes >> 8;
+        let high_is_zero = Self::from(high.simd_eq(Self::splat(8)));
+        high + (bytes & Self::splat(0xFF) & high_is_zero)
+      }
+    }
+  }
+
+  #[inline]
+  pub fn rotate_left(self, rhs: Self) -> Self {
+    let rhs = rhs & Self::splat(15);
+    self.unbounded_shl(rhs) | self.unbounded_shr(Self::splat(16) - rhs)
+  }
+
+  #[inline]
+  pub fn rotate_left_scalar(self, rhs: u32) -> Self {
+    let rhs = rhs % 16;
+    self.unbounded_shl_scalar(rhs) | self.unbounded_shr_scalar(16 - rhs)
+  }
 }
 
 /// The following functionality exists only for [`u16x8`], or only for
@@ -29,6 +29,7 @@ impl_simd! {
     T = u32,
     N = 16,
     Simd = u32x16,
+    Mask = m32x16,
     optional_type_x86_inner { X86Inner = __m512i },
     optional_type_arm_inner {},
     optional_type_wasm_inner {},
@@ -41,8 +42,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_u32_m512i::<{cmp_int_op!(Eq)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_eq(rhs.a),
-          b : self.b.simd_eq(rhs.b),
+          a : self.a.simd_eq(rhs.a).into(),
+          b : self.b.simd_eq(rhs.b).into(),
         }
       }
     }
@@ -55,8 +56,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_u32_m512i::<{cmp_int_op!(Ne)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_ne(rhs.a),
-          b : self.b.simd_ne(rhs.b),
+          a : self.a.simd_ne(rhs.a).into(),
+          b : self.b.simd_ne(rhs.b).into(),
         }
       }
     }
@@ -69,8 +70,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_u32_m512i::<{cmp_int_op!(Lt)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : rhs.a.simd_gt(self.a),
-          b : rhs.b.simd_gt(self.b),
+          a : rhs.a.simd_gt(self.a).into(),
+          b : rhs.b.simd_gt(self.b).into(),
         }
       }
     }
@@ -83,8 +84,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_u32_m512i::<{cmp_int_op!(Nle)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_gt(rhs.a),
-          b : self.b.simd_gt(rhs.b),
+          a : self.a.simd_gt(rhs.a).into(),
+          b : self.b.simd_gt(rhs.b).into(),
         }
       }
     }
@@ -97,8 +98,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_u32_m512i::<{cmp_int_op!(Le)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_le(rhs.a),
-          b : self.b.simd_le(rhs.b),
+          a : self.a.simd_le(rhs.a).into(),
+          b : self.b.simd_le(rhs.b).into(),
         }
       }
     }
@@ -111,8 +112,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_u32_m512i::<{cmp_int_op!(Nlt)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_ge(rhs.a),
-          b : self.b.simd_ge(rhs.b),
+          a : self.a.simd_ge(rhs.a).into(),
+          b : self.b.simd_ge(rhs.b).into(),
         }
       }
     }
@@ -179,6 +180,7 @@ impl_simd_uint! {
     T = u32,
     N = 16,
     Simd = u32x16,
+    Mask = m32x16,
     SignedSimd = i32x16,
     T_BITS = 32,
     T_BITS_MUL_2 = 64,
@@ -462,7 +464,7 @@ impl_simd_uint! {
         let result = self + rhs;
         let overflow = result.simd_lt(self);
         // Return `MAX` (all bits set) if overflow occurs.
-        result | overflow
+        result | Self::from(overflow)
       } else {
         Self {
           a: self.a.saturating_add(rhs.a),
@@ -479,7 +481,7 @@ impl_simd_uint! {
         let result = self - rhs;
         let no_overflow = result.simd_le(self);
         // Return `0` (no bits set) if overflow occurs.
-        result & no_overflow
+        result & Self::from(no_overflow)
       } else {
         Self {
           a: self.a.saturating_sub(rhs.a),
@@ -490,7 +492,7 @@ impl_simd_uint! {
   }
 
   #[inline]
-  pub fn overflowing_mul(self, rhs: Self) -> (Self, Self) {
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m32x16) {
     let (low, high) = self.mul_keep_low_high(rhs);
     let overflow = high.simd_ne(Self::ZERO);
     (low, overflow)
@@ -573,6 +575,70 @@ impl_simd_uint! {
       }
     }
   }
+
+  #[inline]
+  pub fn count_ones(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512vpopcntdq")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm512_popcnt_epi32;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm512_popcnt_epi32;
+
+        Self { avx512: m512i(unsafe { _mm512_popcnt_epi32(self.avx512.0) }) }
+      } else {
+        let [a, b]: [u32x8; 2] = cast(self);
+        cast([a.count_ones(), b.count_ones()])
+      }
+    }
+  }
+
+  #[inline]
+  pub fn leading_zeros(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512cd")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm512_lzcnt_epi32;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm512_lzcnt_epi32;
+
+        Self { avx512: m512i(unsafe { _mm512_lzcnt_epi32(self.avx512.0) }) }
+      } else {
+        let [a, b]: [u32x8; 2] = cast(self);
+        cast([a.leading_zeros(), b.leading_zeros()])
+      }
+    }
+  }
+
+  #[inline]
+  pub fn rotate_left(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512f")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm512_rolv_epi32;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm512_rolv_epi32;
+
+        let rotated = unsafe { _mm512_rolv_epi32(self.avx512.0, rhs.avx512.0) };
+        Self { avx512: m512i(rotated) }
+      } else {
+        let rhs = rhs & Self::splat(31);
+        self.unbounded_shl(rhs) | self.unbounded_shr(Self::splat(32) - rhs)
+      }
+    }
+  }
+
+  #[inline]
+  pub fn rotate_left_scalar(self, rhs: u32) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512f")] {
+        self.rotate_left(Self::splat(rhs))
+      } else {
+        let rhs = rhs % 32;
+        self.unbounded_shl_scalar(rhs) | self.unbounded_shr_scalar(32 - rhs)
+      }
+    }
+  }
 }
 
 impl From<u16x16> for u32x16 {
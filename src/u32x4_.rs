@@ -83,6 +83,7 @@ impl_simd! {
     T = u32,
     N = 4,
     Simd = u32x4,
+    Mask = m32x4,
     optional_type_x86_inner { X86Inner = __m128i },
     optional_type_arm_inner { ArmInner = uint32x4_t },
     optional_type_wasm_inner { WasmInner = v128 },
@@ -112,11 +113,11 @@ impl_simd! {
   fn simd_ne(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: u32x4_ne(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else {
         Self { arr: [
           if self.arr[0] != rhs.arr[0] { u32::MAX } else { 0 },
@@ -131,7 +132,7 @@ impl_simd! {
   #[inline]
   fn simd_lt(self, rhs: Self) -> Self::Output {
     // lt is just gt the other way around
-    rhs.simd_gt(self)
+    Self::from(rhs.simd_gt(self))
   }
 
   #[inline]
@@ -160,11 +161,11 @@ impl_simd! {
   fn simd_le(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
-        !self.simd_gt(rhs)
+        Self::from(!self.simd_gt(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: u32x4_le(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_gt(rhs)
+        Self::from(!self.simd_gt(rhs))
       } else {
         Self { arr: [
           if self.arr[0] <= rhs.arr[0] { u32::MAX } else { 0 },
@@ -180,11 +181,11 @@ impl_simd! {
   fn simd_ge(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
-        !self.simd_lt(rhs)
+        Self::from(!self.simd_lt(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: u32x4_ge(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_lt(rhs)
+        Self::from(!self.simd_lt(rhs))
       } else {
         Self { arr: [
           if self.arr[0] >= rhs.arr[0] { u32::MAX } else { 0 },
@@ -277,6 +278,7 @@ impl_simd_uint! {
     T = u32,
     N = 4,
     Simd = u32x4,
+    Mask = m32x4,
     SignedSimd = i32x4,
     T_BITS = 32,
     T_BITS_MUL_2 = 64,
@@ -626,7 +628,7 @@ impl_simd_uint! {
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
         unsafe {
           // The intrinsic has different semantics so we need to mask ourselves.
-          Self { neon: vshlq_u32(self.neon, vreinterpretq_s32_u32(rhs.neon)) } & rhs.simd_lt(32)
+          Self { neon: vshlq_u32(self.neon, vreinterpretq_s32_u32(rhs.neon)) } & Self::from(rhs.simd_lt(32))
         }
       } else {
         let self_array = self.to_array();
@@ -649,7 +651,7 @@ impl_simd_uint! {
         Self { sse: shl_all_u32_m128i(self.sse, cast([rhs as u64, 0])) }
       } else if #[cfg(target_feature="simd128")] {
         // The intrinsic performs wrapping shift so we need to mask the result.
-        Self { simd: u32x4_shl(self.simd, rhs) } & Self::splat(rhs).simd_lt(32)
+        Self { simd: u32x4_shl(self.simd, rhs) } & Self::from(Self::splat(rhs).simd_lt(32))
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
         // The intrinsic has different semantics so we need to saturate `rhs`.
         unsafe { Self { neon: vshlq_u32(self.neon, vmovq_n_s32(rhs.min(32) as i32)) } }
@@ -673,7 +675,7 @@ impl_simd_uint! {
         unsafe {
           // Negate `rhs` because there is no direct shift-right intrinsic, and
           // mask to hide `rhs` overflow.
-          Self { neon: vshlq_u32(self.neon, vnegq_s32(vreinterpretq_s32_u32(rhs.neon))) } & rhs.simd_lt(32)
+          Self { neon: vshlq_u32(self.neon, vnegq_s32(vreinterpretq_s32_u32(rhs.neon))) } & Self::from(rhs.simd_lt(32))
         }
       } else {
         let self_array = self.to_array();
@@ -722,7 +724,7 @@ impl_simd_uint! {
         let result = self + rhs;
         let overflow = result.simd_lt(self);
         // Return `MAX` (all bits set) if overflow occurs.
-        result | overflow
+        result | Self::from(overflow)
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
         unsafe { Self { neon: vqaddq_u32(self.neon, rhs.neon) } }
       } else {
@@ -745,7 +747,7 @@ impl_simd_uint! {
         let result = self - rhs;
         let no_overflow = result.simd_le(self);
         // Return `0` (no bits set) if overflow occurs.
-        result & no_overflow
+        result & Self::from(no_overflow)
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
         unsafe { Self { neon: vqsubq_u32(self.neon, rhs.neon) } }
       } else {
@@ -762,7 +764,7 @@ impl_simd_uint! {
   }
 
   #[inline]
-  pub fn overflowing_mul(self, rhs: Self) -> (Self, Self) {
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m32x4) {
     let (low, high) = self.mul_keep_low_high(rhs);
     let overflow = high.simd_ne(Self::ZERO);
     (low, overflow)
@@ -927,6 +929,81 @@ impl_simd_uint! {
       }
     }
   }
+
+  #[inline]
+  pub fn count_ones(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512vpopcntdq", target_feature="avx512vl")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm_popcnt_epi32;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm_popcnt_epi32;
+
+        Self { sse: m128i(unsafe { _mm_popcnt_epi32(self.sse.0) }) }
+      } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
+        unsafe {
+          let bytes = vcntq_u8(vreinterpretq_u8_u32(self.neon));
+          Self { neon: vpaddlq_u16(vpaddlq_u8(bytes)) }
+        }
+      } else {
+        // Add the counts of both halves.
+        let halves: Self = cast(u16x8::count_ones(cast(self)));
+        (halves & Self::splat(0xFFFF)) + (halves >> 16)
+      }
+    }
+  }
+
+  #[inline]
+  pub fn leading_zeros(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512cd", target_feature="avx512vl")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm_lzcnt_epi32;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm_lzcnt_epi32;
+
+        Self { sse: m128i(unsafe { _mm_lzcnt_epi32(self.sse.0) }) }
+      } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
+        unsafe { Self { neon: vclzq_u32(self.neon) } }
+      } else {
+        // Only count the zeros of the low half if the high half is zero.
+        let halves: Self = cast(u16x8::leading_zeros(cast(self)));
+        let high: Self = halves >> 16;
+        let high_is_zero = Self::from(high.simd_eq(Self::splat(16)));
+        high + (halves & Self::splat(0xFFFF) & high_is_zero)
+      }
+    }
+  }
+
+  #[inline]
+  pub fn rotate_left(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512f", target_feature="avx512vl")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm_rolv_epi32;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm_rolv_epi32;
+
+        let rotated = unsafe { _mm_rolv_epi32(self.sse.0, rhs.sse.0) };
+        Self { sse: m128i(rotated) }
+      } else {
+        let rhs = rhs & Self::splat(31);
+        self.unbounded_shl(rhs) | self.unbounded_shr(Self::splat(32) - rhs)
+      }
+    }
+  }
+
+  #[inline]
+  pub fn rotate_left_scalar(self, rhs: u32) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512f", target_feature="avx512vl")] {
+        self.rotate_left(Self::splat(rhs))
+      } else {
+        let rhs = rhs % 32;
+        self.unbounded_shl_scalar(rhs) | self.unbounded_shr_scalar(32 - rhs)
+      }
+    }
+  }
 }
 
 /// The following functionality exists only for [`u32x4`], or only for
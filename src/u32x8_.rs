@@ -29,6 +29,7 @@ impl_simd! {
     T = u32,
     N = 8,
     Simd = u32x8,
+    Mask = m32x8,
     optional_type_x86_inner { X86Inner = __m256i },
     optional_type_arm_inner {},
     optional_type_wasm_inner {},
@@ -41,8 +42,8 @@ impl_simd! {
         Self { avx2: cmp_eq_mask_i32_m256i(self.avx2, rhs.avx2 ) }
       } else {
         Self {
-          a : self.a.simd_eq(rhs.a),
-          b : self.b.simd_eq(rhs.b),
+          a : self.a.simd_eq(rhs.a).into(),
+          b : self.b.simd_eq(rhs.b).into(),
         }
       }
     }
@@ -50,13 +51,13 @@ impl_simd! {
 
   #[inline]
   fn simd_ne(self, rhs: Self) -> Self::Output {
-    !self.simd_eq(rhs)
+    Self::from(!self.simd_eq(rhs))
   }
 
   #[inline]
   fn simd_lt(self, rhs: Self) -> Self::Output {
     // lt is just gt the other way around
-    rhs.simd_gt(self)
+    Self::from(rhs.simd_gt(self))
   }
 
   #[inline]
@@ -68,8 +69,8 @@ impl_simd! {
         Self { avx2: cmp_gt_mask_i32_m256i((self ^ highbit).avx2, (rhs ^ highbit).avx2 ) }
       } else {
         Self {
-          a : self.a.simd_gt(rhs.a),
-          b : self.b.simd_gt(rhs.b),
+          a : self.a.simd_gt(rhs.a).into(),
+          b : self.b.simd_gt(rhs.b).into(),
         }
       }
     }
@@ -77,12 +78,12 @@ impl_simd! {
 
   #[inline]
   fn simd_le(self, rhs: Self) -> Self::Output {
-    self.simd_eq(rhs) | self.simd_lt(rhs)
+    Self::from(self.simd_eq(rhs) | self.simd_lt(rhs))
   }
 
   #[inline]
   fn simd_ge(self, rhs: Self) -> Self::Output {
-    self.simd_eq(rhs) | self.simd_gt(rhs)
+    Self::from(self.simd_eq(rhs) | self.simd_gt(rhs))
   }
 
   #[inline]
@@ -158,6 +159,7 @@ impl_simd_uint! {
     T = u32,
     N = 8,
     Simd = u32x8,
+    Mask = m32x8,
     SignedSimd = i32x8,
     T_BITS = 32,
     T_BITS_MUL_2 = 64,
@@ -443,7 +445,7 @@ impl_simd_uint! {
         let result = self + rhs;
         let overflow = result.simd_lt(self);
         // Return `MAX` (all bits set) if overflow occurs.
-        result | overflow
+        result | Self::from(overflow)
       } else {
         Self {
           a: self.a.saturating_add(rhs.a),
@@ -460,7 +462,7 @@ impl_simd_uint! {
         let result = self - rhs;
         let no_overflow = result.simd_le(self);
         // Return `0` (no bits set) if overflow occurs.
-        result & no_overflow
+        result & Self::from(no_overflow)
       } else {
         Self {
           a: self.a.saturating_sub(rhs.a),
@@ -471,7 +473,7 @@ impl_simd_uint! {
   }
 
   #[inline]
-  pub fn overflowing_mul(self, rhs: Self) -> (Self, Self) {
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m32x8) {
     let (low, high) = self.mul_keep_low_high(rhs);
     let overflow = high.simd_ne(Self::ZERO);
     (low, overflow)
@@ -552,6 +554,84 @@ impl_simd_uint! {
       }
     }
   }
+
+  #[inline]
+  pub fn count_ones(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512vpopcntdq", target_feature="avx512vl")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm256_popcnt_epi32;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm256_popcnt_epi32;
+
+        Self { avx2: m256i(unsafe { _mm256_popcnt_epi32(self.avx2.0) }) }
+      } else if #[cfg(target_feature="avx2")] {
+        // Add the counts of both halves.
+        let halves: Self = cast(u16x16::count_ones(cast(self)));
+        (halves & Self::splat(0xFFFF)) + (halves >> 16)
+      } else {
+        Self {
+          a : self.a.count_ones(),
+          b : self.b.count_ones(),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  pub fn leading_zeros(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512cd", target_feature="avx512vl")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm256_lzcnt_epi32;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm256_lzcnt_epi32;
+
+        Self { avx2: m256i(unsafe { _mm256_lzcnt_epi32(self.avx2.0) }) }
+      } else if #[cfg(target_feature="avx2")] {
+        // Only count the zeros of the low half if the high half is zero.
+        let halves: Self = cast(u16x16::leading_zeros(cast(self)));
+        let high: Self = halves >> 16;
+        let high_is_zero = Self::from(high.simd_eq(Self::splat(16)));
+        high + (halves & Self::splat(0xFFFF) & high_is_zero)
+      } else {
+        Self {
+          a : self.a.leading_zeros(),
+          b : self.b.leading_zeros(),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  pub fn rotate_left(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512f", target_feature="avx512vl")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm256_rolv_epi32;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm256_rolv_epi32;
+
+        let rotated = unsafe { _mm256_rolv_epi32(self.avx2.0, rhs.avx2.0) };
+        Self { avx2: m256i(rotated) }
+      } else {
+        let rhs = rhs & Self::splat(31);
+        self.unbounded_shl(rhs) | self.unbounded_shr(Self::splat(32) - rhs)
+      }
+    }
+  }
+
+  #[inline]
+  pub fn rotate_left_scalar(self, rhs: u32) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512f", target_feature="avx512vl")] {
+        self.rotate_left(Self::splat(rhs))
+      } else {
+        let rhs = rhs % 32;
+        self.unbounded_shl_scalar(rhs) | self.unbounded_shr_scalar(32 - rhs)
+      }
+    }
+  }
 }
 
 impl From<u16x8> for u32x8 {
@@ -86,6 +86,7 @@ impl_simd! {
     T = u64,
     N = 2,
     Simd = u64x2,
+    Mask = m64x2,
     optional_type_x86_inner { X86Inner = __m128i },
     optional_type_arm_inner { ArmInner = uint64x2_t },
     optional_type_wasm_inner { WasmInner = v128 },
@@ -115,11 +116,11 @@ impl_simd! {
   fn simd_ne(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse4.1")] {
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: u64x2_ne(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else {
         let s: [u64;2] = cast(self);
         let r: [u64;2] = cast(rhs);
@@ -134,7 +135,7 @@ impl_simd! {
   #[inline]
   fn simd_lt(self, rhs: Self) -> Self::Output {
     // lt is just gt the other way around
-    rhs.simd_gt(self)
+    Self::from(rhs.simd_gt(self))
   }
 
   #[inline]
@@ -162,9 +163,9 @@ impl_simd! {
   fn simd_le(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse4.1")] {
-        !self.simd_gt(rhs)
+        Self::from(!self.simd_gt(rhs))
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_gt(rhs)
+        Self::from(!self.simd_gt(rhs))
       } else {
         let s: [u64;2] = cast(self);
         let r: [u64;2] = cast(rhs);
@@ -180,9 +181,9 @@ impl_simd! {
   fn simd_ge(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse4.1")] {
-        !self.simd_lt(rhs)
+        Self::from(!self.simd_lt(rhs))
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_lt(rhs)
+        Self::from(!self.simd_lt(rhs))
       } else {
         let s: [u64;2] = cast(self);
         let r: [u64;2] = cast(rhs);
@@ -257,6 +258,7 @@ impl_simd_uint! {
     T = u64,
     N = 2,
     Simd = u64x2,
+    Mask = m64x2,
     SignedSimd = i64x2,
     T_BITS = 64,
     T_BITS_MUL_2 = 128,
@@ -544,12 +546,12 @@ impl_simd_uint! {
       } else if #[cfg(all(target_feature="neon", target_arch="aarch64"))] {
         unsafe {
           // The intrinsic has different semantics so we need to mask ourselves.
-          Self { neon: vshlq_u64(self.neon, vreinterpretq_s64_u64(rhs.neon)) } & rhs.simd_lt(64)
+          Self { neon: vshlq_u64(self.neon, vreinterpretq_s64_u64(rhs.neon)) } & Self::from(rhs.simd_lt(64))
         }
       } else {
         // Cannot use scalar `unbounded_shl` because it takes `u32`, which is
         // smaller than `u64`.
-        (self << rhs) & rhs.simd_lt(64)
+        (self << rhs) & Self::from(rhs.simd_lt(64))
       }
     }
   }
@@ -561,7 +563,7 @@ impl_simd_uint! {
         Self { sse: shl_all_u64_m128i(self.sse, cast([rhs as u64, 0])) }
       } else if #[cfg(target_feature="simd128")] {
         // The intrinsic performs wrapping shift so we need to mask the result.
-        Self { simd: u64x2_shl(self.simd, rhs) } & Self::splat(rhs as u64).simd_lt(64)
+        Self { simd: u64x2_shl(self.simd, rhs) } & Self::from(Self::splat(rhs as u64).simd_lt(64))
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
         unsafe { Self { neon: vshlq_u64(self.neon, vmovq_n_s64(rhs.min(64) as i64)) } }
       } else {
@@ -582,12 +584,12 @@ impl_simd_uint! {
         unsafe {
           // Negate `rhs` because there is no direct shift-right intrinsic, and
           // mask to hide `rhs` overflow.
-          Self { neon: vshlq_u64(self.neon, vnegq_s64(vreinterpretq_s64_u64(rhs.neon))) } & rhs.simd_lt(64)
+          Self { neon: vshlq_u64(self.neon, vnegq_s64(vreinterpretq_s64_u64(rhs.neon))) } & Self::from(rhs.simd_lt(64))
         }
       } else {
         // Cannot use scalar `unbounded_shr` because it takes `u32`, which is
         // smaller than `u64`.
-        (self >> rhs) & rhs.simd_lt(64)
+        (self >> rhs) & Self::from(rhs.simd_lt(64))
       }
     }
   }
@@ -623,7 +625,7 @@ impl_simd_uint! {
         let result = self + rhs;
         let overflow = result.simd_lt(self);
         // Return `MAX` (all bits set) if overflow occurs.
-        result | overflow
+        result | Self::from(overflow)
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
         unsafe { Self { neon: vqaddq_u64(self.neon, rhs.neon) } }
       } else {
@@ -644,7 +646,7 @@ impl_simd_uint! {
         let result = self - rhs;
         let no_overflow = result.simd_le(self);
         // Return `0` (no bits set) if overflow occurs.
-        result & no_overflow
+        result & Self::from(no_overflow)
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
         unsafe { Self { neon: vqsubq_u64(self.neon, rhs.neon) } }
       } else {
@@ -659,7 +661,7 @@ impl_simd_uint! {
   }
 
   #[inline]
-  pub fn overflowing_mul(self, rhs: Self) -> (Self, Self) {
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m64x2) {
     // TODO(perf): This implementation looks quite bad. Is there a better
     // one? This intentionally avoids `mul_keep_low_high` because getting the
     // high bits of 64-bit multiplication could be slow.
@@ -673,7 +675,7 @@ impl_simd_uint! {
     ];
     (
       Self::new([result[0].0, result[1].0]),
-      Self::new([-(result[0].1 as i64) as u64, -(result[1].1 as i64) as u64]),
+      m64x2::new([result[0].1, result[1].1]),
     )
   }
 
@@ -715,4 +717,81 @@ impl_simd_uint! {
       ((arr1[1] as u128 * arr2[1] as u128) >> 64) as u64,
     ])
   }
+
+  #[inline]
+  pub fn count_ones(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512vpopcntdq", target_feature="avx512vl")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm_popcnt_epi64;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm_popcnt_epi64;
+
+        Self { sse: m128i(unsafe { _mm_popcnt_epi64(self.sse.0) }) }
+      } else if #[cfg(target_feature="sse2")] {
+        // `psadbw` adds up the counts of the 8 bytes of each element.
+        let bytes = u8x16::count_ones(cast(self));
+        Self { sse: sum_of_u8_abs_diff_m128i(bytes.sse, m128i::default()) }
+      } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
+        unsafe {
+          let bytes = vcntq_u8(vreinterpretq_u8_u64(self.neon));
+          Self { neon: vpaddlq_u32(vpaddlq_u16(vpaddlq_u8(bytes))) }
+        }
+      } else {
+        // Add the counts of both halves.
+        let halves: Self = cast(u32x4::count_ones(cast(self)));
+        (halves & Self::splat(0xFFFF_FFFF)) + (halves >> 32)
+      }
+    }
+  }
+
+  #[inline]
+  pub fn leading_zeros(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512cd", target_feature="avx512vl")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm_lzcnt_epi64;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm_lzcnt_epi64;
+
+        Self { sse: m128i(unsafe { _mm_lzcnt_epi64(self.sse.0) }) }
+      } else {
+        // Only count the zeros of the low half if the high half is zero.
+        let halves: Self = cast(u32x4::leading_zeros(cast(self)));
+        let high: Self = halves >> 32;
+        let high_is_zero = Self::from(high.simd_eq(Self::splat(32)));
+        high + (halves & Self::splat(0xFFFF_FFFF) & high_is_zero)
+      }
+    }
+  }
+
+  #[inline]
+  pub fn rotate_left(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512f", target_feature="avx512vl")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm_rolv_epi64;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm_rolv_epi64;
+
+        let rotated = unsafe { _mm_rolv_epi64(self.sse.0, rhs.sse.0) };
+        Self { sse: m128i(rotated) }
+      } else {
+        let rhs = rhs & Self::splat(63);
+        self.unbounded_shl(rhs) | self.unbounded_shr(Self::splat(64) - rhs)
+      }
+    }
+  }
+
+  #[inline]
+  pub fn rotate_left_scalar(self, rhs: u32) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512f", target_feature="avx512vl")] {
+        self.rotate_left(Self::splat(rhs as u64))
+      } else {
+        let rhs = rhs % 64;
+        self.unbounded_shl_scalar(rhs) | self.unbounded_shr_scalar(64 - rhs)
+      }
+    }
+  }
 }
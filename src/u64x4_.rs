@@ -29,6 +29,7 @@ impl_simd! {
     T = u64,
     N = 4,
     Simd = u64x4,
+    Mask = m64x4,
     optional_type_x86_inner { X86Inner = __m256i },
     optional_type_arm_inner {},
     optional_type_wasm_inner {},
@@ -41,8 +42,8 @@ impl_simd! {
         Self { avx2: cmp_eq_mask_i64_m256i(self.avx2, rhs.avx2) }
       } else {
         Self {
-          a : self.a.simd_eq(rhs.a),
-          b : self.b.simd_eq(rhs.b),
+          a : self.a.simd_eq(rhs.a).into(),
+          b : self.b.simd_eq(rhs.b).into(),
         }
       }
     }
@@ -52,11 +53,11 @@ impl_simd! {
   fn simd_ne(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else {
         Self {
-          a : self.a.simd_ne(rhs.a),
-          b : self.b.simd_ne(rhs.b),
+          a : self.a.simd_ne(rhs.a).into(),
+          b : self.b.simd_ne(rhs.b).into(),
         }
       }
     }
@@ -65,7 +66,7 @@ impl_simd! {
   #[inline]
   fn simd_lt(self, rhs: Self) -> Self::Output {
     // lt is just gt the other way around
-    rhs.simd_gt(self)
+    Self::from(rhs.simd_gt(self))
   }
 
   #[inline]
@@ -77,8 +78,8 @@ impl_simd! {
         Self { avx2: cmp_gt_mask_i64_m256i((self ^ highbit).avx2, (rhs ^ highbit).avx2) }
       } else {
         Self {
-          a : self.a.simd_gt(rhs.a),
-          b : self.b.simd_gt(rhs.b),
+          a : self.a.simd_gt(rhs.a).into(),
+          b : self.b.simd_gt(rhs.b).into(),
         }
       }
     }
@@ -88,11 +89,11 @@ impl_simd! {
   fn simd_le(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        !self.simd_gt(rhs)
+        Self::from(!self.simd_gt(rhs))
       } else {
         Self {
-          a : self.a.simd_le(rhs.a),
-          b : self.b.simd_le(rhs.b),
+          a : self.a.simd_le(rhs.a).into(),
+          b : self.b.simd_le(rhs.b).into(),
         }
       }
     }
@@ -102,11 +103,11 @@ impl_simd! {
   fn simd_ge(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        !self.simd_lt(rhs)
+        Self::from(!self.simd_lt(rhs))
       } else {
         Self {
-          a : self.a.simd_ge(rhs.a),
-          b : self.b.simd_ge(rhs.b),
+          a : self.a.simd_ge(rhs.a).into(),
+          b : self.b.simd_ge(rhs.b).into(),
         }
       }
     }
@@ -173,6 +174,7 @@ impl_simd_uint! {
     T = u64,
     N = 4,
     Simd = u64x4,
+    Mask = m64x4,
     SignedSimd = i64x4,
     T_BITS = 64,
     T_BITS_MUL_2 = 128,
@@ -460,7 +462,7 @@ impl_simd_uint! {
         let result = self + rhs;
         let overflow = result.simd_lt(self);
         // Return `MAX` (all bits set) if overflow occurs.
-        result | overflow
+        result | Self::from(overflow)
       } else {
         Self {
           a: self.a.saturating_add(rhs.a),
@@ -477,7 +479,7 @@ impl_simd_uint! {
         let result = self - rhs;
         let no_overflow = result.simd_le(self);
         // Return `0` (no bits set) if overflow occurs.
-        result & no_overflow
+        result & Self::from(no_overflow)
       } else {
         Self {
           a: self.a.saturating_sub(rhs.a),
@@ -488,7 +490,7 @@ impl_simd_uint! {
   }
 
   #[inline]
-  pub fn overflowing_mul(self, rhs: Self) -> (Self, Self) {
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m64x4) {
     // TODO(perf): This implementation looks quite bad. Is there a better
     // one? This intentionally avoids `mul_keep_low_high` because getting the
     // high bits of 64-bit multiplication could be slow.
@@ -504,11 +506,11 @@ impl_simd_uint! {
     ];
     (
       Self::new([result[0].0, result[1].0, result[2].0, result[3].0]),
-      Self::new([
-        -(result[0].1 as i64) as u64,
-        -(result[1].1 as i64) as u64,
-        -(result[2].1 as i64) as u64,
-        -(result[3].1 as i64) as u64,
+      m64x4::new([
+        result[0].1,
+        result[1].1,
+        result[2].1,
+        result[3].1,
       ]),
     )
   }
@@ -568,4 +570,82 @@ impl_simd_uint! {
       }
     }
   }
+
+  #[inline]
+  pub fn count_ones(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512vpopcntdq", target_feature="avx512vl")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm256_popcnt_epi64;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm256_popcnt_epi64;
+
+        Self { avx2: m256i(unsafe { _mm256_popcnt_epi64(self.avx2.0) }) }
+      } else if #[cfg(target_feature="avx2")] {
+        // `vpsadbw` adds up the counts of the 8 bytes of each element.
+        let bytes = u8x32::count_ones(cast(self));
+        Self { avx2: sum_of_u8_abs_diff_m256i(bytes.avx, m256i::default()) }
+      } else {
+        Self {
+          a : self.a.count_ones(),
+          b : self.b.count_ones(),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  pub fn leading_zeros(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512cd", target_feature="avx512vl")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm256_lzcnt_epi64;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm256_lzcnt_epi64;
+
+        Self { avx2: m256i(unsafe { _mm256_lzcnt_epi64(self.avx2.0) }) }
+      } else if #[cfg(target_feature="avx2")] {
+        // Only count the zeros of the low half if the high half is zero.
+        let halves: Self = cast(u32x8::leading_zeros(cast(self)));
+        let high: Self = halves >> 32;
+        let high_is_zero = Self::from(high.simd_eq(Self::splat(32)));
+        high + (halves & Self::splat(0xFFFF_FFFF) & high_is_zero)
+      } else {
+        Self {
+          a : self.a.leading_zeros(),
+          b : self.b.leading_zeros(),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  pub fn rotate_left(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512f", target_feature="avx512vl")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm256_rolv_epi64;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm256_rolv_epi64;
+
+        let rotated = unsafe { _mm256_rolv_epi64(self.avx2.0, rhs.avx2.0) };
+        Self { avx2: m256i(rotated) }
+      } else {
+        let rhs = rhs & Self::splat(63);
+        self.unbounded_shl(rhs) | self.unbounded_shr(Self::splat(64) - rhs)
+      }
+    }
+  }
+
+  #[inline]
+  pub fn rotate_left_scalar(self, rhs: u32) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512f", target_feature="avx512vl")] {
+        self.rotate_left(Self::splat(rhs as u64))
+      } else {
+        let rhs = rhs % 64;
+        self.unbounded_shl_scalar(rhs) | self.unbounded_shr_scalar(64 - rhs)
+      }
+    }
+  }
 }
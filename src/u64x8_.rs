@@ -29,6 +29,7 @@ impl_simd! {
     T = u64,
     N = 8,
     Simd = u64x8,
+    Mask = m64x8,
     optional_type_x86_inner { X86Inner = __m512i },
     optional_type_arm_inner {},
     optional_type_wasm_inner {},
@@ -41,8 +42,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_u64_m512i::<{cmp_int_op!(Eq)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_eq(rhs.a),
-          b : self.b.simd_eq(rhs.b),
+          a : self.a.simd_eq(rhs.a).into(),
+          b : self.b.simd_eq(rhs.b).into(),
         }
       }
     }
@@ -55,8 +56,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_u64_m512i::<{cmp_int_op!(Ne)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_ne(rhs.a),
-          b : self.b.simd_ne(rhs.b),
+          a : self.a.simd_ne(rhs.a).into(),
+          b : self.b.simd_ne(rhs.b).into(),
         }
       }
     }
@@ -69,8 +70,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_u64_m512i::<{cmp_int_op!(Lt)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_lt(rhs.a),
-          b : self.b.simd_lt(rhs.b),
+          a : self.a.simd_lt(rhs.a).into(),
+          b : self.b.simd_lt(rhs.b).into(),
         }
       }
     }
@@ -83,8 +84,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_u64_m512i::<{cmp_int_op!(Nle)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_gt(rhs.a),
-          b : self.b.simd_gt(rhs.b),
+          a : self.a.simd_gt(rhs.a).into(),
+          b : self.b.simd_gt(rhs.b).into(),
         }
       }
     }
@@ -97,8 +98,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_u64_m512i::<{cmp_int_op!(Le)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_le(rhs.a),
-          b : self.b.simd_le(rhs.b),
+          a : self.a.simd_le(rhs.a).into(),
+          b : self.b.simd_le(rhs.b).into(),
         }
       }
     }
@@ -111,8 +112,8 @@ impl_simd! {
         Self { avx512: cmp_op_mask_u64_m512i::<{cmp_int_op!(Nlt)}>(self.avx512, rhs.avx512) }
       } else {
         Self {
-          a : self.a.simd_ge(rhs.a),
-          b : self.b.simd_ge(rhs.b),
+          a : self.a.simd_ge(rhs.a).into(),
+          b : self.b.simd_ge(rhs.b).into(),
         }
       }
     }
@@ -179,6 +180,7 @@ impl_simd_uint! {
     T = u64,
     N = 8,
     Simd = u64x8,
+    Mask = m64x8,
     SignedSimd = i64x8,
     T_BITS = 64,
     T_BITS_MUL_2 = 128,
@@ -473,7 +475,7 @@ impl_simd_uint! {
         let result = self + rhs;
         let overflow = result.simd_lt(self);
         // Return `MAX` (all bits set) if overflow occurs.
-        result | overflow
+        result | Self::from(overflow)
       } else {
         Self {
           a: self.a.saturating_add(rhs.a),
@@ -490,7 +492,7 @@ impl_simd_uint! {
         let result = self - rhs;
         let no_overflow = result.simd_le(self);
         // Return `0` (no bits set) if overflow occurs.
-        result & no_overflow
+        result & Self::from(no_overflow)
       } else {
         Self {
           a: self.a.saturating_sub(rhs.a),
@@ -501,7 +503,7 @@ impl_simd_uint! {
   }
 
   #[inline]
-  pub fn overflowing_mul(self, rhs: Self) -> (Self, Self) {
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m64x8) {
     // TODO(perf): This implementation looks quite bad. Is there a better
     // one? This intentionally avoids `mul_keep_low_high` because getting the
     // high bits of 64-bit multiplication could be slow.
@@ -530,15 +532,15 @@ impl_simd_uint! {
         result[6].0,
         result[7].0,
       ]),
-      Self::new([
-        -(result[0].1 as i64) as u64,
-        -(result[1].1 as i64) as u64,
-        -(result[2].1 as i64) as u64,
-        -(result[3].1 as i64) as u64,
-        -(result[4].1 as i64) as u64,
-        -(result[5].1 as i64) as u64,
-        -(result[6].1 as i64) as u64,
-        -(result[7].1 as i64) as u64,
+      m64x8::new([
+        result[0].1,
+        result[1].1,
+        result[2].1,
+        result[3].1,
+        result[4].1,
+        result[5].1,
+        result[6].1,
+        result[7].1,
       ]),
     )
   }
@@ -614,4 +616,68 @@ impl_simd_uint! {
       }
     }
   }
+
+  #[inline]
+  pub fn count_ones(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512vpopcntdq")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm512_popcnt_epi64;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm512_popcnt_epi64;
+
+        Self { avx512: m512i(unsafe { _mm512_popcnt_epi64(self.avx512.0) }) }
+      } else {
+        let [a, b]: [u64x4; 2] = cast(self);
+        cast([a.count_ones(), b.count_ones()])
+      }
+    }
+  }
+
+  #[inline]
+  pub fn leading_zeros(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512cd")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm512_lzcnt_epi64;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm512_lzcnt_epi64;
+
+        Self { avx512: m512i(unsafe { _mm512_lzcnt_epi64(self.avx512.0) }) }
+      } else {
+        let [a, b]: [u64x4; 2] = cast(self);
+        cast([a.leading_zeros(), b.leading_zeros()])
+      }
+    }
+  }
+
+  #[inline]
+  pub fn rotate_left(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512f")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm512_rolv_epi64;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm512_rolv_epi64;
+
+        let rotated = unsafe { _mm512_rolv_epi64(self.avx512.0, rhs.avx512.0) };
+        Self { avx512: m512i(rotated) }
+      } else {
+        let rhs = rhs & Self::splat(63);
+        self.unbounded_shl(rhs) | self.unbounded_shr(Self::splat(64) - rhs)
+      }
+    }
+  }
+
+  #[inline]
+  pub fn rotate_left_scalar(self, rhs: u32) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512f")] {
+        self.rotate_left(Self::splat(rhs as u64))
+      } else {
+        let rhs = rhs % 64;
+        self.unbounded_shl_scalar(rhs) | self.unbounded_shr_scalar(64 - rhs)
+      }
+    }
+  }
 }
@@ -83,6 +83,7 @@ impl_simd! {
     T = u8,
     N = 16,
     Simd = u8x16,
+    Mask = m8x16,
     optional_type_x86_inner { X86Inner = __m128i },
     optional_type_arm_inner { ArmInner = uint8x16_t },
     optional_type_wasm_inner { WasmInner = v128 },
@@ -124,11 +125,11 @@ impl_simd! {
   fn simd_ne(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="sse2")] {
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else if #[cfg(target_feature="simd128")] {
         Self { simd: u8x16_ne(self.simd, rhs.simd) }
       } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else {
         Self { arr: [
           if self.arr[0] != rhs.arr[0] { u8::MAX } else { 0 },
@@ -363,6 +364,7 @@ impl_simd_uint! {
     T = u8,
     N = 16,
     Simd = u8x16,
+    Mask = m8x16,
     SignedSimd = i8x16,
     T_BITS = 8,
     T_BITS_MUL_2 = 16,
@@ -991,7 +993,7 @@ impl_simd_uint! {
     pick! {
       if #[cfg(all(target_feature="neon", target_arch="aarch64"))] {
         unsafe {
-          Self { neon: vshlq_u8(self.neon, vreinterpretq_s8_u8(rhs.neon)) } & rhs.simd_lt(8)
+          Self { neon: vshlq_u8(self.neon, vreinterpretq_s8_u8(rhs.neon)) } & Self::from(rhs.simd_lt(8))
         }
       } else {
         let self_array = self.to_array();
@@ -1066,7 +1068,7 @@ impl_simd_uint! {
         unsafe {
           // Negate `rhs` because there is no direct shift-right intrinsic, and
           // mask to hide `rhs` overflow.
-          Self { neon: vshlq_u8(self.neon, vnegq_s8(vreinterpretq_s8_u8(rhs.neon))) } & rhs.simd_lt(8)
+          Self { neon: vshlq_u8(self.neon, vnegq_s8(vreinterpretq_s8_u8(rhs.neon))) } & Self::from(rhs.simd_lt(8))
         }
       } else {
         let self_array = self.to_array();
@@ -1198,7 +1200,7 @@ impl_simd_uint! {
   }
 
   #[inline]
-  pub fn overflowing_mul(self, rhs: Self) -> (Self, Self) {
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m8x16) {
     let (low, high) = self.mul_keep_low_high(rhs);
     let overflow = high.simd_ne(Self::ZERO);
     (low, overflow)
@@ -1368,6 +1370,85 @@ impl_simd_uint! {
       }
     }
   }
+
+  #[inline]
+  pub fn count_ones(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512bitalg", target_feature="avx512vl")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm_popcnt_epi8;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm_popcnt_epi8;
+
+        Self { sse: m128i(unsafe { _mm_popcnt_epi8(self.sse.0) }) }
+      } else if #[cfg(target_feature="ssse3")] {
+        // Look up the number of ones of each nibble.
+        const LUT: [u8; 16] = [0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4];
+        let lut = m128i::from(LUT);
+        let nibble = set_splat_i8_m128i(0x0F);
+        let low = bitand_m128i(self.sse, nibble);
+        let high = bitand_m128i(shr_imm_u16_m128i::<4>(self.sse), nibble);
+        Self {
+          sse: add_i8_m128i(
+            shuffle_av_i8z_all_m128i(lut, low),
+            shuffle_av_i8z_all_m128i(lut, high),
+          ),
+        }
+      } else if #[cfg(target_feature="simd128")] {
+        Self { simd: u8x16_popcnt(self.simd) }
+      } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
+        unsafe { Self { neon: vcntq_u8(self.neon) } }
+      } else {
+        // Count the ones of both bytes of each `u16` in parallel.
+        let x: u16x8 = cast(self);
+        let x = x - ((x >> 1) & u16x8::splat(0x5555));
+        let x = (x & u16x8::splat(0x3333)) + ((x >> 2) & u16x8::splat(0x3333));
+        cast((x + (x >> 4)) & u16x8::splat(0x0F0F))
+      }
+    }
+  }
+
+  #[inline]
+  pub fn leading_zeros(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="ssse3")] {
+        // Look up the leading zeros of each nibble, as if the other nibble
+        // were zero, and keep the smallest count.
+        const LUT_LOW: [u8; 16] = [8, 7, 6, 6, 5, 5, 5, 5, 4, 4, 4, 4, 4, 4, 4, 4];
+        const LUT_HIGH: [u8; 16] = [8, 3, 2, 2, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0];
+        let nibble = set_splat_i8_m128i(0x0F);
+        let low = bitand_m128i(self.sse, nibble);
+        let high = bitand_m128i(shr_imm_u16_m128i::<4>(self.sse), nibble);
+        Self {
+          sse: min_u8_m128i(
+            shuffle_av_i8z_all_m128i(m128i::from(LUT_LOW), low),
+            shuffle_av_i8z_all_m128i(m128i::from(LUT_HIGH), high),
+          ),
+        }
+      } else if #[cfg(all(target_feature="neon",target_arch="aarch64"))]{
+        unsafe { Self { neon: vclzq_u8(self.neon) } }
+      } else {
+        // Set all bits below the highest one, then count the zeros.
+        let x: u16x8 = cast(self);
+        let x = x | ((x >> 1) & u16x8::splat(0x7F7F));
+        let x = x | ((x >> 2) & u16x8::splat(0x3F3F));
+        let x = x | ((x >> 4) & u16x8::splat(0x0F0F));
+        Self::count_ones(!cast::<u16x8, Self>(x))
+      }
+    }
+  }
+
+  #[inline]
+  pub fn rotate_left(self, rhs: Self) -> Self {
+    let rhs = rhs & Self::splat(7);
+    self.unbounded_shl(rhs) | self.unbounded_shr(Self::splat(8) - rhs)
+  }
+
+  #[inline]
+  pub fn rotate_left_scalar(self, rhs: u32) -> Self {
+    let rhs = rhs % 8;
+    self.unbounded_shl_scalar(rhs) | self.unbounded_shr_scalar(8 - rhs)
+  }
 }
 
 /// The following functionality exists only for [`u8x16`], or only for
@@ -29,6 +29,7 @@ impl_simd! {
     T = u8,
     N = 32,
     Simd = u8x32,
+    Mask = m8x32,
     optional_type_x86_inner { X86Inner = __m256i },
     optional_type_arm_inner {},
     optional_type_wasm_inner {},
@@ -41,8 +42,8 @@ impl_simd! {
         Self { avx : cmp_eq_mask_i8_m256i(self.avx,rhs.avx) }
       } else {
         Self {
-          a : self.a.simd_eq(rhs.a),
-          b : self.b.simd_eq(rhs.b),
+          a : self.a.simd_eq(rhs.a).into(),
+          b : self.b.simd_eq(rhs.b).into(),
         }
       }
     }
@@ -52,11 +53,11 @@ impl_simd! {
   fn simd_ne(self, rhs: Self) -> Self::Output {
     pick! {
       if #[cfg(target_feature="avx2")] {
-        !self.simd_eq(rhs)
+        Self::from(!self.simd_eq(rhs))
       } else {
         Self {
-          a : self.a.simd_ne(rhs.a),
-          b : self.b.simd_ne(rhs.b),
+          a : self.a.simd_ne(rhs.a).into(),
+          b : self.b.simd_ne(rhs.b).into(),
         }
       }
     }
@@ -72,7 +73,7 @@ impl_simd! {
         let rhs_i8 = rhs.bitxor(offset).avx;
         Self { avx: cmp_gt_mask_i8_m256i(rhs_i8, self_i8)}
       } else {
-        Self { a: self.a.simd_lt(rhs.a), b: self.b.simd_lt(rhs.b) }
+        Self { a: self.a.simd_lt(rhs.a).into(), b: self.b.simd_lt(rhs.b).into() }
       }
     }
   }
@@ -87,7 +88,7 @@ impl_simd! {
         let rhs_i8 = rhs.bitxor(offset).avx;
         Self { avx : cmp_gt_mask_i8_m256i(self_i8,rhs_i8) }
       } else {
-        Self { a: self.a.simd_gt(rhs.a), b: self.b.simd_gt(rhs.b) }
+        Self { a: self.a.simd_gt(rhs.a).into(), b: self.b.simd_gt(rhs.b).into() }
       }
     }
   }
@@ -103,7 +104,7 @@ impl_simd! {
         let gt_mask = Self { avx : cmp_gt_mask_i8_m256i(self_i8,rhs_i8) };
         Self { avx: gt_mask.bitxor(Self::splat(0xFF)).avx }
       } else {
-        Self { a: self.a.simd_le(rhs.a), b: self.b.simd_le(rhs.b) }
+        Self { a: self.a.simd_le(rhs.a).into(), b: self.b.simd_le(rhs.b).into() }
       }
     }
   }
@@ -119,7 +120,7 @@ impl_simd! {
         let lt_mask = Self { avx: cmp_gt_mask_i8_m256i(rhs_i8, self_i8)};
         Self { avx: lt_mask.bitxor(Self::splat(0xFF)).avx }
       } else {
-        Self { a: self.a.simd_ge(rhs.a), b: self.b.simd_ge(rhs.b) }
+        Self { a: self.a.simd_ge(rhs.a).into(), b: self.b.simd_ge(rhs.b).into() }
       }
     }
   }
@@ -185,6 +186,7 @@ impl_simd_uint! {
     T = u8,
     N = 32,
     Simd = u8x32,
+    Mask = m8x32,
     SignedSimd = i8x32,
     T_BITS = 8,
     T_BITS_MUL_2 = 16,
@@ -445,7 +447,7 @@ impl_simd_uint! {
   }
 
   #[inline]
-  pub fn overflowing_mul(self, rhs: Self) -> (Self, Self) {
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m8x32) {
     let (low, high) = self.mul_keep_low_high(rhs);
     let overflow = high.simd_ne(Self::ZERO);
     (low, overflow)
@@ -487,6 +489,85 @@ impl_simd_uint! {
 
     cast([self_a.mul_keep_high(rhs_a), self_b.mul_keep_high(rhs_b)])
   }
+
+  #[inline]
+  pub fn count_ones(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512bitalg", target_feature="avx512vl")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm256_popcnt_epi8;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm256_popcnt_epi8;
+
+        Self { avx: m256i(unsafe { _mm256_popcnt_epi8(self.avx.0) }) }
+      } else if #[cfg(target_feature="avx2")] {
+        // Look up the number of ones of each nibble.
+        const LUT: [u8; 32] = [
+          0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+          0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+        ];
+        let lut = m256i::from(LUT);
+        let nibble = set_splat_i8_m256i(0x0F);
+        let low = bitand_m256i(self.avx, nibble);
+        let high = bitand_m256i(shr_imm_u16_m256i::<4>(self.avx), nibble);
+        Self {
+          avx: add_i8_m256i(
+            shuffle_av_i8z_half_m256i(lut, low),
+            shuffle_av_i8z_half_m256i(lut, high),
+          ),
+        }
+      } else {
+        Self {
+          a : self.a.count_ones(),
+          b : self.b.count_ones(),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  pub fn leading_zeros(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx2")] {
+        // Look up the leading zeros of each nibble, as if the other nibble
+        // were zero, and keep the smallest count.
+        const LUT_LOW: [u8; 32] = [
+          8, 7, 6, 6, 5, 5, 5, 5, 4, 4, 4, 4, 4, 4, 4, 4,
+          8, 7, 6, 6, 5, 5, 5, 5, 4, 4, 4, 4, 4, 4, 4, 4,
+        ];
+        const LUT_HIGH: [u8; 32] = [
+          8, 3, 2, 2, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+          8, 3, 2, 2, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let nibble = set_splat_i8_m256i(0x0F);
+        let low = bitand_m256i(self.avx, nibble);
+        let high = bitand_m256i(shr_imm_u16_m256i::<4>(self.avx), nibble);
+        Self {
+          avx: min_u8_m256i(
+            shuffle_av_i8z_half_m256i(m256i::from(LUT_LOW), low),
+            shuffle_av_i8z_half_m256i(m256i::from(LUT_HIGH), high),
+          ),
+        }
+      } else {
+        Self {
+          a : self.a.leading_zeros(),
+          b : self.b.leading_zeros(),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  pub fn rotate_left(self, rhs: Self) -> Self {
+    let rhs = rhs & Self::splat(7);
+    self.unbounded_shl(rhs) | self.unbounded_shr(Self::splat(8) - rhs)
+  }
+
+  #[inline]
+  pub fn rotate_left_scalar(self, rhs: u32) -> Self {
+    let rhs = rhs % 8;
+    self.unbounded_shl_scalar(rhs) | self.unbounded_shr_scalar(8 - rhs)
+  }
 }
 
 /// The following functionality exists only for [`u8x32`], or only for
@@ -0,0 +1,514 @@
+use super::*;
+
+pick! {
+  if #[cfg(target_feature="avx512bw")] {
+    /// A SIMD vector with 64 elements of type [`u8`].
+    ///
+    /// See the [crate level documentation] for more information about SIMD
+    /// vectors.
+    ///
+    /// [crate level documentation]: crate
+    #[derive(Default, Clone, Copy, PartialEq, Eq)]
+    #[repr(C, align(64))]
+    pub struct u8x64 { pub(crate) avx512: m512i }
+  } else {
+    /// A SIMD vector with 64 elements of type [`u8`].
+    ///
+    /// See the [crate level documentation] for more information about SIMD
+    /// vectors.
+    ///
+    /// [crate level documentation]: crate
+    #[derive(Default, Clone, Copy, PartialEq, Eq)]
+    #[repr(C, align(64))]
+    pub struct u8x64 { pub(crate) a : u8x32, pub(crate) b : u8x32 }
+  }
+}
+
+impl_simd! {
+  unsafe {
+    T = u8,
+    N = 64,
+    Simd = u8x64,
+    Mask = m8x64,
+    optional_type_x86_inner { X86Inner = __m512i },
+    optional_type_arm_inner {},
+    optional_type_wasm_inner {},
+  }
+
+  #[inline]
+  fn simd_eq(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        Self { avx512: cmp_op_mask_u8_m512i::<{cmp_int_op!(Eq)}>(self.avx512, rhs.avx512) }
+      } else {
+        Self {
+          a : self.a.simd_eq(rhs.a).into(),
+          b : self.b.simd_eq(rhs.b).into(),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  fn simd_ne(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        Self { avx512: cmp_op_mask_u8_m512i::<{cmp_int_op!(Ne)}>(self.avx512, rhs.avx512) }
+      } else {
+        Self {
+          a : self.a.simd_ne(rhs.a).into(),
+          b : self.b.simd_ne(rhs.b).into(),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  fn simd_lt(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        Self { avx512: cmp_op_mask_u8_m512i::<{cmp_int_op!(Lt)}>(self.avx512, rhs.avx512) }
+      } else {
+        Self {
+          a : self.a.simd_lt(rhs.a).into(),
+          b : self.b.simd_lt(rhs.b).into(),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  fn simd_gt(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        Self { avx512: cmp_op_mask_u8_m512i::<{cmp_int_op!(Nle)}>(self.avx512, rhs.avx512) }
+      } else {
+        Self {
+          a : self.a.simd_gt(rhs.a).into(),
+          b : self.b.simd_gt(rhs.b).into(),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  fn simd_le(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        Self { avx512: cmp_op_mask_u8_m512i::<{cmp_int_op!(Le)}>(self.avx512, rhs.avx512) }
+      } else {
+        Self {
+          a : self.a.simd_le(rhs.a).into(),
+          b : self.b.simd_le(rhs.b).into(),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  fn simd_ge(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        Self { avx512: cmp_op_mask_u8_m512i::<{cmp_int_op!(Nlt)}>(self.avx512, rhs.avx512) }
+      } else {
+        Self {
+          a : self.a.simd_ge(rhs.a).into(),
+          b : self.b.simd_ge(rhs.b).into(),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  pub fn bitselect(self, if_one: Self, if_zero: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        Self {
+          avx512: bitor_m512i(
+            bitand_m512i(if_one.avx512, self.avx512),
+            bitandnot_m512i(self.avx512, if_zero.avx512),
+          ),
+        }
+      } else {
+        Self {
+          a: self.a.bitselect(if_one.a, if_zero.a),
+          b: self.b.bitselect(if_one.b, if_zero.b),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  pub fn select(self, if_true: Self, if_false: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        Self { avx512: blend_varying_i8_m512i(if_false.avx512,if_true.avx512,movepi8_mask_m512i(self.avx512)) }
+      } else {
+        Self {
+          a : self.a.select(if_true.a, if_false.a),
+          b : self.b.select(if_true.b, if_false.b),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  pub fn to_bitmask(self) -> u64 {
+    i8x64::to_bitmask(cast(self))
+  }
+
+  #[inline]
+  pub fn any(self) -> bool {
+    i8x64::any(cast(self))
+  }
+
+  #[inline]
+  pub fn all(self) -> bool {
+    i8x64::all(cast(self))
+  }
+
+  ///
+  /// Currently this function is never accelerated.
+  #[inline]
+  pub fn transpose(data: [u8x64; 64]) -> [u8x64; 64] {
+    cast(i8x64::transpose(cast(data)))
+  }
+}
+
+impl_simd_uint! {
+  unsafe {
+    T = u8,
+    N = 64,
+    Simd = u8x64,
+    Mask = m8x64,
+    SignedSimd = i8x64,
+    T_BITS = 8,
+    T_BITS_MUL_2 = 16,
+    [
+      0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+      21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38,
+      39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56,
+      57, 58, 59, 60, 61, 62, 63
+    ],
+  }
+
+  #[inline]
+  fn not(self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        Self { avx512: bitxor_m512i(self.avx512, set_splat_i8_m512i(-1)) }
+      } else {
+        Self {
+          a : self.a.not(),
+          b : self.b.not(),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  fn add(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        Self { avx512: add_i8_m512i(self.avx512, rhs.avx512) }
+      } else {
+        Self {
+          a : self.a.add(rhs.a),
+          b : self.b.add(rhs.b),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  fn sub(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        Self { avx512: sub_i8_m512i(self.avx512, rhs.avx512) }
+      } else {
+        Self {
+          a : self.a.sub(rhs.a),
+          b : self.b.sub(rhs.b),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  fn mul(self, rhs: Self) -> Self::Output {
+    // AVX-512 has no 8-bit multiplication, so this multiplies the halves.
+    let [self_a, self_b]: [u8x32; 2] = cast(self);
+    let [rhs_a, rhs_b]: [u8x32; 2] = cast(rhs);
+    cast([self_a * rhs_a, self_b * rhs_b])
+  }
+
+  #[inline]
+  fn shl(self, rhs: Self) -> Self::Output {
+    // AVX-512 has no 8-bit shifts, so this shifts the halves.
+    let [self_a, self_b]: [u8x32; 2] = cast(self);
+    let [rhs_a, rhs_b]: [u8x32; 2] = cast(rhs);
+    cast([self_a << rhs_a, self_b << rhs_b])
+  }
+
+  #[inline]
+  fn shl(self, rhs: u32) -> Self::Output {
+    // AVX-512 has no 8-bit shifts, so this shifts the halves.
+    let [self_a, self_b]: [u8x32; 2] = cast(self);
+    cast([self_a << rhs, self_b << rhs])
+  }
+
+  #[inline]
+  fn shr(self, rhs: Self) -> Self::Output {
+    // AVX-512 has no 8-bit shifts, so this shifts the halves.
+    let [self_a, self_b]: [u8x32; 2] = cast(self);
+    let [rhs_a, rhs_b]: [u8x32; 2] = cast(rhs);
+    cast([self_a >> rhs_a, self_b >> rhs_b])
+  }
+
+  #[inline]
+  fn shr(self, rhs: u32) -> Self::Output {
+    // AVX-512 has no 8-bit shifts, so this shifts the halves.
+    let [self_a, self_b]: [u8x32; 2] = cast(self);
+    cast([self_a >> rhs, self_b >> rhs])
+  }
+
+  #[inline]
+  fn bitand(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        Self { avx512: bitand_m512i(self.avx512, rhs.avx512) }
+      } else {
+        Self {
+          a : self.a.bitand(rhs.a),
+          b : self.b.bitand(rhs.b),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  fn bitor(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        Self { avx512: bitor_m512i(self.avx512, rhs.avx512) }
+      } else {
+        Self {
+          a : self.a.bitor(rhs.a),
+          b : self.b.bitor(rhs.b),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  fn bitxor(self, rhs: Self) -> Self::Output {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        Self { avx512: bitxor_m512i(self.avx512, rhs.avx512) }
+      } else {
+        Self {
+          a : self.a.bitxor(rhs.a),
+          b : self.b.bitxor(rhs.b),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  pub fn max(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        Self { avx512: max_u8_m512i(self.avx512, rhs.avx512) }
+      } else {
+        Self {
+          a : self.a.max(rhs.a),
+          b : self.b.max(rhs.b),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  pub fn min(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        Self { avx512: min_u8_m512i(self.avx512, rhs.avx512) }
+      } else {
+        Self {
+          a : self.a.min(rhs.a),
+          b : self.b.min(rhs.b),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  pub fn reduce_add(self) -> u8 {
+    let array: [u8x32; 2] = cast(self);
+    (array[0] + array[1]).reduce_add()
+  }
+
+  #[inline]
+  pub fn reduce_mul(self) -> u8 {
+    let array: [u8x32; 2] = cast(self);
+    (array[0] * array[1]).reduce_mul()
+  }
+
+  #[inline]
+  pub fn reduce_max(self) -> u8 {
+    let array: [u8x32; 2] = cast(self);
+    array[0].max(array[1]).reduce_max()
+  }
+
+  #[inline]
+  pub fn reduce_min(self) -> u8 {
+    let array: [u8x32; 2] = cast(self);
+    array[0].min(array[1]).reduce_min()
+  }
+
+  #[inline]
+  pub fn unbounded_shl(self, rhs: Self) -> Self {
+    // AVX-512 has no 8-bit shifts, so this shifts the halves.
+    let [self_a, self_b] = cast::<u8x64, [u8x32; 2]>(self);
+    let [rhs_a, rhs_b] = cast::<u8x64, [u8x32; 2]>(rhs);
+    cast([self_a.unbounded_shl(rhs_a), self_b.unbounded_shl(rhs_b)])
+  }
+
+  #[inline]
+  pub fn unbounded_shl_scalar(self, rhs: u32) -> Self {
+    // AVX-512 has no 8-bit shifts, so this shifts the halves.
+    let [self_a, self_b] = cast::<u8x64, [u8x32; 2]>(self);
+    cast([self_a.unbounded_shl_scalar(rhs), self_b.unbounded_shl_scalar(rhs)])
+  }
+
+  #[inline]
+  pub fn unbounded_shr(self, rhs: Self) -> Self {
+    // AVX-512 has no 8-bit shifts, so this shifts the halves.
+    let [self_a, self_b] = cast::<u8x64, [u8x32; 2]>(self);
+    let [rhs_a, rhs_b] = cast::<u8x64, [u8x32; 2]>(rhs);
+    cast([self_a.unbounded_shr(rhs_a), self_b.unbounded_shr(rhs_b)])
+  }
+
+  #[inline]
+  pub fn unbounded_shr_scalar(self, rhs: u32) -> Self {
+    // AVX-512 has no 8-bit shifts, so this shifts the halves.
+    let [self_a, self_b] = cast::<u8x64, [u8x32; 2]>(self);
+    cast([self_a.unbounded_shr_scalar(rhs), self_b.unbounded_shr_scalar(rhs)])
+  }
+
+  #[inline]
+  pub fn saturating_add(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        Self { avx512: add_saturating_u8_m512i(self.avx512, rhs.avx512) }
+      } else {
+        Self {
+          a : self.a.saturating_add(rhs.a),
+          b : self.b.saturating_add(rhs.b),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  pub fn saturating_sub(self, rhs: Self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512bw")] {
+        Self { avx512: sub_saturating_u8_m512i(self.avx512, rhs.avx512) }
+      } else {
+        Self {
+          a : self.a.saturating_sub(rhs.a),
+          b : self.b.saturating_sub(rhs.b),
+        }
+      }
+    }
+  }
+
+  #[inline]
+  pub fn overflowing_mul(self, rhs: Self) -> (Self, m8x64) {
+    let (low, high) = self.mul_keep_low_high(rhs);
+    let overflow = high.simd_ne(Self::ZERO);
+    (low, overflow)
+  }
+
+  optional_fn_widening_mul {
+    // Cannot have `widening_mul` because there is no `u16x64` type.
+  }
+
+  #[inline]
+  pub fn mul_keep_low_high(self, rhs: Self) -> (Self, Self) {
+    // x86 has no `_mm512_mul_epu8` intrinsic so there is no `avx512`
+    // optimization.
+
+    let [self_a, self_b] = cast::<u8x64, [u8x32; 2]>(self);
+    let [rhs_a, rhs_b] = cast::<u8x64, [u8x32; 2]>(rhs);
+
+    let result_a = self_a.mul_keep_low_high(rhs_a);
+    let result_b = self_b.mul_keep_low_high(rhs_b);
+    (cast([result_a.0, result_b.0]), cast([result_a.1, result_b.1]))
+  }
+
+  #[inline]
+  pub fn mul_keep_high(self, rhs: Self) -> Self {
+    // x86 has no `_mm512_mul_epu8` intrinsic so there is no `avx512`
+    // optimization.
+
+    let [self_a, self_b] = cast::<u8x64, [u8x32; 2]>(self);
+    let [rhs_a, rhs_b] = cast::<u8x64, [u8x32; 2]>(rhs);
+
+    cast([self_a.mul_keep_high(rhs_a), self_b.mul_keep_high(rhs_b)])
+  }
+
+  #[inline]
+  pub fn count_ones(self) -> Self {
+    pick! {
+      if #[cfg(target_feature="avx512bitalg")] {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm512_popcnt_epi8;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm512_popcnt_epi8;
+
+        Self { avx512: m512i(unsafe { _mm512_popcnt_epi8(self.avx512.0) }) }
+      } else {
+        let [a, b]: [u8x32; 2] = cast(self);
+        cast([a.count_ones(), b.count_ones()])
+      }
+    }
+  }
+
+  #[inline]
+  pub fn leading_zeros(self) -> Self {
+    let [a, b]: [u8x32; 2] = cast(self);
+    cast([a.leading_zeros(), b.leading_zeros()])
+  }
+
+  #[inline]
+  pub fn rotate_left(self, rhs: Self) -> Self {
+    let rhs = rhs & Self::splat(7);
+    self.unbounded_shl(rhs) | self.unbounded_shr(Self::splat(8) - rhs)
+  }
+
+  #[inline]
+  pub fn rotate_left_scalar(self, rhs: u32) -> Self {
+    let rhs = rhs % 8;
+    self.unbounded_shl_scalar(rhs) | self.unbounded_shr_scalar(8 - rhs)
+  }
+}
+
+/// The following functionality exists only for [`u8x64`], or only for
+/// particular types inconsistently.
+impl u8x64 {
+  /// Full 64-entry byte table lookup. An index in `[0, 63]` selects
+  /// `self[index]`; any index `>= 64` yields `0`.
+  #[inline]
+  pub fn swizzle(self, rhs: u8x64) -> u8x64 {
+    cast(i8x64::swizzle(cast(self), cast(rhs)))
+  }
+
+  /// Like [`swizzle`](Self::swizzle), but out-of-range indices yield an
+  /// implementation-defined result (`0` or `self[index % 64]`).
+  #[inline]
+  pub fn swizzle_relaxed(self, rhs: u8x64) -> u8x64 {
+    cast(i8x64::swizzle_relaxed(cast(self), cast(rhs)))
+  }
+}
@@ -0,0 +1,423 @@
+//! Checks the stated error bounds of the `fast_*` and `precise_*` accuracy
+//! tiers against the `f64` functions of the standard library, and those of
+//! `gamma` against a reference implementation.
+//!
+//! The tests in `sampled` check evenly spaced bit patterns of `f32` with every
+//! vector width. The ignored tests in `exhaustive` check every `f32` input of
+//! `f32x4`, one test per function, and are meant to be run in release mode
+//! with `cargo test --release -- --ignored accuracy::exhaustive`. They don't
+//! cover `gamma` and the functions of two arguments, which are only sampled.
+
+use wide::{f32x4, f32x8, f32x16, f64x2, f64x4, f64x8};
+
+use crate::utils::random_iter;
+
+/// The distance between sampled bit patterns in the default tests.
+const SAMPLE_STEP: u64 = 65537;
+
+/// Inputs that are checked in addition to the sampled bit patterns.
+const SPECIAL_VALUES: [f32; 16] = [
+  0.0,
+  -0.0,
+  1.0,
+  -1.0,
+  f32::INFINITY,
+  f32::NEG_INFINITY,
+  f32::NAN,
+  f32::MIN_POSITIVE,
+  -f32::MIN_POSITIVE,
+  1e-45,
+  -1e-45,
+  f32::MAX,
+  f32::MIN,
+  f32::EPSILON,
+  core::f32::consts::PI,
+  1e7,
+];
+
+/// Returns the error of `actual` in units in the last place of the `f32`
+/// closest to `expected`.
+///
+/// Infinities count as `2^128`, the next power of two after `f32::MAX`, so
+/// that results which overflow have a finite error.
+fn ulp_error(actual: f32, expected: f64) -> f64 {
+  if expected.is_nan() || actual.is_nan() {
+    return if expected.is_nan() && actual.is_nan() {
+      0.0
+    } else {
+      f64::INFINITY
+    };
+  }
+
+  let limit = 2.0_f64.powi(128);
+  let actual = if actual.is_infinite() {
+    limit.copysign(f64::from(actual))
+  } else {
+    f64::from(actual)
+  };
+  let expected = expected.clamp(-limit, limit);
+
+  // the ULP of `f32` in the binade of `expected`, down to the subnormal ULP,
+  // built from its bits as this runs for every input
+  let exponent = if expected == 0.0 {
+    -149
+  } else {
+    (((expected.abs().to_bits() >> 52) as i64) - 1023 - 23).max(-149)
+  };
+  let ulp = f64::from_bits(((exponent + 1023) as u64) << 52);
+  (actual - expected).abs() / ulp
+}
+
+/// Returns the absolute error of `actual`, which must be NaN if `expected` is.
+fn absolute_error(actual: f32, expected: f64) -> f64 {
+  if expected.is_nan() || actual.is_nan() {
+    return if expected.is_nan() && actual.is_nan() {
+      0.0
+    } else {
+      f64::INFINITY
+    };
+  }
+  (f64::from(actual) - expected).abs()
+}
+
+/// Calls `f` with chunks of the special values and every `step`th bit pattern.
+fn for_each_input(step: u64, mut f: impl FnMut([f32; 16])) {
+  f(SPECIAL_VALUES);
+
+  let mut bits = 0_u64;
+  while bits < 1 << 32 {
+    f(core::array::from_fn(|i| {
+      f32::from_bits(((bits + i as u64 * step) % (1 << 32)) as u32)
+    }));
+    bits += 16 * step;
+  }
+}
+
+/// Evaluates a function of `f32x16` with `f32x4`, `f32x8` and `f32x16` so
+/// that every vector width is checked.
+macro_rules! all_widths {
+  (|$x:ident| $body:expr) => {
+    |input: [f32; 16]| -> [[f32; 16]; 3] {
+      let mut narrow = [0.0; 16];
+      for (i, chunk) in input.chunks_exact(4).enumerate() {
+        let $x = f32x4::from(<[f32; 4]>::try_from(chunk).unwrap());
+        narrow[i * 4..][..4].copy_from_slice(&($body).to_array());
+      }
+      let mut medium = [0.0; 16];
+      for (i, chunk) in input.chunks_exact(8).enumerate() {
+        let $x = f32x8::from(<[f32; 8]>::try_from(chunk).unwrap());
+        medium[i * 8..][..8].copy_from_slice(&($body).to_array());
+      }
+      let $x = f32x16::from(input);
+      [narrow, medium, ($body).to_array()]
+    }
+  };
+}
+
+/// Evaluates a function of `f32x4` only, for the exhaustive tests.
+macro_rules! narrow {
+  (|$x:ident| $body:expr) => {
+    |input: [f32; 16]| -> [[f32; 16]; 1] {
+      let mut output = [0.0; 16];
+      for (i, chunk) in input.chunks_exact(4).enumerate() {
+        let $x = f32x4::from(<[f32; 4]>::try_from(chunk).unwrap());
+        output[i * 4..][..4].copy_from_slice(&($body).to_array());
+      }
+      [output]
+    }
+  };
+}
+
+/// Asserts that `f` is within `max_error` of `reference` for every input
+/// where `domain` holds, measuring with `error`.
+fn check<const W: usize>(
+  name: &str,
+  step: u64,
+  max_error: f64,
+  error: fn(f32, f64) -> f64,
+  domain: impl Fn(f32) -> bool,
+  reference: impl Fn(f64) -> f64,
+  f: impl Fn([f32; 16]) -> [[f32; 16]; W],
+) {
+  for_each_input(step, |input| {
+    for output in f(input) {
+      for (x, actual) in input.into_iter().zip(output) {
+        if !domain(x) {
+          continue;
+        }
+        let expected = reference(f64::from(x));
+        let error = error(actual, expected);
+        assert!(
+          error <= max_error,
+          "{name}({x:e}): expected: {expected:e}\n  actual: {actual:e} \
+           (error {error:e})",
+        );
+      }
+    }
+  });
+}
+
+fn any(_: f32) -> bool {
+  true
+}
+
+fn positive_normal(x: f32) -> bool {
+  x.is_normal() && x > 0.0
+}
+
+/// The domain of the fast trigonometric functions.
+fn small(x: f32) -> bool {
+  x.abs() <= 10000.0
+}
+
+/// Declares a test of each function, named after the function, in `sampled`
+/// for every vector width and in `exhaustive` for every input of `f32x4`.
+///
+/// The exhaustive tests only check one width, so that each of them takes a few
+/// minutes in release mode, and the test harness runs them in parallel.
+macro_rules! accuracy_tests {
+  ($(
+    $test:ident: |$x:ident| $f:expr, $max:expr, $error:expr, $domain:expr,
+    $reference:expr;
+  )*) => {
+    mod sampled {
+      use super::*;
+
+      $(
+        #[test]
+        fn $test() {
+          let f = all_widths!(|$x| $f);
+          check(stringify!($test), SAMPLE_STEP, $max, $error, $domain, $reference, f);
+        }
+      )*
+    }
+
+    mod exhaustive {
+      use super::*;
+
+      $(
+        #[test]
+        #[ignore = "checks all 2^32 inputs, run in release mode"]
+        fn $test() {
+          let f = narrow!(|$x| $f);
+          check(stringify!($test), 1, $max, $error, $domain, $reference, f);
+        }
+      )*
+    }
+  };
+}
+
+fn exp10(x: f64) -> f64 {
+  10.0_f64.powf(x)
+}
+
+fn recip_sqrt(x: f64) -> f64 {
+  x.sqrt().recip()
+}
+
+fn exp_normal(x: f32) -> bool {
+  x.is_finite() && x.exp().is_normal()
+}
+
+fn exp2_normal(x: f32) -> bool {
+  x.is_finite() && x.exp2().is_normal()
+}
+
+fn not_nan(x: f32) -> bool {
+  !x.is_nan()
+}
+
+/// The maximum absolute error of `fast_sin` and `fast_cos`.
+const SIN_COS_ERROR: f64 = 1.0 / 65536.0;
+
+accuracy_tests! {
+  precise_exp: |x| x.precise_exp(), 1.0, ulp_error, any, f64::exp;
+  precise_exp2: |x| x.precise_exp2(), 1.0, ulp_error, any, f64::exp2;
+  precise_exp10: |x| x.precise_exp10(), 1.0, ulp_error, any, exp10;
+  precise_exp_m1: |x| x.precise_exp_m1(), 1.0, ulp_error, any, f64::exp_m1;
+  precise_ln: |x| x.precise_ln(), 1.0, ulp_error, any, f64::ln;
+  precise_ln_1p: |x| x.precise_ln_1p(), 1.0, ulp_error, any, f64::ln_1p;
+  precise_log2: |x| x.precise_log2(), 1.0, ulp_error, any, f64::log2;
+  precise_log10: |x| x.precise_log10(), 1.0, ulp_error, any, f64::log10;
+  precise_sin: |x| x.precise_sin(), 1.0, ulp_error, any, f64::sin;
+  precise_cos: |x| x.precise_cos(), 1.0, ulp_error, any, f64::cos;
+  precise_tan: |x| x.precise_tan(), 1.0, ulp_error, any, f64::tan;
+  precise_asin: |x| x.precise_asin(), 1.0, ulp_error, any, f64::asin;
+  precise_acos: |x| x.precise_acos(), 1.0, ulp_error, any, f64::acos;
+  precise_atan: |x| x.precise_atan(), 1.0, ulp_error, any, f64::atan;
+  precise_sinh: |x| x.precise_sinh(), 1.0, ulp_error, any, f64::sinh;
+  precise_cosh: |x| x.precise_cosh(), 1.0, ulp_error, any, f64::cosh;
+  precise_tanh: |x| x.precise_tanh(), 1.0, ulp_error, any, f64::tanh;
+  precise_cbrt: |x| x.precise_cbrt(), 1.0, ulp_error, any, f64::cbrt;
+  precise_recip: |x| x.precise_recip(), 0.5, ulp_error, any, f64::recip;
+  precise_recip_sqrt: |x| x.precise_recip_sqrt(), 1.0, ulp_error, any,
+    recip_sqrt;
+  precise_sin_cos_sin: |x| x.precise_sin_cos().0, 1.0, ulp_error, any,
+    f64::sin;
+  precise_sin_cos_cos: |x| x.precise_sin_cos().1, 1.0, ulp_error, any,
+    f64::cos;
+
+  fast_exp2: |x| x.fast_exp2(), 64.0, ulp_error, exp2_normal, f64::exp2;
+  fast_exp: |x| x.fast_exp(), 128.0, ulp_error, exp_normal, f64::exp;
+  fast_ln: |x| x.fast_ln(), 256.0, ulp_error, positive_normal, f64::ln;
+  fast_log2: |x| x.fast_log2(), 256.0, ulp_error, positive_normal, f64::log2;
+  fast_sin: |x| x.fast_sin(), SIN_COS_ERROR, absolute_error, small, f64::sin;
+  fast_cos: |x| x.fast_cos(), SIN_COS_ERROR, absolute_error, small, f64::cos;
+  fast_atan: |x| x.fast_atan(), 128.0, ulp_error, not_nan, f64::atan;
+}
+
+#[test]
+fn test_binary_functions() {
+  for (y, x) in random_iter::<f32x16>().zip(random_iter::<f32x16>().skip(7)) {
+    let pairs = y.to_array().into_iter().zip(x.to_array());
+
+    let atan2 = y.precise_atan2(x).to_array();
+    let fast_atan2 = y.fast_atan2(x).to_array();
+    let powf = y.abs().precise_powf_simd(x).to_array();
+    for (i, (y, x)) in pairs.enumerate() {
+      let expected = f64::from(y).atan2(f64::from(x));
+      let actual = atan2[i];
+      assert!(
+        ulp_error(actual, expected) <= 1.0,
+        "precise_atan2({y:e}, {x:e}): expected: {expected:e}\n  actual: {actual:e}",
+      );
+      let actual = fast_atan2[i];
+      assert!(
+        ulp_error(actual, expected) <= 128.0
+          || y == 0.0 && x == 0.0
+          || y.is_infinite() && x.is_infinite()
+          || y.is_nan()
+          || x.is_nan(),
+        "fast_atan2({y:e}, {x:e}): expected: {expected:e}\n  actual: {actual:e}",
+      );
+
+      if !(y.is_finite() && x.is_finite()) {
+        continue;
+      }
+      let y = y.abs();
+      let expected = f64::from(y).powf(f64::from(x));
+      let actual = powf[i];
+      assert!(
+        ulp_error(actual, expected) <= 1.0,
+        "precise_powf_simd({y:e}, {x:e}): expected: {expected:e}\n  actual: {actual:e}",
+      );
+    }
+  }
+}
+
+/// Returns Γ(x) from the Lanczos approximation, which is accurate to about
+/// `1e-15` and so serves as the exact value for `f32`. Numbers below `0.5` use
+/// the reflection formula.
+fn gamma_reference(x: f64) -> f64 {
+  use core::f64::consts::PI;
+  const LANCZOS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+  ];
+
+  if x == 0.0 {
+    return 1.0 / x;
+  }
+  if x < 0.5 {
+    let n = x.round();
+    if x == n {
+      return f64::NAN;
+    }
+    let sin = (PI * (x - n)).sin();
+    let sin = if n % 2.0 == 0.0 { sin } else { -sin };
+    return PI / (sin * gamma_reference(1.0 - x));
+  }
+  if x > 200.0 {
+    return f64::INFINITY;
+  }
+  let x = x - 1.0;
+  let sum = LANCZOS[1..]
+    .iter()
+    .enumerate()
+    .fold(LANCZOS[0], |sum, (i, c)| sum + c / (x + (i + 1) as f64));
+  let t = x + 7.5;
+  // t^(x + 0.5) is split in two, as it overflows before the product does.
+  let power = t.powf(0.5 * (x + 0.5));
+  (2.0 * PI).sqrt() * power * ((-t).exp() * power) * sum
+}
+
+#[test]
+fn test_gamma() {
+  let gamma = all_widths!(|x| x.gamma());
+  check("gamma", SAMPLE_STEP, 8.0, ulp_error, any, gamma_reference, gamma);
+
+  // Γ(x) overflows close to zero, and has subnormal results from about -36.
+  let input = [
+    -1e-40,
+    -1e-39,
+    1e-40,
+    -2.938_736e-39,
+    -2.938_738e-39,
+    -3e-39,
+    -35.999_775,
+    -35.999_9,
+    -36.000_3,
+    -36.5,
+    -37.25,
+    -38.5,
+    -39.75,
+    -41.5,
+    -34.000_01,
+    -1e-45,
+  ];
+  for output in gamma(input) {
+    for (x, actual) in input.into_iter().zip(output) {
+      let expected = gamma_reference(f64::from(x));
+      let error = ulp_error(actual, expected);
+      assert!(
+        error <= 8.0,
+        "gamma({x:e}): expected: {expected:e}\n  actual: {actual:e} \
+         (error {error:e})",
+      );
+    }
+  }
+}
+
+#[test]
+fn test_gamma_f64() {
+  // Values from an arbitrary precision implementation, where the results
+  // overflow or are close to the subnormal numbers.
+  let values = [
+    (-171.999_904_6, 4.913_132_419_133_353e-308),
+    (-171.5, 1.931_626_543_171_2e-310),
+    (-170.343_093_621_115_4, -8.425_147_266_464_697e-308),
+    (-170.5, -3.312_739_521_538_607_4e-308),
+    (-168.25, -4.880_764_597_644_008_5e-303),
+    (-100.5, -3.353_690_819_807_678_7e-159),
+    (-10.1, -2.213_416_583_085_618_5e-6),
+    (-1e-300, -9.999_999_999_999_999e299),
+    (-5.562_684_646_268_01e-309, -1.797_693_134_862_314_3e308),
+    (-5.562_684_646_268_003e-309, f64::NEG_INFINITY),
+    (-1e-310, f64::NEG_INFINITY),
+    (-5e-324, f64::NEG_INFINITY),
+    (5e-324, f64::INFINITY),
+    (170.657_898_336_911_36, 1.251_567_597_690_157e306),
+    (171.5, 9.483_367_566_824_8e307),
+  ];
+  for (x, expected) in values {
+    let results = [
+      f64x2::splat(x).gamma().to_array()[0],
+      f64x4::splat(x).gamma().to_array()[0],
+      f64x8::splat(x).gamma().to_array()[0],
+    ];
+    for actual in results {
+      let ulp = expected.abs().next_up() - expected.abs();
+      assert!(
+        actual == expected || (actual - expected).abs() <= 10.0 * ulp,
+        "gamma({x:e}): expected: {expected:e}\n  actual: {actual:e}",
+      );
+    }
+  }
+}
@@ -0,0 +1,480 @@
+//! Pins the bits of float results with the `deterministic` feature, which must
+//! be the same on every platform and with every set of target features.
+//!
+//! Each operation is evaluated for a fixed list of inputs, and the bits of the
+//! results are hashed and compared to the hash in `EXPECTED_*`. The vector
+//! widths must agree with each other too, except for reductions.
+
+use wide::{SimdVector, f32x4, f32x8, f32x16, f64x2, f64x4, f64x8};
+
+/// Returns the bits of an element of a SIMD vector.
+trait Bits: Copy {
+  fn bits(self) -> u64;
+}
+
+impl Bits for f32 {
+  fn bits(self) -> u64 {
+    self.to_bits().into()
+  }
+}
+
+impl Bits for f64 {
+  fn bits(self) -> u64 {
+    self.to_bits()
+  }
+}
+
+impl Bits for i32 {
+  fn bits(self) -> u64 {
+    (self as u32).into()
+  }
+}
+
+impl Bits for i64 {
+  fn bits(self) -> u64 {
+    self as u64
+  }
+}
+
+/// Hashes bit patterns with 64-bit FNV-1a.
+fn hash(bits: impl IntoIterator<Item = u64>) -> u64 {
+  bits.into_iter().fold(0xcbf29ce484222325, |hash, bits| {
+    bits.to_le_bytes().into_iter().fold(hash, |hash, byte| {
+      (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+  })
+}
+
+/// Generates pseudo-random bit patterns with a fixed seed.
+struct Lcg(u64);
+
+impl Lcg {
+  fn next(&mut self) -> u64 {
+    self.0 = self
+      .0
+      .wrapping_mul(6364136223846793005)
+      .wrapping_add(1442695040888963407);
+    self.0 >> 11
+  }
+
+  /// Returns a number in `[-range, range)`.
+  fn uniform(&mut self, range: f64) -> f64 {
+    (self.next() as f64 / (1_u64 << 53) as f64 * 2.0 - 1.0) * range
+  }
+}
+
+/// The number of inputs, a multiple of every vector width.
+const COUNT: usize = 1024;
+
+/// Returns the inputs: special values, then an equal mix of random bit
+/// patterns and uniformly distributed numbers of small and medium size.
+macro_rules! inputs {
+  ($T:ident, $to_float:expr) => {{
+    let mut inputs: Vec<$T> = vec![
+      0.0,
+      -0.0,
+      1.0,
+      -1.0,
+      0.5,
+      -0.5,
+      2.5,
+      -2.5,
+      $T::INFINITY,
+      $T::NEG_INFINITY,
+      $T::NAN,
+      -$T::NAN,
+      // signaling NaN
+      $T::from_bits($T::INFINITY.to_bits() | 1),
+      -$T::from_bits($T::INFINITY.to_bits() | 1),
+      $T::MIN_POSITIVE,
+      $T::MIN_POSITIVE / 4.0,
+      $T::MAX,
+      $T::MIN,
+    ];
+    let mut lcg = Lcg(0x0123_4567_89ab_cdef);
+    while inputs.len() < COUNT {
+      inputs.push(match inputs.len() % 3 {
+        0 => $to_float(lcg.next()),
+        1 => lcg.uniform(4.0) as $T,
+        _ => lcg.uniform(100.0) as $T,
+      });
+    }
+    inputs
+  }};
+}
+
+/// Hashes the results of `$f` for each vector width, where `$x`, `$y` and `$z`
+/// are vectors of the inputs, asserting that all widths agree.
+macro_rules! hash_widths {
+  ([$($V:ident),*], $inputs:expr, |$x:ident, $y:ident, $z:ident| $f:expr) => {{
+    let (xs, ys, zs) = $inputs;
+    let results = [$({
+      let mut bits = Vec::new();
+      for i in (0..COUNT).step_by($V::LANES) {
+        let $x = $V::from(&xs[i..i + $V::LANES]);
+        let $y = $V::from(&ys[i..i + $V::LANES]);
+        let $z = $V::from(&zs[i..i + $V::LANES]);
+        bits.extend(($f).to_array().map(Bits::bits));
+      }
+      (stringify!($V), bits)
+    }),*];
+    for (name, bits) in &results[1..] {
+      assert!(*bits == results[0].1, "{name} differs from {}", results[0].0);
+    }
+    hash(results[0].1.iter().copied())
+  }};
+}
+
+/// Collects the hash of each listed operation, with the operation name.
+macro_rules! hashes {
+  (
+    $widths:tt, $inputs:expr, |$x:ident, $y:ident, $z:ident|;
+    $($name:literal => $f:expr,)*
+  ) => {
+    vec![$((
+      $name,
+      hash_widths!($widths, $inputs, |$x, $y, $z| {
+        let _ = (&$x, &$y, &$z);
+        $f
+      }),
+    ),)*]
+  };
+}
+
+/// Collects the hashes of the operations of every float type.
+macro_rules! float_hashes {
+  ($widths:tt, $inputs:expr) => {
+    hashes! {
+      $widths, $inputs, |x, y, z|;
+      "add" => x + y,
+      "sub" => x - y,
+      "mul" => x * y,
+      "div" => x / y,
+      "rem" => x % y,
+      "mul_add" => x.mul_add(y, z),
+      "mul_sub" => x.mul_sub(y, z),
+      "mul_neg_add" => x.mul_neg_add(y, z),
+      "mul_neg_sub" => x.mul_neg_sub(y, z),
+      "sqrt" => x.sqrt(),
+      "recip" => x.recip(),
+      "recip_sqrt" => x.recip_sqrt(),
+      "rsqrt" => x.rsqrt(),
+      "max" => x.max(y),
+      "min" => x.min(y),
+      "fast_max" => x.fast_max(y),
+      "fast_min" => x.fast_min(y),
+      "clamp" => x.clamp(y.fast_min(z), z.fast_max(y)),
+      "fast_clamp" => x.fast_clamp(y.fast_min(z), z.fast_max(y)),
+      "floor" => x.floor(),
+      "ceil" => x.ceil(),
+      "round" => x.round(),
+      "round_ties_even" => x.round_ties_even(),
+      "trunc" => x.trunc(),
+      "fract" => x.fract(),
+      "round_int" => x.round_int(),
+      "fast_round_int" => x.fast_round_int(),
+      "trunc_int" => x.trunc_int(),
+      "fast_trunc_int" => x.fast_trunc_int(),
+      "div_euclid" => x.div_euclid(y),
+      "rem_euclid" => x.rem_euclid(y),
+      "exp" => x.exp(),
+      "exp2" => x.exp2(),
+      "exp10" => x.exp10(),
+      "exp_m1" => x.exp_m1(),
+      "ln" => x.ln(),
+      "ln_1p" => x.ln_1p(),
+      "log2" => x.log2(),
+      "log10" => x.log10(),
+      "log" => x.log(y),
+      "powf_simd" => x.powf_simd(y),
+      "powi" => x.powi(7),
+      "cbrt" => x.cbrt(),
+      "hypot" => x.hypot(y),
+      "sin" => x.sin(),
+      "cos" => x.cos(),
+      "tan" => x.tan(),
+      "sin_pi" => x.sin_pi(),
+      "cos_pi" => x.cos_pi(),
+      "tan_pi" => x.tan_pi(),
+      "asin" => x.asin(),
+      "acos" => x.acos(),
+      "atan" => x.atan(),
+      "atan2" => x.atan2(y),
+      "sinh" => x.sinh(),
+      "cosh" => x.cosh(),
+      "tanh" => x.tanh(),
+      "asinh" => x.asinh(),
+      "acosh" => x.acosh(),
+      "atanh" => x.atanh(),
+      "erf" => x.erf(),
+      "erfc" => x.erfc(),
+      "gamma" => x.gamma(),
+      "ln_gamma" => x.ln_gamma(),
+    }
+  };
+}
+
+/// Returns the hashes of the reductions, which differ between widths.
+macro_rules! reduction_hashes {
+  ($($V:ident),*; $x:ident) => {
+    vec![$(
+      (
+        concat!("reduce_add ", stringify!($V)),
+        hash($x.chunks_exact($V::LANES).map(|x| $V::from(x).reduce_add().bits())),
+      ),
+      (
+        concat!("reduce_mul ", stringify!($V)),
+        hash($x.chunks_exact($V::LANES).map(|x| $V::from(x).reduce_mul().bits())),
+      ),
+    )*]
+  };
+}
+
+/// Asserts that every hash matches, listing all operations that do not.
+fn check(actual: Vec<(&str, u64)>, expected: &[(&str, u64)]) {
+  let expected_names: Vec<&str> =
+    expected.iter().map(|&(name, _)| name).collect();
+  let actual_names: Vec<&str> = actual.iter().map(|&(name, _)| name).collect();
+  assert_eq!(actual_names, expected_names);
+
+  let mismatches: Vec<String> = actual
+    .iter()
+    .zip(expected)
+    .filter(|(actual, expected)| actual.1 != expected.1)
+    .map(|((name, hash), _)| format!("(\"{name}\", {hash:#018x}),"))
+    .collect();
+  assert!(
+    mismatches.is_empty(),
+    "mismatched hashes:\n{}",
+    mismatches.join("\n")
+  );
+}
+
+#[test]
+fn test_deterministic_f32() {
+  let x = inputs!(f32, |bits: u64| f32::from_bits(bits as u32));
+  let mut y = x.clone();
+  y.rotate_left(7);
+  let mut z = x.clone();
+  z.rotate_left(100);
+
+  let inputs = (&x, &y, &z);
+  let mut hashes = float_hashes!([f32x4, f32x8, f32x16], inputs);
+  hashes.extend(hashes! {
+    [f32x4, f32x8, f32x16], inputs, |x, y, z|;
+    "fast_exp" => x.fast_exp(),
+    "fast_exp2" => x.fast_exp2(),
+    "fast_ln" => x.fast_ln(),
+    "fast_log2" => x.fast_log2(),
+    "fast_sin" => x.fast_sin(),
+    "fast_cos" => x.fast_cos(),
+    "fast_atan" => x.fast_atan(),
+    "fast_atan2" => x.fast_atan2(y),
+    "precise_exp" => x.precise_exp(),
+    "precise_ln" => x.precise_ln(),
+    "precise_sin" => x.precise_sin(),
+    "precise_cos" => x.precise_cos(),
+    "precise_atan2" => x.precise_atan2(y),
+    "precise_powf_simd" => x.precise_powf_simd(y),
+    "precise_recip" => x.precise_recip(),
+    "precise_recip_sqrt" => x.precise_recip_sqrt(),
+  });
+  hashes.extend(reduction_hashes!(f32x4, f32x8, f32x16; x));
+
+  check(hashes, EXPECTED_F32);
+}
+
+#[test]
+fn test_deterministic_f64() {
+  let x = inputs!(f64, f64::from_bits);
+  let mut y = x.clone();
+  y.rotate_left(7);
+  let mut z = x.clone();
+  z.rotate_left(100);
+
+  let mut hashes = float_hashes!([f64x2, f64x4, f64x8], (&x, &y, &z));
+  hashes.extend(reduction_hashes!(f64x2, f64x4, f64x8; x));
+
+  check(hashes, EXPECTED_F64);
+}
+
+#[test]
+fn test_deterministic_examples() {
+  // `mul_add` always rounds twice, this would be `-2^-48` if fused.
+  let a = f32x4::splat(1.0 + f32::EPSILON);
+  let b = f32x4::splat(1.0 - f32::EPSILON);
+  assert_eq!(a.mul_add(b, f32x4::splat(-1.0)).to_array(), [0.0; 4]);
+
+  // `recip` is exact instead of the approximation of `rcpps`.
+  assert_eq!(f32x4::splat(3.0).recip().to_array(), [1.0 / 3.0; 4]);
+
+  // NaN results are always `NAN`, whichever NaN the platform generates.
+  let nan = (f32x4::ZERO * f32x4::INFINITY).to_array();
+  assert_eq!(nan.map(f32::to_bits), [f32::NAN.to_bits(); 4]);
+  let nan = f64x2::splat(-1.0).sqrt().to_array();
+  assert_eq!(nan.map(f64::to_bits), [f64::NAN.to_bits(); 2]);
+  let nan = (f32x4::splat(-f32::NAN) + f32x4::ONE).to_array();
+  assert_eq!(nan.map(f32::to_bits), [f32::NAN.to_bits(); 4]);
+}
+
+const EXPECTED_F32: &[(&str, u64)] = &[
+  ("add", 0x75b8d45dff10094d),
+  ("sub", 0x085d9ff4cbda9834),
+  ("mul", 0x8d55f8f0189be835),
+  ("div", 0xa0b763ab501183c5),
+  ("rem", 0xb79b4f65919546fb),
+  ("mul_add", 0x50f5533b7c18618f),
+  ("mul_sub", 0xf40a3619bdff6617),
+  ("mul_neg_add", 0xc506c0d1341fe497),
+  ("mul_neg_sub", 0x16909b240b04e70f),
+  ("sqrt", 0x60708df0f0d94157),
+  ("recip", 0xaf3ad40c50cc6080),
+  ("recip_sqrt", 0xc75d7f5b8fb66c6d),
+  ("rsqrt", 0x23445fd943928df8),
+  ("max", 0x2166b510abdac0d7),
+  ("min", 0x5d17b0398f0bef1f),
+  ("fast_max", 0x3ba191bd3807f789),
+  ("fast_min", 0x49d854e4546f9301),
+  ("clamp", 0xbe5edbec68822b16),
+  ("fast_clamp", 0xe3532d3223933d5e),
+  ("floor", 0x1a5834929f7ab08d),
+  ("ceil", 0x166b542f7f3d2ae7),
+  ("round", 0xfcb49431cc68dc6e),
+  ("round_ties_even", 0xe801eeea5ea50422),
+  ("trunc", 0xe85a5faa258d747b),
+  ("fract", 0x0dbd28d52fa9ef19),
+  ("round_int", 0x134049f6703ef9f0),
+  ("fast_round_int", 0x134049f6703ef9f0),
+  ("trunc_int", 0x13d4d3f298028bb6),
+  ("fast_trunc_int", 0x13d4d3f298028bb6),
+  ("div_euclid", 0x0e6c32aa90054f79),
+  ("rem_euclid", 0xc3e5c9c92107118c),
+  ("exp", 0x50cf611034229d97),
+  ("exp2", 0x8ad361b57e087a3f),
+  ("exp10", 0x325710148fc537d7),
+  ("exp_m1", 0x99230bcb7077adc8),
+  ("ln", 0xd1de93c1f2103819),
+  ("ln_1p", 0xf6368e81dc4106ad),
+  ("log2", 0xb4de40de770e3b95),
+  ("log10", 0x171df572b12fddf0),
+  ("log", 0x6c08d5e84c1a8810),
+  ("powf_simd", 0x59c13c784644432d),
+  ("powi", 0x67ab32abcfe285ae),
+  ("cbrt", 0xfdd5ef554e08f262),
+  ("hypot", 0xd7be57a457732f12),
+  ("sin", 0x2fdccdb076be227b),
+  ("cos", 0x34c25e9ee66121ac),
+  ("tan", 0x08fcfa6ec8997ceb),
+  ("sin_pi", 0x3a4060f26bc8255e),
+  ("cos_pi", 0x28c9b6ec5a32b822),
+  ("tan_pi", 0x28ab9e00fb1c0ada),
+  ("asin", 0x22d85a61ee0b03bd),
+  ("acos", 0x28e4d17d91abdef1),
+  ("atan", 0xd80e9c1c975ba319),
+  ("atan2", 0xd59685dd63dd890f),
+  ("sinh", 0xe9b5e0c285ed092e),
+  ("cosh", 0xb3ceba71d1a70e88),
+  ("tanh", 0xed4d04606f3fc37b),
+  ("asinh", 0x6e1693cc25cdd15a),
+  ("acosh", 0xb53136d6dfcb8f02),
+  ("atanh", 0xea2ade6f8224fa53),
+  ("erf", 0x0fb771a4f48739d6),
+  ("erfc", 0x56765fce1b806cc4),
+  ("gamma", 0x3b4e697bd8eaea6e),
+  ("ln_gamma", 0xf658d42df21905e7),
+  ("fast_exp", 0x25ff77dabc17b5aa),
+  ("fast_exp2", 0xb4022afdc361b345),
+  ("fast_ln", 0x210780944338caba),
+  ("fast_log2", 0x01df052332382216),
+  ("fast_sin", 0xac3d2833e9aa64ec),
+  ("fast_cos", 0xbf9cb963126935e5),
+  ("fast_atan", 0x657df7a505ae5cfd),
+  ("fast_atan2", 0x3538206642a3ae59),
+  ("precise_exp", 0x85926455655406b7),
+  ("precise_ln", 0x84057ffb2c87ebdf),
+  ("precise_sin", 0x6f135b82017efa84),
+  ("precise_cos", 0xe39dd3eaafe89d3b),
+  ("precise_atan2", 0x7a0991841a1bce14),
+  ("precise_powf_simd", 0xedff56c060ca2e41),
+  ("precise_recip", 0xaf3ad40c50cc6080),
+  ("precise_recip_sqrt", 0xa12e8ce5662d489f),
+  ("reduce_add f32x4", 0xeb32d0c3869f4cd9),
+  ("reduce_mul f32x4", 0x5698b292d89da68d),
+  ("reduce_add f32x8", 0xe878704eaef5c276),
+  ("reduce_mul f32x8", 0xc06ad3d42232c607),
+  ("reduce_add f32x16", 0x8f7db6b43bcc6f29),
+  ("reduce_mul f32x16", 0x055b63ad165ada17),
+];
+
+const EXPECTED_F64: &[(&str, u64)] = &[
+  ("add", 0x7b84834772a6d772),
+  ("sub", 0x3782abaaa2471470),
+  ("mul", 0xa1fc84359fbb83d4),
+  ("div", 0x148afe34ff3cdc77),
+  ("rem", 0xb39cea75b3ff063c),
+  ("mul_add", 0x8ddb72d9cdd19a08),
+  ("mul_sub", 0x0a9afd21891f08f1),
+  ("mul_neg_add", 0x69723a4206e1c271),
+  ("mul_neg_sub", 0x992ab0cecfdfeb88),
+  ("sqrt", 0xfcfc48e51b571cb5),
+  ("recip", 0xf61771e0c0b37923),
+  ("recip_sqrt", 0x96e5a38016f03619),
+  ("rsqrt", 0xd2adf52aa8b6a5d2),
+  ("max", 0x4724f3a9debb9dce),
+  ("min", 0xa9ba43f91b2c1b0c),
+  ("fast_max", 0x0defea1b03944301),
+  ("fast_min", 0x7c4b93494c1fd2cb),
+  ("clamp", 0x3a153181ae8e66d9),
+  ("fast_clamp", 0x8089bff9282131d4),
+  ("floor", 0x85661786a1815044),
+  ("ceil", 0x851baa4bcaae16ee),
+  ("round", 0x894d25d2380e0dd8),
+  ("round_ties_even", 0x7eda99f8b768a038),
+  ("trunc", 0x042c43eb4bb74afc),
+  ("fract", 0x6459e493e80edcab),
+  ("round_int", 0x00ac2e7bea7d0bb5),
+  ("fast_round_int", 0x00ac2e7bea7d0bb5),
+  ("trunc_int", 0xfe499e9cdfa1c536),
+  ("fast_trunc_int", 0xfe499e9cdfa1c536),
+  ("div_euclid", 0x13eeca852e3cffd5),
+  ("rem_euclid", 0x720bef77613a82e1),
+  ("exp", 0xa19db06e284d9678),
+  ("exp2", 0xb499b4caa4fe536f),
+  ("exp10", 0x571b01c9c7eb8207),
+  ("exp_m1", 0xa7ba28d7f9754f88),
+  ("ln", 0x6f0161d911745773),
+  ("ln_1p", 0x1fda8c9e954a76d3),
+  ("log2", 0xb686400bfed8447b),
+  ("log10", 0xce99facc563631c0),
+  ("log", 0x30ee076a89a1a65d),
+  ("powf_simd", 0x77beb4a017d0fad8),
+  ("powi", 0x4e0d89979cbf1bc6),
+  ("cbrt", 0x3043923d844e4bf5),
+  ("hypot", 0xc31b5bcb2db5749f),
+  ("sin", 0xaae17573ba85c212),
+  ("cos", 0x010952d8f520d84f),
+  ("tan", 0x5611ed6d5c3c6eda),
+  ("sin_pi", 0xaf25f4e8efd8c92e),
+  ("cos_pi", 0x77824202a2f1d7a9),
+  ("tan_pi", 0xe5e86b447a25c71c),
+  ("asin", 0xfd4fb256649da975),
+  ("acos", 0xc548e6394e57e9ec),
+  ("atan", 0x6ce31753bcd4ae05),
+  ("atan2", 0x72beade6971b7791),
+  ("sinh", 0x986cfcfdda9762d6),
+  ("cosh", 0x7345a68e407a5e44),
+  ("tanh", 0xb78a1cbf377baa5a),
+  ("asinh", 0x66b030913e5e6661),
+  ("acosh", 0x3ca9999f8543069f),
+  ("atanh", 0xc33b9cc0bb1fafe1),
+  ("erf", 0x856143fe6968a828),
+  ("erfc", 0xa6fb2bf7514abf86),
+  ("gamma", 0x55752782e50e303b),
+  ("ln_gamma", 0xea875fc7365d5ca1),
+  ("reduce_add f64x2", 0x739784f79b8757d6),
+  ("reduce_mul f64x2", 0xa6bed550fdca5fd3),
+  ("reduce_add f64x4", 0x8765b57541d3139b),
+  ("reduce_mul f64x4", 0xff96ce671f56481c),
+  ("reduce_add f64x8", 0xc93378f093596d59),
+  ("reduce_mul f64x8", 0x3dbcddc3d8a3015d),
+];
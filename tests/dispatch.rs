@@ -0,0 +1,81 @@
+use wide::{
+  SimdFloat, SimdInt, SimdVector,
+  dispatch::{self, Kernel, Level, Width},
+};
+
+struct Dot<'a>(&'a [f32], &'a [f32]);
+
+impl Kernel for Dot<'_> {
+  type Output = f32;
+
+  #[inline(always)]
+  fn run<W: Width>(self) -> f32 {
+    let lanes = W::F32::LANES;
+    let mut acc = W::F32::ZERO;
+    for (a, b) in self.0.chunks(lanes).zip(self.1.chunks(lanes)) {
+      let a = W::F32::load_or(a, 0.0);
+      let b = W::F32::load_or(b, 0.0);
+      acc = a.mul_add(b, acc);
+    }
+    acc.reduce_add()
+  }
+}
+
+struct AbsInPlace<'a>(&'a mut [i32]);
+
+impl Kernel for AbsInPlace<'_> {
+  type Output = usize;
+
+  #[inline(always)]
+  fn run<W: Width>(self) -> usize {
+    let lanes = W::I32::LANES;
+    for chunk in self.0.chunks_mut(lanes) {
+      W::I32::load_or(chunk, 0).abs().store_partial(chunk);
+    }
+    W::BITS
+  }
+}
+
+fn supported_levels() -> impl Iterator<Item = Level> {
+  [Level::Baseline, Level::Avx2, Level::Avx512]
+    .into_iter()
+    .filter(|&level| level <= dispatch::detect())
+}
+
+#[test]
+fn test_dispatch_levels() {
+  let a = Vec::from_iter((0..100).map(|i| i as f32));
+  let b = Vec::from_iter((0..100).map(|i| (i % 3) as f32));
+  let expected = a.iter().zip(&b).map(|(x, y)| x * y).sum::<f32>();
+
+  for level in supported_levels() {
+    assert_eq!(dispatch::run_at(level, Dot(&a, &b)), expected, "{level:?}");
+  }
+  assert_eq!(dispatch::run(Dot(&a, &b)), expected);
+}
+
+#[test]
+fn test_dispatch_width() {
+  for level in supported_levels() {
+    let mut values = Vec::from_iter((0..37).map(|i| 18 - i));
+    let bits = dispatch::run_at(level, AbsInPlace(&mut values));
+
+    let expected_bits = match level {
+      Level::Baseline => 128,
+      Level::Avx2 => 256,
+      _ => 512,
+    };
+    assert_eq!(bits, expected_bits);
+    assert_eq!(values, Vec::from_iter((0..37).map(|i: i32| (18 - i).abs())));
+  }
+}
+
+#[test]
+fn test_dispatch_unsupported_level() {
+  if dispatch::detect() < Level::Avx512 {
+    let result = std::panic::catch_unwind(|| {
+      dispatch::run_at(Level::Avx512, Dot(&[], &[]))
+    });
+    assert!(result.is_err());
+  }
+}
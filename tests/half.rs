@@ -0,0 +1,228 @@
+use wide::{
+  ParseSimdError, bf16x8, bf16x16, f16x8, f16x16, f32x8, f32x16, u16x8, u16x16,
+};
+
+use crate::utils::random_iter;
+
+/// A 16-bit float format, for computing expected values with `f64`.
+struct Format {
+  mantissa_bits: u32,
+  exponent_bits: u32,
+}
+
+const F16: Format = Format { mantissa_bits: 10, exponent_bits: 5 };
+const BF16: Format = Format { mantissa_bits: 7, exponent_bits: 8 };
+
+impl Format {
+  fn infinity(&self) -> u16 {
+    ((1 << self.exponent_bits) - 1) << self.mantissa_bits
+  }
+
+  fn value(&self, bits: u16) -> f64 {
+    let sign = if bits & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let unsigned = bits & 0x7fff;
+    let bias = (1 << (self.exponent_bits - 1)) - 1;
+    let exponent = i32::from(unsigned >> self.mantissa_bits);
+    let mantissa = f64::from(unsigned & ((1 << self.mantissa_bits) - 1));
+    let scale = 2.0f64.powi(self.mantissa_bits as i32);
+    sign
+      * if unsigned > self.infinity() {
+        f64::NAN
+      } else if unsigned == self.infinity() {
+        f64::INFINITY
+      } else if exponent == 0 {
+        mantissa / scale * 2.0f64.powi(1 - bias)
+      } else {
+        (1.0 + mantissa / scale) * 2.0f64.powi(exponent - bias)
+      }
+  }
+
+  /// Rounds `x` to the nearest value, with ties to even.
+  fn round(&self, x: f32) -> u16 {
+    if x.is_nan() {
+      return self.infinity() | 1;
+    }
+    let sign = if x.is_sign_negative() { 0x8000 } else { 0 };
+    let x = f64::from(x.abs());
+
+    // Finite positive values are ordered like their bits.
+    let max = self.infinity() - 1;
+    let (mut below, mut high) = (0, max);
+    while below < high {
+      let middle = (below + high).div_ceil(2);
+      if self.value(middle) <= x {
+        below = middle;
+      } else {
+        high = middle - 1;
+      }
+    }
+    let above = below + 1;
+    let above_value = if above == self.infinity() {
+      2.0 * self.value(max) - self.value(max - 1)
+    } else {
+      self.value(above)
+    };
+    let (below_diff, above_diff) = (x - self.value(below), above_value - x);
+    if below_diff < above_diff || (below_diff == above_diff && below % 2 == 0) {
+      sign | below
+    } else {
+      sign | above
+    }
+  }
+}
+
+fn assert_same(actual: f32, expected: f64, bits: u16) {
+  if expected.is_nan() {
+    assert!(actual.is_nan(), "{bits:#06x}: {actual}");
+  } else {
+    assert_eq!(f64::from(actual).to_bits(), expected.to_bits(), "{bits:#06x}");
+  }
+}
+
+fn assert_same_half(actual: u16, expected: u16, format: &Format, x: f32) {
+  if expected & 0x7fff > format.infinity() {
+    assert!(actual & 0x7fff > format.infinity(), "{x}: {actual:#06x}");
+  } else {
+    assert_eq!(actual, expected, "{x}");
+  }
+}
+
+/// Values to convert to the 16-bit formats: every value of the format, the
+/// values halfway between them, and random values.
+fn f32_values(format: &Format) -> Vec<f32> {
+  let mut values = Vec::new();
+  for bits in 0..=u16::MAX {
+    let value = format.value(bits);
+    let next = format.value(bits.wrapping_add(1));
+    values.push(value as f32);
+    values.push(((value + next) / 2.0) as f32);
+  }
+  values.extend(random_iter::<f32x8>().flat_map(f32x8::to_array));
+  values.extend([65519.99, 65520.0, 1e-8, -3e-8, f32::MAX, f32::MIN_POSITIVE]);
+  values
+}
+
+#[test]
+fn test_f16_to_f32() {
+  for start in (0..=u16::MAX).step_by(16) {
+    let bits: [u16; 16] = std::array::from_fn(|i| start + i as u16);
+
+    let low = f16x8::from_bits(u16x8::new(bits[..8].try_into().unwrap()));
+    for (i, actual) in low.to_f32x8().to_array().into_iter().enumerate() {
+      assert_same(actual, F16.value(bits[i]), bits[i]);
+    }
+    let all = f16x16::from_bits(u16x16::new(bits));
+    for (i, actual) in f32x16::from(all).to_array().into_iter().enumerate() {
+      assert_same(actual, F16.value(bits[i]), bits[i]);
+    }
+  }
+}
+
+#[test]
+fn test_f16_from_f32() {
+  for chunk in f32_values(&F16).chunks_exact(16) {
+    let values = f32x16::new(chunk.try_into().unwrap());
+    let actual = f16x16::from_f32x16(values).to_bits().to_array();
+    for (i, &x) in chunk.iter().enumerate() {
+      assert_same_half(actual[i], F16.round(x), &F16, x);
+    }
+
+    let values = f32x8::new(chunk[..8].try_into().unwrap());
+    let actual = f16x8::from_f32x8(values).to_bits().to_array();
+    for (i, &x) in chunk[..8].iter().enumerate() {
+      assert_same_half(actual[i], F16.round(x), &F16, x);
+    }
+  }
+}
+
+#[test]
+fn test_bf16_to_f32() {
+  for start in (0..=u16::MAX).step_by(16) {
+    let bits: [u16; 16] = std::array::from_fn(|i| start + i as u16);
+
+    let low = bf16x8::from_bits(u16x8::new(bits[..8].try_into().unwrap()));
+    for (i, actual) in f32x8::from(low).to_array().into_iter().enumerate() {
+      assert_same(actual, BF16.value(bits[i]), bits[i]);
+    }
+    let all = bf16x16::from_bits(u16x16::new(bits));
+    for (i, actual) in all.to_f32x16().to_array().into_iter().enumerate() {
+      assert_same(actual, BF16.value(bits[i]), bits[i]);
+    }
+  }
+}
+
+#[test]
+fn test_bf16_from_f32() {
+  for chunk in f32_values(&BF16).chunks_exact(16) {
+    let values = f32x16::new(chunk.try_into().unwrap());
+    let actual = bf16x16::from_f32x16(values).to_bits().to_array();
+    for (i, &x) in chunk.iter().enumerate() {
+      assert_same_half(actual[i], BF16.round(x), &BF16, x);
+    }
+
+    let values = f32x8::new(chunk[..8].try_into().unwrap());
+    let actual = bf16x8::from_f32x8(values).to_bits().to_array();
+    for (i, &x) in chunk[..8].iter().enumerate() {
+      assert_same_half(actual[i], BF16.round(x), &BF16, x);
+    }
+  }
+}
+
+#[test]
+fn test_half_arithmetic() {
+  let a = f16x8::from_f32x8(f32x8::from([
+    1.0, 2.5, -3.0, 1e-3, 60000.0, 0.1, 0.0, 7.0,
+  ]));
+  let b = f16x8::from_f32x8(f32x8::from([
+    2.0, 0.5, 3.0, 1e-3, 60000.0, 0.2, -0.0, 3.0,
+  ]));
+
+  let expected = f16x8::from_f32x8(a.to_f32x8() + b.to_f32x8());
+  assert_eq!((a + b).to_bits(), expected.to_bits());
+  assert_eq!((a + b).to_f32x8().to_array()[4], f32::INFINITY);
+  let expected = f16x8::from_f32x8(a.to_f32x8() / b.to_f32x8());
+  assert_eq!((a / b).to_bits(), expected.to_bits());
+  assert_eq!((a - a).to_f32x8(), f32x8::ZERO);
+  assert_eq!((-a).to_f32x8(), -a.to_f32x8());
+
+  let a = bf16x16::from_f32x16(f32x16::splat(3.0));
+  let b = bf16x16::from_f32x16(f32x16::splat(0.25));
+  assert_eq!((a * b).to_f32x16(), f32x16::splat(0.75));
+  assert_eq!(format!("{:?}", bf16x8::default()), format!("{:?}", f32x8::ZERO));
+}
+
+#[test]
+fn test_half_eq() {
+  let a =
+    f16x8::from_f32x8(f32x8::from([1.0, 0.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]));
+  assert_eq!(a, a);
+  assert_ne!(a, a + f16x8::from_f32x8(f32x8::splat(1.0)));
+
+  // Compared as floats, not as bits.
+  let b = a.to_bits() | u16x8::new([0, 0x8000, 0, 0, 0, 0, 0, 0]);
+  assert_eq!(a, f16x8::from_bits(b));
+  let nan = f16x16::from_f32x16(f32x16::splat(f32::NAN));
+  assert_ne!(nan, nan);
+
+  let a = bf16x16::from_f32x16(f32x16::splat(-0.0));
+  assert_eq!(a, bf16x16::default());
+  assert_ne!(bf16x8::from_f32x8(f32x8::ONE), bf16x8::default());
+}
+
+#[test]
+fn test_half_from_str() {
+  for bits in random_iter::<[u16; 8]>() {
+    let value = f16x8::from_bits(u16x8::new(bits));
+    let parsed: f16x8 = format!("{value:?}").parse().unwrap();
+    assert_eq!(format!("{parsed:?}"), format!("{value:?}"));
+
+    let value = bf16x8::from_bits(u16x8::new(bits));
+    let parsed: bf16x8 = format!("{value:?}").parse().unwrap();
+    assert_eq!(format!("{parsed:?}"), format!("{value:?}"));
+  }
+  assert_eq!("1.5".parse::<f16x16>().unwrap().to_f32x16(), f32x16::splat(1.5));
+  assert_eq!(
+    "[1, 2]".parse::<bf16x16>().map(bf16x16::to_bits),
+    Err(ParseSimdError::LaneCount { expected: 16, found: 2 })
+  );
+}
@@ -1,6 +1,13 @@
+mod accuracy;
+#[cfg(feature = "deterministic")]
+mod deterministic;
+mod dispatch;
+mod half;
+mod mask;
 mod simd;
 mod simd_float;
 mod simd_integer;
 mod simd_signed;
+mod traits;
 mod usefulness;
 mod utils;
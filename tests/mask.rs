@@ -0,0 +1,142 @@
+use wide::{
+  f32x4, f64x4, i8x16, i16x16, i32x4, m8x16, m16x16, m32x4, m64x4, u32x4,
+};
+
+use crate::utils::{for_simd_types, random_iter, simd_chunks};
+
+#[test]
+fn test_mask_array_roundtrip() {
+  for_simd_types!(|T, N| {
+    let array: [bool; N] = std::array::from_fn(|i| i % 3 == 0);
+    let mask = Mask::new(array);
+
+    assert_eq!(mask.to_array(), array);
+    assert_eq!(<[bool; N]>::from(mask), array);
+    for (i, &value) in array.iter().enumerate() {
+      assert_eq!(mask.test(i), value);
+    }
+  });
+}
+
+#[test]
+fn test_mask_set() {
+  for_simd_types!(|T, N| {
+    let mut mask = Mask::splat(false);
+    mask.set(N - 1, true);
+
+    assert!(mask.test(N - 1));
+    assert_eq!(mask.to_bitmask(), 1 << (N - 1));
+    mask.set(N - 1, false);
+    assert_eq!(mask, Mask::splat(false));
+  });
+}
+
+#[test]
+fn test_mask_bitmask_roundtrip() {
+  for_simd_types!(|T, N| {
+    let all_bits = u64::MAX >> (64 - N);
+    for bitmask in [0, 1, 0b1010_0110, all_bits, all_bits >> 1, all_bits - 1] {
+      let bitmask = bitmask & all_bits;
+      let mask = Mask::from_bitmask(bitmask);
+
+      assert_eq!(mask.to_bitmask(), bitmask);
+      assert_eq!(mask.any(), bitmask != 0);
+      assert_eq!(mask.all(), bitmask == all_bits);
+      assert_eq!(mask.none(), bitmask == 0);
+    }
+  });
+}
+
+#[test]
+fn test_mask_ops() {
+  for_simd_types!(|T, N| {
+    let a = Mask::new(std::array::from_fn(|i| i % 2 == 0));
+    let b = Mask::new(std::array::from_fn(|i| i % 3 == 0));
+
+    assert_eq!(
+      (a & b).to_array(),
+      std::array::from_fn(|i| a.test(i) & b.test(i))
+    );
+    assert_eq!(
+      (a | b).to_array(),
+      std::array::from_fn(|i| a.test(i) | b.test(i))
+    );
+    assert_eq!(
+      (a ^ b).to_array(),
+      std::array::from_fn(|i| a.test(i) ^ b.test(i))
+    );
+    assert_eq!((!a).to_array(), std::array::from_fn(|i| !a.test(i)));
+  });
+}
+
+#[test]
+fn test_mask_select() {
+  for_simd_types!(|T: Integer, N| {
+    for [left, right] in
+      simd_chunks!([5, 1, 2, 3], [1, 5, 2, 0]).chain(random_iter())
+    {
+      let expected = Simd::new(std::array::from_fn(|i| left[i].max(right[i])));
+      let mask = Simd::new(left).simd_gt(Simd::new(right));
+
+      assert_eq!(mask.select(Simd::new(left), Simd::new(right)), expected);
+    }
+  });
+}
+
+#[test]
+fn test_mask_to_int() {
+  let mask = m32x4::new([true, false, false, true]);
+
+  assert_eq!(mask.to_int().to_array(), [-1, 0, 0, -1]);
+  assert_eq!(m32x4::from_int(i32x4::from([7, 0, -3, 0])).to_bitmask(), 0b0101);
+  assert_eq!(u32x4::from(mask).to_array(), [u32::MAX, 0, 0, u32::MAX]);
+}
+
+#[test]
+fn test_mask_width_conversions() {
+  let mask = m32x4::new([true, false, true, true]);
+  let wide = m64x4::from(mask);
+
+  assert_eq!(wide.to_array(), mask.to_array());
+  assert_eq!(m32x4::from(wide), mask);
+
+  let values = f64x4::from([1.0, 2.0, 3.0, 4.0]);
+  assert_eq!(wide.select(values, f64x4::ZERO).to_array(), [1.0, 0.0, 3.0, 4.0]);
+
+  let narrow = i8x16::from(std::array::from_fn::<i8, 16, _>(|i| i as i8 - 8));
+  let mask = narrow.is_negative();
+  let wide = m16x16::from(mask);
+
+  assert_eq!(wide.to_bitmask(), 0x00FF);
+  assert_eq!(m8x16::from(wide), mask);
+  assert_eq!(
+    wide.select(i16x16::splat(1), i16x16::splat(2)).to_array()[..9],
+    [1, 1, 1, 1, 1, 1, 1, 1, 2],
+  );
+}
+
+#[test]
+fn test_mask_debug() {
+  let mask = m32x4::new([true, false, true, false]);
+
+  assert_eq!(format!("{mask:?}"), "(true, false, true, false)");
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_same_typed_masks() {
+  let value = f32x4::from([-1.0, 2.0, -3.0, 4.0]);
+  let mask = f32x4::from(value.is_sign_negative());
+
+  assert_eq!(
+    u64::from(mask.to_bitmask()),
+    value.is_sign_negative().to_bitmask()
+  );
+  assert!(mask.any());
+  assert!(!mask.all());
+  assert!(!mask.none());
+  assert_eq!(
+    mask.select(f32x4::ONE, f32x4::ZERO).to_array(),
+    [1.0, 0.0, 1.0, 0.0],
+  );
+}
@@ -2,9 +2,10 @@ use core::ops::{BitOr, Neg, Not};
 use std::{convert::identity, iter::once};
 
 use wide::{
-  AlignTo, f32x4, f32x8, f32x16, f64x2, f64x4, f64x8, i8x16, i8x32, i16x8,
-  i16x16, i16x32, i32x4, i32x8, i32x16, i64x2, i64x4, i64x8, u8x16, u8x32,
-  u16x8, u16x16, u16x32, u32x4, u32x8, u32x16, u64x2, u64x4, u64x8,
+  AlignTo, ParseSimdError, SimdCast, SimdVector, f32x4, f32x8, f32x16, f64x2,
+  f64x4, f64x8, i8x16, i8x32, i16x8, i16x16, i16x32, i32x4, i32x8, i32x16,
+  i64x2, i64x4, i64x8, u8x16, u8x32, u16x8, u16x16, u16x32, u32x4, u32x8,
+  u32x16, u64x2, u64x4, u64x8,
 };
 
 use crate::utils::{for_simd_types, random_iter, simd_chunks};
@@ -68,6 +69,94 @@ fn test_debug() {
   });
 }
 
+#[test]
+fn test_integer_formatting() {
+  for_simd_types!(|T: Integer, N| {
+    let value = Simd::new(std::array::from_fn(|i| (i as T).wrapping_mul(37)));
+    let lanes = |format: fn(&T) -> String| {
+      let lanes: Vec<_> = value.to_array().iter().map(format).collect();
+      format!("({})", lanes.join(", "))
+    };
+
+    assert_eq!(format!("{value:x}"), lanes(|x| format!("{x:x}")));
+    assert_eq!(format!("{value:#06x}"), lanes(|x| format!("{x:#06x}")));
+    assert_eq!(format!("{value:X}"), lanes(|x| format!("{x:X}")));
+    assert_eq!(format!("{value:#b}"), lanes(|x| format!("{x:#b}")));
+    assert_eq!(format!("{value:>12b}"), lanes(|x| format!("{x:>12b}")));
+    assert_eq!(format!("{value:#o}"), lanes(|x| format!("{x:#o}")));
+  });
+}
+
+#[test]
+fn test_hash() {
+  use std::collections::HashSet;
+  use std::hash::{BuildHasher, RandomState};
+
+  for_simd_types!(|T: Integer, N| {
+    let state = RandomState::new();
+    let mut set = HashSet::new();
+    for value in random_iter::<[T; N]>() {
+      let simd = Simd::new(value);
+      assert_eq!(state.hash_one(simd), state.hash_one(Simd::new(value)));
+      set.insert(simd);
+    }
+    for value in random_iter::<[T; N]>() {
+      assert!(set.contains(&Simd::new(value)));
+    }
+  });
+}
+
+#[test]
+fn test_from_str() {
+  for_simd_types!(|T, N| {
+    for value in random_iter::<[T; N]>() {
+      let simd = Simd::new(value);
+      // Comparing the output also works for NaN.
+      let expected = format!("{simd:?}");
+      let parse = |s: String| format!("{:?}", s.parse::<Simd>().unwrap());
+
+      assert_eq!(parse(format!("{simd}")), expected);
+      assert_eq!(parse(format!("{simd:?}")), expected);
+      assert_eq!(parse(format!("{value:?}")), expected);
+      let spaced = value.map(|x| x.to_string()).join(" ,");
+      assert_eq!(parse(format!(" [ {spaced} ] ")), expected);
+      let splat = Simd::splat(value[0]);
+      assert_eq!(parse(format!(" {} ", value[0])), format!("{splat:?}"));
+    }
+
+    let zeros = || std::iter::repeat_n("0", N);
+    let list = |lanes: Vec<&str>| format!("({})", lanes.join(", "));
+    assert_eq!(
+      list(zeros().take(N - 1).collect()).parse::<Simd>(),
+      Err(ParseSimdError::LaneCount { expected: N, found: N - 1 })
+    );
+    assert_eq!(
+      list(zeros().chain(["0"]).collect()).parse::<Simd>(),
+      Err(ParseSimdError::LaneCount { expected: N, found: N + 1 })
+    );
+    assert_eq!(
+      "()".parse::<Simd>(),
+      Err(ParseSimdError::LaneCount { expected: N, found: 0 })
+    );
+    let mut lanes: Vec<_> = zeros().collect();
+    lanes[N - 1] = "x";
+    assert_eq!(
+      list(lanes).parse::<Simd>(),
+      Err(ParseSimdError::InvalidLane { lane: N - 1 })
+    );
+    assert_eq!(
+      "".parse::<Simd>(),
+      Err(ParseSimdError::InvalidLane { lane: 0 })
+    );
+    for unmatched in ["(0, 0", "0, 0]", "[0, 0)"] {
+      assert_eq!(
+        unmatched.parse::<Simd>(),
+        Err(ParseSimdError::UnmatchedBracket)
+      );
+    }
+  });
+}
+
 #[test]
 fn test_neg() {
   for_simd_types!(|T: Float, N| {
@@ -1286,13 +1375,11 @@ fn test_simd_eq() {
     )
     .chain(random_iter())
     {
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] == right[i] { T::from_bits(!0) } else { 0.0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] == right[i]));
       let actual = Simd::new(left).simd_eq(Simd::new(right));
 
       assert!(
-        actual ^ expected == Simd::ZERO,
+        actual == expected,
         "expected: {expected:?}\n  actual: {actual:?}\n    left: {left:?}\n   right: {right:?}",
       );
     }
@@ -1304,9 +1391,7 @@ fn test_simd_eq() {
     )
     .chain(random_iter())
     {
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] == right[i] { !0 } else { 0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] == right[i]));
       let actual = Simd::new(left).simd_eq(Simd::new(right));
 
       assert!(
@@ -1322,9 +1407,7 @@ fn test_simd_eq() {
     )
     .chain(random_iter())
     {
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] == right[i] { !0 } else { 0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] == right[i]));
       let actual = Simd::new(left).simd_eq(Simd::new(right));
 
       assert!(
@@ -1340,13 +1423,11 @@ fn test_simd_eq_scalar() {
   for_simd_types!(|T: Float, N| {
     for left in simd_chunks!([5.0, -0.0, 1.0, 5.0, 6.3, -30.2, 1e3, 20.2]) {
       let right = 5.0;
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] == right { T::from_bits(!0) } else { 0.0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] == right));
       let actual = Simd::new(left).simd_eq(right);
 
       assert!(
-        actual ^ expected == Simd::ZERO,
+        actual == expected,
         "expected: {expected:?}\n  actual: {actual:?}\n    left: {left:?}\n   right: {right:?}",
       );
     }
@@ -1356,12 +1437,7 @@ fn test_simd_eq_scalar() {
       simd_chunks!([5, 1, 5, -5, -5, -1, -3, 20, T::MIN + 5, 0, T::MAX - 5])
     {
       let right = 5;
-      let expected =
-        Simd::new(std::array::from_fn(
-          |i| {
-            if left[i] == right { !0 } else { 0 }
-          },
-        ));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] == right));
       let actual = Simd::new(left).simd_eq(right);
 
       assert!(
@@ -1373,12 +1449,7 @@ fn test_simd_eq_scalar() {
   for_simd_types!(|T: Integer, N| {
     for left in simd_chunks!([5, 1, 5, T::MAX - 5, 20, T::MAX]) {
       let right = 5;
-      let expected =
-        Simd::new(std::array::from_fn(
-          |i| {
-            if left[i] == right { !0 } else { 0 }
-          },
-        ));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] == right));
       let actual = Simd::new(left).simd_eq(right);
 
       assert!(
@@ -1398,13 +1469,11 @@ fn test_simd_ne() {
     )
     .chain(random_iter())
     {
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] != right[i] { T::from_bits(!0) } else { 0.0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] != right[i]));
       let actual = Simd::new(left).simd_ne(Simd::new(right));
 
       assert!(
-        actual ^ expected == Simd::ZERO,
+        actual == expected,
         "expected: {expected:?}\n  actual: {actual:?}\n    left: {left:?}\n   right: {right:?}",
       );
     }
@@ -1416,9 +1485,7 @@ fn test_simd_ne() {
     )
     .chain(random_iter())
     {
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] != right[i] { !0 } else { 0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] != right[i]));
       let actual = Simd::new(left).simd_ne(Simd::new(right));
 
       assert!(
@@ -1434,9 +1501,7 @@ fn test_simd_ne() {
     )
     .chain(random_iter())
     {
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] != right[i] { !0 } else { 0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] != right[i]));
       let actual = Simd::new(left).simd_ne(Simd::new(right));
 
       assert!(
@@ -1452,13 +1517,11 @@ fn test_simd_ne_scalar() {
   for_simd_types!(|T: Float, N| {
     for left in simd_chunks!([5.0, -0.0, 1.0, 5.0, 6.3, -30.2, 1e3, 20.2]) {
       let right = 5.0;
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] != right { T::from_bits(!0) } else { 0.0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] != right));
       let actual = Simd::new(left).simd_ne(right);
 
       assert!(
-        actual ^ expected == Simd::ZERO,
+        actual == expected,
         "expected: {expected:?}\n  actual: {actual:?}\n    left: {left:?}\n   right: {right:?}",
       );
     }
@@ -1468,12 +1531,7 @@ fn test_simd_ne_scalar() {
       simd_chunks!([5, 1, 5, -5, -5, -1, -3, 20, T::MIN + 5, 0, T::MAX - 5])
     {
       let right = 5;
-      let expected =
-        Simd::new(std::array::from_fn(
-          |i| {
-            if left[i] != right { !0 } else { 0 }
-          },
-        ));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] != right));
       let actual = Simd::new(left).simd_ne(right);
 
       assert!(
@@ -1485,12 +1543,7 @@ fn test_simd_ne_scalar() {
   for_simd_types!(|T: Integer, N| {
     for left in simd_chunks!([5, 1, 5, T::MAX - 5, 20, T::MAX]) {
       let right = 5;
-      let expected =
-        Simd::new(std::array::from_fn(
-          |i| {
-            if left[i] != right { !0 } else { 0 }
-          },
-        ));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] != right));
       let actual = Simd::new(left).simd_ne(right);
 
       assert!(
@@ -1510,13 +1563,11 @@ fn test_simd_lt() {
     )
     .chain(random_iter())
     {
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] < right[i] { T::from_bits(!0) } else { 0.0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] < right[i]));
       let actual = Simd::new(left).simd_lt(Simd::new(right));
 
       assert!(
-        actual ^ expected == Simd::ZERO,
+        actual == expected,
         "expected: {expected:?}\n  actual: {actual:?}\n    left: {left:?}\n   right: {right:?}",
       );
     }
@@ -1528,12 +1579,7 @@ fn test_simd_lt() {
     )
     .chain(random_iter())
     {
-      let expected =
-        Simd::new(std::array::from_fn(
-          |i| {
-            if left[i] < right[i] { !0 } else { 0 }
-          },
-        ));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] < right[i]));
       let actual = Simd::new(left).simd_lt(Simd::new(right));
 
       assert!(
@@ -1549,12 +1595,7 @@ fn test_simd_lt() {
     )
     .chain(random_iter())
     {
-      let expected =
-        Simd::new(std::array::from_fn(
-          |i| {
-            if left[i] < right[i] { !0 } else { 0 }
-          },
-        ));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] < right[i]));
       let actual = Simd::new(left).simd_lt(Simd::new(right));
 
       assert!(
@@ -1570,13 +1611,11 @@ fn test_simd_lt_scalar() {
   for_simd_types!(|T: Float, N| {
     for left in simd_chunks!([5.0, -0.0, 1.0, 5.0, 6.3, -30.2, 1e3, 20.2]) {
       let right = 5.0;
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] < right { T::from_bits(!0) } else { 0.0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] < right));
       let actual = Simd::new(left).simd_lt(right);
 
       assert!(
-        actual ^ expected == Simd::ZERO,
+        actual == expected,
         "expected: {expected:?}\n  actual: {actual:?}\n    left: {left:?}\n   right: {right:?}",
       );
     }
@@ -1586,12 +1625,7 @@ fn test_simd_lt_scalar() {
       simd_chunks!([5, 1, 5, -5, -5, -1, -3, 20, T::MIN + 5, 0, T::MAX - 5])
     {
       let right = 5;
-      let expected =
-        Simd::new(std::array::from_fn(
-          |i| {
-            if left[i] < right { !0 } else { 0 }
-          },
-        ));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] < right));
       let actual = Simd::new(left).simd_lt(right);
 
       assert!(
@@ -1603,12 +1637,7 @@ fn test_simd_lt_scalar() {
   for_simd_types!(|T: Integer, N| {
     for left in simd_chunks!([5, 1, 5, T::MAX - 5, 20, T::MAX]) {
       let right = 5;
-      let expected =
-        Simd::new(std::array::from_fn(
-          |i| {
-            if left[i] < right { !0 } else { 0 }
-          },
-        ));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] < right));
       let actual = Simd::new(left).simd_lt(right);
 
       assert!(
@@ -1628,13 +1657,11 @@ fn test_simd_gt() {
     )
     .chain(random_iter())
     {
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] > right[i] { T::from_bits(!0) } else { 0.0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] > right[i]));
       let actual = Simd::new(left).simd_gt(Simd::new(right));
 
       assert!(
-        actual ^ expected == Simd::ZERO,
+        actual == expected,
         "expected: {expected:?}\n  actual: {actual:?}\n    left: {left:?}\n   right: {right:?}",
       );
     }
@@ -1646,12 +1673,7 @@ fn test_simd_gt() {
     )
     .chain(random_iter())
     {
-      let expected =
-        Simd::new(std::array::from_fn(
-          |i| {
-            if left[i] > right[i] { !0 } else { 0 }
-          },
-        ));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] > right[i]));
       let actual = Simd::new(left).simd_gt(Simd::new(right));
 
       assert!(
@@ -1667,12 +1689,7 @@ fn test_simd_gt() {
     )
     .chain(random_iter())
     {
-      let expected =
-        Simd::new(std::array::from_fn(
-          |i| {
-            if left[i] > right[i] { !0 } else { 0 }
-          },
-        ));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] > right[i]));
       let actual = Simd::new(left).simd_gt(Simd::new(right));
 
       assert!(
@@ -1688,13 +1705,11 @@ fn test_simd_gt_scalar() {
   for_simd_types!(|T: Float, N| {
     for left in simd_chunks!([5.0, -0.0, 1.0, 5.0, 6.3, -30.2, 1e3, 20.2]) {
       let right = 5.0;
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] > right { T::from_bits(!0) } else { 0.0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] > right));
       let actual = Simd::new(left).simd_gt(right);
 
       assert!(
-        actual ^ expected == Simd::ZERO,
+        actual == expected,
         "expected: {expected:?}\n  actual: {actual:?}\n    left: {left:?}\n   right: {right:?}",
       );
     }
@@ -1704,12 +1719,7 @@ fn test_simd_gt_scalar() {
       simd_chunks!([5, 1, 5, -5, -5, -1, -3, 20, T::MIN + 5, 0, T::MAX - 5])
     {
       let right = 5;
-      let expected =
-        Simd::new(std::array::from_fn(
-          |i| {
-            if left[i] > right { !0 } else { 0 }
-          },
-        ));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] > right));
       let actual = Simd::new(left).simd_gt(right);
 
       assert!(
@@ -1721,12 +1731,7 @@ fn test_simd_gt_scalar() {
   for_simd_types!(|T: Integer, N| {
     for left in simd_chunks!([5, 1, 5, T::MAX - 5, 20, T::MAX]) {
       let right = 5;
-      let expected =
-        Simd::new(std::array::from_fn(
-          |i| {
-            if left[i] > right { !0 } else { 0 }
-          },
-        ));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] > right));
       let actual = Simd::new(left).simd_gt(right);
 
       assert!(
@@ -1746,13 +1751,11 @@ fn test_simd_le() {
     )
     .chain(random_iter())
     {
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] <= right[i] { T::from_bits(!0) } else { 0.0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] <= right[i]));
       let actual = Simd::new(left).simd_le(Simd::new(right));
 
       assert!(
-        actual ^ expected == Simd::ZERO,
+        actual == expected,
         "expected: {expected:?}\n  actual: {actual:?}\n    left: {left:?}\n   right: {right:?}",
       );
     }
@@ -1764,9 +1767,7 @@ fn test_simd_le() {
     )
     .chain(random_iter())
     {
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] <= right[i] { !0 } else { 0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] <= right[i]));
       let actual = Simd::new(left).simd_le(Simd::new(right));
 
       assert!(
@@ -1782,9 +1783,7 @@ fn test_simd_le() {
     )
     .chain(random_iter())
     {
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] <= right[i] { !0 } else { 0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] <= right[i]));
       let actual = Simd::new(left).simd_le(Simd::new(right));
 
       assert!(
@@ -1800,13 +1799,11 @@ fn test_simd_le_scalar() {
   for_simd_types!(|T: Float, N| {
     for left in simd_chunks!([5.0, -0.0, 1.0, 5.0, 6.3, -30.2, 1e3, 20.2]) {
       let right = 5.0;
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] <= right { T::from_bits(!0) } else { 0.0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] <= right));
       let actual = Simd::new(left).simd_le(right);
 
       assert!(
-        actual ^ expected == Simd::ZERO,
+        actual == expected,
         "expected: {expected:?}\n  actual: {actual:?}\n    left: {left:?}\n   right: {right:?}",
       );
     }
@@ -1816,12 +1813,7 @@ fn test_simd_le_scalar() {
       simd_chunks!([5, 1, 5, -5, -5, -1, -3, 20, T::MIN + 5, 0, T::MAX - 5])
     {
       let right = 5;
-      let expected =
-        Simd::new(std::array::from_fn(
-          |i| {
-            if left[i] <= right { !0 } else { 0 }
-          },
-        ));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] <= right));
       let actual = Simd::new(left).simd_le(right);
 
       assert!(
@@ -1833,12 +1825,7 @@ fn test_simd_le_scalar() {
   for_simd_types!(|T: Integer, N| {
     for left in simd_chunks!([5, 1, 5, T::MAX - 5, 20, T::MAX]) {
       let right = 5;
-      let expected =
-        Simd::new(std::array::from_fn(
-          |i| {
-            if left[i] <= right { !0 } else { 0 }
-          },
-        ));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] <= right));
       let actual = Simd::new(left).simd_le(right);
 
       assert!(
@@ -1858,13 +1845,11 @@ fn test_simd_ge() {
     )
     .chain(random_iter())
     {
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] >= right[i] { T::from_bits(!0) } else { 0.0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] >= right[i]));
       let actual = Simd::new(left).simd_ge(Simd::new(right));
 
       assert!(
-        actual ^ expected == Simd::ZERO,
+        actual == expected,
         "expected: {expected:?}\n  actual: {actual:?}\n    left: {left:?}\n   right: {right:?}",
       );
     }
@@ -1876,9 +1861,7 @@ fn test_simd_ge() {
     )
     .chain(random_iter())
     {
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] >= right[i] { !0 } else { 0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] >= right[i]));
       let actual = Simd::new(left).simd_ge(Simd::new(right));
 
       assert!(
@@ -1894,9 +1877,7 @@ fn test_simd_ge() {
     )
     .chain(random_iter())
     {
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] >= right[i] { !0 } else { 0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] >= right[i]));
       let actual = Simd::new(left).simd_ge(Simd::new(right));
 
       assert!(
@@ -1912,13 +1893,11 @@ fn test_simd_ge_scalar() {
   for_simd_types!(|T: Float, N| {
     for left in simd_chunks!([5.0, -0.0, 1.0, 5.0, 6.3, -30.2, 1e3, 20.2]) {
       let right = 5.0;
-      let expected = Simd::new(std::array::from_fn(|i| {
-        if left[i] >= right { T::from_bits(!0) } else { 0.0 }
-      }));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] >= right));
       let actual = Simd::new(left).simd_ge(right);
 
       assert!(
-        actual ^ expected == Simd::ZERO,
+        actual == expected,
         "expected: {expected:?}\n  actual: {actual:?}\n    left: {left:?}\n   right: {right:?}",
       );
     }
@@ -1928,12 +1907,7 @@ fn test_simd_ge_scalar() {
       simd_chunks!([5, 1, 5, -5, -5, -1, -3, 20, T::MIN + 5, 0, T::MAX - 5])
     {
       let right = 5;
-      let expected =
-        Simd::new(std::array::from_fn(
-          |i| {
-            if left[i] >= right { !0 } else { 0 }
-          },
-        ));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] >= right));
       let actual = Simd::new(left).simd_ge(right);
 
       assert!(
@@ -1945,12 +1919,7 @@ fn test_simd_ge_scalar() {
   for_simd_types!(|T: Integer, N| {
     for left in simd_chunks!([5, 1, 5, T::MAX - 5, 20, T::MAX]) {
       let right = 5;
-      let expected =
-        Simd::new(std::array::from_fn(
-          |i| {
-            if left[i] >= right { !0 } else { 0 }
-          },
-        ));
+      let expected = Mask::new(std::array::from_fn(|i| left[i] >= right));
       let actual = Simd::new(left).simd_ge(right);
 
       assert!(
@@ -2063,12 +2032,12 @@ fn test_to_bitmask() {
     {
       let expected = (0..N)
         .map(|i| if value[i].is_sign_negative() { 1 << i } else { 0 })
-        .fold(0, u32::bitor);
-      let actual = Simd::new(value).to_bitmask();
+        .fold(0, u64::bitor);
+      let actual = Simd::new(value).is_sign_negative().to_bitmask();
 
       assert!(
         actual == expected,
-        "expected: {expected:0>32b}\n  actual: {actual:0>32b}\n   value: {value:?}",
+        "expected: {expected:0>64b}\n  actual: {actual:0>64b}\n   value: {value:?}",
       );
     }
   });
@@ -2079,12 +2048,12 @@ fn test_to_bitmask() {
     {
       let expected = (0..N)
         .map(|i| if value[i].is_negative() { 1 << i } else { 0 })
-        .fold(0, u32::bitor);
-      let actual = Simd::new(value).to_bitmask();
+        .fold(0, u64::bitor);
+      let actual = Simd::new(value).is_negative().to_bitmask();
 
       assert!(
         actual == expected,
-        "expected: {expected:0>32b}\n  actual: {actual:0>32b}\n   value: {value:?}",
+        "expected: {expected:0>64b}\n  actual: {actual:0>64b}\n   value: {value:?}",
       );
     }
   });
@@ -2095,12 +2064,12 @@ fn test_to_bitmask() {
     {
       let expected = (0..N)
         .map(|i| if value[i] > T::MAX >> 1 { 1 << i } else { 0 })
-        .fold(0, u32::bitor);
-      let actual = Simd::new(value).to_bitmask();
+        .fold(0, u64::bitor);
+      let actual = Simd::new(value).simd_gt(T::MAX >> 1).to_bitmask();
 
       assert!(
         actual == expected,
-        "expected: {expected:0>32b}\n  actual: {actual:0>32b}\n   value: {value:?}",
+        "expected: {expected:0>64b}\n  actual: {actual:0>64b}\n   value: {value:?}",
       );
     }
   });
@@ -2114,7 +2083,7 @@ fn test_any() {
       .chain([[0.0 as T; N], [-0.0 as T; N]])
     {
       let expected = value.into_iter().any(|x| x.is_sign_negative());
-      let actual = Simd::new(value).any();
+      let actual = Simd::new(value).is_sign_negative().any();
 
       assert_eq!(actual, expected, "\n   value: {value:?}");
     }
@@ -2125,7 +2094,7 @@ fn test_any() {
       .chain([[2 as T; N], [-4 as T; N]])
     {
       let expected = value.into_iter().any(|x| x.is_negative());
-      let actual = Simd::new(value).any();
+      let actual = Simd::new(value).is_negative().any();
 
       assert_eq!(actual, expected, "\n   value: {value:?}");
     }
@@ -2136,7 +2105,7 @@ fn test_any() {
       .chain([[2 as T; N], [!0 - 6 as T; N]])
     {
       let expected = value.into_iter().any(|x| x.cast_signed().is_negative());
-      let actual = Simd::new(value).any();
+      let actual = Simd::new(value).simd_gt(T::MAX >> 1).any();
 
       assert_eq!(actual, expected, "\n   value: {value:?}");
     }
@@ -2151,7 +2120,7 @@ fn test_all() {
       .chain([[0.0 as T; N], [-0.0 as T; N]])
     {
       let expected = value.into_iter().all(|x| x.is_sign_negative());
-      let actual = Simd::new(value).all();
+      let actual = Simd::new(value).is_sign_negative().all();
 
       assert_eq!(actual, expected, "\n   value: {value:?}");
     }
@@ -2162,7 +2131,7 @@ fn test_all() {
       .chain([[2 as T; N], [-4 as T; N]])
     {
       let expected = value.into_iter().all(|x| x.is_negative());
-      let actual = Simd::new(value).all();
+      let actual = Simd::new(value).is_negative().all();
 
       assert_eq!(actual, expected, "\n   value: {value:?}");
     }
@@ -2173,7 +2142,7 @@ fn test_all() {
       .chain([[2 as T; N], [!0 - 6 as T; N]])
     {
       let expected = value.into_iter().all(|x| x.cast_signed().is_negative());
-      let actual = Simd::new(value).all();
+      let actual = Simd::new(value).simd_gt(T::MAX >> 1).all();
 
       assert_eq!(actual, expected, "\n   value: {value:?}");
     }
@@ -2188,7 +2157,7 @@ fn test_none() {
       .chain([[0.0 as T; N], [-0.0 as T; N]])
     {
       let expected = !value.into_iter().any(|x| x.is_sign_negative());
-      let actual = Simd::new(value).none();
+      let actual = Simd::new(value).is_sign_negative().none();
 
       assert_eq!(actual, expected, "\n   value: {value:?}");
     }
@@ -2199,7 +2168,7 @@ fn test_none() {
       .chain([[2 as T; N], [-4 as T; N]])
     {
       let expected = !value.into_iter().any(|x| x.is_negative());
-      let actual = Simd::new(value).none();
+      let actual = Simd::new(value).is_negative().none();
 
       assert_eq!(actual, expected, "\n   value: {value:?}");
     }
@@ -2210,7 +2179,7 @@ fn test_none() {
       .chain([[2 as T; N], [!0 - 6 as T; N]])
     {
       let expected = !value.into_iter().any(|x| x.cast_signed().is_negative());
-      let actual = Simd::new(value).none();
+      let actual = Simd::new(value).simd_gt(T::MAX >> 1).none();
 
       assert_eq!(actual, expected, "\n   value: {value:?}");
     }
@@ -2547,6 +2516,143 @@ fn test_reduce_min() {
   });
 }
 
+#[test]
+fn test_argmax_argmin() {
+  for_simd_types!(|T, N| {
+    for value in simd_chunks!([1 as T, 2 as T, 9 as T, 0 as T, 9 as T, 2 as T])
+    {
+      let simd = Simd::new(value);
+      let max = value.into_iter().reduce(|a, b| if b > a { b } else { a });
+      let min = value.into_iter().reduce(|a, b| if b < a { b } else { a });
+
+      assert_eq!(
+        simd.argmax(),
+        value.iter().position(|&x| Some(x) == max).unwrap()
+      );
+      assert_eq!(
+        simd.argmin(),
+        value.iter().position(|&x| Some(x) == min).unwrap()
+      );
+    }
+
+    let last: [T; N] = std::array::from_fn(|i| i as T);
+    assert_eq!(Simd::new(last).argmax(), N - 1);
+    assert_eq!(Simd::new(last).argmin(), 0);
+  });
+  for_simd_types!(|T: Float, N| {
+    // NaN is ignored, and `+0.0` and `-0.0` are equal.
+    let mut value = [T::NAN; N];
+    assert_eq!(Simd::new(value).argmax(), 0);
+    value[N - 1] = 0.0;
+    value[N / 2] = -0.0;
+    assert_eq!(Simd::new(value).argmax(), N / 2);
+    assert_eq!(Simd::new(value).argmin(), N / 2);
+  });
+}
+
+#[test]
+fn test_reduce_bitwise() {
+  for_simd_types!(|T: Integer, N| {
+    for value in simd_chunks!([1, 2, 6, 8, 100, 9, T::MAX, T::MIN, 0x55])
+      .chain(random_iter())
+    {
+      let simd = Simd::new(value);
+
+      assert_eq!(simd.reduce_and(), value.into_iter().fold(!0, |a, b| a & b));
+      assert_eq!(simd.reduce_or(), value.into_iter().fold(0, |a, b| a | b));
+      assert_eq!(simd.reduce_xor(), value.into_iter().fold(0, |a, b| a ^ b));
+    }
+  });
+}
+
+#[test]
+fn test_scan() {
+  /// Returns the running results of `op`, like a sequential loop.
+  fn scan<T: Copy, const N: usize>(
+    value: [T; N],
+    op: impl Fn(T, T) -> T,
+  ) -> [T; N] {
+    let mut acc = value[0];
+    std::array::from_fn(|i| {
+      if i > 0 {
+        acc = op(acc, value[i]);
+      }
+      acc
+    })
+  }
+
+  for_simd_types!(|T: Integer, N| {
+    for value in simd_chunks!([1, 2, 6, 8, 100, 9, T::MAX, T::MIN, 0x55])
+      .chain(random_iter())
+    {
+      let simd = Simd::new(value);
+      let sums = scan(value, T::wrapping_add);
+      let mut exclusive = [0; N];
+      exclusive[1..].copy_from_slice(&sums[..N - 1]);
+
+      assert_eq!(simd.scan_add(), Simd::new(sums));
+      assert_eq!(simd.scan_add_exclusive(), Simd::new(exclusive));
+      assert_eq!(simd.scan_max(), Simd::new(scan(value, T::max)));
+      assert_eq!(simd.scan_min(), Simd::new(scan(value, T::min)));
+      assert_eq!(simd.scan_or(), Simd::new(scan(value, |a, b| a | b)));
+    }
+  });
+  for_simd_types!(|T: Float, N| {
+    // Small integers, so that the sums are exact.
+    for value in simd_chunks!([1.0, -2.0, 6.0, 0.5, -100.0, 9.0, 0.0, 3.0]) {
+      let simd = Simd::new(value);
+      let sums = scan(value, |a, b| a + b);
+      let mut exclusive = [0.0; N];
+      exclusive[1..].copy_from_slice(&sums[..N - 1]);
+
+      assert_eq!(simd.scan_add(), Simd::new(sums));
+      assert_eq!(simd.scan_add_exclusive(), Simd::new(exclusive));
+      assert_eq!(simd.scan_max(), Simd::new(scan(value, T::max)));
+      assert_eq!(simd.scan_min(), Simd::new(scan(value, T::min)));
+
+      let or = scan(value, |a, b| T::from_bits(a.to_bits() | b.to_bits()));
+      assert_eq!(simd.scan_or().to_array().map(T::to_bits), or.map(T::to_bits));
+    }
+
+    // A sum of `-0.0` is `-0.0`, like in a sequential loop.
+    assert!(Simd::splat(-0.0).scan_add().is_sign_negative().all());
+  });
+}
+
+#[test]
+fn test_prefix_sum() {
+  for_simd_types!(|T: Integer, N| {
+    for len in [0, 1, N - 1, N, N + 1, 3 * N + 2] {
+      let mut values: Vec<T> = (0..len).map(|i| (i % 7) as T).collect();
+      let expected = values
+        .iter()
+        .scan(0 as T, |acc, &x| {
+          *acc = acc.wrapping_add(x);
+          Some(*acc)
+        })
+        .collect::<Vec<T>>();
+
+      Simd::prefix_sum(&mut values);
+
+      assert_eq!(values, expected);
+    }
+  });
+  for_simd_types!(|T: Float, N| {
+    let mut values: Vec<T> = (0..3 * N + 1).map(|i| i as T).collect();
+    let expected: Vec<T> =
+      (0..3 * N + 1).map(|i| (i * (i + 1) / 2) as T).collect();
+
+    Simd::prefix_sum(&mut values);
+
+    assert_eq!(values, expected);
+
+    let mut values = vec![-0.0; 2 * N + 1];
+    Simd::prefix_sum(&mut values);
+
+    assert!(values.iter().all(|x: &T| x.is_sign_negative()));
+  });
+}
+
 #[test]
 fn test_from_small() {
   // `from_{small}` is inconsistently missing from types.
@@ -2624,6 +2730,136 @@ fn test_from_small() {
   }
 }
 
+#[test]
+fn test_simd_cast() {
+  // Values around the limits of all element types, converted with `as`.
+  const FLOATS: [f64; 26] = [
+    0.0,
+    -0.0,
+    0.5,
+    -0.5,
+    1.5,
+    -2.5,
+    100.7,
+    127.5,
+    128.0,
+    -128.9,
+    -129.0,
+    255.9,
+    256.0,
+    32767.9,
+    32768.0,
+    -32769.0,
+    65536.0,
+    2147483648.0,
+    -2147483649.0,
+    4294967296.0,
+    1.9e19,
+    -9.3e18,
+    3e-40,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+    f64::NAN,
+  ];
+  const INTS: [i128; 6] = [
+    -3,
+    (1 << 24) + 1,
+    (1 << 53) + 1,
+    i64::MAX as i128,
+    i64::MIN as i128 + 1,
+    u64::MAX as i128,
+  ];
+
+  fn same<X: PartialEq>(left: &[X], right: &[X]) -> bool {
+    left.iter().zip(right).all(|(l, r)| l == r || (l != l && r != r))
+  }
+
+  macro_rules! check {
+    (ints = $ints:tt, floats = $floats:tt,) => {
+      check!(@rows $ints, $ints, $floats);
+      check!(@rows $floats, $ints, $floats);
+    };
+    (@rows [$($From:ident: $T:ident),*], $ints:tt, $floats:tt) => {
+      $(check!(@row $From: $T, $ints, $floats);)*
+    };
+    (
+      @row $From:ident: $T:ident,
+      [$($Int:ident: $IT:ident),*], [$($Float:ident: $FT:ident),*]
+    ) => {
+      let values: Vec<$T> = FLOATS
+        .iter()
+        .map(|&x| x as $T)
+        .chain(INTS.iter().map(|&x| x as $T))
+        .collect();
+      let chunks = values
+        .chunks(<$From as SimdVector>::LANES)
+        .map($From::from_slice);
+      for value in chunks.chain(random_iter::<$From>()) {
+        let array = value.to_array();
+        $(
+          let expected = array.map(|x| x as $IT);
+          let actual: $Int = value.cast_lossy();
+          assert_eq!(actual.to_array(), expected, "{value:?}");
+
+          let expected = array.map(|x| {
+            (x as i128).clamp($IT::MIN as i128, $IT::MAX as i128) as $IT
+          });
+          let actual: $Int = value.saturating_cast();
+          assert_eq!(actual.to_array(), expected, "{value:?}");
+        )*
+        $(
+          let expected = array.map(|x| x as $FT);
+          let actual: $Float = value.cast_lossy();
+          assert!(same(&actual.to_array(), &expected), "{value:?}");
+          let actual: $Float = value.saturating_cast();
+          assert!(same(&actual.to_array(), &expected), "{value:?}");
+        )*
+      }
+    };
+  }
+
+  check! {
+    ints = [i64x2: i64, u64x2: u64],
+    floats = [f64x2: f64],
+  }
+  check! {
+    ints = [i32x4: i32, u32x4: u32, i64x4: i64, u64x4: u64],
+    floats = [f32x4: f32, f64x4: f64],
+  }
+  check! {
+    ints = [
+      i16x8: i16, u16x8: u16, i32x8: i32, u32x8: u32, i64x8: i64, u64x8: u64
+    ],
+    floats = [f32x8: f32, f64x8: f64],
+  }
+  check! {
+    ints = [
+      i8x16: i8, u8x16: u8, i16x16: i16, u16x16: u16, i32x16: i32, u32x16: u32
+    ],
+    floats = [f32x16: f32],
+  }
+  check! {
+    ints = [i8x32: i8, u8x32: u8, i16x32: i16, u16x32: u16],
+    floats = [],
+  }
+}
+
+#[test]
+fn test_convert() {
+  let actual: f64x4 = f32x4::from([1.5, -0.0, 3e38, 1e-40]).convert();
+  let expected = [1.5, -0.0, 3e38_f32 as f64, 1e-40_f32 as f64];
+  assert_eq!(actual.to_array(), expected);
+
+  let actual: i32x8 = u16x8::MAX.convert();
+  assert_eq!(actual, i32x8::splat(65535));
+  let actual: f32x16 = i8x16::MIN.convert();
+  assert_eq!(actual, f32x16::splat(-128.0));
+  let actual: f64x4 = u32x4::MAX.convert();
+  assert_eq!(actual, f64x4::splat(4294967295.0));
+  assert_eq!(i16x32::from(u8x32::MAX), i16x32::splat(255));
+  assert_eq!(i64x4::from(u32x4::MAX), i64x4::splat(4294967295));
+}
+
 #[test]
 fn test_to_array() {
   for_simd_types!(|T, N| {
@@ -2687,6 +2923,491 @@ fn test_from_slice() {
   });
 }
 
+#[test]
+fn test_load_or() {
+  for_simd_types!(|T, N| {
+    let vec = Vec::from_iter((0..N + 3).map(|i| i as T + 1 as T));
+    for len in 0..=N + 3 {
+      let slice = &vec[..len];
+      let expected = Simd::new(std::array::from_fn(|i| {
+        slice.get(i).copied().unwrap_or(100 as T)
+      }));
+      let actual = Simd::load_or(slice, 100 as T);
+
+      assert_eq!(actual, expected, "\n     len: {len}");
+    }
+  });
+}
+
+#[test]
+fn test_store_partial() {
+  for_simd_types!(|T, N| {
+    let value = Simd::new(std::array::from_fn(|i| i as T + 1 as T));
+    for len in 0..=N + 3 {
+      let mut actual = vec![100 as T; len];
+      value.store_partial(&mut actual);
+
+      let expected = Vec::from_iter(
+        (0..len).map(|i| if i < N { value.as_array()[i] } else { 100 as T }),
+      );
+      assert_eq!(actual, expected, "\n     len: {len}");
+    }
+  });
+}
+
+#[test]
+fn test_masked_load() {
+  for_simd_types!(|T, N| {
+    let vec = Vec::from_iter((0..N).map(|i| i as T + 1 as T));
+    let fill = Simd::splat(100 as T);
+    for bitmask in [0, 1, 0b1011_0110, u64::MAX, u64::MAX >> 1, 1 << (N - 1)] {
+      let mask = Mask::from_bitmask(bitmask);
+      for len in 0..=N {
+        let slice = &vec[..len];
+        let expected = Simd::new(std::array::from_fn(|i| {
+          if i < len && mask.test(i) { slice[i] } else { 100 as T }
+        }));
+        let actual = Simd::masked_load(slice, mask, fill);
+
+        assert_eq!(
+          actual, expected,
+          "\n     len: {len}\n bitmask: {bitmask:b}"
+        );
+      }
+    }
+  });
+}
+
+#[test]
+fn test_masked_store() {
+  for_simd_types!(|T, N| {
+    let value = Simd::new(std::array::from_fn(|i| i as T + 1 as T));
+    for bitmask in [0, 1, 0b1011_0110, u64::MAX, u64::MAX >> 1, 1 << (N - 1)] {
+      let mask = Mask::from_bitmask(bitmask);
+      for len in 0..=N + 1 {
+        let mut actual = vec![100 as T; len];
+        value.masked_store(&mut actual, mask);
+
+        let expected = Vec::from_iter((0..len).map(|i| {
+          if i < N && mask.test(i) { value.as_array()[i] } else { 100 as T }
+        }));
+        assert_eq!(
+          actual, expected,
+          "\n     len: {len}\n bitmask: {bitmask:b}"
+        );
+      }
+    }
+  });
+}
+
+#[test]
+fn test_compress() {
+  for_simd_types!(|T, N| {
+    let value = Simd::new(std::array::from_fn(|i| i as T + 1 as T));
+    for bitmask in [
+      0,
+      1,
+      0b1011_0110,
+      u64::MAX,
+      u64::MAX >> 1,
+      1 << (N - 1),
+      0x5555_5555_5555_5555,
+      0xDEAD_BEEF_1234_5678,
+    ] {
+      let mask = Mask::from_bitmask(bitmask);
+      let selected = Vec::from_iter(
+        (0..N).filter(|&i| mask.test(i)).map(|i| value.as_array()[i]),
+      );
+      let mut expected = [0 as T; N];
+      expected[..selected.len()].copy_from_slice(&selected);
+
+      assert_eq!(
+        value.compress(mask),
+        (Simd::new(expected), selected.len()),
+        "\n bitmask: {bitmask:b}"
+      );
+
+      let mut actual = vec![100 as T; N + 1];
+      assert_eq!(value.compress_store(mask, &mut actual), selected.len());
+      assert_eq!(actual[..selected.len()], selected);
+      assert!(actual[selected.len()..].iter().all(|&x| x == 100 as T));
+    }
+  });
+}
+
+#[test]
+fn test_expand() {
+  for_simd_types!(|T, N| {
+    let value = Simd::new(std::array::from_fn(|i| i as T + 1 as T));
+    let src = Simd::splat(100 as T);
+    for bitmask in [
+      0,
+      1,
+      0b1011_0110,
+      u64::MAX,
+      u64::MAX >> 1,
+      1 << (N - 1),
+      0x5555_5555_5555_5555,
+      0xDEAD_BEEF_1234_5678,
+    ] {
+      let mask = Mask::from_bitmask(bitmask);
+      let mut next = value.to_array().into_iter();
+      let expected = Simd::new(std::array::from_fn(|i| {
+        if mask.test(i) { next.next().unwrap() } else { 100 as T }
+      }));
+
+      assert_eq!(value.expand(mask, src), expected, "\n bitmask: {bitmask:b}");
+
+      // Compressing undoes expanding.
+      let count = mask.to_bitmask().count_ones() as usize;
+      let first = Simd::new(std::array::from_fn(|i| {
+        if i < count { value.as_array()[i] } else { 0 as T }
+      }));
+      assert_eq!(value.expand(mask, src).compress(mask), (first, count));
+    }
+  });
+}
+
+#[test]
+fn test_rotate_lanes() {
+  for_simd_types!(|T, N| {
+    let array: [T; N] = std::array::from_fn(|i| i as T + 1 as T);
+    let value = Simd::new(array);
+
+    let mut expected = array;
+    expected.rotate_left(1);
+    assert_eq!(value.rotate_lanes_left::<1>().to_array(), expected);
+    assert_eq!(value.rotate_lanes_right::<{ N - 1 }>().to_array(), expected);
+
+    let mut expected = array;
+    expected.rotate_right(3 % N);
+    assert_eq!(value.rotate_lanes_right::<3>().to_array(), expected);
+    assert_eq!(value.rotate_lanes_left::<{ 2 * N - 3 }>().to_array(), expected);
+
+    assert_eq!(value.rotate_lanes_left::<0>(), value);
+    assert_eq!(value.rotate_lanes_right::<N>(), value);
+  });
+}
+
+#[test]
+fn test_shuffle() {
+  for_simd_types!(|T, N| {
+    let array: [T; N] = std::array::from_fn(|i| i as T + 1 as T);
+    let other: [T; N] = std::array::from_fn(|i| i as T + 50 as T);
+    let value = Simd::new(array);
+
+    let idx: [usize; N] = std::array::from_fn(|i| (i * 3 + 1) % N);
+    assert_eq!(value.shuffle(idx).to_array(), idx.map(|i| array[i]));
+
+    let idx: [usize; N] = std::array::from_fn(|i| (i * 5 + 3) % (N * 2));
+    let both = [array, other].concat();
+    assert_eq!(
+      value.shuffle2(Simd::new(other), idx).to_array(),
+      idx.map(|i| both[i])
+    );
+  });
+}
+
+#[test]
+fn test_shuffle_macro() {
+  for_simd_types!(|T, N| {
+    let array: [T; N] = std::array::from_fn(|i| i as T + 1 as T);
+    let other: [T; N] = std::array::from_fn(|i| i as T + 50 as T);
+    let value = Simd::new(array);
+
+    const IDX: [usize; N] = {
+      let mut idx = [0; N];
+      let mut i = 0;
+      while i < N {
+        idx[i] = (i * 3 + 1) % N;
+        i += 1;
+      }
+      idx
+    };
+    assert_eq!(wide::shuffle!(value, IDX), value.shuffle(IDX));
+
+    const IDX2: [usize; N] = {
+      let mut idx = [0; N];
+      let mut i = 0;
+      while i < N {
+        idx[i] = (i * 5 + 3) % (N * 2);
+        i += 1;
+      }
+      idx
+    };
+    let other = Simd::new(other);
+    assert_eq!(
+      wide::shuffle2!(value, other, IDX2),
+      value.shuffle2(other, IDX2)
+    );
+  });
+
+  let value = u8x16::new(std::array::from_fn(|i| i as u8));
+  assert_eq!(
+    wide::shuffle!(
+      value,
+      [15, 0, 14, 1, 13, 2, 12, 3, 11, 4, 10, 5, 9, 6, 8, 7]
+    ),
+    u8x16::new([15, 0, 14, 1, 13, 2, 12, 3, 11, 4, 10, 5, 9, 6, 8, 7])
+  );
+}
+
+#[test]
+#[should_panic]
+fn test_shuffle_out_of_range() {
+  let _ = f32x4::ONE.shuffle([0, 1, 2, 4]);
+}
+
+#[test]
+fn test_permute() {
+  for_simd_types!(|T: Permute, N| {
+    let array: [T; N] = std::array::from_fn(|i| i as T + 1 as T);
+    let value = Simd::new(array);
+
+    let indices: [i64; N] =
+      std::array::from_fn(|i| (i as i64 * 7 + 3) % N as i64);
+    let actual = value.permute(Index::new(indices.map(|i| i as _)));
+    assert_eq!(actual.to_array(), indices.map(|i| array[i as usize]));
+
+    // Out-of-range and negative indices select zero.
+    let indices: [i64; N] = std::array::from_fn(|i| match i % 4 {
+      0 => N as i64 - 1 - i as i64,
+      1 => N as i64 + i as i64,
+      2 => -1 - i as i64,
+      _ => (i as i64 * 3) % N as i64,
+    });
+    let expected = indices.map(|i| {
+      if (0..N as i64).contains(&i) { array[i as usize] } else { 0 as T }
+    });
+    let actual = value.permute(Index::new(indices.map(|i| i as _)));
+    assert_eq!(actual.to_array(), expected);
+
+    let min = Index::splat(<Index as wide::SimdVector>::Elem::MIN);
+    assert_eq!(value.permute(min), Simd::ZERO);
+  });
+}
+
+#[test]
+fn test_reverse() {
+  for_simd_types!(|T, N| {
+    let array: [T; N] = std::array::from_fn(|i| i as T + 1 as T);
+
+    let mut expected = array;
+    expected.reverse();
+    assert_eq!(Simd::new(array).reverse().to_array(), expected);
+  });
+}
+
+#[test]
+fn test_broadcast_lane() {
+  for_simd_types!(|T, N| {
+    let value = Simd::new(std::array::from_fn(|i| i as T + 1 as T));
+
+    assert_eq!(value.broadcast_lane::<0>(), Simd::splat(1 as T));
+    assert_eq!(value.broadcast_lane::<{ N - 1 }>(), Simd::splat(N as T));
+  });
+}
+
+#[test]
+fn test_interleave() {
+  for_simd_types!(|T, N| {
+    let array: [T; N] = std::array::from_fn(|i| i as T + 1 as T);
+    let other: [T; N] = std::array::from_fn(|i| i as T + 50 as T);
+    let (value, other_value) = (Simd::new(array), Simd::new(other));
+
+    let expected: [T; N] =
+      std::array::from_fn(|i| [array, other][i % 2][i / 2]);
+    assert_eq!(value.interleave_low(other_value).to_array(), expected);
+
+    let expected: [T; N] =
+      std::array::from_fn(|i| [array, other][i % 2][N / 2 + i / 2]);
+    assert_eq!(value.interleave_high(other_value).to_array(), expected);
+  });
+}
+
+#[test]
+fn test_concat_split() {
+  let low = f32x4::from([1.0, 2.0, 3.0, 4.0]);
+  let high = f32x4::from([5.0, 6.0, 7.0, 8.0]);
+  let full = low.concat(high);
+
+  assert_eq!(full.to_array(), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+  assert_eq!(full.split(), (low, high));
+
+  let low = u16x16::from(std::array::from_fn::<u16, 16, _>(|i| i as u16));
+  let high = u16x16::MAX;
+  let full = low.concat(high);
+
+  assert_eq!(full.to_array()[..16], low.to_array());
+  assert_eq!(full.to_array()[16..], high.to_array());
+  assert_eq!(full.split(), (low, high));
+  assert_eq!(u16x32::MAX.split(), (u16x16::MAX, u16x16::MAX));
+  assert_eq!(
+    i64x2::from([1, 2]).concat(i64x2::from([3, 4])).split().1.to_array(),
+    [3, 4]
+  );
+}
+
+#[test]
+fn test_deinterleave() {
+  for_simd_types!(|T, N| {
+    let data: Vec<T> = (0..4 * N).map(|i| i as T).collect();
+    let vectors: Vec<Simd> =
+      data.chunks(N).map(|c| Simd::new(c.try_into().unwrap())).collect();
+    let plane =
+      |k: usize, j: usize| Simd::new(std::array::from_fn(|i| data[i * k + j]));
+
+    let planes = Simd::deinterleave2([vectors[0], vectors[1]]);
+    assert_eq!(planes, [plane(2, 0), plane(2, 1)]);
+    assert_eq!(Simd::interleave2(planes), [vectors[0], vectors[1]]);
+
+    let planes = Simd::deinterleave3([vectors[0], vectors[1], vectors[2]]);
+    assert_eq!(planes, [plane(3, 0), plane(3, 1), plane(3, 2)]);
+    assert_eq!(Simd::interleave3(planes), [vectors[0], vectors[1], vectors[2]]);
+
+    let planes = Simd::deinterleave4(vectors.clone().try_into().unwrap());
+    assert_eq!(planes, [plane(4, 0), plane(4, 1), plane(4, 2), plane(4, 3)]);
+    assert_eq!(Simd::interleave4(planes).as_slice(), vectors.as_slice());
+  });
+}
+
+#[test]
+fn test_load_store_interleaved() {
+  for_simd_types!(|T, N| {
+    let data: Vec<T> = (0..4 * N + 1).map(|i| i as T).collect();
+
+    let planes = Simd::load_deinterleaved3(&data);
+    for (j, plane) in planes.iter().enumerate() {
+      let expected: [T; N] = std::array::from_fn(|i| data[3 * i + j]);
+      assert_eq!(plane.to_array(), expected);
+    }
+
+    let mut out = vec![0 as T; 3 * N + 1];
+    Simd::store_interleaved3(planes, &mut out);
+    assert_eq!(out[..3 * N], data[..3 * N]);
+    assert_eq!(out[3 * N], 0 as T);
+
+    let planes = Simd::load_deinterleaved2(&data);
+    let mut out = vec![0 as T; 2 * N];
+    Simd::store_interleaved2(planes, &mut out);
+    assert_eq!(out, data[..2 * N]);
+
+    let planes = Simd::load_deinterleaved4(&data);
+    let mut out = vec![0 as T; 4 * N];
+    Simd::store_interleaved4(planes, &mut out);
+    assert_eq!(out, data[..4 * N]);
+  });
+}
+
+#[test]
+#[should_panic]
+fn test_load_deinterleaved_short_slice() {
+  let _ = f32x4::load_deinterleaved3(&[0.0; 11]);
+}
+
+#[test]
+fn test_gather() {
+  for_simd_types!(|T: Gather, N| {
+    let table = Vec::from_iter((0..40).map(|i| i as T * 3 as T));
+    let pattern = [0, 39, 5, 5, 12, 1, 38, 0, 7, 20, 3, 9];
+    for offset in 0..pattern.len() {
+      let indices: [usize; N] =
+        std::array::from_fn(|i| pattern[(i + offset) % pattern.len()]);
+      let expected = Simd::new(indices.map(|i| table[i]));
+      let actual = Simd::gather(&table, Index::new(indices.map(|i| i as _)));
+
+      assert_eq!(actual, expected, "\n indices: {indices:?}");
+    }
+  });
+}
+
+#[test]
+fn test_gather_out_of_bounds() {
+  for_simd_types!(|T: Gather, N| {
+    let table = [1 as T; 10];
+    for bad_index in [-1, 10, 1000] {
+      let mut indices = Index::splat(2);
+      indices.as_mut_array()[N - 1] = bad_index;
+
+      let result = std::panic::catch_unwind(|| Simd::gather(&table, indices));
+      assert!(result.is_err(), "\n   index: {bad_index}");
+    }
+  });
+}
+
+#[test]
+fn test_masked_gather() {
+  for_simd_types!(|T: Gather, N| {
+    let table = Vec::from_iter((0..40).map(|i| i as T + 1 as T));
+    let fill = Simd::splat(100 as T);
+    for bitmask in [0, 1, 0b1011_0110, u64::MAX, 1 << (N - 1)] {
+      let mask = Mask::from_bitmask(bitmask);
+      let indices = Index::new(std::array::from_fn(|i| {
+        // Disabled lanes get indices that are out of bounds.
+        let index =
+          if mask.test(i) { (i * 5 % 40) as i64 } else { -1 - i as i64 };
+        index as _
+      }));
+      let expected = Simd::new(std::array::from_fn(|i| {
+        if mask.test(i) { table[i * 5 % 40] } else { 100 as T }
+      }));
+      let actual = Simd::masked_gather(&table, indices, mask, fill);
+
+      assert_eq!(actual, expected, "\n bitmask: {bitmask:b}");
+    }
+  });
+}
+
+#[test]
+fn test_scatter() {
+  for_simd_types!(|T: Gather, N| {
+    let value = Simd::new(std::array::from_fn(|i| i as T + 1 as T));
+    let pattern = [0, 39, 5, 6, 12, 1, 38, 2, 7, 20, 3, 9, 11, 13, 14, 15];
+    for offset in 0..pattern.len() {
+      let indices: [usize; N] =
+        std::array::from_fn(|i| pattern[(i + offset) % pattern.len()]);
+      let mut expected = vec![100 as T; 40];
+      for (&index, &elem) in indices.iter().zip(value.as_array()) {
+        expected[index] = elem;
+      }
+      let mut actual = vec![100 as T; 40];
+      value.scatter(&mut actual, Index::new(indices.map(|i| i as _)));
+
+      assert_eq!(actual, expected, "\n indices: {indices:?}");
+    }
+
+    // The last lane wins when indices repeat.
+    let mut actual = vec![100 as T; 4];
+    value.scatter(&mut actual, Index::splat(3));
+    assert_eq!(actual[3], N as T);
+  });
+}
+
+#[test]
+fn test_masked_scatter() {
+  for_simd_types!(|T: Gather, N| {
+    let value = Simd::new(std::array::from_fn(|i| i as T + 1 as T));
+    for bitmask in [0, 1, 0b1011_0110, u64::MAX, 1 << (N - 1)] {
+      let mask = Mask::from_bitmask(bitmask);
+      let indices = Index::new(std::array::from_fn(|i| {
+        // Disabled lanes get indices that are out of bounds.
+        let index = if mask.test(i) { (i * 2) as i64 } else { -1 - i as i64 };
+        index as _
+      }));
+      let mut expected = vec![100 as T; 2 * N];
+      for i in 0..N {
+        if mask.test(i) {
+          expected[i * 2] = value.as_array()[i];
+        }
+      }
+      let mut actual = vec![100 as T; 2 * N];
+      value.masked_scatter(&mut actual, indices, mask);
+
+      assert_eq!(actual, expected, "\n bitmask: {bitmask:b}");
+    }
+  });
+}
+
 #[test]
 fn test_simd_align_to() {
   for_simd_types!(|T, N| {
@@ -1,3 +1,5 @@
+#![allow(clippy::excessive_precision)]
+
 use wide::{f32x4, f32x8, f32x16, f64x2, f64x4, f64x8, i32x4, i32x8, i32x16};
 
 use crate::utils::{for_simd_types, random_iter, simd_chunks};
@@ -266,6 +268,73 @@ fn test_min() {
   });
 }
 
+#[test]
+fn test_reduce_max_min() {
+  for_simd_types!(|T: Float, N| {
+    for value in simd_chunks!([1.0, -2.0, 0.0, -0.0, 6.0, -8.0, 3.5, -1.5])
+      .chain(random_iter())
+    {
+      for i in 0..=N {
+        let mut value = value;
+        if i != N {
+          value[i] = T::NAN;
+        }
+        let simd = Simd::new(value);
+
+        let expected = value.into_iter().reduce(T::max).unwrap();
+        let actual = simd.reduce_max();
+        assert!(actual == expected || actual.is_nan() && expected.is_nan());
+
+        let expected = value.into_iter().reduce(T::min).unwrap();
+        let actual = simd.reduce_min();
+        assert!(actual == expected || actual.is_nan() && expected.is_nan());
+      }
+    }
+    assert!(Simd::NAN.reduce_max().is_nan());
+    assert!(Simd::NAN.reduce_min().is_nan());
+  });
+}
+
+#[test]
+fn test_reduce_maximum_minimum() {
+  for_simd_types!(|T: Float, N| {
+    for value in simd_chunks!([1.0, -2.0, 0.0, -0.0, 6.0, -8.0, -0.0, 0.0])
+      .chain(simd_chunks!([-0.0, -1.0, 0.0, -0.0, -5.0, -0.0]))
+      .chain(simd_chunks!([0.0, 1.0, 0.0, -0.0, 5.0, 0.0]))
+      .chain(random_iter())
+    {
+      for i in 0..=N {
+        let mut value = value;
+        if i != N {
+          value[i] = T::NAN;
+        }
+        let simd = Simd::new(value);
+
+        if value.iter().any(|x| x.is_nan()) {
+          assert!(simd.reduce_maximum().is_nan());
+          assert!(simd.reduce_minimum().is_nan());
+          continue;
+        }
+        let maximum = value
+          .into_iter()
+          .reduce(
+            |a, b| if a > b || a == b && a.is_sign_positive() { a } else { b },
+          )
+          .unwrap();
+        let minimum = value
+          .into_iter()
+          .reduce(
+            |a, b| if a < b || a == b && a.is_sign_negative() { a } else { b },
+          )
+          .unwrap();
+
+        assert_eq!(simd.reduce_maximum().to_bits(), maximum.to_bits());
+        assert_eq!(simd.reduce_minimum().to_bits(), minimum.to_bits());
+      }
+    }
+  });
+}
+
 #[test]
 fn test_clamp() {
   for_simd_types!(|T: Float, N| {
@@ -386,13 +455,11 @@ fn test_is_nan() {
     ])
     .chain(random_iter())
     {
-      let expected = Simd::new(
-        value.map(|x| if x.is_nan() { T::from_bits(!0) } else { 0.0 }),
-      );
+      let expected = Mask::new(value.map(|x| x.is_nan()));
       let actual = Simd::new(value).is_nan();
 
       assert!(
-        actual ^ expected == Simd::ZERO,
+        actual == expected,
         "expected: {expected:?}\n  actual: {actual:?}",
       );
     }
@@ -416,13 +483,11 @@ fn test_is_finite() {
     ])
     .chain(random_iter())
     {
-      let expected = Simd::new(
-        value.map(|x| if x.is_finite() { T::from_bits(!0) } else { 0.0 }),
-      );
+      let expected = Mask::new(value.map(|x| x.is_finite()));
       let actual = Simd::new(value).is_finite();
 
       assert!(
-        actual ^ expected == Simd::ZERO,
+        actual == expected,
         "expected: {expected:?}\n  actual: {actual:?}",
       );
     }
@@ -446,13 +511,11 @@ fn test_is_inf() {
     ])
     .chain(random_iter())
     {
-      let expected = Simd::new(
-        value.map(|x| if x.is_infinite() { T::from_bits(!0) } else { 0.0 }),
-      );
+      let expected = Mask::new(value.map(|x| x.is_infinite()));
       let actual = Simd::new(value).is_inf();
 
       assert!(
-        actual ^ expected == Simd::ZERO,
+        actual == expected,
         "expected: {expected:?}\n  actual: {actual:?}",
       );
     }
@@ -1683,14 +1746,11 @@ fn test_is_sign_positive() {
     for value in simd_chunks!([2401.0, -123.0, T::INFINITY, T::NEG_INFINITY])
       .chain(random_iter())
     {
-      let expected = Simd::new(
-        value
-          .map(|x| if x.is_sign_positive() { T::from_bits(!0) } else { 0.0 }),
-      );
+      let expected = Mask::new(value.map(|x| x.is_sign_positive()));
       let actual = Simd::new(value).is_sign_positive();
 
       assert!(
-        actual ^ expected == Simd::ZERO,
+        actual == expected,
         "expected: {expected:?}\n  actual: {actual:?}",
       );
     }
@@ -1703,14 +1763,11 @@ fn test_is_sign_negative() {
     for value in simd_chunks!([2401.0, -123.0, T::INFINITY, T::NEG_INFINITY])
       .chain(random_iter())
     {
-      let expected = Simd::new(
-        value
-          .map(|x| if x.is_sign_negative() { T::from_bits(!0) } else { 0.0 }),
-      );
+      let expected = Mask::new(value.map(|x| x.is_sign_negative()));
       let actual = Simd::new(value).is_sign_negative();
 
       assert!(
-        actual ^ expected == Simd::ZERO,
+        actual == expected,
         "expected: {expected:?}\n  actual: {actual:?}",
       );
     }
@@ -2188,114 +2245,849 @@ fn test_cbrt() {
 }
 
 #[test]
-fn test_exp_overflow_boundary() {
+fn test_erf() {
   for_simd_types!(|T: Float, N| {
-    let max_x: T =
-      if std::mem::size_of::<T>() == 4 { 88.723 as T } else { 709.783 as T };
-    let near_max = max_x * (0.999 as T);
-    let over_max = max_x * (1.001 as T);
+    for [value, expected] in simd_chunks!(
+      [
+        -3.0,
+        -1.0,
+        -0.5,
+        0.0,
+        1e-10,
+        0.5,
+        1.0,
+        1.5,
+        3.0,
+        6.0,
+        T::INFINITY,
+        T::NAN
+      ],
+      [
+        -0.9999779095030014,
+        -0.8427007929497149,
+        -0.5204998778130465,
+        0.0,
+        1.1283791670955126e-10,
+        0.5204998778130465,
+        0.8427007929497149,
+        0.9661051464753108,
+        0.9999779095030014,
+        1.0,
+        1.0,
+        T::NAN,
+      ],
+    ) {
+      let expected = Simd::new(expected);
+      let actual = Simd::new(value).erf();
+      let tol = expected.abs() * Simd::splat(T::EPSILON * 1.5);
 
-    for v in [max_x, near_max, over_max, -max_x] {
-      let input = Simd::splat(v);
-      let expected = Simd::splat(T::exp(v));
-      let actual = input.exp();
-      let tol = expected.abs().fast_max(Simd::splat(T::MIN_POSITIVE))
-        * Simd::splat(T::EPSILON * 1.0);
       assert!(
         ((actual - expected).abs().simd_le(tol)
-          | actual.simd_eq(expected)
           | actual.is_nan() & expected.is_nan())
         .all(),
-        "exp({v:e}) expected: {expected:?} actual: {actual:?}"
+        "expected: {expected:?}\n  actual: {actual:?}\n value: {value:?}",
       );
     }
   });
 }
 
 #[test]
-fn test_exp_subnormal() {
+fn test_erfc() {
   for_simd_types!(|T: Float, N| {
-    let subnormal_input: T =
-      if std::mem::size_of::<T>() == 4 { (-88.0) as T } else { (-710.0) as T };
-    let deeper: T =
-      if std::mem::size_of::<T>() == 4 { (-95.0) as T } else { (-730.0) as T };
-    for v in [subnormal_input, deeper] {
-      let input = Simd::splat(v);
-      let expected = Simd::splat(T::exp(v));
-      let actual = input.exp();
-      let tol = expected.abs().fast_max(Simd::splat(T::MIN_POSITIVE))
-        * Simd::splat(T::EPSILON * 1.0);
+    for [value, expected] in simd_chunks!(
+      [-1.0, 0.0, 0.5, 1.0, 2.0, 5.0, 9.0, 30.0, T::NEG_INFINITY, T::NAN],
+      [
+        1.8427007929497148,
+        1.0,
+        0.4795001221869535,
+        0.15729920705028513,
+        0.004677734981047266,
+        1.537459794428035e-12,
+        4.13703174651381e-37,
+        0.0,
+        2.0,
+        T::NAN,
+      ],
+    ) {
+      let expected = Simd::new(expected);
+      let actual = Simd::new(value).erfc();
+      let tol = expected.abs() * Simd::splat(T::EPSILON * 4.0);
+
       assert!(
-        (actual - expected).abs().simd_le(tol).all(),
-        "exp({v:e}) = {actual:?} expected {expected:?}"
+        ((actual - expected).abs().simd_le(tol)
+          | actual.is_nan() & expected.is_nan())
+        .all(),
+        "expected: {expected:?}\n  actual: {actual:?}\n value: {value:?}",
       );
     }
   });
 }
 
 #[test]
-fn test_math_nan_propagation() {
+fn test_ln_gamma() {
   for_simd_types!(|T: Float, N| {
-    let nan = T::NAN;
-    let mut arr = [0.0 as T; N];
-    arr[0] = nan;
-    if N > 1 {
-      arr[1] = 1.0 as T;
-    }
-    let input = Simd::from(arr);
+    for [value, expected] in simd_chunks!(
+      [
+        0.1,
+        0.5,
+        1.0,
+        1.5,
+        2.0,
+        2.5,
+        4.0,
+        10.0,
+        50.0,
+        1000.0,
+        -0.5,
+        -3.5,
+        0.0,
+        -1.0,
+        T::INFINITY,
+        T::NEG_INFINITY,
+        T::NAN,
+        1.5
+      ],
+      [
+        2.252712651734206,
+        0.5723649429247001,
+        0.0,
+        -0.12078223763524522,
+        0.0,
+        0.2846828704729192,
+        1.791759469228055,
+        12.801827480081469,
+        144.5657439463449,
+        5905.220423209181,
+        1.2655121234846454,
+        -1.3090066849930417,
+        T::INFINITY,
+        T::INFINITY,
+        T::INFINITY,
+        T::INFINITY,
+        T::NAN,
+        -0.12078223763524522,
+      ],
+    ) {
+      let expected = Simd::new(expected);
+      let actual = Simd::new(value).ln_gamma();
+      let tol = expected.abs() * Simd::splat(T::EPSILON * 6.0);
 
-    let result = input.exp().to_array();
-    assert!(result[0].is_nan(), "exp: lane 0 NaN");
-    if N > 1 {
-      assert!(!result[1].is_nan(), "exp: lane 1 finite");
+      assert!(
+        ((actual - expected).abs().simd_le(tol)
+          | actual.simd_eq(expected)
+          | actual.is_nan() & expected.is_nan())
+        .all(),
+        "expected: {expected:?}\n  actual: {actual:?}\n value: {value:?}",
+      );
     }
+  });
+}
 
-    let result = input.exp_m1().to_array();
-    assert!(result[0].is_nan(), "exp_m1: lane 0 NaN");
-    if N > 1 {
-      assert!(!result[1].is_nan(), "exp_m1: lane 1 finite");
-    }
+#[test]
+fn test_gamma() {
+  for_simd_types!(|T: Float, N| {
+    for [value, expected] in simd_chunks!(
+      [
+        0.1,
+        0.5,
+        1.0,
+        1.5,
+        2.5,
+        5.0,
+        10.5,
+        20.0,
+        30.0,
+        -0.5,
+        -1.5,
+        -2.5,
+        0.0,
+        -0.0,
+        -1.0,
+        200.0,
+        T::INFINITY,
+        T::NEG_INFINITY,
+        T::NAN,
+        5.0
+      ],
+      [
+        9.51350769866873,
+        1.772453850905516,
+        1.0,
+        0.886226925452758,
+        1.3293403881791372,
+        24.0,
+        1133278.3889487856,
+        1.21645100408832e17,
+        8.841761993739702e30,
+        -3.544907701811032,
+        2.363271801207355,
+        -0.9453087204829419,
+        T::INFINITY,
+        T::NEG_INFINITY,
+        T::NAN,
+        T::INFINITY,
+        T::INFINITY,
+        T::NAN,
+        T::NAN,
+        24.0,
+      ],
+    ) {
+      let expected = Simd::new(expected);
+      let actual = Simd::new(value).gamma();
+      let tol = expected.abs() * Simd::splat(T::EPSILON * 8.0);
 
-    let result = input.ln().to_array();
-    assert!(result[0].is_nan(), "ln: lane 0 NaN");
-    if N > 1 {
-      assert!(!result[1].is_nan(), "ln: lane 1 finite");
+      assert!(
+        ((actual - expected).abs().simd_le(tol)
+          | actual.simd_eq(expected)
+          | actual.is_nan() & expected.is_nan())
+        .all(),
+        "expected: {expected:?}\n  actual: {actual:?}\n value: {value:?}",
+      );
     }
+  });
+}
 
-    let result = input.ln_1p().to_array();
-    assert!(result[0].is_nan(), "ln_1p: lane 0 NaN");
-    if N > 1 {
-      assert!(!result[1].is_nan(), "ln_1p: lane 1 finite");
-    }
+#[test]
+fn test_hypot() {
+  for_simd_types!(|T: Float, N| {
+    for [value, other] in simd_chunks!(
+      [
+        3.0,
+        -3.0,
+        0.0,
+        1e-30,
+        1e30,
+        T::MAX,
+        T::MIN_POSITIVE,
+        T::INFINITY,
+        T::NAN,
+        1.0
+      ],
+      [
+        4.0,
+        4.0,
+        -0.0,
+        1e-30,
+        -1e30,
+        T::MAX,
+        T::MIN_POSITIVE,
+        T::NAN,
+        1.0,
+        T::NAN
+      ],
+    ) {
+      let expected =
+        Simd::new(std::array::from_fn(|i| value[i].hypot(other[i])));
+      let actual = Simd::new(value).hypot(Simd::new(other));
+      let tol = expected.abs() * Simd::splat(T::EPSILON * 2.0);
 
-    let result = input.sinh().to_array();
-    assert!(result[0].is_nan(), "sinh: lane 0 NaN");
-    if N > 1 {
-      assert!(!result[1].is_nan(), "sinh: lane 1 finite");
+      assert!(
+        ((actual - expected).abs().simd_le(tol)
+          | actual.simd_eq(expected)
+          | actual.is_nan() & expected.is_nan())
+        .all(),
+        "expected: {expected:?}\n  actual: {actual:?}\n value: {value:?}",
+      );
     }
 
-    let result = input.cosh().to_array();
-    assert!(result[0].is_nan(), "cosh: lane 0 NaN");
-    if N > 1 {
-      assert!(!result[1].is_nan(), "cosh: lane 1 finite");
-    }
+    let mut x = 0x5eed_1e55_u64;
+    for _ in 0..2000 {
+      x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+      let (u, v) = if size_of::<T>() == 4 {
+        (
+          f32::from_bits(x as u32) as f64,
+          f32::from_bits((x >> 32) as u32) as f64,
+        )
+      } else {
+        (f64::from_bits(x), f64::from_bits(x.rotate_left(32)))
+      };
+      let u = if u.is_finite() { u as T } else { 0.0 };
+      let v = if v.is_finite() { v as T } else { 0.0 };
+      let expected = Simd::from(u.hypot(v));
+      let actual = Simd::from(u).hypot(Simd::from(v));
+      let tol = expected * Simd::from(T::EPSILON * 2.0);
 
-    let result = input.tanh().to_array();
-    assert!(result[0].is_nan(), "tanh: lane 0 NaN");
-    if N > 1 {
-      assert!(!result[1].is_nan(), "tanh: lane 1 finite");
+      assert!(
+        (actual - expected).abs().simd_le(tol).all(),
+        "hypot({u:e}, {v:e}) actual: {actual:?} expected: {expected:?}"
+      );
     }
+  });
+}
 
-    let result = input.cbrt().to_array();
-    assert!(result[0].is_nan(), "cbrt: lane 0 NaN");
-    if N > 1 {
-      assert!(!result[1].is_nan(), "cbrt: lane 1 finite");
+#[test]
+fn test_asinh() {
+  for_simd_types!(|T: Float, N| {
+    for value in simd_chunks!([
+      -1e30,
+      -3.0,
+      -1.0,
+      -0.1,
+      -0.0,
+      0.0,
+      1e-30,
+      0.5,
+      2.0,
+      1e10,
+      T::MAX,
+      T::INFINITY,
+      T::NEG_INFINITY,
+      T::NAN
+    ]) {
+      let expected = Simd::new(value.map(T::asinh));
+      let actual = Simd::new(value).asinh();
+      let tol = expected.abs() * Simd::splat(T::EPSILON * 3.0);
+
+      assert!(
+        ((actual - expected).abs().simd_le(tol)
+          | actual.simd_eq(expected)
+          | actual.is_nan() & expected.is_nan())
+        .all(),
+        "expected: {expected:?}\n  actual: {actual:?}\n value: {value:?}",
+      );
+      assert!(
+        (expected.simd_eq(Simd::splat(0.0))
+          & (actual.is_sign_negative() ^ expected.is_sign_negative()))
+        .none(),
+        "sign of zero differs\nexpected: {expected:?}\n  actual: {actual:?}",
+      );
     }
   });
 }
 
 #[test]
-fn test_sign_preservation() {
+fn test_acosh() {
+  for_simd_types!(|T: Float, N| {
+    for [value, expected] in simd_chunks!(
+      [
+        1.0,
+        1.0009765625,
+        1.5,
+        2.0,
+        10.0,
+        1e30,
+        T::INFINITY,
+        0.5,
+        -1.0,
+        -3.0,
+        T::NEG_INFINITY,
+        T::NAN
+      ],
+      [
+        0.0,
+        0.044190578083110096,
+        0.9624236501192069,
+        1.3169578969248166,
+        2.993222846126381,
+        69.77069997038132,
+        T::INFINITY,
+        T::NAN,
+        T::NAN,
+        T::NAN,
+        T::NAN,
+        T::NAN,
+      ],
+    ) {
+      let expected = Simd::new(expected);
+      let actual = Simd::new(value).acosh();
+      let tol = expected.abs() * Simd::splat(T::EPSILON * 3.0);
+
+      assert!(
+        ((actual - expected).abs().simd_le(tol)
+          | actual.simd_eq(expected)
+          | actual.is_nan() & expected.is_nan())
+        .all(),
+        "expected: {expected:?}\n  actual: {actual:?}\n value: {value:?}",
+      );
+    }
+  });
+}
+
+#[test]
+fn test_atanh() {
+  for_simd_types!(|T: Float, N| {
+    for [value, expected] in simd_chunks!(
+      [
+        -1.0,
+        -0.9921875,
+        -0.5,
+        -1e-30,
+        -0.0,
+        0.0,
+        0.25,
+        0.75,
+        1.0,
+        1.5,
+        -2.0,
+        T::INFINITY,
+        T::NAN
+      ],
+      [
+        T::NEG_INFINITY,
+        -2.770631772579213,
+        -0.5493061443340548,
+        -1e-30,
+        -0.0,
+        0.0,
+        0.25541281188299536,
+        0.9729550745276566,
+        T::INFINITY,
+        T::NAN,
+        T::NAN,
+        T::NAN,
+        T::NAN,
+      ],
+    ) {
+      let expected = Simd::new(expected);
+      let actual = Simd::new(value).atanh();
+      let tol = expected.abs() * Simd::splat(T::EPSILON * 3.0);
+
+      assert!(
+        ((actual - expected).abs().simd_le(tol)
+          | actual.simd_eq(expected)
+          | actual.is_nan() & expected.is_nan())
+        .all(),
+        "expected: {expected:?}\n  actual: {actual:?}\n value: {value:?}",
+      );
+      assert!(
+        (expected.simd_eq(Simd::splat(0.0))
+          & (actual.is_sign_negative() ^ expected.is_sign_negative()))
+        .none(),
+        "sign of zero differs\nexpected: {expected:?}\n  actual: {actual:?}",
+      );
+    }
+  });
+}
+
+#[test]
+fn test_exp10() {
+  for_simd_types!(|T: Float, N| {
+    for value in simd_chunks!([
+      -400.0,
+      -30.5,
+      -3.0,
+      -1.0,
+      -0.0,
+      0.0,
+      0.5,
+      1.0,
+      2.5,
+      10.0,
+      30.0,
+      400.0,
+      T::INFINITY,
+      T::NEG_INFINITY,
+      T::NAN
+    ]) {
+      let expected = Simd::new(value.map(|x| (10.0 as T).powf(x)));
+      let actual = Simd::new(value).exp10();
+      let tol = expected.abs() * Simd::splat(T::EPSILON * 2.0);
+
+      assert!(
+        ((actual - expected).abs().simd_le(tol)
+          | actual.simd_eq(expected)
+          | actual.is_nan() & expected.is_nan())
+        .all(),
+        "expected: {expected:?}\n  actual: {actual:?}\n value: {value:?}",
+      );
+    }
+  });
+}
+
+#[test]
+fn test_sin_cos_pi() {
+  for_simd_types!(|T: Float, N| {
+    for [value, expected_sin, expected_cos] in simd_chunks!(
+      [
+        0.0,
+        -0.0,
+        0.25,
+        0.5,
+        1.0,
+        -1.0,
+        1.5,
+        -2.0,
+        2.5,
+        1.0 / 6.0,
+        -1.75,
+        1e30,
+        -1e30,
+        T::INFINITY,
+        T::NAN
+      ],
+      [
+        0.0,
+        -0.0,
+        core::f64::consts::FRAC_1_SQRT_2 as T,
+        1.0,
+        0.0,
+        -0.0,
+        -1.0,
+        -0.0,
+        1.0,
+        0.5,
+        core::f64::consts::FRAC_1_SQRT_2 as T,
+        0.0,
+        -0.0,
+        T::NAN,
+        T::NAN,
+      ],
+      [
+        1.0,
+        1.0,
+        core::f64::consts::FRAC_1_SQRT_2 as T,
+        0.0,
+        -1.0,
+        -1.0,
+        0.0,
+        1.0,
+        0.0,
+        0.8660254037844386,
+        core::f64::consts::FRAC_1_SQRT_2 as T,
+        1.0,
+        1.0,
+        T::NAN,
+        T::NAN,
+      ],
+    ) {
+      let (actual_sin, actual_cos) = Simd::new(value).sin_cos_pi();
+      for (actual, expected) in
+        [(actual_sin, expected_sin), (actual_cos, expected_cos)]
+      {
+        let expected = Simd::new(expected);
+        let tol = expected.abs() * Simd::splat(T::EPSILON * 2.0);
+
+        assert!(
+          ((actual - expected).abs().simd_le(tol)
+            | actual.is_nan() & expected.is_nan())
+          .all(),
+          "expected: {expected:?}\n  actual: {actual:?}\n value: {value:?}",
+        );
+        // zeros are exact and have the IEEE 754 sign
+        assert_eq!(
+          actual.to_bits().simd_eq(Simd::ZERO.to_bits()),
+          expected.to_bits().simd_eq(Simd::ZERO.to_bits()),
+          "expected: {expected:?}\n  actual: {actual:?}\n value: {value:?}",
+        );
+      }
+      assert_eq!(Simd::new(value).sin_pi().to_bits(), actual_sin.to_bits());
+      assert_eq!(Simd::new(value).cos_pi().to_bits(), actual_cos.to_bits());
+    }
+  });
+}
+
+#[test]
+fn test_tan_pi() {
+  for_simd_types!(|T: Float, N| {
+    for [value, expected] in simd_chunks!(
+      [0.0, 0.25, -0.25, 0.5, 1.5, -0.5, 1.0, 0.75, 1e30, T::INFINITY, T::NAN],
+      [
+        0.0,
+        1.0,
+        -1.0,
+        T::INFINITY,
+        T::NEG_INFINITY,
+        T::NEG_INFINITY,
+        -0.0,
+        -1.0,
+        0.0,
+        T::NAN,
+        T::NAN,
+      ],
+    ) {
+      let expected = Simd::new(expected);
+      let actual = Simd::new(value).tan_pi();
+      let tol = expected.abs() * Simd::splat(T::EPSILON * 4.0);
+
+      assert!(
+        ((actual - expected).abs().simd_le(tol)
+          | actual.simd_eq(expected)
+          | actual.is_nan() & expected.is_nan())
+        .all(),
+        "expected: {expected:?}\n  actual: {actual:?}\n value: {value:?}",
+      );
+    }
+  });
+}
+
+#[test]
+fn test_powi() {
+  for_simd_types!(|T: Float, N| {
+    for value in simd_chunks!([
+      -2.0,
+      -0.5,
+      -0.0,
+      0.0,
+      0.1,
+      1.5,
+      3.0,
+      1e10,
+      T::INFINITY,
+      T::NEG_INFINITY,
+      T::NAN
+    ]) {
+      for n in [-40, -3, -2, -1, 0, 1, 2, 3, 7, 40, i32::MIN, i32::MAX] {
+        let expected = Simd::new(value.map(|x| x.powi(n)));
+        let actual = Simd::new(value).powi(n);
+        let tol = expected.abs() * Simd::splat(T::EPSILON * 8.0);
+
+        assert!(
+          ((actual - expected).abs().simd_le(tol)
+            | actual.simd_eq(expected)
+            | actual.is_nan() & expected.is_nan())
+          .all(),
+          "expected: {expected:?}\n  actual: {actual:?}\n value: {value:?} \
+           n: {n}",
+        );
+      }
+    }
+  });
+}
+
+#[test]
+fn test_rsqrt() {
+  for_simd_types!(|T: Float, N| {
+    for value in simd_chunks!([
+      0.0,
+      -0.0,
+      0.25,
+      2.0,
+      1e-30,
+      1e30,
+      T::MIN_POSITIVE,
+      T::MAX,
+      T::MIN_POSITIVE * T::EPSILON,
+      T::INFINITY,
+      -1.0,
+      -T::MIN_POSITIVE * T::EPSILON,
+      -T::MIN_POSITIVE / 2.0,
+      T::NAN
+    ]) {
+      let expected = Simd::new(value.map(|x| 1.0 / x.sqrt()));
+      let actual = Simd::new(value).rsqrt();
+      let tol = expected.abs() * Simd::splat(T::EPSILON * 4.0);
+
+      assert!(
+        ((actual - expected).abs().simd_le(tol)
+          | actual.simd_eq(expected)
+          | actual.is_nan() & expected.is_nan())
+        .all(),
+        "expected: {expected:?}\n  actual: {actual:?}\n value: {value:?}",
+      );
+    }
+
+    let mut x = 0x5eed_1e55_u64;
+    for _ in 0..2000 {
+      x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+      let u = if size_of::<T>() == 4 {
+        f32::from_bits(x as u32 & 0x7fff_ffff) as f64
+      } else {
+        f64::from_bits(x & 0x7fff_ffff_ffff_ffff)
+      };
+      let u = if u.is_finite() { u as T } else { 1.0 };
+      let expected = Simd::from(1.0 / u.sqrt());
+      let actual = Simd::from(u).rsqrt();
+      let tol = expected * Simd::from(T::EPSILON * 4.0);
+
+      assert!(
+        (actual - expected).abs().simd_le(tol).all(),
+        "rsqrt({u:e}) actual: {actual:?} expected: {expected:?}"
+      );
+    }
+  });
+}
+
+#[test]
+fn test_log() {
+  for_simd_types!(|T: Float, N| {
+    for [value, base] in simd_chunks!(
+      [8.0, 100.0, 0.5, 10.0, 0.0, 5.0, -1.0, T::INFINITY, 2.0, T::NAN],
+      [2.0, 10.0, 2.0, 0.5, 3.0, 1.0, 2.0, 10.0, T::NAN, 2.0],
+    ) {
+      let expected = Simd::new(std::array::from_fn(|i| value[i].log(base[i])));
+      let actual = Simd::new(value).log(Simd::new(base));
+      let tol = expected.abs() * Simd::splat(T::EPSILON * 4.0);
+
+      assert!(
+        ((actual - expected).abs().simd_le(tol)
+          | actual.simd_eq(expected)
+          | actual.is_nan() & expected.is_nan())
+        .all(),
+        "expected: {expected:?}\n  actual: {actual:?}\n value: {value:?}",
+      );
+    }
+  });
+}
+
+#[test]
+fn test_exp_overflow_boundary() {
+  for_simd_types!(|T: Float, N| {
+    let max_x: T =
+      if std::mem::size_of::<T>() == 4 { 88.723 as T } else { 709.783 as T };
+    let near_max = max_x * (0.999 as T);
+    let over_max = max_x * (1.001 as T);
+
+    for v in [max_x, near_max, over_max, -max_x] {
+      let input = Simd::splat(v);
+      let expected = Simd::splat(T::exp(v));
+      let actual = input.exp();
+      let tol = expected.abs().fast_max(Simd::splat(T::MIN_POSITIVE))
+        * Simd::splat(T::EPSILON * 1.0);
+      assert!(
+        ((actual - expected).abs().simd_le(tol)
+          | actual.simd_eq(expected)
+          | actual.is_nan() & expected.is_nan())
+        .all(),
+        "exp({v:e}) expected: {expected:?} actual: {actual:?}"
+      );
+    }
+  });
+}
+
+#[test]
+fn test_exp_subnormal() {
+  for_simd_types!(|T: Float, N| {
+    let subnormal_input: T =
+      if std::mem::size_of::<T>() == 4 { (-88.0) as T } else { (-710.0) as T };
+    let deeper: T =
+      if std::mem::size_of::<T>() == 4 { (-95.0) as T } else { (-730.0) as T };
+    for v in [subnormal_input, deeper] {
+      let input = Simd::splat(v);
+      let expected = Simd::splat(T::exp(v));
+      let actual = input.exp();
+      let tol = expected.abs().fast_max(Simd::splat(T::MIN_POSITIVE))
+        * Simd::splat(T::EPSILON * 1.0);
+      assert!(
+        (actual - expected).abs().simd_le(tol).all(),
+        "exp({v:e}) = {actual:?} expected {expected:?}"
+      );
+    }
+  });
+}
+
+#[test]
+fn test_math_nan_propagation() {
+  for_simd_types!(|T: Float, N| {
+    let nan = T::NAN;
+    let mut arr = [0.0 as T; N];
+    arr[0] = nan;
+    if N > 1 {
+      arr[1] = 1.0 as T;
+    }
+    let input = Simd::from(arr);
+
+    let result = input.exp().to_array();
+    assert!(result[0].is_nan(), "exp: lane 0 NaN");
+    if N > 1 {
+      assert!(!result[1].is_nan(), "exp: lane 1 finite");
+    }
+
+    let result = input.exp_m1().to_array();
+    assert!(result[0].is_nan(), "exp_m1: lane 0 NaN");
+    if N > 1 {
+      assert!(!result[1].is_nan(), "exp_m1: lane 1 finite");
+    }
+
+    let result = input.ln().to_array();
+    assert!(result[0].is_nan(), "ln: lane 0 NaN");
+    if N > 1 {
+      assert!(!result[1].is_nan(), "ln: lane 1 finite");
+    }
+
+    let result = input.ln_1p().to_array();
+    assert!(result[0].is_nan(), "ln_1p: lane 0 NaN");
+    if N > 1 {
+      assert!(!result[1].is_nan(), "ln_1p: lane 1 finite");
+    }
+
+    let result = input.sinh().to_array();
+    assert!(result[0].is_nan(), "sinh: lane 0 NaN");
+    if N > 1 {
+      assert!(!result[1].is_nan(), "sinh: lane 1 finite");
+    }
+
+    let result = input.cosh().to_array();
+    assert!(result[0].is_nan(), "cosh: lane 0 NaN");
+    if N > 1 {
+      assert!(!result[1].is_nan(), "cosh: lane 1 finite");
+    }
+
+    let result = input.tanh().to_array();
+    assert!(result[0].is_nan(), "tanh: lane 0 NaN");
+    if N > 1 {
+      assert!(!result[1].is_nan(), "tanh: lane 1 finite");
+    }
+
+    let result = input.cbrt().to_array();
+    assert!(result[0].is_nan(), "cbrt: lane 0 NaN");
+    if N > 1 {
+      assert!(!result[1].is_nan(), "cbrt: lane 1 finite");
+    }
+
+    let result = input.erf().to_array();
+    assert!(result[0].is_nan(), "erf: lane 0 NaN");
+    if N > 1 {
+      assert!(!result[1].is_nan(), "erf: lane 1 finite");
+    }
+
+    let result = input.erfc().to_array();
+    assert!(result[0].is_nan(), "erfc: lane 0 NaN");
+    if N > 1 {
+      assert!(!result[1].is_nan(), "erfc: lane 1 finite");
+    }
+
+    let result = input.ln_gamma().to_array();
+    assert!(result[0].is_nan(), "ln_gamma: lane 0 NaN");
+    if N > 1 {
+      assert!(!result[1].is_nan(), "ln_gamma: lane 1 finite");
+    }
+
+    let result = input.gamma().to_array();
+    assert!(result[0].is_nan(), "gamma: lane 0 NaN");
+    if N > 1 {
+      assert!(!result[1].is_nan(), "gamma: lane 1 finite");
+    }
+
+    let result = input.asinh().to_array();
+    assert!(result[0].is_nan(), "asinh: lane 0 NaN");
+    if N > 1 {
+      assert!(!result[1].is_nan(), "asinh: lane 1 finite");
+    }
+
+    let result = input.acosh().to_array();
+    assert!(result[0].is_nan(), "acosh: lane 0 NaN");
+    if N > 1 {
+      assert!(!result[1].is_nan(), "acosh: lane 1 finite");
+    }
+
+    let result = input.atanh().to_array();
+    assert!(result[0].is_nan(), "atanh: lane 0 NaN");
+    if N > 1 {
+      assert!(!result[1].is_nan(), "atanh: lane 1 finite");
+    }
+
+    let result = input.exp10().to_array();
+    assert!(result[0].is_nan(), "exp10: lane 0 NaN");
+    if N > 1 {
+      assert!(!result[1].is_nan(), "exp10: lane 1 finite");
+    }
+
+    let result = input.sin_pi().to_array();
+    assert!(result[0].is_nan(), "sin_pi: lane 0 NaN");
+    if N > 1 {
+      assert!(!result[1].is_nan(), "sin_pi: lane 1 finite");
+    }
+
+    let result = input.rsqrt().to_array();
+    assert!(result[0].is_nan(), "rsqrt: lane 0 NaN");
+    if N > 1 {
+      assert!(!result[1].is_nan(), "rsqrt: lane 1 finite");
+    }
+  });
+}
+
+#[test]
+fn test_sign_preservation() {
   for_simd_types!(|T: Float, N| {
     let neg_zero = -T::from_bits(0);
     let input = Simd::splat(neg_zero);
@@ -2315,9 +3107,291 @@ fn test_sign_preservation() {
       assert!(v.is_sign_negative(), "cbrt(-0.0) should be -0.0, got {v:e}");
     }
 
+    let result = input.erf().to_array();
+    for &v in result.iter() {
+      assert!(v.is_sign_negative(), "erf(-0.0) should be -0.0, got {v:e}");
+    }
+
     let result = input.ln_1p().to_array();
     for &v in result.iter() {
       assert!(v.is_sign_negative(), "ln_1p(-0.0) should be -0.0, got {v:e}");
     }
+
+    let result = input.asinh().to_array();
+    for &v in result.iter() {
+      assert!(v.is_sign_negative(), "asinh(-0.0) should be -0.0, got {v:e}");
+    }
+
+    let result = input.atanh().to_array();
+    for &v in result.iter() {
+      assert!(v.is_sign_negative(), "atanh(-0.0) should be -0.0, got {v:e}");
+    }
+
+    let result = input.sin_pi().to_array();
+    for &v in result.iter() {
+      assert!(v.is_sign_negative(), "sin_pi(-0.0) should be -0.0, got {v:e}");
+    }
+  });
+}
+
+/// Values with every floating point category, including the smallest and
+/// largest subnormal numbers.
+macro_rules! special_values {
+  ($T:ty) => {
+    simd_chunks!([
+      1.0,
+      -1.5,
+      0.0,
+      -0.0,
+      <$T>::MIN_POSITIVE,
+      -<$T>::MIN_POSITIVE / 2.0,
+      <$T>::from_bits(1),
+      <$T>::MIN_POSITIVE - <$T>::from_bits(1),
+      <$T>::MAX,
+      <$T>::MIN,
+      <$T>::INFINITY,
+      <$T>::NEG_INFINITY,
+      <$T>::NAN,
+      3.0e-30,
+      -7.5e20,
+      0.75,
+    ])
+  };
+}
+
+#[test]
+fn test_classify() {
+  for_simd_types!(|T: Float, N| {
+    for value in special_values!(T).chain(random_iter()) {
+      let simd = Simd::new(value);
+      assert_eq!(simd.classify(), value.map(T::classify));
+      assert_eq!(simd.is_normal().to_array(), value.map(T::is_normal));
+      assert_eq!(simd.is_subnormal().to_array(), value.map(T::is_subnormal));
+    }
+  });
+}
+
+#[test]
+fn test_logb_ilogb_frexp() {
+  for_simd_types!(|T: Float, N| {
+    let ilogb = |x: T| -> Signed {
+      if x.is_nan() || x == 0.0 {
+        Signed::MIN
+      } else if x.is_infinite() {
+        Signed::MAX
+      } else {
+        // Subnormal numbers are scaled by `2^MANTISSA_DIGITS` to make them
+        // normal.
+        let (x, offset) = if x.is_subnormal() {
+          (x * (2.0 / T::EPSILON), T::MANTISSA_DIGITS as Signed)
+        } else {
+          (x, 0)
+        };
+        let biased = (x.abs().to_bits() >> (T::MANTISSA_DIGITS - 1)) as Signed;
+        biased - (T::MAX_EXP - 1) as Signed - offset
+      }
+    };
+    for value in special_values!(T).chain(random_iter()) {
+      let simd = Simd::new(value);
+      let expected = value.map(ilogb);
+      assert_eq!(simd.ilogb(), SimdSigned::new(expected));
+
+      let logb = simd.logb().to_array();
+      let (mantissa, exponent) = simd.frexp();
+      for i in 0..N {
+        let x = value[i];
+        if x.is_nan() {
+          assert!(logb[i].is_nan());
+          assert!(mantissa.to_array()[i].is_nan());
+          assert_eq!(exponent.to_array()[i], 0);
+        } else if x == 0.0 || x.is_infinite() {
+          let expected = if x == 0.0 { T::NEG_INFINITY } else { T::INFINITY };
+          assert_eq!(logb[i], expected);
+          assert_eq!(mantissa.to_array()[i].to_bits(), x.to_bits());
+          assert_eq!(exponent.to_array()[i], 0);
+        } else {
+          assert_eq!(logb[i], expected[i] as T);
+          let m = mantissa.to_array()[i];
+          assert!((0.5..1.0).contains(&m.abs()), "{x:?} -> {m:?}");
+          assert_eq!(m.is_sign_negative(), x.is_sign_negative());
+          assert_eq!(exponent.to_array()[i], expected[i] + 1);
+        }
+      }
+      let restored = mantissa.ldexp(exponent).to_array();
+      for i in 0..N {
+        assert!(restored[i] == value[i] || value[i].is_nan());
+      }
+    }
+  });
+}
+
+#[test]
+fn test_ldexp() {
+  for_simd_types!(|T: Float, N| {
+    // Doubling and halving are exact until the result overflows or becomes
+    // subnormal, so the only rounding is in the final multiplication.
+    let ldexp = |mut x: T, mut n: Signed| -> T {
+      if x == 0.0 || !x.is_finite() {
+        return x;
+      }
+      while n > 0 && x.is_finite() {
+        x *= 2.0;
+        n -= 1;
+      }
+      while n < 0 && x.abs() >= 2.0 * T::MIN_POSITIVE {
+        x *= 0.5;
+        n += 1;
+      }
+      let mut scale: T = 1.0;
+      while n < 0 && scale != 0.0 {
+        scale *= 0.5;
+        n += 1;
+      }
+      x * scale
+    };
+    let exponents: [Signed; 19] = [
+      0,
+      1,
+      -1,
+      5,
+      -24,
+      -60,
+      100,
+      -126,
+      -149,
+      -150,
+      300,
+      -1000,
+      1023,
+      -1074,
+      -1075,
+      2000,
+      -2100,
+      Signed::MAX,
+      Signed::MIN,
+    ];
+    for value in special_values!(T).chain(random_iter()) {
+      for n in exponents {
+        let expected = value.map(|x| ldexp(x, n));
+        let actual = Simd::new(value).ldexp(SimdSigned::splat(n)).to_array();
+        for i in 0..N {
+          assert!(
+            actual[i].to_bits() == expected[i].to_bits()
+              || actual[i].is_nan() && expected[i].is_nan(),
+            "{:?} · 2^{n}: expected {:?}, got {:?}",
+            value[i],
+            expected[i],
+            actual[i],
+          );
+        }
+      }
+    }
+    // Halfway cases round to even, once.
+    let tiny = Simd::splat(T::from_bits(1));
+    assert_eq!(Simd::splat(1.5).ldexp(tiny.ilogb()), tiny * 2.0);
+    assert_eq!(Simd::splat(2.5).ldexp(tiny.ilogb()), tiny * 2.0);
+  });
+}
+
+#[test]
+fn test_next_up_down() {
+  for_simd_types!(|T: Float, N| {
+    for value in special_values!(T).chain(random_iter()) {
+      let simd = Simd::new(value);
+      let expected = value.map(|x| T::next_up(x).to_bits());
+      assert_eq!(simd.next_up().to_array().map(T::to_bits), expected);
+      let expected = value.map(|x| T::next_down(x).to_bits());
+      assert_eq!(simd.next_down().to_array().map(T::to_bits), expected);
+    }
+  });
+}
+
+#[test]
+fn test_maximum_minimum() {
+  for_simd_types!(|T: Float, N| {
+    let maximum = |a: T, b: T| -> T {
+      if a.is_nan() || b.is_nan() {
+        T::NAN
+      } else if a > b || a == b && a.is_sign_positive() {
+        a
+      } else {
+        b
+      }
+    };
+    let minimum = |a: T, b: T| -> T {
+      if a.is_nan() || b.is_nan() {
+        T::NAN
+      } else if a < b || a == b && a.is_sign_negative() {
+        a
+      } else {
+        b
+      }
+    };
+    let ignore_nan = |op: &dyn Fn(T, T) -> T, a: T, b: T| -> T {
+      if a.is_nan() {
+        b
+      } else if b.is_nan() {
+        a
+      } else {
+        op(a, b)
+      }
+    };
+    let same = |actual: [T; N], expected: [T; N]| {
+      (0..N).all(|i| {
+        actual[i].to_bits() == expected[i].to_bits()
+          || actual[i].is_nan() && expected[i].is_nan()
+      })
+    };
+    for value in special_values!(T).chain(random_iter()) {
+      let mut rotated = value;
+      rotated.rotate_left(1);
+      for other in [value, rotated, value.map(|x| -x)] {
+        let a = Simd::new(value);
+        let b = Simd::new(other);
+        let expected: [T; N] =
+          core::array::from_fn(|i| maximum(value[i], other[i]));
+        assert!(same(a.maximum(b).to_array(), expected));
+        let expected: [T; N] =
+          core::array::from_fn(|i| minimum(value[i], other[i]));
+        assert!(same(a.minimum(b).to_array(), expected));
+        let expected: [T; N] =
+          core::array::from_fn(|i| ignore_nan(&maximum, value[i], other[i]));
+        assert!(same(a.maximum_number(b).to_array(), expected));
+        let expected: [T; N] =
+          core::array::from_fn(|i| ignore_nan(&minimum, value[i], other[i]));
+        assert!(same(a.minimum_number(b).to_array(), expected));
+      }
+    }
+  });
+}
+
+#[test]
+fn test_total_cmp() {
+  for_simd_types!(|T: Float, N| {
+    for value in special_values!(T)
+      .chain(simd_chunks!([-T::NAN, -0.0, T::NAN, 0.0]))
+      .chain(random_iter())
+    {
+      let mut rotated = value;
+      rotated.rotate_left(1);
+      for other in [value, rotated, value.map(|x| -x)] {
+        let a = Simd::new(value);
+        let b = Simd::new(other);
+        let ordering: [_; N] =
+          core::array::from_fn(|i| value[i].total_cmp(&other[i]));
+        assert_eq!(a.total_eq(b).to_array(), ordering.map(|o| o.is_eq()));
+        assert_eq!(a.total_ne(b).to_array(), ordering.map(|o| o.is_ne()));
+        assert_eq!(a.total_lt(b).to_array(), ordering.map(|o| o.is_lt()));
+        assert_eq!(a.total_le(b).to_array(), ordering.map(|o| o.is_le()));
+        assert_eq!(a.total_gt(b).to_array(), ordering.map(|o| o.is_gt()));
+        assert_eq!(a.total_ge(b).to_array(), ordering.map(|o| o.is_ge()));
+
+        let a_bits = a.to_ordered_bits().to_array();
+        let b_bits = b.to_ordered_bits().to_array();
+        let actual: [_; N] =
+          core::array::from_fn(|i| a_bits[i].cmp(&b_bits[i]));
+        assert_eq!(actual, ordering);
+      }
+    }
   });
 }
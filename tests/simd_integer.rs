@@ -1,6 +1,7 @@
 use wide::{
-  f32x4, f32x8, f32x16, i8x16, i8x32, i16x8, i16x16, i32x4, i32x8, i32x16,
-  u8x16, u8x32, u16x8,
+  f32x4, f32x8, f32x16, i8x16, i8x32, i8x64, i16x8, i16x16, i16x32, i32x4,
+  i32x8, i32x16, i64x2, i64x4, i64x8, u8x16, u8x32, u8x64, u16x8, u16x16,
+  u16x32, u32x4, u32x8, u32x16, u64x2, u64x4, u64x8,
 };
 
 use crate::utils::{for_simd_types, random_iter, simd_chunks};
@@ -492,9 +493,7 @@ fn test_overflowing_add() {
     {
       let expected = (
         Simd::new(std::array::from_fn(|i| left[i].overflowing_add(right[i]).0)),
-        Simd::new(std::array::from_fn(|i| {
-          if left[i].overflowing_add(right[i]).1 { !0 } else { 0 }
-        })),
+        Mask::new(std::array::from_fn(|i| left[i].overflowing_add(right[i]).1)),
       );
       let actual = Simd::new(left).overflowing_add(Simd::new(right));
 
@@ -510,9 +509,7 @@ fn test_overflowing_add() {
     {
       let expected = (
         Simd::new(std::array::from_fn(|i| left[i].overflowing_add(right[i]).0)),
-        Simd::new(std::array::from_fn(|i| {
-          if left[i].overflowing_add(right[i]).1 { !0 } else { 0 }
-        })),
+        Mask::new(std::array::from_fn(|i| left[i].overflowing_add(right[i]).1)),
       );
       let actual = Simd::new(left).overflowing_add(Simd::new(right));
 
@@ -532,9 +529,7 @@ fn test_overflowing_sub() {
     {
       let expected = (
         Simd::new(std::array::from_fn(|i| left[i].overflowing_sub(right[i]).0)),
-        Simd::new(std::array::from_fn(|i| {
-          if left[i].overflowing_sub(right[i]).1 { !0 } else { 0 }
-        })),
+        Mask::new(std::array::from_fn(|i| left[i].overflowing_sub(right[i]).1)),
       );
       let actual = Simd::new(left).overflowing_sub(Simd::new(right));
 
@@ -550,9 +545,7 @@ fn test_overflowing_sub() {
     {
       let expected = (
         Simd::new(std::array::from_fn(|i| left[i].overflowing_sub(right[i]).0)),
-        Simd::new(std::array::from_fn(|i| {
-          if left[i].overflowing_sub(right[i]).1 { !0 } else { 0 }
-        })),
+        Mask::new(std::array::from_fn(|i| left[i].overflowing_sub(right[i]).1)),
       );
       let actual = Simd::new(left).overflowing_sub(Simd::new(right));
 
@@ -586,9 +579,7 @@ fn test_overflowing_mul() {
     {
       let expected = (
         Simd::new(std::array::from_fn(|i| left[i].overflowing_mul(right[i]).0)),
-        Simd::new(std::array::from_fn(|i| {
-          if left[i].overflowing_mul(right[i]).1 { !0 } else { 0 }
-        })),
+        Mask::new(std::array::from_fn(|i| left[i].overflowing_mul(right[i]).1)),
       );
       let actual = Simd::new(left).overflowing_mul(Simd::new(right));
 
@@ -643,9 +634,7 @@ fn test_overflowing_mul() {
     {
       let expected = (
         Simd::new(std::array::from_fn(|i| left[i].overflowing_mul(right[i]).0)),
-        Simd::new(std::array::from_fn(|i| {
-          if left[i].overflowing_mul(right[i]).1 { !0 } else { 0 }
-        })),
+        Mask::new(std::array::from_fn(|i| left[i].overflowing_mul(right[i]).1)),
       );
       let actual = Simd::new(left).overflowing_mul(Simd::new(right));
 
@@ -674,9 +663,7 @@ fn test_overflowing_div() {
 
       let expected = (
         Simd::new(std::array::from_fn(|i| left[i].overflowing_div(right[i]).0)),
-        Simd::new(std::array::from_fn(|i| {
-          if left[i].overflowing_div(right[i]).1 { !0 } else { 0 }
-        })),
+        Mask::new(std::array::from_fn(|i| left[i].overflowing_div(right[i]).1)),
       );
       let actual = Simd::new(left).overflowing_div(Simd::new(right));
 
@@ -701,9 +688,7 @@ fn test_overflowing_div() {
 
       let expected = (
         Simd::new(std::array::from_fn(|i| left[i].overflowing_div(right[i]).0)),
-        Simd::new(std::array::from_fn(|i| {
-          if left[i].overflowing_div(right[i]).1 { !0 } else { 0 }
-        })),
+        Mask::new(std::array::from_fn(|i| left[i].overflowing_div(right[i]).1)),
       );
       let actual = Simd::new(left).overflowing_div(Simd::new(right));
 
@@ -732,9 +717,7 @@ fn test_overflowing_rem() {
 
       let expected = (
         Simd::new(std::array::from_fn(|i| left[i].overflowing_rem(right[i]).0)),
-        Simd::new(std::array::from_fn(|i| {
-          if left[i].overflowing_rem(right[i]).1 { !0 } else { 0 }
-        })),
+        Mask::new(std::array::from_fn(|i| left[i].overflowing_rem(right[i]).1)),
       );
       let actual = Simd::new(left).overflowing_rem(Simd::new(right));
 
@@ -759,9 +742,7 @@ fn test_overflowing_rem() {
 
       let expected = (
         Simd::new(std::array::from_fn(|i| left[i].overflowing_rem(right[i]).0)),
-        Simd::new(std::array::from_fn(|i| {
-          if left[i].overflowing_rem(right[i]).1 { !0 } else { 0 }
-        })),
+        Mask::new(std::array::from_fn(|i| left[i].overflowing_rem(right[i]).1)),
       );
       let actual = Simd::new(left).overflowing_rem(Simd::new(right));
 
@@ -1266,10 +1247,63 @@ fn test_u8x32_swizzle() {
 
   // relaxed: in-range only, must match table lookup
   let rev: [u8; 32] = core::array::from_fn(|i| 31 - i as u8);
-  let rev_expected: [u8; 32] = core::array::from_fn(|i| table_arr[(31 - i) as usize]);
+  let rev_expected: [u8; 32] = core::array::from_fn(|i| table_arr[31 - i]);
   assert_eq!(table.swizzle_relaxed(u8x32::new(rev)), u8x32::new(rev_expected));
 }
 
+#[test]
+fn test_i8x64_swizzle() {
+  let table_arr: [i8; 64] = core::array::from_fn(|i| (i as i8) + 1); // 1..=64
+  let table = i8x64::new(table_arr);
+  let cases: [[i8; 64]; 4] = [
+    core::array::from_fn(|i| i as i8), // identity
+    core::array::from_fn(|i| 63 - i as i8), // reverse
+    core::array::from_fn(|i| ((i + 40) % 64) as i8), // cross-half rotate by 40
+    {
+      let mut a = [35i8; 64];
+      a[0] = 64; // out of range -> 0
+      a[1] = 100; // out of range -> 0
+      a[2] = -1; // 255 unsigned -> 0
+      a[40] = -96; // 160 unsigned -> 0
+      a
+    },
+  ];
+  for idx_arr in cases {
+    let expected: [i8; 64] = core::array::from_fn(|i| {
+      let ix = idx_arr[i] as u8 as usize; // unsigned interpretation
+      if ix < 64 { table_arr[ix] } else { 0 }
+    });
+    let actual = table.swizzle(i8x64::new(idx_arr));
+    assert_eq!(actual, i8x64::new(expected), "idx={:?}", idx_arr);
+    if idx_arr.iter().all(|&ix| (0..64).contains(&ix)) {
+      let actual = table.swizzle_relaxed(i8x64::new(idx_arr));
+      assert_eq!(actual, i8x64::new(expected), "idx={:?}", idx_arr);
+    }
+  }
+}
+
+#[test]
+fn test_u8x64_swizzle() {
+  let table_arr: [u8; 64] = core::array::from_fn(|i| (i as u8) + 1); // 1..=64
+  let table = u8x64::new(table_arr);
+  // strict: unsigned indices, out-of-range (incl. 128 and 255) -> 0
+  let mut idx_arr: [u8; 64] = core::array::from_fn(|i| (i * 7 % 64) as u8);
+  idx_arr[2] = 64; // OOR -> 0
+  idx_arr[3] = 128; // OOR -> 0 (would be negative i8 after cast)
+  idx_arr[50] = 255; // OOR -> 0
+  let expected: [u8; 64] = core::array::from_fn(|i| {
+    let ix = idx_arr[i] as usize;
+    if ix < 64 { table_arr[ix] } else { 0 }
+  });
+  let actual = table.swizzle(u8x64::new(idx_arr));
+  assert_eq!(actual, u8x64::new(expected), "idx={:?}", idx_arr);
+
+  // relaxed: in-range only, must match table lookup
+  let rev: [u8; 64] = core::array::from_fn(|i| 63 - i as u8);
+  let rev_expected: [u8; 64] = core::array::from_fn(|i| table_arr[63 - i]);
+  assert_eq!(table.swizzle_relaxed(u8x64::new(rev)), u8x64::new(rev_expected));
+}
+
 #[test]
 fn test_swizzle_half_out_of_range_zeroes() {
   // `swizzle_half` is strict: any index outside `[0, 15]` (per half) must zero
@@ -1335,3 +1369,276 @@ fn test_narrow_i16x8() {
   let actual = u8x16::narrow_i16x8(a, b);
   assert_eq!(actual, expected);
 }
+
+#[test]
+fn test_count_ones() {
+  for_simd_types!(|T: Unsigned, N| {
+    for value in simd_chunks!([0, 1, 2, 3, T::MAX, T::MAX - 1, T::MAX / 2, 85])
+      .chain(random_iter())
+    {
+      let expected =
+        Simd::new(std::array::from_fn(|i| value[i].count_ones() as T));
+      let actual = Simd::new(value).count_ones();
+
+      assert!(
+        actual == expected,
+        "expected: {expected:?}\n  actual: {actual:?}\n   value: {value:?}",
+      );
+    }
+  });
+  for_simd_types!(|T: Signed, N| {
+    for value in simd_chunks!([0, 1, -1, -2, T::MIN, T::MAX, T::MIN + 1, 85])
+      .chain(random_iter())
+    {
+      let expected = SimdUnsigned::new(std::array::from_fn(|i| {
+        value[i].count_ones() as Unsigned
+      }));
+      let actual = Simd::new(value).count_ones();
+
+      assert!(
+        actual == expected,
+        "expected: {expected:?}\n  actual: {actual:?}\n   value: {value:?}",
+      );
+    }
+  });
+}
+
+#[test]
+fn test_leading_zeros() {
+  for_simd_types!(|T: Unsigned, N| {
+    for value in
+      simd_chunks!([0, 1, 2, 3, T::MAX, T::MAX / 2, T::MAX / 2 + 1, 85])
+        .chain(random_iter())
+    {
+      let expected =
+        Simd::new(std::array::from_fn(|i| value[i].leading_zeros() as T));
+      let actual = Simd::new(value).leading_zeros();
+
+      assert!(
+        actual == expected,
+        "expected: {expected:?}\n  actual: {actual:?}\n   value: {value:?}",
+      );
+    }
+  });
+  for_simd_types!(|T: Signed, N| {
+    for value in simd_chunks!([0, 1, -1, 16, T::MIN, T::MAX, T::MAX / 4, 85])
+      .chain(random_iter())
+    {
+      let expected = SimdUnsigned::new(std::array::from_fn(|i| {
+        value[i].leading_zeros() as Unsigned
+      }));
+      let actual = Simd::new(value).leading_zeros();
+
+      assert!(
+        actual == expected,
+        "expected: {expected:?}\n  actual: {actual:?}\n   value: {value:?}",
+      );
+    }
+  });
+}
+
+#[test]
+fn test_trailing_zeros() {
+  for_simd_types!(|T: Unsigned, N| {
+    for value in simd_chunks!([0, 1, 2, 8, T::MAX, T::MAX / 2 + 1, 96, 85])
+      .chain(random_iter())
+    {
+      let expected =
+        Simd::new(std::array::from_fn(|i| value[i].trailing_zeros() as T));
+      let actual = Simd::new(value).trailing_zeros();
+
+      assert!(
+        actual == expected,
+        "expected: {expected:?}\n  actual: {actual:?}\n   value: {value:?}",
+      );
+    }
+  });
+  for_simd_types!(|T: Signed, N| {
+    for value in
+      simd_chunks!([0, 1, -1, -16, T::MIN, T::MAX, 96, 85]).chain(random_iter())
+    {
+      let expected = SimdUnsigned::new(std::array::from_fn(|i| {
+        value[i].trailing_zeros() as Unsigned
+      }));
+      let actual = Simd::new(value).trailing_zeros();
+
+      assert!(
+        actual == expected,
+        "expected: {expected:?}\n  actual: {actual:?}\n   value: {value:?}",
+      );
+    }
+  });
+}
+
+#[test]
+fn test_reverse_bits() {
+  for_simd_types!(|T: Integer, N| {
+    for value in simd_chunks!([0, 1, 2, 3, T::MAX, T::MIN, T::MAX - 1, 85])
+      .chain(random_iter())
+    {
+      let expected = Simd::new(value.map(T::reverse_bits));
+      let actual = Simd::new(value).reverse_bits();
+
+      assert!(
+        actual == expected,
+        "expected: {expected:?}\n  actual: {actual:?}\n   value: {value:?}",
+      );
+    }
+  });
+}
+
+#[test]
+fn test_rotate_left() {
+  for_simd_types!(|T: Unsigned, N| {
+    for [left, right] in simd_chunks!(
+      [1, 2, T::MAX, T::MAX - 1, T::MAX / 2, 85, 3, 96],
+      [0, 1, 3, 7, 8, 9, T::MAX, 100],
+    )
+    .chain(random_iter())
+    {
+      let expected = Simd::new(std::array::from_fn(|i| {
+        left[i].rotate_left((right[i] as u128 % T::BITS as u128) as u32)
+      }));
+      let actual = Simd::new(left).rotate_left(Simd::new(right));
+
+      assert!(
+        actual == expected,
+        "expected: {expected:?}\n  actual: {actual:?}\n    left: {left:?}\n   right: {right:?}",
+      );
+
+      let expected = Simd::new(std::array::from_fn(|i| {
+        left[i].rotate_right((right[i] as u128 % T::BITS as u128) as u32)
+      }));
+      let actual = Simd::new(left).rotate_right(Simd::new(right));
+
+      assert!(
+        actual == expected,
+        "expected: {expected:?}\n  actual: {actual:?}\n    left: {left:?}\n   right: {right:?}",
+      );
+    }
+  });
+  for_simd_types!(|T: Signed, N| {
+    for (left, right) in simd_chunks!(
+      [1, -2, T::MAX, T::MIN, -85, 85, 3, 96],
+      [0, 1, 3, 7, 8, 9, -1, 100],
+    )
+    .chain(random_iter())
+    .map(|[left, right]| (left, right.map(T::cast_unsigned)))
+    {
+      let expected = Simd::new(std::array::from_fn(|i| {
+        left[i].rotate_left((right[i] as u128 % T::BITS as u128) as u32)
+      }));
+      let actual = Simd::new(left).rotate_left(SimdUnsigned::new(right));
+
+      assert!(
+        actual == expected,
+        "expected: {expected:?}\n  actual: {actual:?}\n    left: {left:?}\n   right: {right:?}",
+      );
+
+      let expected = Simd::new(std::array::from_fn(|i| {
+        left[i].rotate_right((right[i] as u128 % T::BITS as u128) as u32)
+      }));
+      let actual = Simd::new(left).rotate_right(SimdUnsigned::new(right));
+
+      assert!(
+        actual == expected,
+        "expected: {expected:?}\n  actual: {actual:?}\n    left: {left:?}\n   right: {right:?}",
+      );
+    }
+  });
+}
+
+#[test]
+fn test_rotate_left_scalar() {
+  for_simd_types!(|T: Integer, N| {
+    for left in simd_chunks!([1, 2, T::MAX, T::MIN, T::MAX / 2, 85, 3, 96])
+      .chain(random_iter())
+    {
+      for right in [0, 1, 3, 7, 8, 9, 31, 32, 63, 64, 100, u32::MAX] {
+        let expected = Simd::new(left.map(|x| x.rotate_left(right)));
+        let actual = Simd::new(left).rotate_left_scalar(right);
+
+        assert!(
+          actual == expected,
+          "expected: {expected:?}\n  actual: {actual:?}\n    left: {left:?}\n   right: {right:?}",
+        );
+
+        let expected = Simd::new(left.map(|x| x.rotate_right(right)));
+        let actual = Simd::new(left).rotate_right_scalar(right);
+
+        assert!(
+          actual == expected,
+          "expected: {expected:?}\n  actual: {actual:?}\n    left: {left:?}\n   right: {right:?}",
+        );
+      }
+    }
+  });
+}
+
+#[test]
+fn test_reduce_add_wide() {
+  macro_rules! check {
+    ($($Simd:ident: $T:ident => $reduce_add_wide:ident: $Wide:ident,)*) => {$({
+      type T = $T;
+      const N: usize = $Simd::LANES as usize;
+      for value in simd_chunks!([T::MAX, T::MAX, T::MIN, 1, T::MAX - 1, 0])
+        .chain(random_iter())
+      {
+        let expected: $Wide = value.into_iter().map($Wide::from).sum();
+        let actual = $Simd::new(value).$reduce_add_wide();
+
+        assert_eq!(actual, expected, "{}", stringify!($Simd));
+      }
+    })*};
+  }
+
+  check! {
+    i8x16: i8 => reduce_add_i32: i32,
+    i8x32: i8 => reduce_add_i32: i32,
+    i8x64: i8 => reduce_add_i32: i32,
+    i16x8: i16 => reduce_add_i32: i32,
+    i16x16: i16 => reduce_add_i32: i32,
+    i16x32: i16 => reduce_add_i32: i32,
+    i32x4: i32 => reduce_add_i64: i64,
+    i32x8: i32 => reduce_add_i64: i64,
+    i32x16: i32 => reduce_add_i64: i64,
+    i64x2: i64 => reduce_add_i128: i128,
+    i64x4: i64 => reduce_add_i128: i128,
+    i64x8: i64 => reduce_add_i128: i128,
+    u8x16: u8 => reduce_add_u32: u32,
+    u8x32: u8 => reduce_add_u32: u32,
+    u8x64: u8 => reduce_add_u32: u32,
+    u16x8: u16 => reduce_add_u32: u32,
+    u16x16: u16 => reduce_add_u32: u32,
+    u16x32: u16 => reduce_add_u32: u32,
+    u32x4: u32 => reduce_add_u64: u64,
+    u32x8: u32 => reduce_add_u64: u64,
+    u32x16: u32 => reduce_add_u64: u64,
+    u64x2: u64 => reduce_add_u128: u128,
+    u64x4: u64 => reduce_add_u128: u128,
+    u64x8: u64 => reduce_add_u128: u128,
+  }
+}
+
+#[test]
+fn test_sad() {
+  for_simd_types!(|T: Integer, N| {
+    for [left, right] in simd_chunks!(
+      [T::MAX, T::MIN, 1, 0, T::MAX - 1, 7, T::MIN],
+      [T::MIN, T::MAX, T::MAX, 0, 3, 7, T::MIN],
+    )
+    .chain(random_iter())
+    {
+      let expected: u128 =
+        (0..N).map(|i| widen(left[i].abs_diff(right[i]))).sum();
+      let actual = widen(Simd::new(left).sad(Simd::new(right)));
+
+      assert_eq!(actual, expected);
+    }
+  });
+}
+
+/// Converts the differently sized results of `sad` to one type.
+fn widen(value: impl Into<u128>) -> u128 {
+  value.into()
+}
@@ -8,8 +8,7 @@ fn test_is_positive() {
     for value in
       simd_chunks!([1, -1, 2, 3, -2, -5, 0, 6, 9, -4, 0, T::MIN, T::MAX])
     {
-      let expected =
-        Simd::new(value.map(|x| if x.is_positive() { !0 } else { 0 }));
+      let expected = Mask::new(value.map(|x| x.is_positive()));
       let actual = Simd::new(value).is_positive();
 
       assert_eq!(actual, expected);
@@ -23,8 +22,7 @@ fn test_is_negative() {
     for value in
       simd_chunks!([1, -1, 2, 3, -2, -5, 0, 6, 9, -4, 0, T::MIN, T::MAX])
     {
-      let expected =
-        Simd::new(value.map(|x| if x.is_negative() { !0 } else { 0 }));
+      let expected = Mask::new(value.map(|x| x.is_negative()));
       let actual = Simd::new(value).is_negative();
 
       assert_eq!(actual, expected);
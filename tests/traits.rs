@@ -0,0 +1,91 @@
+use wide::{SimdFloat, SimdInt, SimdUint, SimdVector};
+
+use crate::utils::{for_simd_types, random_iter, simd_chunks};
+
+fn generic_sum_of_squares<V: SimdVector>(values: &[V]) -> V::Elem {
+  values.iter().map(|&v| v * v).sum::<V>().reduce_add()
+}
+
+fn generic_slice_sum<V: SimdVector>(slice: &[V::Elem]) -> V::Elem {
+  let zero = V::Elem::default();
+  let chunks = slice.chunks(V::LANES);
+  chunks.map(|chunk| V::load_or(chunk, zero)).sum::<V>().reduce_add()
+}
+
+fn generic_hypot<V: SimdFloat>(x: V, y: V) -> V {
+  x.mul_add(x, y * y).sqrt()
+}
+
+fn generic_abs_diff<V: SimdInt>(a: V, b: V) -> V {
+  (a - b).abs()
+}
+
+fn generic_abs_diff_unsigned<V: SimdUint>(a: V, b: V) -> V {
+  a.max(b) - a.min(b)
+}
+
+#[test]
+fn test_generic_lanes() {
+  for_simd_types!(|T, N| {
+    assert_eq!(<Simd as SimdVector>::LANES, N);
+    assert_eq!(Simd::splat(1 as T).as_slice().len(), N);
+  });
+}
+
+#[test]
+fn test_generic_sum_of_squares() {
+  for_simd_types!(|T: Float, N| {
+    let values = [Simd::splat(2.0), Simd::splat(3.0)];
+    assert_eq!(generic_sum_of_squares(&values), 13.0 * N as T);
+  });
+  for_simd_types!(|T: Integer, N| {
+    let values = [Simd::splat(2), Simd::splat(3)];
+    assert_eq!(generic_sum_of_squares(&values), (13 * N) as T);
+  });
+}
+
+#[test]
+fn test_generic_slice_sum() {
+  for_simd_types!(|T, N| {
+    for len in [0, 1, N - 1, N, N + 1, 3 * N + 2] {
+      let vec = Vec::from_iter((0..len).map(|i| (i % 2) as T));
+      let expected = vec.iter().copied().sum::<T>();
+
+      assert_eq!(generic_slice_sum::<Simd>(&vec), expected);
+    }
+  });
+}
+
+#[test]
+fn test_generic_float() {
+  for_simd_types!(|T: Float, N| {
+    for [x, y] in simd_chunks!([3.0, 5.0, 8.0, 0.0], [4.0, 12.0, 15.0, 0.0]) {
+      let expected = Simd::new(std::array::from_fn(|i| x[i].hypot(y[i])));
+      let actual = generic_hypot(Simd::new(x), Simd::new(y));
+
+      assert_eq!(actual, expected);
+    }
+  });
+}
+
+#[test]
+fn test_generic_integer() {
+  for_simd_types!(|T: Signed, N| {
+    for [a, b] in simd_chunks!([1, -5, 100, 0], [7, 3, -20, 0]) {
+      let expected = Simd::new(std::array::from_fn(|i| (a[i] - b[i]).abs()));
+      let actual = generic_abs_diff(Simd::new(a), Simd::new(b));
+
+      assert_eq!(actual, expected);
+    }
+  });
+  for_simd_types!(|T: Unsigned, N| {
+    for [a, b] in
+      simd_chunks!([1, 5, 100, 0], [7, 3, 20, 0]).chain(random_iter())
+    {
+      let expected = Simd::new(std::array::from_fn(|i| a[i].abs_diff(b[i])));
+      let actual = generic_abs_diff_unsigned(Simd::new(a), Simd::new(b));
+
+      assert_eq!(actual, expected);
+    }
+  });
+}
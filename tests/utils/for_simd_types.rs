@@ -10,12 +10,14 @@ use std::panic::{UnwindSafe, catch_unwind, resume_unwind};
 /// // - type T
 /// // - const N: usize
 /// // - type Simd
+/// // - type Mask
 /// for_simd_types!(|T, N| ...);
 ///
 /// // Has access to:
 /// // - type T
 /// // - const N: usize
 /// // - type Simd
+/// // - type Mask
 /// // - type Signed
 /// // - type SimdSigned
 /// for_simd_types!(|T: Float, N| ...);
@@ -24,12 +26,14 @@ use std::panic::{UnwindSafe, catch_unwind, resume_unwind};
 /// // - type T
 /// // - const N: usize
 /// // - type Simd
+/// // - type Mask
 /// for_simd_types!(|T: Integer, N| ...);
 ///
 /// // Has access to:
 /// // - type T
 /// // - const N: usize
 /// // - type Simd
+/// // - type Mask
 /// // - type Unsigned
 /// // - type SimdUnsigned
 /// for_simd_types!(|T: Signed, N| ...);
@@ -38,7 +42,24 @@ use std::panic::{UnwindSafe, catch_unwind, resume_unwind};
 /// // - type T
 /// // - const N: usize
 /// // - type Simd
+/// // - type Mask
 /// for_simd_types!(|T: Unsigned, N| ...);
+///
+/// // Only types with 32-bit or 64-bit elements. Has access to:
+/// // - type T
+/// // - const N: usize
+/// // - type Simd
+/// // - type Mask
+/// // - type Index
+/// for_simd_types!(|T: Gather, N| ...);
+///
+/// // Only types with 16-bit, 32-bit or 64-bit elements. Has access to:
+/// // - type T
+/// // - const N: usize
+/// // - type Simd
+/// // - type Mask
+/// // - type Index
+/// for_simd_types!(|T: Permute, N| ...);
 /// ```
 macro_rules! for_simd_types {
   (|T, N| $expr:expr) => {
@@ -60,6 +81,7 @@ macro_rules! for_simd_types {
   (|T: Signed, N| $expr:expr) => {
     for_simd_types!(signed!(i8, 16, i8x16, u8, u8x16, i16, (), $expr));
     for_simd_types!(signed!(i8, 32, i8x32, u8, u8x32, i16, (), $expr));
+    for_simd_types!(signed!(i8, 64, i8x64, u8, u8x64, i16, (), $expr));
     for_simd_types!(signed!(i16, 8, i16x8, u16, u16x8, i32, (), $expr));
     for_simd_types!(signed!(i16, 16, i16x16, u16, u16x16, i32, (), $expr));
     for_simd_types!(signed!(i16, 32, i16x32, u16, u16x32, i32, (), $expr));
@@ -73,6 +95,7 @@ macro_rules! for_simd_types {
   (|T: Signed, N, DoubleSizedSimd| $expr:expr) => {
     for_simd_types!(signed!(i8, 16, i8x16, u8, u8x16, i16, (i16x16), $expr));
     for_simd_types!(signed!(i8, 32, i8x32, u8, u8x32, i16, (i16x32), $expr));
+    // for_simd_types!(signed!(i8, 64, i8x64, u8, u8x64, i16, (i16x64), $expr));
     for_simd_types!(signed!(i16, 8, i16x8, u16, u16x8, i32, (i32x8), $expr));
     for_simd_types!(signed!(i16, 16, i16x16, u16, u16x16, i32, (i32x16), $expr));
     // for_simd_types!(signed!(i16, 32, i16x32, u16, u16x32, i32, (i32x32), $expr));
@@ -86,6 +109,7 @@ macro_rules! for_simd_types {
   (|T: Unsigned, N| $expr:expr) => {
     for_simd_types!(unsigned!(u8, 16, u8x16, u16, (), $expr));
     for_simd_types!(unsigned!(u8, 32, u8x32, u16, (), $expr));
+    for_simd_types!(unsigned!(u8, 64, u8x64, u16, (), $expr));
     for_simd_types!(unsigned!(u16, 8, u16x8, u32, (), $expr));
     for_simd_types!(unsigned!(u16, 16, u16x16, u32, (), $expr));
     for_simd_types!(unsigned!(u16, 32, u16x32, u32, (), $expr));
@@ -99,6 +123,7 @@ macro_rules! for_simd_types {
   (|T: Unsigned, N, DoubleSizedSimd| $expr:expr) => {
     for_simd_types!(unsigned!(u8, 16, u8x16, u16, (u16x16), $expr));
     for_simd_types!(unsigned!(u8, 32, u8x32, u16, (u16x32), $expr));
+    // for_simd_types!(unsigned!(u8, 64, u8x64, u16, (u16x64), $expr));
     for_simd_types!(unsigned!(u16, 8, u16x8, u32, (u32x8), $expr));
     for_simd_types!(unsigned!(u16, 16, u16x16, u32, (u32x16), $expr));
     // for_simd_types!(unsigned!(u16, 32, u16x32, u32, (u32x32), $expr));
@@ -109,9 +134,54 @@ macro_rules! for_simd_types {
     // for_simd_types!(unsigned!(u64, 4, u64x4, u128, (u128x4), $expr));
     // for_simd_types!(unsigned!(u64, 8, u64x8, u128, (u128x8), $expr));
   };
+  (|T: Gather, N| $expr:expr) => {
+    for_simd_types!(gather!(f32, 4, f32x4, i32x4, $expr));
+    for_simd_types!(gather!(f32, 8, f32x8, i32x8, $expr));
+    for_simd_types!(gather!(f32, 16, f32x16, i32x16, $expr));
+    for_simd_types!(gather!(f64, 2, f64x2, i64x2, $expr));
+    for_simd_types!(gather!(f64, 4, f64x4, i64x4, $expr));
+    for_simd_types!(gather!(f64, 8, f64x8, i64x8, $expr));
+    for_simd_types!(gather!(i32, 4, i32x4, i32x4, $expr));
+    for_simd_types!(gather!(i32, 8, i32x8, i32x8, $expr));
+    for_simd_types!(gather!(i32, 16, i32x16, i32x16, $expr));
+    for_simd_types!(gather!(i64, 2, i64x2, i64x2, $expr));
+    for_simd_types!(gather!(i64, 4, i64x4, i64x4, $expr));
+    for_simd_types!(gather!(i64, 8, i64x8, i64x8, $expr));
+    for_simd_types!(gather!(u32, 4, u32x4, i32x4, $expr));
+    for_simd_types!(gather!(u32, 8, u32x8, i32x8, $expr));
+    for_simd_types!(gather!(u32, 16, u32x16, i32x16, $expr));
+    for_simd_types!(gather!(u64, 2, u64x2, i64x2, $expr));
+    for_simd_types!(gather!(u64, 4, u64x4, i64x4, $expr));
+    for_simd_types!(gather!(u64, 8, u64x8, i64x8, $expr));
+  };
+  (|T: Permute, N| $expr:expr) => {
+    for_simd_types!(gather!(i16, 8, i16x8, i16x8, $expr));
+    for_simd_types!(gather!(i16, 16, i16x16, i16x16, $expr));
+    for_simd_types!(gather!(i16, 32, i16x32, i16x32, $expr));
+    for_simd_types!(gather!(u16, 8, u16x8, i16x8, $expr));
+    for_simd_types!(gather!(u16, 16, u16x16, i16x16, $expr));
+    for_simd_types!(gather!(u16, 32, u16x32, i16x32, $expr));
+    for_simd_types!(|T: Gather, N| $expr);
+  };
+  (gather!($T:ident, $N:literal, $Simd:ident, $Index:ident, $expr:expr)) => {{
+    #[allow(dead_code)]
+    type Simd = wide::$Simd;
+    #[allow(dead_code)]
+    type Mask = <wide::$Simd as wide::SimdVector>::Mask;
+    #[allow(dead_code)]
+    type T = $T;
+    #[allow(dead_code)]
+    const N: usize = $N;
+    #[allow(dead_code)]
+    type Index = wide::$Index;
+    $crate::utils::for_simd_types_helper(|| $expr, stringify!($T), $N);
+  }};
   (float!($T:ident, $N:literal, $Simd:ident, $Signed:ident, $SimdSigned:ident, $expr:expr)) => {{
+    #[allow(dead_code)]
     type Simd = wide::$Simd;
     #[allow(dead_code)]
+    type Mask = <wide::$Simd as wide::SimdVector>::Mask;
+    #[allow(dead_code)]
     type T = $T;
     #[allow(dead_code)]
     const N: usize = $N;
@@ -131,8 +201,11 @@ macro_rules! for_simd_types {
     ($($DoubleSizedSimd:ident)?),
     $expr:expr
   )) => {{
+    #[allow(dead_code)]
     type Simd = wide::$Simd;
     #[allow(dead_code)]
+    type Mask = <wide::$Simd as wide::SimdVector>::Mask;
+    #[allow(dead_code)]
     type T = $T;
     #[allow(dead_code)]
     const N: usize = $N;
@@ -156,8 +229,11 @@ macro_rules! for_simd_types {
     ($($DoubleSizedSimd:ident)?),
     $expr:expr
   )) => {{
+    #[allow(dead_code)]
     type Simd = wide::$Simd;
     #[allow(dead_code)]
+    type Mask = <wide::$Simd as wide::SimdVector>::Mask;
+    #[allow(dead_code)]
     type T = $T;
     #[allow(dead_code)]
     const N: usize = $N;